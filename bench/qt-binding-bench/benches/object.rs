@@ -0,0 +1,46 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use qt_binding::model::StringListModel;
+use qt_binding::object::Object;
+use qt_binding::state::StateMachine;
+use qt_binding::variant::Variant;
+
+fn properties(c: &mut Criterion) {
+    let mut model = StringListModel::new();
+    model.set_object_name("model");
+    let mut handle = Variant::from_object(&model).try_to_object().unwrap();
+    let value = Variant::from(123);
+
+    c.bench_function("object get name", |b| {
+        b.iter(|| black_box(&model).object_name())
+    });
+    c.bench_function("object set name", |b| {
+        b.iter(|| model.set_object_name(black_box("model")))
+    });
+    c.bench_function("object get property", |b| {
+        handle.set_property("value", &value);
+        b.iter(|| black_box(&handle).property("value"))
+    });
+    c.bench_function("object set property", |b| {
+        b.iter(|| handle.set_property("value", black_box(&value)))
+    });
+}
+
+fn signals(c: &mut Criterion) {
+    // Each transition emits stateChanged
+    let mut machine = StateMachine::new("off");
+    machine
+        .add_transition("off", "toggle", "on")
+        .add_transition("on", "toggle", "off");
+    c.bench_function("signal emission on state change", |b| {
+        b.iter(|| machine.trigger(black_box("toggle")))
+    });
+
+    // Each push emits rowsAboutToBeInserted and rowsInserted
+    let mut model = StringListModel::new();
+    c.bench_function("signal emission on row insertion", |b| {
+        b.iter(|| model.push(black_box("item")))
+    });
+}
+
+criterion_group!(benches, properties, signals);
+criterion_main!(benches);
@@ -0,0 +1,65 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use qt_binding::variant::{Variant, VariantValue};
+use std::convert::TryFrom;
+
+fn primitives(c: &mut Criterion) {
+    c.bench_function("variant from i32", |b| {
+        b.iter(|| Variant::from(black_box(123)))
+    });
+    c.bench_function("variant to i32", |b| {
+        let variant = Variant::from(123);
+        b.iter(|| i32::try_from(black_box(&variant)).unwrap())
+    });
+    c.bench_function("variant from f64", |b| {
+        b.iter(|| Variant::from(black_box(1.5)))
+    });
+    c.bench_function("variant clone", |b| {
+        let variant = Variant::from(123);
+        b.iter(|| black_box(&variant).clone())
+    });
+}
+
+fn strings(c: &mut Criterion) {
+    let short = "hello 世界";
+    let long = "a".repeat(1024);
+
+    c.bench_function("variant from short string", |b| {
+        b.iter(|| Variant::from(black_box(short)))
+    });
+    c.bench_function("variant from 1 KiB string", |b| {
+        b.iter(|| Variant::from(black_box(long.as_str())))
+    });
+    c.bench_function("variant to 1 KiB string", |b| {
+        let variant = Variant::from(long.as_str());
+        b.iter(|| String::try_from(black_box(&variant)).unwrap())
+    });
+}
+
+fn lists(c: &mut Criterion) {
+    let items = (0..1000).map(Variant::from).collect::<Vec<_>>();
+    let points = (0..10_000)
+        .map(|i| (f64::from(i), f64::from(i * i)))
+        .collect::<Vec<_>>();
+
+    c.bench_function("variant collect 1000 items", |b| {
+        b.iter(|| black_box(&items).iter().collect::<Variant>())
+    });
+    c.bench_function("variant to list of 1000 items", |b| {
+        let variant = items.iter().collect::<Variant>();
+        b.iter(|| Vec::<Variant>::try_from(black_box(&variant)).unwrap())
+    });
+    c.bench_function("variant from 10000 points", |b| {
+        b.iter(|| Variant::from_points(black_box(&points)))
+    });
+    c.bench_function("variant to 10000 points", |b| {
+        let variant = Variant::from_points(&points);
+        b.iter(|| black_box(&variant).to_points().unwrap())
+    });
+    c.bench_function("variant from value list of 1000 items", |b| {
+        let value = VariantValue::List((0..1000).map(VariantValue::from).collect());
+        b.iter(|| Variant::from(black_box(&value)))
+    });
+}
+
+criterion_group!(benches, primitives, strings, lists);
+criterion_main!(benches);
@@ -0,0 +1,7 @@
+//! Benchmarks for `qt-binding`
+//!
+//! This crate has no code. Benchmarks, in `benches`, measure the cost of the `Variant` and FFI
+//! layer of `qt-binding`, and are run with `cargo bench`.
+//!
+//! Qt is located like for other crates, with `qmake` in the `PATH`, or with the
+//! `QT_INSTALL_DIR` environment variable, to benchmark against a given Qt installation.
@@ -6,7 +6,7 @@ rustup self update
 if [[ "$TRAVIS_OS_NAME" == "linux" ]]
 then
     sudo apt-get update
-    sudo apt-get install qt5-default qtdeclarative5-dev
+    sudo apt-get install qt5-default qtdeclarative5-dev libqt5sql5-sqlite
 fi
 
 if [[ "$TRAVIS_OS_NAME" == "osx" ]]
@@ -5,6 +5,11 @@ set -euxo pipefail
 cargo build --verbose
 cargo test --verbose
 
+pushd qt-binding
+cargo test --verbose --features mock
+cargo test --verbose --features "sql qml"
+popd
+
 cd tests
 
 pushd base
@@ -18,3 +23,11 @@ popd
 pushd widgets
 cargo test --verbose
 popd
+
+pushd sql
+cargo test --verbose
+popd
+
+pushd ../bench
+cargo bench --verbose --no-run
+popd
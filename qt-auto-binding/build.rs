@@ -0,0 +1,23 @@
+use std::env;
+
+/// Variables published by `qt-sys` and `qt-binding`, forwarded to the build scripts of the
+/// crates depending on `qt-auto-binding`
+const FORWARDED: &[(&str, &str)] = &[
+    ("QT", "QT_MAJOR_VERSION"),
+    ("QT", "QT_VERSION"),
+    ("QT", "QT_BIN_DIR"),
+    ("QT", "QT_LIB_DIR"),
+    ("QT", "QT_INCLUDE_DIR"),
+    ("QT", "QT_NAMESPACE"),
+    ("QT", "QT_CONFIG"),
+    ("QT_BINDING", "METADATA_VERSION"),
+    ("QT_BINDING", "VERSION"),
+];
+
+fn main() {
+    for (links, key) in FORWARDED {
+        if let Ok(value) = env::var(format!("DEP_{}_{}", links, key)) {
+            println!("cargo:{}={}", key, value);
+        }
+    }
+}
@@ -0,0 +1,67 @@
+#![warn(missing_docs)]
+
+//! Qt bindings for Rust, in a single crate
+//!
+//! `qt-auto-binding` re-exports the modules of [`qt-binding`], and forwards its features to
+//! `qt-binding`, `qt-sys` and to the Qt libraries that are linked. An application only needs
+//! this crate as a dependency, and [`qt-binding-build`] as a build dependency when it compiles
+//! its own C++ sources or Qt resources:
+//!
+//! ```toml
+//! [dependencies]
+//! qt-auto-binding = {version = "0.1", features = ["gui", "qml"]}
+//!
+//! [build-dependencies]
+//! qt-binding-build = "0.1"
+//! ```
+//!
+//! `qt-auto-binding` forwards the location of Qt and the version of `qt-binding` to the build
+//! script, so that `qt-binding-build` uses the same Qt installation, without depending on
+//! `qt-sys` directly. The features of `qt-binding-build` still select the Qt modules linked by
+//! the build script, and must match the features of `qt-auto-binding`.
+//!
+//! The [`prelude`] re-exports the types used by most applications.
+//!
+//! [`qt-binding`]: ../qt_binding/index.html
+//! [`qt-binding-build`]: ../qt_binding_build/index.html
+//! [`prelude`]: prelude/index.html
+//!
+//! # Features
+//!
+//! Features are the features of `qt-binding`, like `gui`, `widgets` or `qml`. See
+//! [`qt-binding`] for the complete list.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use qt_auto_binding::prelude::*;
+//! use std::process::exit;
+//!
+//! # #[cfg(not(feature = "mock"))]
+//! fn main() {
+//!     let mut app = Application::new();
+//!     let variant = Variant::from("Hello");
+//!     let code = app.exec();
+//!     exit(code);
+//! }
+//! # #[cfg(feature = "mock")]
+//! # fn main() {}
+//! ```
+
+pub use qt_binding::*;
+
+/// Types used by most applications
+///
+/// ```
+/// use qt_auto_binding::prelude::*;
+/// ```
+pub mod prelude {
+    #[cfg(not(feature = "mock"))]
+    pub use qt_binding::app::Application;
+    #[cfg(not(feature = "mock"))]
+    pub use qt_binding::model::StringListModel;
+    pub use qt_binding::object::{Object, ObjectHandle};
+    #[cfg(all(feature = "qml", not(feature = "mock")))]
+    pub use qt_binding::qml::Engine;
+    pub use qt_binding::variant::{Variant, VariantValue};
+}
@@ -0,0 +1,201 @@
+use crate::metadata::METADATA_VERSION;
+use qt_install::QtInstall;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Suffix of the build summaries written in `OUT_DIR`, after the name of the library
+const SUMMARY_SUFFIX: &str = "qt-binding-build.json";
+
+/// What a build produced
+///
+/// `BuildArtifacts` is returned by [`Builder::build`]. It describes the static library that was
+/// built, the files that were generated by Qt tools, the Qt modules that were linked and the
+/// Qt installation that was used.
+///
+/// The same information is written as JSON in `OUT_DIR`, in a file named
+/// `<name>.qt-binding-build.json`, for tools that cannot call into the build script. Build
+/// scripts building several libraries get a summary for each library.
+///
+/// [`Builder::build`]: struct.Builder.html#method.build
+#[derive(Clone, Debug)]
+pub struct BuildArtifacts {
+    lib_name: String,
+    out_dir: PathBuf,
+    generated_files: Vec<PathBuf>,
+    modules: Vec<&'static str>,
+    qt_install: QtInstall,
+}
+
+impl BuildArtifacts {
+    pub(crate) fn new(
+        lib_name: &str,
+        out_dir: &Path,
+        generated_files: Vec<PathBuf>,
+        modules: Vec<&'static str>,
+        qt_install: QtInstall,
+    ) -> Self {
+        BuildArtifacts {
+            lib_name: lib_name.to_string(),
+            out_dir: out_dir.to_path_buf(),
+            generated_files,
+            modules,
+            qt_install,
+        }
+    }
+
+    /// Name of the static library
+    pub fn lib_name(&self) -> &str {
+        &self.lib_name
+    }
+
+    /// Directory containing the static library and generated files
+    pub fn out_dir(&self) -> &Path {
+        &self.out_dir
+    }
+
+    /// Files generated by `moc`, `rcc` and `qsb`
+    pub fn generated_files(&self) -> &[PathBuf] {
+        &self.generated_files
+    }
+
+    /// Qt modules linked against, like `Core` or `Gui`
+    pub fn modules(&self) -> &[&'static str] {
+        &self.modules
+    }
+
+    /// Qt installation used for the build
+    pub fn qt_install(&self) -> &QtInstall {
+        &self.qt_install
+    }
+
+    /// Path to the JSON build summary
+    pub fn summary_file(&self) -> PathBuf {
+        self.out_dir
+            .join(format!("{}.{}", self.lib_name, SUMMARY_SUFFIX))
+    }
+
+    pub(crate) fn write_summary(&self) {
+        let path = self.summary_file();
+        fs::write(&path, self.to_json())
+            .unwrap_or_else(|err| panic!("Failed to write {}: {}", path.display(), err));
+    }
+
+    fn to_json(&self) -> String {
+        let generated_files = self
+            .generated_files
+            .iter()
+            .map(|path| json_path(path))
+            .collect::<Vec<_>>();
+        let modules = self
+            .modules
+            .iter()
+            .map(|module| json_string(module))
+            .collect::<Vec<_>>();
+        let qt_install = &self.qt_install;
+        let config = qt_install
+            .config()
+            .iter()
+            .map(|config| json_string(config))
+            .collect::<Vec<_>>();
+
+        format!(
+            "{{\n  \"metadata_version\": {},\n  \"lib_name\": {},\n  \"out_dir\": {},\n  \
+             \"generated_files\": [{}],\n  \
+             \"modules\": [{}],\n  \"qt_install\": {{\n    \"major_version\": {},\n    \
+             \"version\": {},\n    \"bin_dir\": {},\n    \"lib_dir\": {},\n    \
+             \"include_dir\": {},\n    \"namespace\": {},\n    \"config\": [{}]\n  }}\n}}\n",
+            METADATA_VERSION,
+            json_string(&self.lib_name),
+            json_path(&self.out_dir),
+            generated_files.join(", "),
+            modules.join(", "),
+            json_string(&qt_install.major_version().to_string()),
+            json_string(qt_install.version()),
+            json_path(qt_install.bin_dir()),
+            json_path(qt_install.lib_dir()),
+            json_path(qt_install.include_dir()),
+            qt_install
+                .namespace()
+                .map_or("null".to_string(), json_string),
+            config.join(", "),
+        )
+    }
+}
+
+fn json_path(path: &Path) -> String {
+    json_string(&path.to_string_lossy())
+}
+
+fn json_string(value: &str) -> String {
+    let mut output = String::with_capacity(value.len() + 2);
+    output.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => output.push_str("\\\""),
+            '\\' => output.push_str("\\\\"),
+            '\n' => output.push_str("\\n"),
+            '\r' => output.push_str("\\r"),
+            '\t' => output.push_str("\\t"),
+            c if (c as u32) < 0x20 => output.push_str(&format!("\\u{:04x}", c as u32)),
+            c => output.push(c),
+        }
+    }
+    output.push('"');
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use qt_install::MajorVersion;
+
+    #[test]
+    fn test_json_string() {
+        assert_eq!(json_string("abc"), "\"abc\"");
+        assert_eq!(json_string("c:\\qt\\bin"), "\"c:\\\\qt\\\\bin\"");
+        assert_eq!(json_string("a \"b\"\n"), "\"a \\\"b\\\"\\n\"");
+        assert_eq!(json_string("\u{1}"), "\"\\u0001\"");
+    }
+
+    #[test]
+    fn test_to_json() {
+        let qt_install = QtInstall::new(
+            MajorVersion::Qt5,
+            "5.11.1".to_string(),
+            PathBuf::from("/qt/bin"),
+            PathBuf::from("/qt/lib"),
+            PathBuf::from("/qt/include"),
+        )
+        .with_config(vec!["shared".to_string(), "release".to_string()]);
+        let artifacts = BuildArtifacts::new(
+            "mylib",
+            Path::new("/out"),
+            vec![PathBuf::from("/out/moc_object.cpp")],
+            vec!["Core", "Gui"],
+            qt_install,
+        );
+
+        let expected = r#"{
+  "metadata_version": 1,
+  "lib_name": "mylib",
+  "out_dir": "/out",
+  "generated_files": ["/out/moc_object.cpp"],
+  "modules": ["Core", "Gui"],
+  "qt_install": {
+    "major_version": "Qt5",
+    "version": "5.11.1",
+    "bin_dir": "/qt/bin",
+    "lib_dir": "/qt/lib",
+    "include_dir": "/qt/include",
+    "namespace": null,
+    "config": ["shared", "release"]
+  }
+}
+"#;
+        assert_eq!(artifacts.to_json(), expected);
+        assert_eq!(
+            artifacts.summary_file(),
+            Path::new("/out/mylib.qt-binding-build.json")
+        );
+    }
+}
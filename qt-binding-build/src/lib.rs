@@ -35,21 +35,117 @@
 //! finding `qmake` automatically. You must use the `QT_INSTALL_DIR` environment variable described
 //! below.
 //!
+//! Both MSVC and MinGW builds of Qt are supported under Windows. The Rust target must use the
+//! same toolchain as Qt: `windows-msvc` targets for MSVC builds, and `windows-gnu` targets for
+//! MinGW builds. When using MinGW, the MinGW compiler shipped with Qt should be in `PATH`, and
+//! the Windows libraries needed by Qt, like `ole32` or `gdi32`, are linked automatically.
+//!
 //! # Overriding Qt location
 //!
 //! You can override Qt location with `QT_INSTALL_DIR` environment variable. If this variable is
 //! present, this function will *only* search `qmake` in `${QT_INSTALL_DIR}/bin`.
 //!
+//! # Using Qt located by CMake
+//!
+//! In mixed CMake and Cargo builds, for example with corrosion, CMake already located Qt, and
+//! `qmake` might select another installation. When `QT_BINDING_FROM_CMAKE` is set, `qmake` is
+//! not used, and Qt is described by environment variables set by the CMake build:
+//!
+//! - `QT_BINDING_QT_VERSION`, like `5.15.2`,
+//! - `QT_BINDING_QT_BIN_DIR`, containing `moc` and `rcc`,
+//! - `QT_BINDING_QT_LIB_DIR`, containing Qt libraries,
+//! - `QT_BINDING_QT_INCLUDE_DIR`, containing `QtCore` and the headers of the other modules,
+//! - `QT_BINDING_QT_HOST_DATA_DIR`, containing `mkspecs`, optional, to detect namespaced Qt.
+//!
+//! The layout of the provided directories is validated like for Qt located by `qmake`.
+//!
+//! # Running Qt tools
+//!
+//! `Builder` runs `moc` and `rcc`, and `qsb` to compile shaders added with
+//! [`Builder::shader_file`]. Other tools of the same Qt installation, like `uic` or
+//! `lrelease`, are run with [`QtInstall::tool`], from the installation returned by
+//! [`Builder::qt_install`].
+//!
+//! [`Builder::shader_file`]: struct.Builder.html#method.shader_file
+//! [`QtInstall::tool`]: struct.QtInstall.html#method.tool
+//! [`Builder::qt_install`]: struct.Builder.html#method.qt_install
+//!
+//! # Namespaced Qt
+//!
+//! Qt can be built with `-qtnamespace`, in which case all Qt classes are declared inside a C++
+//! namespace. Qt headers then define `QT_NAMESPACE`, and sources should use
+//! `QT_USE_NAMESPACE` after including Qt headers, or `QT_BEGIN_NAMESPACE` and
+//! `QT_END_NAMESPACE` around forward declarations of Qt classes. These macros expand to nothing
+//! for Qt builds without namespace.
+//!
 //! # Features
 //!
 //! By default `qt-binding-build` will only link against `QtCore`. To link against additional
 //! modules, you need to use features:
 //!
+//! - `bluetooth` enables linking against `QtBluetooth`
+//! - `dbus` enables linking against `QtDBus`
 //! - `gui` enables linking against `QtGui`
+//! - `multimedia` enables linking against `QtMultimedia`
+//! - `network` enables linking against `QtNetwork`
+//! - `pdf` enables linking against `QtPdf`
+//! - `positioning` enables linking against `QtPositioning`
+//! - `printsupport` enables linking against `QtPrintSupport` and `QtWidgets`
 //! - `qml` enables linking against `QtQml`
 //! - `quick` enables linking against `QtQuick`
+//! - `quick-test` enables linking against `QtQuickTest`
+//! - `serialport` enables linking against `QtSerialPort`
+//! - `sql` enables linking against `QtSql`
+//! - `websockets` enables linking against `QtWebSockets`
 //! - `widgets` enables linking against `QtWidgets`
 //!
+//! Modules enabled with features are required. Modules that might be missing from minimal Qt
+//! installations can be declared with [`Builder::optional_module`] instead, to build with
+//! reduced functionality when they are missing.
+//!
+//! [`Builder::optional_module`]: struct.Builder.html#method.optional_module
+//!
+//! # Parallel compilation
+//!
+//! Source files are compiled in parallel. Jobs are taken from Cargo's jobserver, so that the
+//! C++ compilation shares the jobs given to Cargo, with `cargo build -j`, `CARGO_BUILD_JOBS` or
+//! the `build.jobs` configuration, with the Rust compilation of other crates, without
+//! oversubscribing the machine. When Cargo is run by `make`, Cargo forwards the jobserver that
+//! `make` passes in `MAKEFLAGS`, so jobs are also shared with the rest of the `make` build.
+//!
+//! The jobserver is never replaced: [`Builder::jobs`], or `CARGO_BUILD_JOBS` or `NUM_JOBS` when
+//! they are set in the environment of the build script, only cap how many files are compiled at
+//! once, and each file still waits for a job from the jobserver. Cargo sets `NUM_JOBS` from
+//! `cargo build -j` or the `build.jobs` configuration. The environment of the build script,
+//! including `MAKEFLAGS` and `CARGO_MAKEFLAGS`, is left untouched.
+//!
+//! [`Builder::jobs`]: struct.Builder.html#method.jobs
+//!
+//! # Compatibility with `qt-binding`
+//!
+//! `qt-binding` and `qt-binding-build` evolve together. `qt-binding` publishes the
+//! [`METADATA_VERSION`] it was built with to the build scripts of the crates depending on it,
+//! and [`Builder::build`] fails with an error telling which crate to upgrade when the versions
+//! don't match. The version is also written in the build summary.
+//!
+//! [`METADATA_VERSION`]: constant.METADATA_VERSION.html
+//! [`Builder::build`]: struct.Builder.html#method.build
+//!
+//! # macOS bundles
+//!
+//! [`MacBundle`] generates the `Info.plist` of an application bundle, embedded in the
+//! executables by [`Builder::mac_bundle`], and assembles and deploys the `.app` bundle, with
+//! the Qt frameworks and QML imports, after `cargo build`.
+//!
+//! [`MacBundle`]: struct.MacBundle.html
+//! [`Builder::mac_bundle`]: struct.Builder.html#method.mac_bundle
+//!
+//! # Scaffolding
+//!
+//! The [`scaffold`] module generates the files of a minimal Rust and QML application, for
+//! project generators and `xtask`s.
+//!
+//! [`scaffold`]: scaffold/index.html
 //!
 //! # Examples
 //!
@@ -64,14 +160,34 @@
 //!     .build("mylib");
 //! ```
 
+mod artifacts;
+mod macos;
+mod metadata;
+mod pch;
+mod resources;
+pub mod scaffold;
+mod shaders;
 mod tool;
+mod unity;
+mod windows;
 
+pub use self::artifacts::BuildArtifacts;
+pub use self::macos::MacBundle;
+pub use self::metadata::METADATA_VERSION;
+use self::pch::PrecompiledHeader;
+pub use self::shaders::ShaderTargets;
 use self::tool::Tool;
+pub use self::windows::{DpiAwareness, WindowsResource};
 use cc::Build;
-use qt_install::{lib_name, MajorVersion, QtInstall};
+use qt_install::{lib_name, MajorVersion};
+pub use qt_install::{QtInstall, QtTool, ToolError, ToolOutput};
 use std::collections::HashMap;
 use std::env;
 use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
 
 /// Provides the build directory used for build scripts
 ///
@@ -88,6 +204,81 @@ pub fn build_dir() -> PathBuf {
     PathBuf::from(&build_dir)
 }
 
+/// Read a variable published by the build script of a dependency, like `qt-sys`
+///
+/// Variables of `qt-sys` and `qt-binding` are also forwarded by `qt-auto-binding`, for crates
+/// that only depend on it.
+pub(crate) fn dep_var(links: &str, key: &str) -> Option<String> {
+    env::var(format!("DEP_{}_{}", links, key))
+        .or_else(|_| env::var(format!("DEP_QT_AUTO_BINDING_{}", key)))
+        .ok()
+}
+
+/// Read the number of jobs given to Cargo
+///
+/// `CARGO_BUILD_JOBS` is read first, then `NUM_JOBS`, that Cargo sets for build scripts from
+/// `cargo build -j` or the `build.jobs` configuration. Values that are not a positive number of
+/// jobs, like `default`, are ignored.
+fn cargo_build_jobs() -> Option<usize> {
+    ["CARGO_BUILD_JOBS", "NUM_JOBS"]
+        .iter()
+        .filter_map(|key| env::var(key).ok())
+        .filter_map(|jobs| jobs.parse().ok())
+        .find(|jobs| *jobs > 0)
+}
+
+/// Check if Cargo knows the `rustc-check-cfg` instruction
+///
+/// `rustc-check-cfg` is stable since Cargo 1.80. The version of Cargo is read by running
+/// `CARGO`, that Cargo sets for build scripts, and older or unknown versions are assumed not to
+/// support it.
+fn cargo_supports_check_cfg() -> bool {
+    env::var_os("CARGO")
+        .and_then(|cargo| Command::new(cargo).arg("--version").output().ok())
+        .and_then(|output| String::from_utf8(output.stdout).ok())
+        .and_then(|version| cargo_version(&version))
+        .is_some_and(|version| version >= (1, 80))
+}
+
+/// Parse the major and minor versions from the output of `cargo --version`
+///
+/// The output looks like `cargo 1.80.0 (376290515 2024-07-16)`.
+fn cargo_version(output: &str) -> Option<(u32, u32)> {
+    let mut parts = output.split_whitespace().nth(1)?.split('.');
+    let major = parts.next()?.parse().ok()?;
+    let minor = parts.next()?.parse().ok()?;
+    Some((major, minor))
+}
+
+/// Compile `files` into objects, compiling at most `jobs` files at once
+///
+/// Each worker starts compiling the next file as soon as its previous file is compiled, so a
+/// slow file does not hold back the others. Each file still takes a job from the jobserver.
+/// Objects are returned in the order of `files`.
+fn compile_objects(builder: &Build, files: &[&PathBuf], jobs: usize) -> Vec<PathBuf> {
+    let next = AtomicUsize::new(0);
+    let objects = Mutex::new(vec![Vec::new(); files.len()]);
+    thread::scope(|scope| {
+        for _ in 0..jobs.min(files.len()) {
+            scope.spawn(|| loop {
+                let index = next.fetch_add(1, Ordering::Relaxed);
+                let file = match files.get(index) {
+                    Some(file) => file,
+                    None => break,
+                };
+                let compiled = builder.clone().file(file).compile_intermediates();
+                objects.lock().unwrap()[index] = compiled;
+            });
+        }
+    });
+    objects
+        .into_inner()
+        .unwrap()
+        .into_iter()
+        .flatten()
+        .collect()
+}
+
 trait ReadMajorVersion {
     fn from_str(version: &str) -> MajorVersion;
 }
@@ -109,7 +300,21 @@ pub struct Builder {
     files: Vec<PathBuf>,
     moc_files: Vec<PathBuf>,
     res_files: Vec<PathBuf>,
+    res_dirs: Vec<(PathBuf, String)>,
+    shader_files: Vec<PathBuf>,
+    shader_targets: ShaderTargets,
+    windows_resource_files: Vec<PathBuf>,
+    windows_resource: Option<WindowsResource>,
+    mac_bundle: Option<MacBundle>,
     definitions: HashMap<&'static str, Option<&'static str>>,
+    debug: Option<bool>,
+    split_debug_info: bool,
+    hidden_visibility: bool,
+    precompiled_header: bool,
+    unity_batch_size: Option<usize>,
+    jobs: Option<usize>,
+    private_header_modules: Vec<&'static str>,
+    optional_modules: Vec<&'static str>,
 }
 
 impl Builder {
@@ -149,23 +354,72 @@ impl Builder {
         let lib_dir = Builder::sys_qt_install_info("QT_LIB_DIR");
         let include_dir = Builder::sys_qt_install_info("QT_INCLUDE_DIR");
 
+        let namespace = dep_var("QT", "QT_NAMESPACE");
+        let config = dep_var("QT", "QT_CONFIG")
+            .map(|config| config.split_whitespace().map(ToString::to_string).collect())
+            .unwrap_or_default();
+
         let qt_install = QtInstall::new(
             MajorVersion::from_str(&major_version),
             version,
             PathBuf::from(bin_dir),
             PathBuf::from(lib_dir),
             PathBuf::from(include_dir),
-        );
+        )
+        .with_namespace(namespace)
+        .with_config(config);
 
         Builder {
             qt_install,
             files: Vec::new(),
             moc_files: Vec::new(),
             res_files: Vec::new(),
+            res_dirs: Vec::new(),
+            shader_files: Vec::new(),
+            shader_targets: ShaderTargets::default(),
+            windows_resource_files: Vec::new(),
+            windows_resource: None,
+            mac_bundle: None,
             definitions: HashMap::new(),
+            debug: None,
+            split_debug_info: false,
+            hidden_visibility: false,
+            precompiled_header: false,
+            unity_batch_size: None,
+            jobs: None,
+            private_header_modules: Vec::new(),
+            optional_modules: Vec::new(),
         }
     }
 
+    /// Qt installation used for the build
+    ///
+    /// Build scripts use it to run other Qt tools, with [`QtInstall::tool`].
+    ///
+    /// [`QtInstall::tool`]: struct.QtInstall.html#method.tool
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use qt_binding_build::{build_dir, Builder};
+    ///
+    /// let mut builder = Builder::new();
+    /// let qm = build_dir().join("app_fr.qm");
+    /// builder
+    ///     .qt_install()
+    ///     .tool("lrelease")
+    ///     .unwrap()
+    ///     .arg("translations/app_fr.ts")
+    ///     .arg("-qm")
+    ///     .arg(&qm)
+    ///     .run()
+    ///     .unwrap();
+    /// builder.file("src/app.cpp").build("app");
+    /// ```
+    pub fn qt_install(&self) -> &QtInstall {
+        &self.qt_install
+    }
+
     /// Add a definition (`-D` compiler flag)
     ///
     /// # Examples
@@ -187,6 +441,228 @@ impl Builder {
         self
     }
 
+    /// Generate debug information
+    ///
+    /// By default, debug information is generated when cargo's profile has debug information
+    /// enabled, which is the case for the `dev` profile.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use qt_binding_build::Builder;
+    ///
+    /// Builder::new()
+    ///     .file("first.cpp")
+    ///     .debug(true);
+    ///
+    /// // first.cpp will be compiled with `-g` or `/Z7`, even in release
+    /// ```
+    pub fn debug(&mut self, debug: bool) -> &mut Self {
+        self.debug = Some(debug);
+        self
+    }
+
+    /// Write debug information in separate files
+    ///
+    /// When debug information is generated, write it in separate files in `OUT_DIR`, instead of
+    /// in object files.
+    ///
+    /// With MSVC, source files are compiled with `/Zi` instead of `/Z7`, and debug information
+    /// is written in a PDB named after the library. The objects record the path of this PDB.
+    /// When the final binary is linked with `/DEBUG`, which rustc does when the profile has
+    /// debug information, the linker reads this PDB and merges it into the PDB of the binary,
+    /// written next to the binary, where debuggers look for it.
+    ///
+    /// With other compilers, `.dwo` files are written next to the object files with
+    /// `-gsplit-dwarf`. Debuggers locate them with the absolute path recorded in the linked
+    /// binary, so they must be kept in `OUT_DIR`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use qt_binding_build::Builder;
+    ///
+    /// Builder::new()
+    ///     .file("first.cpp")
+    ///     .split_debug_info(true)
+    ///     .build("mylib");
+    ///
+    /// // With MSVC, debug information is written in `mylib.pdb`
+    /// ```
+    pub fn split_debug_info(&mut self, split_debug_info: bool) -> &mut Self {
+        self.split_debug_info = split_debug_info;
+        self
+    }
+
+    /// Compile with hidden symbol visibility
+    ///
+    /// Disabled by default. When enabled, functions of the static library, like the `extern "C"`
+    /// functions called from Rust, are only visible to the binary they are linked in, and are not
+    /// exported from shared libraries, like `cdylib` crates. This keeps the dynamic symbol table
+    /// small, and lets the linker remove or merge unused code.
+    ///
+    /// Symbols are hidden with `-fvisibility=hidden` and `-fvisibility-inlines-hidden` with GCC
+    /// and Clang. MSVC does not export symbols by default, and is not affected.
+    ///
+    /// Only enable it when the compiled C++ code is not called from other shared libraries, for
+    /// example when its symbols are not loaded with `dlsym`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use qt_binding_build::Builder;
+    ///
+    /// Builder::new()
+    ///     .file("bindings.cpp")
+    ///     .hidden_visibility(true)
+    ///     .build("mylib");
+    /// ```
+    pub fn hidden_visibility(&mut self, hidden_visibility: bool) -> &mut Self {
+        self.hidden_visibility = hidden_visibility;
+        self
+    }
+
+    /// Use a precompiled header for Qt headers
+    ///
+    /// When enabled, a header including the headers of all the linked Qt modules, like
+    /// `QtCore/QtCore`, is generated and precompiled, and force-included in every compiled
+    /// file. This usually speeds up builds with many source files including Qt headers
+    /// significantly, at the cost of compiling the precompiled header first.
+    ///
+    /// GCC, Clang and MSVC are supported.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use qt_binding_build::Builder;
+    ///
+    /// Builder::new()
+    ///     .files(&["first.cpp", "second.cpp"])
+    ///     .precompiled_header(true)
+    ///     .build("mylib");
+    /// ```
+    pub fn precompiled_header(&mut self, precompiled_header: bool) -> &mut Self {
+        self.precompiled_header = precompiled_header;
+        self
+    }
+
+    /// Compile source files in unity build batches
+    ///
+    /// When enabled, source files and files generated by `moc` are grouped in batches of
+    /// `batch_size` files, and each batch is compiled as a single file including all the files
+    /// of the batch. This reduces the number of compiler invocations for projects with many
+    /// source files. Files generated by `rcc` are always compiled separately.
+    ///
+    /// Files in the same batch share the same translation unit, so they must not define
+    /// conflicting symbols with internal linkage, like `static` functions with the same name.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use qt_binding_build::Builder;
+    ///
+    /// Builder::new()
+    ///     .files(&["first.cpp", "second.cpp", "third.cpp"])
+    ///     .unity_build(2)
+    ///     .build("mylib");
+    ///
+    /// // first.cpp and second.cpp are compiled together, then third.cpp
+    /// ```
+    pub fn unity_build(&mut self, batch_size: usize) -> &mut Self {
+        self.unity_batch_size = Some(batch_size);
+        self
+    }
+
+    /// Set the number of source files compiled at once
+    ///
+    /// By default, source files are compiled in parallel, using the jobs of Cargo's jobserver,
+    /// and at most `CARGO_BUILD_JOBS` or `NUM_JOBS` files at once when they are set. With `jobs`,
+    /// at most `jobs` files are compiled at once. Each file still takes a job from the jobserver
+    /// inherited through `CARGO_MAKEFLAGS` or `MAKEFLAGS`, so `jobs` can lower the parallelism,
+    /// but never raise it above what Cargo or `make` allows. See the crate level documentation
+    /// for more information. `jobs(1)` compiles files one by one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `jobs` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use qt_binding_build::Builder;
+    ///
+    /// Builder::new()
+    ///     .files(&["first.cpp", "second.cpp"])
+    ///     .jobs(2)
+    ///     .build("mylib");
+    /// ```
+    pub fn jobs(&mut self, jobs: usize) -> &mut Self {
+        assert!(jobs > 0, "Cannot compile with 0 jobs");
+        self.jobs = Some(jobs);
+        self
+    }
+
+    /// Make the private headers of a Qt module available
+    ///
+    /// Private headers, like the `qpa` headers of `QtGui`, are not part of Qt's public API, and
+    /// may change between Qt versions. They are installed in a directory named after the Qt
+    /// version, that is added to the include paths, so that they can be included with paths
+    /// like `<qpa/qplatformnativeinterface.h>` or `<private/qobject_p.h>`.
+    ///
+    /// Modules are named like features, without the `Qt` prefix, like `Gui`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use qt_binding_build::Builder;
+    ///
+    /// Builder::new()
+    ///     .file("window.cpp")
+    ///     .private_headers("Gui")
+    ///     .build("mylib");
+    /// ```
+    pub fn private_headers(&mut self, module: &'static str) -> &mut Self {
+        self.private_header_modules.push(module);
+        self
+    }
+
+    /// Link against a Qt module if it is available
+    ///
+    /// Modules enabled with features are required, and the build fails if they are missing.
+    /// Optional modules are probed instead: when the module is part of the Qt installation,
+    /// it is linked, the `QT_BINDING_HAS_<MODULE>` definition is set for the compiled sources,
+    /// like `QT_BINDING_HAS_QUICK`, and the `qt_module` configuration option is set to the name
+    /// of the module for the crate. Otherwise a warning is printed, and the crate is built
+    /// without it.
+    ///
+    /// With Cargo 1.80 or later, `qt_module` is also declared with `rustc-check-cfg`, so that
+    /// `#[cfg(qt_module = "...")]` does not trigger the `unexpected_cfgs` lint. Older Cargo
+    /// versions do not know this instruction, and it is not emitted for them.
+    ///
+    /// Modules are named like features, without the `Qt` prefix, like `Quick`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use qt_binding_build::Builder;
+    ///
+    /// Builder::new()
+    ///     .file("source.cpp")
+    ///     .optional_module("Quick")
+    ///     .build("mylib");
+    /// ```
+    ///
+    /// With, in the crate
+    ///
+    /// ```ignore
+    /// #[cfg(qt_module = "Quick")]
+    /// mod quick;
+    /// ```
+    pub fn optional_module(&mut self, module: &'static str) -> &mut Self {
+        self.optional_modules.push(module);
+        self
+    }
+
     /// Add a source file to be compiled
     ///
     /// Adds a single file to the list of files to be compiled.
@@ -295,6 +771,18 @@ impl Builder {
     /// Generated files will automatically be included in the list of source files
     /// to be compiled.
     ///
+    /// Resources compiled in a static library are not registered automatically. When resource
+    /// files are added, [`build`] also generates `<name>_resources.rs` in `OUT_DIR`, that
+    /// defines an `init_resources` function registering all the resources of the library. It
+    /// should be included in the crate, and called before using resources.
+    ///
+    /// When the library has a single resource file, it is named after the library, and can
+    /// also be registered from C++ with `Q_INIT_RESOURCE(<name>)`. When it has several, each
+    /// resource file is named `<name>_<stem>`, and they should be registered with
+    /// `init_resources`.
+    ///
+    /// [`build`]: #method.build
+    ///
     /// # Examples
     ///
     /// ```no_run
@@ -306,6 +794,17 @@ impl Builder {
     ///
     /// // builder now contains ["rcc_res.cpp", "source.cpp"]
     /// ```
+    ///
+    /// Registering the resources in the crate
+    ///
+    /// ```ignore
+    /// include!(concat!(env!("OUT_DIR"), "/mylib_resources.rs"));
+    ///
+    /// fn main() {
+    ///     init_resources();
+    ///     // Resources are now available with qrc:/ paths
+    /// }
+    /// ```
     pub fn res_file<P>(&mut self, path: P) -> &mut Self
     where
         P: AsRef<Path>,
@@ -343,6 +842,168 @@ impl Builder {
         self
     }
 
+    /// Add a directory of resources
+    ///
+    /// All the files in the directory, and in its sub-directories, are embedded as Qt
+    /// resources, under `prefix`, with their path relative to the directory. A resource file
+    /// listing them is generated in `OUT_DIR`, and handled like resource files added with
+    /// [`res_file`]. The build script is rerun when files are added, removed or modified.
+    ///
+    /// Sub-directories used by Qt's file selectors, like `+android` or `+tablet`, are kept, so
+    /// that platform specific variants of QML files are selected at runtime.
+    ///
+    /// [`res_file`]: #method.res_file
+    ///
+    /// # Panics
+    ///
+    /// [`build`] will panic if the directory can't be read.
+    ///
+    /// [`build`]: #method.build
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use qt_binding_build::Builder;
+    ///
+    /// Builder::new()
+    ///     .res_dir("qml", "/qml")
+    ///     .build("mylib");
+    ///
+    /// // qml/main.qml is available as qrc:/qml/main.qml
+    /// ```
+    pub fn res_dir<P, S>(&mut self, dir: P, prefix: S) -> &mut Self
+    where
+        P: AsRef<Path>,
+        S: Into<String>,
+    {
+        self.res_dirs
+            .push((dir.as_ref().to_path_buf(), prefix.into()));
+        self
+    }
+
+    /// Add a shader
+    ///
+    /// Shaders, like `.vert` and `.frag` files, are compiled with `qsb` into shader packs, as
+    /// required by custom materials and shader effects of Qt Quick and Qt Quick 3D. Shader
+    /// packs are embedded as Qt resources, under `/shaders`, with the name of the shader
+    /// followed by `.qsb`. The build script is rerun when shaders are modified.
+    ///
+    /// Shaders are compiled for the targets set with [`shader_targets`].
+    ///
+    /// [`shader_targets`]: #method.shader_targets
+    ///
+    /// # Panics
+    ///
+    /// [`build`] will panic if `qsb` is not part of the Qt installation. `qsb` is provided by
+    /// the Qt Shader Tools module, from Qt 5.15.
+    ///
+    /// [`build`]: #method.build
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use qt_binding_build::Builder;
+    ///
+    /// Builder::new()
+    ///     .shader_file("shaders/wobble.vert")
+    ///     .shader_file("shaders/wobble.frag")
+    ///     .build("mylib");
+    ///
+    /// // shaders/wobble.frag is available as qrc:/shaders/wobble.frag.qsb
+    /// ```
+    pub fn shader_file<P>(&mut self, path: P) -> &mut Self
+    where
+        P: AsRef<Path>,
+    {
+        self.shader_files.push(path.as_ref().to_path_buf());
+        self
+    }
+
+    /// Set shaders
+    ///
+    /// See [`shader_file`] for more information.
+    ///
+    /// [`shader_file`]: #method.shader_file
+    pub fn shader_files<P>(&mut self, paths: P) -> &mut Self
+    where
+        P: IntoIterator,
+        P::Item: AsRef<Path>,
+    {
+        self.shader_files = paths
+            .into_iter()
+            .map(|path| path.as_ref().to_path_buf())
+            .collect();
+        self
+    }
+
+    /// Set the shading languages that shaders are compiled to
+    ///
+    /// See [`ShaderTargets`] for the default targets.
+    ///
+    /// [`ShaderTargets`]: struct.ShaderTargets.html
+    pub fn shader_targets(&mut self, targets: ShaderTargets) -> &mut Self {
+        self.shader_targets = targets;
+        self
+    }
+
+    /// Add a Windows resource script
+    ///
+    /// Resource scripts (`.rc` files) embed icons, manifests or version information in Windows
+    /// executables. They are compiled with `rc.exe` for MSVC targets, and with `windres` for
+    /// GNU targets, and linked in the executables of the crate whose build script calls
+    /// [`build`]. `windres` can be overridden with the `WINDRES` environment variable.
+    ///
+    /// Resource scripts are ignored when the target is not Windows.
+    ///
+    /// [`build`]: #method.build
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use qt_binding_build::Builder;
+    ///
+    /// Builder::new()
+    ///     .file("src/app.cpp")
+    ///     .windows_resource_file("assets/app.rc")
+    ///     .build("app");
+    /// ```
+    pub fn windows_resource_file<P>(&mut self, path: P) -> &mut Self
+    where
+        P: AsRef<Path>,
+    {
+        self.windows_resource_files
+            .push(path.as_ref().to_path_buf());
+        self
+    }
+
+    /// Generate a Windows resource script, with an icon and an application manifest
+    ///
+    /// The resource script is generated in `OUT_DIR`, and compiled like resource scripts added
+    /// with [`windows_resource_file`]. See [`WindowsResource`] for more information.
+    ///
+    /// [`windows_resource_file`]: #method.windows_resource_file
+    /// [`WindowsResource`]: struct.WindowsResource.html
+    pub fn windows_resource(&mut self, resource: WindowsResource) -> &mut Self {
+        self.windows_resource = Some(resource);
+        self
+    }
+
+    /// Embed the `Info.plist` of a macOS application bundle in the executables
+    ///
+    /// The `Info.plist` is generated in `OUT_DIR`, and embedded in the `__info_plist` section of
+    /// the executables of the crate whose build script calls [`build`], giving them a bundle
+    /// identifier even when they are not run from a bundle. See [`MacBundle`] for assembling
+    /// and deploying the bundle itself.
+    ///
+    /// The bundle is ignored when the target is not macOS.
+    ///
+    /// [`build`]: #method.build
+    /// [`MacBundle`]: struct.MacBundle.html
+    pub fn mac_bundle(&mut self, bundle: MacBundle) -> &mut Self {
+        self.mac_bundle = Some(bundle);
+        self
+    }
+
     /// Build a project
     ///
     /// The project will be built as a static library with the supplied name.
@@ -350,11 +1011,27 @@ impl Builder {
     /// The built library and it's Qt dependencies will automatically be linked to the Rust library
     /// or executable that is being built.
     ///
+    /// The version and library directory of the Qt installation used for the build are available
+    /// to the compiled sources as the `QT_BINDING_QT_VERSION` and `QT_BINDING_QT_LIB_DIR` string
+    /// definitions.
+    ///
+    /// A [`BuildArtifacts`] describing what was built is returned. The same information is
+    /// written in `<name>.qt-binding-build.json`, in `OUT_DIR`.
+    ///
+    /// [`BuildArtifacts`]: struct.BuildArtifacts.html
+    ///
     /// # Panics
     ///
     /// This method will panic with a user-friendly error message when not being able to run `moc`
     /// or not being able to build the supplied source files.
     ///
+    /// It will also panic when the crate being built depends on a `qt-binding` that was built
+    /// with an incompatible version of `qt-binding-build`, see [`METADATA_VERSION`], and when
+    /// `moc` or `rcc` report a Qt version whose major or minor version differs from the Qt
+    /// installation, as the generated code would not match the headers and libraries.
+    ///
+    /// [`METADATA_VERSION`]: constant.METADATA_VERSION.html
+    ///
     /// # Examples
     ///
     /// The example below is an example of build script to build some C++ source files using Qt.
@@ -369,28 +1046,89 @@ impl Builder {
     ///         .build("mylib");
     /// }
     /// ```
-    pub fn build(&self, name: &str) {
+    pub fn build(&self, name: &str) -> BuildArtifacts {
+        metadata::check();
         let out_dir = build_dir();
 
         let moc = Tool::moc(self.qt_install.moc());
         let moc_files = &self.moc_files;
+        if !moc_files.is_empty() {
+            tool::check_version("moc", self.qt_install.moc(), self.qt_install.version());
+        }
         let moc_outputs = moc_files
             .iter()
             .map(|input| out_dir.join(moc.exec(&out_dir, input)))
             .collect::<Vec<_>>();
 
-        let rcc = Tool::rcc(self.qt_install.rcc(), name);
-        let res_files = &self.res_files;
-        let res_outputs = res_files
+        let mut shader_outputs = Vec::new();
+        if !self.shader_files.is_empty() {
+            let qsb = self
+                .qt_install
+                .tool("qsb")
+                .unwrap_or_else(|err| panic!("{}", err));
+            let qsb = Tool::qsb(qsb.path(), self.shader_targets.args());
+            for input in &self.shader_files {
+                println!("cargo:rerun-if-changed={}", input.display());
+                shader_outputs.push(qsb.exec(&out_dir, input));
+            }
+        }
+        let shader_res_file = if shader_outputs.is_empty() {
+            None
+        } else {
+            Some(resources::write_shader_resource(
+                &out_dir,
+                name,
+                &shader_outputs,
+                "/shaders",
+            ))
+        };
+
+        let res_dir_files = self
+            .res_dirs
+            .iter()
+            .map(|(dir, prefix)| resources::write_dir_resource(&out_dir, name, dir, prefix));
+        let res_files = self
+            .res_files
             .iter()
-            .map(|input| out_dir.join(rcc.exec(&out_dir, input)))
+            .cloned()
+            .chain(res_dir_files)
+            .chain(shader_res_file)
             .collect::<Vec<_>>();
+        let resource_names = resources::resource_names(name, &res_files);
+        if !res_files.is_empty() {
+            tool::check_version("rcc", self.qt_install.rcc(), self.qt_install.version());
+        }
+        let mut res_outputs = res_files
+            .iter()
+            .zip(resource_names.iter())
+            .map(|(input, resource_name)| {
+                let rcc = Tool::rcc(self.qt_install.rcc(), resource_name);
+                out_dir.join(rcc.exec(&out_dir, input))
+            })
+            .collect::<Vec<_>>();
+        let mut resource_initializer = None;
+        if !res_files.is_empty() {
+            let (cpp, rs) = resources::write_initializer(&out_dir, name, &resource_names);
+            res_outputs.push(cpp);
+            resource_initializer = Some(rs);
+        }
 
-        let files = self
+        let sources = self
             .files
             .iter()
             .chain(moc_outputs.iter())
-            .chain(res_outputs.iter());
+            .cloned()
+            .collect::<Vec<_>>();
+        let unity_files = match self.unity_batch_size {
+            Some(batch_size) => unity::write_batches(&out_dir, name, &sources, batch_size),
+            None => Vec::new(),
+        };
+        let sources = if self.unity_batch_size.is_some() {
+            &unity_files
+        } else {
+            &sources
+        };
+        let files = sources.iter().chain(res_outputs.iter()).collect::<Vec<_>>();
 
         let include_dir = self.qt_install.include_dir();
         let lib_dir_str = self.qt_install.lib_dir().to_string_lossy();
@@ -398,16 +1136,87 @@ impl Builder {
         let mut builder = Build::new();
         builder
             .cpp(true)
-            .files(files)
-            .include(out_dir)
+            .include(&out_dir)
             .include(include_dir)
             .flag_if_supported("-std=c++11");
+        if self.hidden_visibility {
+            builder
+                .flag_if_supported("-fvisibility=hidden")
+                .flag_if_supported("-fvisibility-inlines-hidden");
+        }
+
+        for module in &self.private_header_modules {
+            let module_name = format!("Qt{}", module);
+            let private_dir = include_dir
+                .join(&module_name)
+                .join(self.qt_install.version());
+            builder
+                .include(private_dir.join(&module_name))
+                .include(private_dir);
+        }
 
         for (key, value) in &self.definitions {
-            builder.define(key, value.clone());
+            builder.define(key, *value);
+        }
+
+        let debug = self
+            .debug
+            .unwrap_or_else(|| env::var("DEBUG").is_ok_and(|d| d != "false"));
+        if debug && self.split_debug_info && builder.get_compiler().is_like_msvc() {
+            // cc passes /Z7 when debug is enabled, and /Zi would override it with warning D9025
+            let pdb = out_dir.join(format!("{}.pdb", name));
+            builder
+                .debug(false)
+                .flag("/Zi")
+                .flag("/FS")
+                .flag(format!("/Fd{}", pdb.display()));
+        } else {
+            builder.debug(debug);
+            if debug && self.split_debug_info {
+                builder.flag_if_supported("-gsplit-dwarf");
+            }
         }
 
-        builder.compile(name);
+        let version = format!("\"{}\"", self.qt_install.version());
+        let lib_dir = format!("\"{}\"", lib_dir_str.replace('\\', "\\\\"));
+        builder
+            .define("QT_BINDING_QT_VERSION", version.as_str())
+            .define("QT_BINDING_QT_LIB_DIR", lib_dir.as_str());
+
+        let mut modules = Builder::modules();
+        if !self.optional_modules.is_empty() && cargo_supports_check_cfg() {
+            println!("cargo:rustc-check-cfg=cfg(qt_module, values(any()))");
+        }
+        for module in &self.optional_modules {
+            if self.qt_install.has_module(module) {
+                if !modules.contains(module) {
+                    modules.push(*module);
+                }
+                let definition = format!("QT_BINDING_HAS_{}", module.to_uppercase());
+                builder.define(&definition, "1");
+                println!("cargo:rustc-cfg=qt_module=\"{}\"", module);
+            } else {
+                println!(
+                    "cargo:warning=Qt module {} is not available, building without it",
+                    module
+                );
+            }
+        }
+
+        let pch_files = if self.precompiled_header {
+            PrecompiledHeader::new(&out_dir, name).build(&mut builder, &modules)
+        } else {
+            Vec::new()
+        };
+
+        match self.jobs.or_else(cargo_build_jobs) {
+            Some(jobs) => {
+                let objects = compile_objects(&builder, &files, jobs);
+                let library = builder.create_archive(name, &objects);
+                cc::emit_link_directives(&builder, library);
+            }
+            None => builder.files(files).compile(name),
+        }
 
         // Link against Qt
         if cfg!(target_os = "macos") {
@@ -415,30 +1224,111 @@ impl Builder {
         } else {
             println!("cargo:rustc-link-search=native={}", lib_dir_str);
         }
-        self.link_lib("Core");
+        for module in &modules {
+            self.link_lib(module);
+        }
+        if windows::is_mingw_target() {
+            for lib in windows::mingw_system_libs(&modules) {
+                println!("cargo:rustc-link-lib={}", lib);
+            }
+        }
+
+        let mut windows_resource_outputs = Vec::new();
+        if windows::is_target() {
+            let generated = self
+                .windows_resource
+                .as_ref()
+                .map(|resource| resource.write(&out_dir, name));
+            for input in self.windows_resource_files.iter().chain(generated.iter()) {
+                windows_resource_outputs.push(windows::compile(&out_dir, input));
+            }
+        }
+
+        let info_plist = self
+            .mac_bundle
+            .as_ref()
+            .filter(|_| macos::is_target())
+            .map(|bundle| bundle.embed(&out_dir, name));
+
+        let generated_files = moc_outputs
+            .into_iter()
+            .chain(shader_outputs)
+            .chain(windows_resource_outputs)
+            .chain(info_plist)
+            .chain(res_outputs)
+            .chain(pch_files)
+            .chain(unity_files)
+            .chain(resource_initializer)
+            .collect();
+        let artifacts = BuildArtifacts::new(
+            name,
+            &out_dir,
+            generated_files,
+            modules,
+            self.qt_install.clone(),
+        );
+        artifacts.write_summary();
+        artifacts
+    }
+
+    fn modules() -> Vec<&'static str> {
+        let mut modules = vec!["Core"];
+        if cfg!(feature = "bluetooth") {
+            modules.push("Bluetooth");
+        }
+        if cfg!(feature = "dbus") {
+            modules.push("DBus");
+        }
         if cfg!(feature = "gui") {
-            self.link_lib("Gui");
+            modules.push("Gui");
+        }
+        if cfg!(feature = "multimedia") {
+            modules.push("Multimedia");
+        }
+        if cfg!(feature = "network") {
+            modules.push("Network");
+        }
+        if cfg!(feature = "pdf") {
+            modules.push("Pdf");
+        }
+        if cfg!(feature = "positioning") {
+            modules.push("Positioning");
+        }
+        if cfg!(feature = "printsupport") {
+            modules.push("PrintSupport");
         }
         if cfg!(feature = "qml") {
-            self.link_lib("Qml");
+            modules.push("Qml");
         }
         if cfg!(feature = "quick") {
-            self.link_lib("Quick");
+            modules.push("Quick");
+        }
+        if cfg!(feature = "quick-test") {
+            modules.push("QuickTest");
+        }
+        if cfg!(feature = "serialport") {
+            modules.push("SerialPort");
+        }
+        if cfg!(feature = "sql") {
+            modules.push("Sql");
+        }
+        if cfg!(feature = "websockets") {
+            modules.push("WebSockets");
         }
         if cfg!(feature = "widgets") {
-            self.link_lib("Widgets");
+            modules.push("Widgets");
         }
+        modules
     }
 
     fn sys_qt_install_info(key: &str) -> String {
-        env::var(format!("DEP_QT_{}", key)) //
-            .unwrap_or_else(|_| {
-                panic!(
-                    "Could not find Qt installation from qt-sys. \
-                     Have you added qt-sys as a dependency ? \
-                     Are you running inside a build script ?",
-                )
-            })
+        dep_var("QT", key).unwrap_or_else(|| {
+            panic!(
+                "Could not find Qt installation from qt-sys. \
+                 Have you added qt-sys or qt-auto-binding as a dependency ? \
+                 Are you running inside a build script ?",
+            )
+        })
     }
 
     fn link_lib(&self, module: &str) {
@@ -456,3 +1346,60 @@ impl Default for Builder {
         Builder::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cargo_version() {
+        assert_eq!(
+            cargo_version("cargo 1.80.0 (376290515 2024-07-16)"),
+            Some((1, 80))
+        );
+        assert_eq!(cargo_version("cargo 1.36.0"), Some((1, 36)));
+        assert_eq!(cargo_version("cargo"), None);
+        assert_eq!(cargo_version(""), None);
+    }
+
+    // The objects are compiled for the host, with the target given explicitly
+    #[test]
+    #[cfg(all(target_os = "linux", target_arch = "x86_64"))]
+    fn test_compile_objects() {
+        use std::fs;
+
+        let dir = env::temp_dir().join(format!("qt-binding-objects-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let files = (0..5)
+            .map(|i| {
+                let file = dir.join(format!("source{}.c", i));
+                fs::write(
+                    &file,
+                    format!("int source{}(void) {{ return {}; }}\n", i, i),
+                )
+                .unwrap();
+                file
+            })
+            .collect::<Vec<_>>();
+
+        let mut builder = Build::new();
+        builder
+            .out_dir(&dir)
+            .target("x86_64-unknown-linux-gnu")
+            .host("x86_64-unknown-linux-gnu")
+            .opt_level(0)
+            .cargo_metadata(false);
+        let files = files.iter().collect::<Vec<_>>();
+        let objects = compile_objects(&builder, &files, 2);
+
+        let names = objects
+            .iter()
+            .map(|object| object.file_name().unwrap().to_string_lossy().into_owned())
+            .collect::<Vec<_>>();
+        assert_eq!(names.len(), 5);
+        for (i, name) in names.iter().enumerate() {
+            assert!(name.ends_with(&format!("source{}.o", i)), "{}", name);
+        }
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,322 @@
+use qt_install::{QtInstall, ToolError, ToolOutput};
+use std::env;
+use std::ffi::OsString;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// A macOS application bundle
+///
+/// `MacBundle` describes the `Info.plist` of an application, with its bundle identifier,
+/// name and version. It is used in two places:
+///
+/// - in the build script, with [`Builder::mac_bundle`], to embed the `Info.plist` in the
+///   executables of the crate. Executables started with `cargo run`, outside of a bundle, then
+///   have a bundle identifier, that is used by Qt, for example for the native menu bar.
+/// - after `cargo build`, usually from an `xtask`, to assemble a `.app` bundle around the built
+///   executable with [`write`], and to copy the Qt frameworks, plugins and QML imports in it
+///   with [`deploy`], that runs `macdeployqt`.
+///
+/// Build scripts run before the executables are linked, so the bundle cannot be assembled from
+/// the build script.
+///
+/// `QSettings` and `QStandardPaths` use the organization domain and the application name,
+/// rather than the bundle identifier. They should be set to match it, with
+/// `Application::set_organization_domain` and `Application::set_application_name` from
+/// `qt-binding`: with `example.com` and `App`, settings are stored in
+/// `~/Library/Preferences/com.example.App.plist`.
+///
+/// [`Builder::mac_bundle`]: struct.Builder.html#method.mac_bundle
+/// [`write`]: #method.write
+/// [`deploy`]: #method.deploy
+///
+/// # Examples
+///
+/// In `build.rs`
+///
+/// ```no_run
+/// use qt_binding_build::{Builder, MacBundle};
+///
+/// let mut bundle = MacBundle::new("App", "com.example.App");
+/// bundle.version("1.2.0");
+///
+/// Builder::new()
+///     .file("src/app.cpp")
+///     .mac_bundle(bundle)
+///     .build("app");
+/// ```
+///
+/// In an `xtask`, after `cargo build --release`
+///
+/// ```no_run
+/// use qt_binding_build::{MacBundle, QtInstall};
+///
+/// fn bundle(qt_install: &QtInstall) {
+///     let mut bundle = MacBundle::new("App", "com.example.App");
+///     bundle
+///         .version("1.2.0")
+///         .executable("app")
+///         .icon("assets/app.icns")
+///         .qml_dir("qml");
+///
+///     let app = bundle.write("target/release/app", "target/release").unwrap();
+///     bundle.deploy(qt_install, &app).unwrap();
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct MacBundle {
+    name: String,
+    identifier: String,
+    version: String,
+    executable: Option<String>,
+    icon: Option<PathBuf>,
+    minimum_system_version: Option<String>,
+    qml_dir: Option<PathBuf>,
+}
+
+impl MacBundle {
+    /// New instance, with the name and the bundle identifier of the application
+    ///
+    /// The bundle identifier is usually a reversed domain name, like `com.example.App`.
+    pub fn new<S, T>(name: S, identifier: T) -> Self
+    where
+        S: Into<String>,
+        T: Into<String>,
+    {
+        MacBundle {
+            name: name.into(),
+            identifier: identifier.into(),
+            version: "1.0".to_string(),
+            executable: None,
+            icon: None,
+            minimum_system_version: None,
+            qml_dir: None,
+        }
+    }
+
+    /// Name of the application, displayed in the menu bar and by the Finder
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Bundle identifier of the application
+    pub fn identifier(&self) -> &str {
+        &self.identifier
+    }
+
+    /// Version of the application
+    ///
+    /// Defaults to `1.0`.
+    pub fn version<S>(&mut self, version: S) -> &mut Self
+    where
+        S: Into<String>,
+    {
+        self.version = version.into();
+        self
+    }
+
+    /// Name of the executable in the bundle
+    ///
+    /// Defaults to the name of the application.
+    pub fn executable<S>(&mut self, executable: S) -> &mut Self
+    where
+        S: Into<String>,
+    {
+        self.executable = Some(executable.into());
+        self
+    }
+
+    /// Icon of the application, as an `.icns` file
+    pub fn icon<P>(&mut self, path: P) -> &mut Self
+    where
+        P: AsRef<Path>,
+    {
+        self.icon = Some(path.as_ref().to_path_buf());
+        self
+    }
+
+    /// Minimum version of macOS required to run the application, like `10.13`
+    pub fn minimum_system_version<S>(&mut self, version: S) -> &mut Self
+    where
+        S: Into<String>,
+    {
+        self.minimum_system_version = Some(version.into());
+        self
+    }
+
+    /// Directory containing the QML files of the application
+    ///
+    /// [`deploy`] scans the QML files for imports, and copies the imported QML modules in the
+    /// bundle.
+    ///
+    /// [`deploy`]: #method.deploy
+    pub fn qml_dir<P>(&mut self, path: P) -> &mut Self
+    where
+        P: AsRef<Path>,
+    {
+        self.qml_dir = Some(path.as_ref().to_path_buf());
+        self
+    }
+
+    /// Content of `Info.plist`
+    pub fn info_plist(&self) -> String {
+        let mut entries = vec![
+            ("CFBundleDevelopmentRegion", string("en")),
+            ("CFBundleExecutable", string(self.executable_name())),
+            ("CFBundleIdentifier", string(&self.identifier)),
+            ("CFBundleInfoDictionaryVersion", string("6.0")),
+            ("CFBundleName", string(&self.name)),
+            ("CFBundlePackageType", string("APPL")),
+            ("CFBundleShortVersionString", string(&self.version)),
+            ("CFBundleVersion", string(&self.version)),
+        ];
+        if let Some(icon) = self.icon_name() {
+            entries.push(("CFBundleIconFile", string(&icon)));
+        }
+        if let Some(version) = &self.minimum_system_version {
+            entries.push(("LSMinimumSystemVersion", string(version)));
+        }
+        entries.push(("NSHighResolutionCapable", "<true/>".to_string()));
+        entries.push(("NSPrincipalClass", string("NSApplication")));
+
+        let mut content = String::from(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+             <!DOCTYPE plist PUBLIC \"-//Apple//DTD PLIST 1.0//EN\" \
+             \"http://www.apple.com/DTDs/PropertyList-1.0.dtd\">\n\
+             <plist version=\"1.0\">\n\
+             <dict>\n",
+        );
+        for (key, value) in entries {
+            content.push_str(&format!("  <key>{}</key>\n  {}\n", key, value));
+        }
+        content.push_str("</dict>\n</plist>\n");
+        content
+    }
+
+    /// Assemble the bundle around a built executable
+    ///
+    /// `<name>.app` is created in a directory, with the executable, `Info.plist` and the icon.
+    /// An existing bundle is updated. Returns the path to the bundle.
+    pub fn write<P, Q>(&self, executable: P, dir: Q) -> io::Result<PathBuf>
+    where
+        P: AsRef<Path>,
+        Q: AsRef<Path>,
+    {
+        let app = dir.as_ref().join(format!("{}.app", self.name));
+        let contents = app.join("Contents");
+        let macos = contents.join("MacOS");
+        let resources = contents.join("Resources");
+        fs::create_dir_all(&macos)?;
+        fs::create_dir_all(&resources)?;
+
+        fs::copy(executable, macos.join(self.executable_name()))?;
+        fs::write(contents.join("Info.plist"), self.info_plist())?;
+        fs::write(contents.join("PkgInfo"), "APPL????")?;
+        if let (Some(icon), Some(name)) = (&self.icon, self.icon_name()) {
+            fs::copy(icon, resources.join(name))?;
+        }
+        Ok(app)
+    }
+
+    /// Copy the Qt frameworks, plugins and QML imports in a bundle, with `macdeployqt`
+    ///
+    /// `macdeployqt` is run from the Qt installation used for the build, and scans the QML files
+    /// in the directory set with [`qml_dir`] for imports.
+    ///
+    /// [`qml_dir`]: #method.qml_dir
+    pub fn deploy<P>(&self, qt_install: &QtInstall, app: P) -> Result<ToolOutput, ToolError>
+    where
+        P: AsRef<Path>,
+    {
+        let mut tool = qt_install.tool("macdeployqt")?;
+        tool.arg(app.as_ref());
+        if let Some(qml_dir) = &self.qml_dir {
+            let mut arg = OsString::from("-qmldir=");
+            arg.push(qml_dir);
+            tool.arg(arg);
+        }
+        tool.run()
+    }
+
+    /// Write `Info.plist` in `OUT_DIR`, and embed it in the executables of the crate
+    pub(crate) fn embed(&self, out_dir: &Path, lib_name: &str) -> PathBuf {
+        let path = out_dir.join(format!("{}.Info.plist", lib_name));
+        fs::write(&path, self.info_plist())
+            .unwrap_or_else(|err| panic!("Failed to write Info.plist {}: {}", path.display(), err));
+        println!(
+            "cargo:rustc-link-arg-bins=-Wl,-sectcreate,__TEXT,__info_plist,{}",
+            path.display()
+        );
+        path
+    }
+
+    fn executable_name(&self) -> &str {
+        self.executable.as_deref().unwrap_or(&self.name)
+    }
+
+    fn icon_name(&self) -> Option<String> {
+        self.icon
+            .as_ref()
+            .and_then(|icon| icon.file_name())
+            .map(|name| name.to_string_lossy().to_string())
+    }
+}
+
+/// Returns `true` if the crate is built for macOS
+pub(crate) fn is_target() -> bool {
+    env::var("CARGO_CFG_TARGET_OS").is_ok_and(|os| os == "macos")
+}
+
+fn string(value: &str) -> String {
+    let value = value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;");
+    format!("<string>{}</string>", value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_info_plist() {
+        let content = MacBundle::new("R&D", "com.example.App").info_plist();
+        assert!(
+            content.contains("<key>CFBundleIdentifier</key>\n  <string>com.example.App</string>")
+        );
+        assert!(content.contains("<key>CFBundleExecutable</key>\n  <string>R&amp;D</string>"));
+        assert!(content.contains("<key>CFBundleVersion</key>\n  <string>1.0</string>"));
+        assert!(!content.contains("CFBundleIconFile"));
+
+        let content = MacBundle::new("App", "com.example.App")
+            .executable("app")
+            .icon("assets/app.icns")
+            .minimum_system_version("10.13")
+            .info_plist();
+        assert!(content.contains("<key>CFBundleExecutable</key>\n  <string>app</string>"));
+        assert!(content.contains("<key>CFBundleIconFile</key>\n  <string>app.icns</string>"));
+        assert!(content.contains("<key>LSMinimumSystemVersion</key>\n  <string>10.13</string>"));
+    }
+
+    #[test]
+    fn test_write() {
+        let dir = env::temp_dir().join(format!("qt-binding-bundle-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let executable = dir.join("app");
+        fs::write(&executable, "binary").unwrap();
+
+        let bundle = MacBundle::new("App", "com.example.App");
+        let app = bundle.write(&executable, &dir).unwrap();
+        assert_eq!(app, dir.join("App.app"));
+        assert_eq!(
+            fs::read_to_string(app.join("Contents").join("MacOS").join("App")).unwrap(),
+            "binary"
+        );
+        assert_eq!(
+            fs::read_to_string(app.join("Contents").join("Info.plist")).unwrap(),
+            bundle.info_plist()
+        );
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
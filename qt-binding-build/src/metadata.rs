@@ -0,0 +1,67 @@
+use crate::dep_var;
+
+/// Version of the metadata shared by `qt-binding` and `qt-binding-build`
+///
+/// This version is increased when the files generated by `qt-binding-build`, or the symbols
+/// they use, change in a way that is incompatible with previous versions of `qt-binding`.
+pub const METADATA_VERSION: u32 = 1;
+
+/// Check that `qt-binding` was built with a compatible `qt-binding-build`
+///
+/// `qt-binding` publishes the metadata version it was built with to the build scripts of the
+/// crates depending on it, as `DEP_QT_BINDING_METADATA_VERSION`, also forwarded by
+/// `qt-auto-binding`. Build scripts of crates that don't depend on `qt-binding` are not checked.
+pub(crate) fn check() {
+    let version = dep_var("QT_BINDING", "METADATA_VERSION");
+    let qt_binding_version = dep_var("QT_BINDING", "VERSION").unwrap_or_default();
+    if let Err(message) = compare(version.as_deref(), &qt_binding_version) {
+        panic!("{}", message);
+    }
+}
+
+fn compare(version: Option<&str>, qt_binding_version: &str) -> Result<(), String> {
+    let version = match version {
+        Some(version) => version,
+        None => return Ok(()),
+    };
+    let version = version
+        .parse::<u32>()
+        .map_err(|_| format!("Invalid qt-binding metadata version {:?}", version))?;
+
+    let upgrade = if version > METADATA_VERSION {
+        format!("Upgrade qt-binding-build to {}", qt_binding_version)
+    } else if version < METADATA_VERSION {
+        format!(
+            "Upgrade qt-binding to {}, or downgrade qt-binding-build to {}",
+            env!("CARGO_PKG_VERSION"),
+            qt_binding_version
+        )
+    } else {
+        return Ok(());
+    };
+    Err(format!(
+        "qt-binding {} uses metadata version {}, but qt-binding-build {} uses metadata version \
+         {}. These crates must be used with matching versions. {}.",
+        qt_binding_version,
+        version,
+        env!("CARGO_PKG_VERSION"),
+        METADATA_VERSION,
+        upgrade
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compare() {
+        assert!(compare(None, "").is_ok());
+        assert!(compare(Some(&METADATA_VERSION.to_string()), "0.1.0").is_ok());
+        assert!(compare(Some("abc"), "0.1.0").is_err());
+
+        let newer = (METADATA_VERSION + 1).to_string();
+        let message = compare(Some(&newer), "0.9.0").unwrap_err();
+        assert!(message.ends_with("Upgrade qt-binding-build to 0.9.0."));
+    }
+}
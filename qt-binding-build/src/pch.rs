@@ -0,0 +1,133 @@
+use cc::Build;
+use std::ffi::OsString;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// Precompiled header including the headers of the linked Qt modules
+pub(crate) struct PrecompiledHeader {
+    header: PathBuf,
+    source: PathBuf,
+    output: PathBuf,
+}
+
+impl PrecompiledHeader {
+    pub(crate) fn new(out_dir: &Path, name: &str) -> Self {
+        PrecompiledHeader {
+            header: out_dir.join(format!("{}_pch.h", name)),
+            source: out_dir.join(format!("{}_pch.cpp", name)),
+            output: out_dir.join(format!("{}.pch", name)),
+        }
+    }
+
+    /// Generate and compile the precompiled header, and configure the build to use it
+    ///
+    /// Returns the generated files.
+    pub(crate) fn build(&self, builder: &mut Build, modules: &[&str]) -> Vec<PathBuf> {
+        fs::write(&self.header, header_content(modules)).unwrap_or_else(|err| {
+            panic!(
+                "Failed to write precompiled header {}: {}",
+                self.header.display(),
+                err
+            )
+        });
+
+        let compiler = builder.get_compiler();
+        if compiler.is_like_msvc() {
+            // MSVC creates the precompiled header while compiling a source file that includes it
+            let header_name = self.header.file_name().unwrap().to_string_lossy();
+            let object = self.source.with_extension("obj");
+            fs::write(&self.source, format!("#include \"{}\"\n", header_name)).unwrap_or_else(
+                |err| {
+                    panic!(
+                        "Failed to write precompiled header source {}: {}",
+                        self.source.display(),
+                        err
+                    )
+                },
+            );
+
+            Self::exec(
+                compiler.to_command(),
+                &[
+                    OsString::from("/c"),
+                    OsString::from(format!("/Yc{}", header_name)),
+                    OsString::from(format!("/Fp{}", self.output.display())),
+                    OsString::from(format!("/Fo{}", object.display())),
+                    OsString::from(&self.source),
+                ],
+            );
+
+            builder
+                .object(object)
+                .flag(format!("/Yu{}", header_name))
+                .flag(format!("/FI{}", header_name))
+                .flag(format!("/Fp{}", self.output.display()));
+            vec![
+                self.header.clone(),
+                self.source.clone(),
+                self.output.clone(),
+            ]
+        } else {
+            // GCC and Clang look for a precompiled header next to included headers
+            let extension = if compiler.is_like_clang() {
+                "pch"
+            } else {
+                "gch"
+            };
+            let output = PathBuf::from(format!("{}.{}", self.header.display(), extension));
+
+            Self::exec(
+                compiler.to_command(),
+                &[
+                    OsString::from("-x"),
+                    OsString::from("c++-header"),
+                    OsString::from(&self.header),
+                    OsString::from("-o"),
+                    OsString::from(&output),
+                ],
+            );
+
+            builder.flag("-include").flag(self.header.to_str().unwrap());
+            vec![self.header.clone(), output]
+        }
+    }
+
+    fn exec(mut command: Command, args: &[OsString]) {
+        let output = command.args(args).output().unwrap_or_else(|err| {
+            panic!(
+                "Failed to run the compiler for the precompiled header: {}",
+                err
+            )
+        });
+
+        if !output.status.success() {
+            panic!(
+                "Failed to compile the precompiled header.\n\n{}{}",
+                String::from_utf8_lossy(&output.stdout),
+                String::from_utf8_lossy(&output.stderr)
+            )
+        }
+    }
+}
+
+fn header_content(modules: &[&str]) -> String {
+    let mut content = String::from("#pragma once\n\n");
+    for module in modules {
+        content.push_str(&format!("#include <Qt{0}/Qt{0}>\n", module));
+    }
+    content
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_header_content() {
+        assert_eq!(
+            header_content(&["Core", "Gui"]),
+            "#pragma once\n\n#include <QtCore/QtCore>\n#include <QtGui/QtGui>\n"
+        );
+    }
+}
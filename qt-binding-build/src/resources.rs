@@ -0,0 +1,293 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Names given to `rcc` for the resource files of a library
+///
+/// `rcc` generates a `qInitResources_<name>` function for each resource file. A single resource
+/// file is named after the library, so that `Q_INIT_RESOURCE(<lib>)` can be called from C++.
+/// When there are several resource files, each one gets a distinct name.
+pub(crate) fn resource_names(lib_name: &str, inputs: &[PathBuf]) -> Vec<String> {
+    if inputs.len() == 1 {
+        vec![lib_name.to_string()]
+    } else {
+        inputs
+            .iter()
+            .map(|input| resource_name(lib_name, input))
+            .collect()
+    }
+}
+
+fn resource_name(lib_name: &str, input: &Path) -> String {
+    let stem = input
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    identifier(&format!("{}_{}", lib_name, stem))
+}
+
+/// Name of the function registering all the resources of a library
+pub(crate) fn init_function(lib_name: &str) -> String {
+    format!("qt_binding_init_resources_{}", identifier(lib_name))
+}
+
+/// Write a resource file listing all the files of a directory
+///
+/// Files are available under `prefix`, with their path relative to the directory. Cargo is
+/// asked to rerun the build script when the directory, or any of the files, changes. Returns
+/// the resource file, written in `OUT_DIR`.
+pub(crate) fn write_dir_resource(
+    out_dir: &Path,
+    lib_name: &str,
+    dir: &Path,
+    prefix: &str,
+) -> PathBuf {
+    let mut files = Vec::new();
+    list_files(dir, &mut files);
+    files.sort();
+
+    println!("cargo:rerun-if-changed={}", dir.display());
+    let entries = files
+        .iter()
+        .map(|file| {
+            println!("cargo:rerun-if-changed={}", file.display());
+            let alias = file.strip_prefix(dir).unwrap_or(file);
+            let path = fs::canonicalize(file).unwrap_or_else(|_| file.to_path_buf());
+            (slashes(alias), slashes(&path))
+        })
+        .collect::<Vec<_>>();
+
+    let output = out_dir.join(format!(
+        "{}.qrc",
+        identifier(&format!("{}_{}", lib_name, slashes(dir)))
+    ));
+    write(&output, &qrc_content(prefix, &entries));
+    output
+}
+
+/// Write a resource file listing the shaders compiled by `qsb`
+///
+/// Shaders are available under `prefix`, with their file name. Returns the resource file,
+/// written in `OUT_DIR`.
+pub(crate) fn write_shader_resource(
+    out_dir: &Path,
+    lib_name: &str,
+    shaders: &[PathBuf],
+    prefix: &str,
+) -> PathBuf {
+    let entries = shaders
+        .iter()
+        .map(|shader| {
+            let alias = shader.file_name().map(Path::new).unwrap_or(shader);
+            (slashes(alias), slashes(shader))
+        })
+        .collect::<Vec<_>>();
+
+    let output = out_dir.join(format!("{}_shaders.qrc", identifier(lib_name)));
+    write(&output, &qrc_content(prefix, &entries));
+    output
+}
+
+fn list_files(dir: &Path, files: &mut Vec<PathBuf>) {
+    let entries = fs::read_dir(dir).unwrap_or_else(|err| {
+        panic!(
+            "Failed to read resource directory {}: {}",
+            dir.display(),
+            err
+        )
+    });
+    for entry in entries {
+        let path = entry
+            .unwrap_or_else(|err| {
+                panic!(
+                    "Failed to read resource directory {}: {}",
+                    dir.display(),
+                    err
+                )
+            })
+            .path();
+        if path.is_dir() {
+            list_files(&path, files);
+        } else {
+            files.push(path);
+        }
+    }
+}
+
+fn slashes(path: &Path) -> String {
+    path.to_string_lossy().replace('\\', "/")
+}
+
+fn escape(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+fn qrc_content(prefix: &str, entries: &[(String, String)]) -> String {
+    let mut content = format!("<RCC>\n    <qresource prefix=\"{}\">\n", escape(prefix));
+    for (alias, path) in entries {
+        content.push_str(&format!(
+            "        <file alias=\"{}\">{}</file>\n",
+            escape(alias),
+            escape(path)
+        ));
+    }
+    content.push_str("    </qresource>\n</RCC>\n");
+    content
+}
+
+/// Write the sources that register the resources of a library
+///
+/// A C++ source, to be compiled in the library, defines an `extern "C"` function that calls
+/// the initializers generated by `rcc`. A Rust source, to be included by the crate, calls it.
+/// Returns the C++ and the Rust sources.
+pub(crate) fn write_initializer(
+    out_dir: &Path,
+    lib_name: &str,
+    resource_names: &[String],
+) -> (PathBuf, PathBuf) {
+    let cpp = out_dir.join(format!("{}_resources.cpp", lib_name));
+    let rs = out_dir.join(format!("{}_resources.rs", lib_name));
+    write(&cpp, &cpp_content(lib_name, resource_names));
+    write(&rs, &rs_content(lib_name));
+    (cpp, rs)
+}
+
+fn write(path: &Path, content: &str) {
+    fs::write(path, content).unwrap_or_else(|err| {
+        panic!(
+            "Failed to write resource initializer {}: {}",
+            path.display(),
+            err
+        )
+    });
+}
+
+fn identifier(name: &str) -> String {
+    name.chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect()
+}
+
+fn cpp_content(lib_name: &str, resource_names: &[String]) -> String {
+    let mut content = String::from(
+        "#include <QtCore/qglobal.h>\n\
+         \n\
+         // Same mangling as the files generated by rcc\n\
+         #ifdef QT_NAMESPACE\n\
+         #define QT_RCC_MANGLE_NAMESPACE0(x) x\n\
+         #define QT_RCC_MANGLE_NAMESPACE1(a, b) a##_##b\n\
+         #define QT_RCC_MANGLE_NAMESPACE2(a, b) QT_RCC_MANGLE_NAMESPACE1(a, b)\n\
+         #define QT_RCC_MANGLE_NAMESPACE(name) \\\n    \
+         QT_RCC_MANGLE_NAMESPACE2(QT_RCC_MANGLE_NAMESPACE0(name), \
+         QT_RCC_MANGLE_NAMESPACE0(QT_NAMESPACE))\n\
+         #else\n\
+         #define QT_RCC_MANGLE_NAMESPACE(name) name\n\
+         #endif\n\
+         \n",
+    );
+    // rcc replaces the characters that are not valid in identifiers
+    let resource_names = resource_names
+        .iter()
+        .map(|name| identifier(name))
+        .collect::<Vec<_>>();
+    for name in &resource_names {
+        content.push_str(&format!(
+            "int QT_RCC_MANGLE_NAMESPACE(qInitResources_{})();\n",
+            name
+        ));
+    }
+    content.push_str(&format!(
+        "\nextern \"C\" void {}()\n{{\n",
+        init_function(lib_name)
+    ));
+    for name in &resource_names {
+        content.push_str(&format!(
+            "    QT_RCC_MANGLE_NAMESPACE(qInitResources_{})();\n",
+            name
+        ));
+    }
+    content.push_str("}\n");
+    content
+}
+
+fn rs_content(lib_name: &str) -> String {
+    format!(
+        "/// Register the Qt resources compiled in `{0}`\n\
+         ///\n\
+         /// Resources compiled in a static library are not registered automatically. This\n\
+         /// function should be called once, before accessing resources with `qrc:/` paths.\n\
+         pub fn init_resources() {{\n    \
+         extern \"C\" {{\n        \
+         fn {1}();\n    \
+         }}\n    \
+         unsafe {{ {1}() }}\n\
+         }}\n",
+        lib_name,
+        init_function(lib_name)
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resource_name() {
+        assert_eq!(
+            resource_name("my-lib", Path::new("qml/main.res.qrc")),
+            "my_lib_main_res"
+        );
+        assert_eq!(
+            resource_names("bindings", &[PathBuf::from("src/res.qrc")]),
+            vec!["bindings"]
+        );
+        assert_eq!(
+            resource_names(
+                "bindings",
+                &[PathBuf::from("src/res.qrc"), PathBuf::from("qml/qml.qrc")]
+            ),
+            vec!["bindings_res", "bindings_qml"]
+        );
+        assert_eq!(init_function("my-lib"), "qt_binding_init_resources_my_lib");
+    }
+
+    #[test]
+    fn test_qrc_content() {
+        let entries = vec![
+            ("main.qml".to_string(), "/src/qml/main.qml".to_string()),
+            (
+                "R&D/icon.png".to_string(),
+                "/src/qml/R&D/icon.png".to_string(),
+            ),
+        ];
+        assert_eq!(
+            qrc_content("/", &entries),
+            "<RCC>\n    <qresource prefix=\"/\">\n        \
+             <file alias=\"main.qml\">/src/qml/main.qml</file>\n        \
+             <file alias=\"R&amp;D/icon.png\">/src/qml/R&amp;D/icon.png</file>\n    \
+             </qresource>\n</RCC>\n"
+        );
+    }
+
+    #[test]
+    fn test_cpp_content() {
+        let content = cpp_content("my-lib", &["my-lib".to_string()]);
+        assert!(content.contains("int QT_RCC_MANGLE_NAMESPACE(qInitResources_my_lib)();\n"));
+
+        let content = cpp_content("mylib", &["mylib_res".to_string()]);
+        assert!(content.contains("int QT_RCC_MANGLE_NAMESPACE(qInitResources_mylib_res)();\n"));
+        assert!(content.contains("extern \"C\" void qt_binding_init_resources_mylib()\n"));
+        assert!(content.contains("    QT_RCC_MANGLE_NAMESPACE(qInitResources_mylib_res)();\n"));
+    }
+
+    #[test]
+    fn test_rs_content() {
+        let content = rs_content("mylib");
+        assert!(content.contains("pub fn init_resources() {\n"));
+        assert!(content.contains("        fn qt_binding_init_resources_mylib();\n"));
+        assert!(content.contains("    unsafe { qt_binding_init_resources_mylib() }\n"));
+    }
+}
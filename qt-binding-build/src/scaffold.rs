@@ -0,0 +1,271 @@
+//! Project scaffolding
+//!
+//! [`Project`] generates the files of a minimal Rust and QML application using `qt-binding`,
+//! following the conventions of this crate: a build script compiling a Qt resource file with
+//! the QML files, and a `main.rs` registering the resources, creating the [`Application`] and
+//! a QML engine loading `qml/main.qml`.
+//!
+//! It is meant to be called by project generators, or by `xtask`s, instead of copying the
+//! files from the documentation.
+//!
+//! [`Project`]: struct.Project.html
+//! [`Application`]: ../../qt_binding/app/struct.Application.html
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use qt_binding_build::scaffold::Project;
+//!
+//! Project::new("hello-qml")
+//!     .qt_binding_path("../qt-auto-binding")
+//!     .write("hello-qml")
+//!     .unwrap();
+//! ```
+//!
+//! The generated project is then built and run with `cargo run`.
+
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+/// Version of the `qt-binding` crates used by generated projects
+const VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// A generated Rust and QML project
+///
+/// See module level documentation for more information.
+#[derive(Clone, Debug)]
+pub struct Project {
+    name: String,
+    qt_binding_path: Option<PathBuf>,
+}
+
+impl Project {
+    /// Creates a `Project` with a package name
+    pub fn new<S>(name: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Project {
+            name: name.into(),
+            qt_binding_path: None,
+        }
+    }
+
+    /// Depend on `qt-binding` crates from a local checkout
+    ///
+    /// The path is the directory containing the `qt-auto-binding` and `qt-binding-build` crates,
+    /// relative to the generated project or absolute. By default, generated projects depend on
+    /// the version of these crates matching this crate.
+    pub fn qt_binding_path<P>(&mut self, path: P) -> &mut Self
+    where
+        P: AsRef<Path>,
+    {
+        self.qt_binding_path = Some(path.as_ref().to_path_buf());
+        self
+    }
+
+    /// Content of `Cargo.toml`
+    ///
+    /// `qt-auto-binding` is used with the `gui` and `qml` features, so that the application
+    /// can create the window declared in `qml/main.qml`.
+    pub fn cargo_toml(&self) -> String {
+        format!(
+            "[package]\n\
+             name = \"{}\"\n\
+             version = \"0.1.0\"\n\
+             edition = \"2018\"\n\
+             build = \"build.rs\"\n\
+             \n\
+             [dependencies]\n\
+             qt-auto-binding = {}\n\
+             \n\
+             [build-dependencies]\n\
+             qt-binding-build = {}\n",
+            self.name,
+            self.dependency("qt-auto-binding", &["gui", "qml"]),
+            self.dependency("qt-binding-build", &[]),
+        )
+    }
+
+    /// Content of `build.rs`, compiling `qml.qrc`
+    pub fn build_rs(&self) -> String {
+        format!(
+            "use qt_binding_build::Builder;\n\
+             \n\
+             fn main() {{\n    \
+             println!(\"cargo:rerun-if-changed=qml\");\n    \
+             println!(\"cargo:rerun-if-changed=qml.qrc\");\n    \
+             Builder::new().res_file(\"qml.qrc\").build(\"{}\");\n\
+             }}\n",
+            self.lib_name()
+        )
+    }
+
+    /// Content of `src/main.rs`, loading `qml/main.qml` from the resources
+    pub fn main_rs(&self) -> String {
+        format!(
+            "use qt_auto_binding::app::Application;\n\
+             use qt_auto_binding::qml::Engine;\n\
+             use std::process::exit;\n\
+             \n\
+             include!(concat!(env!(\"OUT_DIR\"), \"/{}_resources.rs\"));\n\
+             \n\
+             fn main() {{\n    \
+             let mut app = Application::new();\n    \
+             init_resources();\n\
+             \n    \
+             let code = {{\n        \
+             let mut engine = Engine::new();\n        \
+             if !engine.load(\"qrc:/qml/main.qml\") {{\n            \
+             exit(1);\n        \
+             }}\n        \
+             app.exec()\n    \
+             }};\n    \
+             exit(code);\n\
+             }}\n",
+            self.lib_name()
+        )
+    }
+
+    /// Content of `qml/main.qml`
+    pub fn main_qml(&self) -> String {
+        format!(
+            "import QtQuick 2.0\n\
+             import QtQuick.Window 2.0\n\
+             \n\
+             Window {{\n    \
+             width: 640\n    \
+             height: 480\n    \
+             visible: true\n    \
+             title: \"{}\"\n\
+             \n    \
+             Text {{\n        \
+             anchors.centerIn: parent\n        \
+             text: \"Hello from Rust and QML\"\n    \
+             }}\n\
+             }}\n",
+            self.name
+        )
+    }
+
+    /// Content of `qml.qrc`, listing `qml/main.qml`
+    pub fn qrc(&self) -> String {
+        "<RCC>\n    \
+         <qresource prefix=\"/\">\n        \
+         <file>qml/main.qml</file>\n    \
+         </qresource>\n\
+         </RCC>\n"
+            .to_string()
+    }
+
+    /// Generated files, with their path relative to the project directory
+    pub fn files(&self) -> Vec<(PathBuf, String)> {
+        vec![
+            (PathBuf::from("Cargo.toml"), self.cargo_toml()),
+            (PathBuf::from("build.rs"), self.build_rs()),
+            (Path::new("src").join("main.rs"), self.main_rs()),
+            (Path::new("qml").join("main.qml"), self.main_qml()),
+            (PathBuf::from("qml.qrc"), self.qrc()),
+        ]
+    }
+
+    /// Write the project in a directory
+    ///
+    /// The directory is created if needed. Existing files are never overwritten: an error
+    /// with the [`AlreadyExists`] kind is returned instead, and files written before are kept.
+    ///
+    /// [`AlreadyExists`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html#variant.AlreadyExists
+    pub fn write<P>(&self, dir: P) -> io::Result<()>
+    where
+        P: AsRef<Path>,
+    {
+        let dir = dir.as_ref();
+        for (path, content) in self.files() {
+            let path = dir.join(path);
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            let mut file = OpenOptions::new()
+                .write(true)
+                .create_new(true)
+                .open(&path)?;
+            file.write_all(content.as_bytes())?;
+        }
+        Ok(())
+    }
+
+    fn lib_name(&self) -> String {
+        format!("{}_qml", self.name.replace('-', "_"))
+    }
+
+    fn dependency(&self, name: &str, features: &[&str]) -> String {
+        let source = match &self.qt_binding_path {
+            Some(path) => format!(
+                "path = \"{}\"",
+                path.join(name).to_string_lossy().replace('\\', "/")
+            ),
+            None => format!("version = \"{}\"", VERSION),
+        };
+        if features.is_empty() {
+            format!("{{{}}}", source)
+        } else {
+            let features = features
+                .iter()
+                .map(|feature| format!("\"{}\"", feature))
+                .collect::<Vec<_>>();
+            format!("{{{}, features = [{}]}}", source, features.join(", "))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    #[test]
+    fn test_cargo_toml() {
+        let content = Project::new("hello-qml").cargo_toml();
+        assert!(content.contains("name = \"hello-qml\"\n"));
+        assert!(content.contains(&format!(
+            "qt-auto-binding = {{version = \"{}\", features = [\"gui\", \"qml\"]}}\n",
+            VERSION
+        )));
+
+        let content = Project::new("hello-qml")
+            .qt_binding_path("../qt")
+            .cargo_toml();
+        assert!(content.contains(
+            "qt-auto-binding = {path = \"../qt/qt-auto-binding\", features = [\"gui\", \"qml\"]}\n"
+        ));
+        assert!(content.contains("qt-binding-build = {path = \"../qt/qt-binding-build\"}\n"));
+    }
+
+    #[test]
+    fn test_resources() {
+        let project = Project::new("hello-qml");
+        assert!(project
+            .build_rs()
+            .contains("Builder::new().res_file(\"qml.qrc\").build(\"hello_qml_qml\");"));
+        assert!(project
+            .main_rs()
+            .contains("include!(concat!(env!(\"OUT_DIR\"), \"/hello_qml_qml_resources.rs\"));"));
+        assert!(project.qrc().contains("<file>qml/main.qml</file>"));
+    }
+
+    #[test]
+    fn test_write() {
+        let dir = env::temp_dir().join(format!("qt-binding-scaffold-{}", std::process::id()));
+        let project = Project::new("hello-qml");
+        project.write(&dir).unwrap();
+        assert_eq!(
+            fs::read_to_string(dir.join("qml").join("main.qml")).unwrap(),
+            project.main_qml()
+        );
+
+        let err = project.write(&dir).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::AlreadyExists);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,108 @@
+/// Shading languages that `qsb` compiles shaders to
+///
+/// Qt Quick and Qt Quick 3D pick the variant of a shader matching the graphics API used at
+/// runtime, so a shader should be compiled for all the APIs an application might use. The
+/// default targets are the ones recommended by Qt: GLSL `100 es`, `120` and `150`, HLSL `50`
+/// and MSL `12`.
+///
+/// See [`Builder::shader_targets`] for more information.
+///
+/// [`Builder::shader_targets`]: struct.Builder.html#method.shader_targets
+///
+/// # Examples
+///
+/// ```no_run
+/// use qt_binding_build::{Builder, ShaderTargets};
+///
+/// let mut targets = ShaderTargets::new();
+/// targets.glsl("300 es,330").msl(None);
+///
+/// Builder::new()
+///     .shader_file("shaders/wobble.frag")
+///     .shader_targets(targets)
+///     .build("mylib");
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ShaderTargets {
+    glsl: Option<String>,
+    hlsl: Option<String>,
+    msl: Option<String>,
+}
+
+impl ShaderTargets {
+    /// New instance, with the default targets
+    pub fn new() -> Self {
+        ShaderTargets::default()
+    }
+
+    /// GLSL versions, separated by commas, like `100 es,120,150`, or `None` to skip GLSL
+    pub fn glsl<'s, S>(&mut self, versions: S) -> &mut Self
+    where
+        S: Into<Option<&'s str>>,
+    {
+        self.glsl = versions.into().map(ToString::to_string);
+        self
+    }
+
+    /// HLSL shader model versions, separated by commas, like `50`, or `None` to skip HLSL
+    pub fn hlsl<'s, S>(&mut self, versions: S) -> &mut Self
+    where
+        S: Into<Option<&'s str>>,
+    {
+        self.hlsl = versions.into().map(ToString::to_string);
+        self
+    }
+
+    /// Metal Shading Language versions, separated by commas, like `12`, or `None` to skip MSL
+    pub fn msl<'s, S>(&mut self, versions: S) -> &mut Self
+    where
+        S: Into<Option<&'s str>>,
+    {
+        self.msl = versions.into().map(ToString::to_string);
+        self
+    }
+
+    pub(crate) fn args(&self) -> Vec<String> {
+        let targets = [
+            ("--glsl", &self.glsl),
+            ("--hlsl", &self.hlsl),
+            ("--msl", &self.msl),
+        ];
+        targets
+            .iter()
+            .filter_map(|(flag, versions)| {
+                versions
+                    .as_ref()
+                    .map(|versions| vec![flag.to_string(), versions.clone()])
+            })
+            .flatten()
+            .collect()
+    }
+}
+
+impl Default for ShaderTargets {
+    fn default() -> Self {
+        ShaderTargets {
+            glsl: Some("100 es,120,150".to_string()),
+            hlsl: Some("50".to_string()),
+            msl: Some("12".to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_args() {
+        assert_eq!(
+            ShaderTargets::new().args(),
+            vec!["--glsl", "100 es,120,150", "--hlsl", "50", "--msl", "12"]
+        );
+
+        let mut targets = ShaderTargets::new();
+        targets.glsl("300 es").hlsl(None).msl(None);
+        assert_eq!(targets.args(), vec!["--glsl", "300 es"]);
+    }
+}
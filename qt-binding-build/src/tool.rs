@@ -1,11 +1,23 @@
+use qt_install::QtTool;
 use std::ffi::OsString;
 use std::path::{Path, PathBuf};
-use std::process::Command;
+
+/// Kind of file generated by a tool
+enum Output {
+    /// A C++ source, named after the tool and the input, like `moc_object.cpp`
+    Source,
+    /// A shader pack, named after the input, like `wobble.frag.qsb`
+    ///
+    /// Shaders of different stages usually share their name, like `wobble.vert` and
+    /// `wobble.frag`, so the extension of the input is kept.
+    Shader,
+}
 
 pub(crate) struct Tool<'a> {
     name: &'static str,
     tool: &'a Path,
     args: Vec<OsString>,
+    output: Output,
 }
 
 impl<'a> Tool<'a> {
@@ -14,6 +26,7 @@ impl<'a> Tool<'a> {
             name: "moc",
             tool,
             args: Vec::new(),
+            output: Output::Source,
         }
     }
 
@@ -24,34 +37,119 @@ impl<'a> Tool<'a> {
             name: "rcc",
             tool,
             args,
+            output: Output::Source,
+        }
+    }
+
+    pub(crate) fn qsb(tool: &'a Path, args: Vec<String>) -> Self {
+        Tool {
+            name: "qsb",
+            tool,
+            args: args.into_iter().map(OsString::from).collect(),
+            output: Output::Shader,
         }
     }
 
     pub(crate) fn exec(&self, out_dir: &Path, input: &Path) -> PathBuf {
-        let output = input
-            .file_stem()
-            .unwrap_or_else(|| panic!("{} takes files as input.", self.name));
-        let output = out_dir.join(format!("{}_{}.cpp", self.name, output.to_string_lossy()));
-
-        let command = {
-            let args = &[
-                OsString::from(input),
-                OsString::from("-o"),
-                OsString::from(&output),
-            ];
-            let args = self.args.iter().chain(args.iter());
-
-            Command::new(self.tool).args(args).output().unwrap()
-        };
+        let output = out_dir.join(self.output_name(input));
+
+        QtTool::new(self.tool)
+            .args(&self.args)
+            .arg(input)
+            .arg("-o")
+            .arg(&output)
+            .run()
+            .unwrap_or_else(|err| panic!("{}", err));
+        output
+    }
 
-        if command.status.success() {
-            output
-        } else {
-            panic!(
-                "Failed to execute {}.\n\n{}",
-                self.name,
-                String::from_utf8_lossy(&command.stderr)
-            )
+    fn output_name(&self, input: &Path) -> String {
+        let name = match self.output {
+            Output::Source => input.file_stem(),
+            Output::Shader => input.file_name(),
+        };
+        let name = name
+            .unwrap_or_else(|| panic!("{} takes files as input.", self.name))
+            .to_string_lossy();
+        match self.output {
+            Output::Source => format!("{}_{}.cpp", self.name, name),
+            Output::Shader => format!("{}.qsb", name),
         }
     }
 }
+
+/// Check that a tool comes from the same Qt version as the headers and libraries
+///
+/// Running `moc` from one Qt version and linking against another leads to meta-object errors
+/// at runtime that are hard to understand, so the build fails when the major or minor versions
+/// differ. Tools that fail to run, or whose version can't be read, are not checked.
+pub(crate) fn check_version(name: &str, tool: &Path, qt_version: &str) {
+    let output = match QtTool::new(tool).arg("--version").run() {
+        Ok(output) => output,
+        Err(_) => return,
+    };
+    if let Err(message) = compare_versions(name, tool, &output.stdout, qt_version) {
+        panic!("{}", message)
+    }
+}
+
+fn compare_versions(
+    name: &str,
+    tool: &Path,
+    tool_output: &str,
+    qt_version: &str,
+) -> Result<(), String> {
+    let tool_version = match tool_output.split_whitespace().find_map(major_minor) {
+        Some(tool_version) => tool_version,
+        None => return Ok(()),
+    };
+    match major_minor(qt_version) {
+        Some(version) if version != tool_version => Err(format!(
+            "{} ({}) is from Qt {}.{}, but the Qt installation is Qt {}. \
+             Code generated by {} might not work with the headers and libraries of this \
+             installation. Make sure that PATH or QT_INSTALL_DIR select a single Qt installation.",
+            name,
+            tool.display(),
+            tool_version.0,
+            tool_version.1,
+            qt_version,
+            name
+        )),
+        _ => Ok(()),
+    }
+}
+
+fn major_minor(version: &str) -> Option<(u32, u32)> {
+    let mut parts = version.trim_start_matches('(').split('.');
+    let major = parts.next()?.parse().ok()?;
+    let minor = parts.next()?.parse().ok()?;
+    Some((major, minor))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_output_name() {
+        let moc = Tool::moc(Path::new("moc"));
+        assert_eq!(moc.output_name(Path::new("src/object.h")), "moc_object.cpp");
+
+        let qsb = Tool::qsb(Path::new("qsb"), Vec::new());
+        assert_eq!(
+            qsb.output_name(Path::new("shaders/wobble.frag")),
+            "wobble.frag.qsb"
+        );
+    }
+
+    #[test]
+    fn test_compare_versions() {
+        let moc = Path::new("/usr/bin/moc");
+        assert!(compare_versions("moc", moc, "moc 5.15.2\n", "5.15.2").is_ok());
+        assert!(compare_versions("moc", moc, "moc 5.15.8\n", "5.15.2").is_ok());
+        assert!(compare_versions("moc", moc, "unknown\n", "5.15.2").is_ok());
+
+        let message = compare_versions("moc", moc, "moc 5.9.5\n", "5.15.2").unwrap_err();
+        assert!(message.starts_with("moc (/usr/bin/moc) is from Qt 5.9, but the Qt installation"));
+    }
+}
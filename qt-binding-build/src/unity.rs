@@ -0,0 +1,60 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Group source files in unity build batches
+///
+/// Each batch is written in `OUT_DIR` as a source file including the files of the batch.
+/// Returns the generated files, that replace the grouped files in the build.
+pub(crate) fn write_batches(
+    out_dir: &Path,
+    name: &str,
+    files: &[PathBuf],
+    batch_size: usize,
+) -> Vec<PathBuf> {
+    files
+        .chunks(batch_size.max(1))
+        .enumerate()
+        .map(|(index, batch)| {
+            let output = out_dir.join(format!("{}_unity_{}.cpp", name, index));
+            let files = batch
+                .iter()
+                .map(|file| fs::canonicalize(file).unwrap_or_else(|_| file.to_path_buf()))
+                .collect::<Vec<_>>();
+            fs::write(&output, batch_content(&files)).unwrap_or_else(|err| {
+                panic!(
+                    "Failed to write unity build file {}: {}",
+                    output.display(),
+                    err
+                )
+            });
+            output
+        })
+        .collect()
+}
+
+fn batch_content(files: &[PathBuf]) -> String {
+    files
+        .iter()
+        .map(|file| {
+            let path = file.to_string_lossy().replace('\\', "/");
+            format!("#include \"{}\"\n", path)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_batch_content() {
+        let files = vec![
+            PathBuf::from("/src/first.cpp"),
+            PathBuf::from("C:\\src\\second.cpp"),
+        ];
+        assert_eq!(
+            batch_content(&files),
+            "#include \"/src/first.cpp\"\n#include \"C:/src/second.cpp\"\n"
+        );
+    }
+}
@@ -0,0 +1,312 @@
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// DPI awareness declared in the application manifest
+///
+/// See Microsoft's documentation on high DPI desktop applications for more information.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DpiAwareness {
+    /// The application is scaled by Windows, and looks blurry on high DPI screens
+    Unaware,
+    /// The application is aware of the DPI of the primary screen
+    System,
+    /// The application is aware of the DPI of each screen
+    PerMonitor,
+    /// The application is aware of the DPI of each screen, with the improvements of Windows 10
+    ///
+    /// Falls back to `PerMonitor` on older versions of Windows.
+    PerMonitorV2,
+}
+
+impl DpiAwareness {
+    fn values(self) -> (&'static str, &'static str) {
+        match self {
+            DpiAwareness::Unaware => ("false", "unaware"),
+            DpiAwareness::System => ("true", "system"),
+            DpiAwareness::PerMonitor => ("true/pm", "PerMonitor"),
+            DpiAwareness::PerMonitorV2 => ("true/pm", "PerMonitorV2, PerMonitor"),
+        }
+    }
+}
+
+/// A Windows resource script, with an icon and an application manifest
+///
+/// `WindowsResource` generates a resource script, and a manifest, from a few options, to be
+/// compiled with [`Builder::windows_resource`].
+///
+/// [`Builder::windows_resource`]: struct.Builder.html#method.windows_resource
+///
+/// # Examples
+///
+/// ```no_run
+/// use qt_binding_build::{Builder, DpiAwareness, WindowsResource};
+///
+/// let mut resource = WindowsResource::new();
+/// resource
+///     .icon("assets/app.ico")
+///     .dpi_awareness(DpiAwareness::PerMonitorV2)
+///     .common_controls(true);
+///
+/// Builder::new()
+///     .file("src/app.cpp")
+///     .windows_resource(resource)
+///     .build("app");
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct WindowsResource {
+    icon: Option<PathBuf>,
+    dpi_awareness: Option<DpiAwareness>,
+    common_controls: bool,
+}
+
+impl WindowsResource {
+    /// New instance, without icon nor manifest
+    pub fn new() -> Self {
+        WindowsResource::default()
+    }
+
+    /// Icon of the executable, displayed by the file explorer, as an `.ico` file
+    pub fn icon<P>(&mut self, path: P) -> &mut Self
+    where
+        P: AsRef<Path>,
+    {
+        self.icon = Some(path.as_ref().to_path_buf());
+        self
+    }
+
+    /// Declare the DPI awareness of the application in the manifest
+    pub fn dpi_awareness(&mut self, dpi_awareness: DpiAwareness) -> &mut Self {
+        self.dpi_awareness = Some(dpi_awareness);
+        self
+    }
+
+    /// Depend on version 6 of the common controls in the manifest, for themed native dialogs
+    pub fn common_controls(&mut self, common_controls: bool) -> &mut Self {
+        self.common_controls = common_controls;
+        self
+    }
+
+    /// Write the resource script, and the manifest, in `OUT_DIR`
+    pub(crate) fn write(&self, out_dir: &Path, lib_name: &str) -> PathBuf {
+        let manifest = self.manifest_content().map(|content| {
+            let manifest = out_dir.join(format!("{}.manifest", lib_name));
+            write(&manifest, &content);
+            manifest
+        });
+        if let Some(icon) = &self.icon {
+            println!("cargo:rerun-if-changed={}", icon.display());
+        }
+
+        let icon = self.icon.as_ref().map(|icon| absolute(icon));
+        let rc = out_dir.join(format!("{}.rc", lib_name));
+        write(&rc, &rc_content(icon.as_deref(), manifest.as_deref()));
+        rc
+    }
+
+    fn manifest_content(&self) -> Option<String> {
+        if self.dpi_awareness.is_none() && !self.common_controls {
+            return None;
+        }
+
+        let mut content = String::from(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\" standalone=\"yes\"?>\n\
+             <assembly xmlns=\"urn:schemas-microsoft-com:asm.v1\" manifestVersion=\"1.0\">\n",
+        );
+        if let Some(dpi_awareness) = self.dpi_awareness {
+            let (dpi_aware, dpi_awareness) = dpi_awareness.values();
+            content.push_str(&format!(
+                "  <application xmlns=\"urn:schemas-microsoft-com:asm.v3\">\n    \
+                 <windowsSettings>\n      \
+                 <dpiAware xmlns=\"http://schemas.microsoft.com/SMI/2005/WindowsSettings\">\
+                 {}</dpiAware>\n      \
+                 <dpiAwareness xmlns=\"http://schemas.microsoft.com/SMI/2016/WindowsSettings\">\
+                 {}</dpiAwareness>\n    \
+                 </windowsSettings>\n  \
+                 </application>\n",
+                dpi_aware, dpi_awareness
+            ));
+        }
+        if self.common_controls {
+            content.push_str(
+                "  <dependency>\n    \
+                 <dependentAssembly>\n      \
+                 <assemblyIdentity type=\"win32\" name=\"Microsoft.Windows.Common-Controls\" \
+                 version=\"6.0.0.0\" processorArchitecture=\"*\" \
+                 publicKeyToken=\"6595b64144ccf1df\" language=\"*\"/>\n    \
+                 </dependentAssembly>\n  \
+                 </dependency>\n",
+            );
+        }
+        content.push_str("</assembly>\n");
+        Some(content)
+    }
+}
+
+fn rc_content(icon: Option<&Path>, manifest: Option<&Path>) -> String {
+    let mut content = String::new();
+    if let Some(icon) = icon {
+        content.push_str(&format!("1 ICON \"{}\"\n", slashes(icon)));
+    }
+    if let Some(manifest) = manifest {
+        // CREATEPROCESS_MANIFEST_RESOURCE_ID RT_MANIFEST
+        content.push_str(&format!("1 24 \"{}\"\n", slashes(manifest)));
+    }
+    content
+}
+
+/// Returns `true` if the crate is built for Windows
+pub(crate) fn is_target() -> bool {
+    env::var("CARGO_CFG_TARGET_OS").is_ok_and(|os| os == "windows")
+}
+
+/// Returns `true` if the crate is built for Windows with MinGW
+pub(crate) fn is_mingw_target() -> bool {
+    is_target() && env::var("CARGO_CFG_TARGET_ENV").is_ok_and(|target_env| target_env == "gnu")
+}
+
+/// Windows libraries needed by Qt when linking with MinGW
+///
+/// `rustc` does not link MinGW's default libraries, so they are passed explicitly, following
+/// `QMAKE_LIBS_CORE` and `QMAKE_LIBS_GUI` of the `win32-g++` mkspec.
+pub(crate) fn mingw_system_libs(modules: &[&str]) -> Vec<&'static str> {
+    let mut libs = vec![
+        "ole32", "uuid", "ws2_32", "advapi32", "shell32", "user32", "kernel32",
+    ];
+    if modules.contains(&"Gui") {
+        libs.extend(&["gdi32", "comdlg32", "oleaut32", "imm32", "winmm"]);
+    }
+    libs
+}
+
+/// Compile a resource script, and link it in the executables of the crate
+///
+/// Resources are compiled with `rc.exe` for MSVC targets, and with `windres` for GNU targets.
+/// `windres` can be overridden with the `WINDRES` environment variable. Returns the compiled
+/// resources.
+pub(crate) fn compile(out_dir: &Path, input: &Path) -> PathBuf {
+    println!("cargo:rerun-if-changed={}", input.display());
+    let stem = input
+        .file_stem()
+        .unwrap_or_else(|| panic!("rc takes files as input."))
+        .to_string_lossy();
+
+    let msvc = env::var("CARGO_CFG_TARGET_ENV").is_ok_and(|target_env| target_env == "msvc");
+    let (name, output, mut command) = if msvc {
+        let output = out_dir.join(format!("{}.res", stem));
+        let target = env::var("TARGET").unwrap_or_default();
+        let mut command =
+            cc::windows_registry::find(&target, "rc.exe").unwrap_or_else(|| Command::new("rc.exe"));
+        command.arg("/nologo").arg("/fo").arg(&output).arg(input);
+        ("rc.exe", output, command)
+    } else {
+        let output = out_dir.join(format!("{}_res.o", stem));
+        let mut command = Command::new(windres());
+        command
+            .arg("--input")
+            .arg(input)
+            .arg("--output-format=coff")
+            .arg("--output")
+            .arg(&output);
+        ("windres", output, command)
+    };
+
+    let result = command
+        .output()
+        .unwrap_or_else(|err| panic!("Failed to execute {}: {}", name, err));
+    if !result.status.success() {
+        panic!(
+            "Failed to execute {}.\n\n{}{}",
+            name,
+            String::from_utf8_lossy(&result.stdout),
+            String::from_utf8_lossy(&result.stderr)
+        )
+    }
+
+    // Resources are not referenced by any symbol, and would be discarded from a static library
+    println!("cargo:rustc-link-arg-bins={}", output.display());
+    output
+}
+
+fn windres() -> String {
+    if let Ok(windres) = env::var("WINDRES") {
+        return windres;
+    }
+    if cfg!(windows) {
+        "windres".to_string()
+    } else {
+        let arch = env::var("CARGO_CFG_TARGET_ARCH").unwrap_or_default();
+        let arch = if arch == "x86" { "i686" } else { &arch };
+        format!("{}-w64-mingw32-windres", arch)
+    }
+}
+
+fn absolute(path: &Path) -> PathBuf {
+    fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf())
+}
+
+fn slashes(path: &Path) -> String {
+    path.to_string_lossy()
+        .trim_start_matches(r"\\?\")
+        .replace('\\', "/")
+}
+
+fn write(path: &Path, content: &str) {
+    fs::write(path, content).unwrap_or_else(|err| {
+        panic!(
+            "Failed to write Windows resource {}: {}",
+            path.display(),
+            err
+        )
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rc_content() {
+        assert_eq!(rc_content(None, None), "");
+        assert_eq!(
+            rc_content(
+                Some(Path::new(r"C:\app\app.ico")),
+                Some(Path::new("/out/app.manifest"))
+            ),
+            "1 ICON \"C:/app/app.ico\"\n1 24 \"/out/app.manifest\"\n"
+        );
+    }
+
+    #[test]
+    fn test_mingw_system_libs() {
+        let core = mingw_system_libs(&["Core"]);
+        assert!(core.contains(&"ole32"));
+        assert!(!core.contains(&"gdi32"));
+
+        let gui = mingw_system_libs(&["Core", "Gui"]);
+        assert!(gui.starts_with(&core));
+        assert!(gui.contains(&"gdi32"));
+        assert!(gui.contains(&"imm32"));
+    }
+
+    #[test]
+    fn test_manifest_content() {
+        assert_eq!(WindowsResource::new().manifest_content(), None);
+
+        let content = WindowsResource::new()
+            .dpi_awareness(DpiAwareness::PerMonitorV2)
+            .manifest_content()
+            .unwrap();
+        assert!(content.contains(">true/pm</dpiAware>"));
+        assert!(content.contains(">PerMonitorV2, PerMonitor</dpiAwareness>"));
+        assert!(!content.contains("Common-Controls"));
+
+        let content = WindowsResource::new()
+            .common_controls(true)
+            .manifest_content()
+            .unwrap();
+        assert!(!content.contains("dpiAware"));
+        assert!(content.contains("name=\"Microsoft.Windows.Common-Controls\""));
+    }
+}
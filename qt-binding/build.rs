@@ -1,16 +1,119 @@
 use qt_binding_build::Builder;
 
 fn main() {
+    // Crates building their own bindings check that their qt-binding-build is compatible
+    println!(
+        "cargo:METADATA_VERSION={}",
+        qt_binding_build::METADATA_VERSION
+    );
+    println!("cargo:VERSION={}", env!("CARGO_PKG_VERSION"));
+
+    // The mock backend is implemented in Rust, and does not use Qt
+    if cfg!(feature = "mock") {
+        return;
+    }
+
     let mut builder = Builder::new();
-    builder.files(&["src/app.cpp", "src/variant.cpp", "src/variant/convert.cpp"]);
+    builder.files(&[
+        "src/animation.cpp",
+        "src/app.cpp",
+        "src/app/embed.cpp",
+        "src/file.cpp",
+        "src/locale.cpp",
+        "src/logging.cpp",
+        "src/mime.cpp",
+        "src/object.cpp",
+        "src/model/fetch.cpp",
+        "src/model/point_list.cpp",
+        "src/model/string_list.cpp",
+        "src/sharedmem.cpp",
+        "src/state.cpp",
+        "src/string.cpp",
+        "src/variant.cpp",
+        "src/variant/convert.cpp",
+    ]);
+    builder.moc_file("src/state.h");
 
     #[cfg(feature = "gui")]
     {
-        builder.define("QT_BINDING_WITH_GUI", "1");
+        builder
+            .define("QT_BINDING_WITH_GUI", "1")
+            .file("src/accessibility.cpp")
+            .file("src/app/platform.cpp")
+            .file("src/font.cpp")
+            .file("src/image.cpp")
+            .file("src/shortcut.cpp")
+            .file("src/text.cpp")
+            .file("src/theme.cpp")
+            .file("src/validator.cpp")
+            .file("src/window.cpp");
+    }
+    #[cfg(all(feature = "gui", feature = "raw-window-handle"))]
+    {
+        builder
+            .define("QT_BINDING_WITH_NATIVE_INTERFACE", "1")
+            .private_headers("Gui");
     }
     #[cfg(feature = "widgets")]
     {
-        builder.define("QT_BINDING_WITH_WIDGETS", "1");
+        builder
+            .define("QT_BINDING_WITH_WIDGETS", "1")
+            .file("src/crash.cpp")
+            .file("src/menu.cpp")
+            .file("src/notification.cpp")
+            .file("src/undo.cpp");
+    }
+
+    #[cfg(feature = "qml")]
+    {
+        builder
+            .file("src/qml.cpp")
+            .file("src/qml/component.cpp")
+            .file("src/qml/config.cpp")
+            .file("src/qml/dev_tools.cpp")
+            .file("src/qml/engine.cpp");
+    }
+    #[cfg(feature = "quick-test")]
+    {
+        builder.file("src/qml/test.cpp");
+    }
+
+    #[cfg(feature = "bluetooth")]
+    {
+        builder.file("src/bluetooth.cpp");
+    }
+    #[cfg(feature = "dbus")]
+    {
+        builder.moc_file("src/dbus.h").file("src/dbus.cpp");
+    }
+    #[cfg(feature = "multimedia")]
+    {
+        builder.file("src/multimedia.cpp");
+    }
+    #[cfg(feature = "pdf")]
+    {
+        builder.file("src/pdf.cpp");
+    }
+    #[cfg(feature = "positioning")]
+    {
+        builder.file("src/positioning.cpp");
+    }
+    #[cfg(feature = "printsupport")]
+    {
+        builder.file("src/print.cpp");
+    }
+    #[cfg(feature = "serialport")]
+    {
+        builder.file("src/serialport.cpp");
+    }
+    #[cfg(feature = "sql")]
+    {
+        builder.file("src/sql.cpp");
+    }
+
+    #[cfg(feature = "websockets")]
+    {
+        builder.file("src/websocket.cpp");
     }
 
     #[cfg(feature = "futures-executor")]
@@ -0,0 +1,79 @@
+#include <QtCore/QObject>
+#include <QtGui/QAccessible>
+#include <cstdint>
+#ifdef QT_BINDING_WITH_WIDGETS
+#include <QtWidgets/QWidget>
+#endif // QT_BINDING_WITH_WIDGETS
+
+QT_USE_NAMESPACE
+
+static QString toString(const char *value, std::uint32_t size)
+{
+    return QString::fromUtf8(value, static_cast<int>(size));
+}
+
+static QAccessible::Event toEvent(int event)
+{
+    switch (event) {
+    case 0:
+        return QAccessible::Focus;
+    case 1:
+        return QAccessible::NameChanged;
+    case 2:
+        return QAccessible::DescriptionChanged;
+    case 3:
+        return QAccessible::ValueChanged;
+    case 4:
+        return QAccessible::SelectionChanged;
+    case 5:
+        return QAccessible::Alert;
+    default:
+        return QAccessible::ObjectShow;
+    }
+}
+
+static bool setText(QObject *object, QAccessible::Text text, const QString &value)
+{
+#ifdef QT_BINDING_WITH_WIDGETS
+    if (auto widget = qobject_cast<QWidget *>(object)) {
+        if (text == QAccessible::Name) {
+            widget->setAccessibleName(value);
+        } else {
+            widget->setAccessibleDescription(value);
+        }
+        return true;
+    }
+#endif // QT_BINDING_WITH_WIDGETS
+    auto interface = QAccessible::queryAccessibleInterface(object);
+    if (interface == nullptr) {
+        return false;
+    }
+    interface->setText(text, value);
+    return true;
+}
+
+extern "C" {
+
+bool qt_binding_accessibility_is_active()
+{
+    return QAccessible::isActive();
+}
+
+bool qt_binding_accessibility_set_name(QObject *object, const char *name, std::uint32_t size)
+{
+    return setText(object, QAccessible::Name, toString(name, size));
+}
+
+bool qt_binding_accessibility_set_description(QObject *object, const char *description,
+                                              std::uint32_t size)
+{
+    return setText(object, QAccessible::Description, toString(description, size));
+}
+
+void qt_binding_accessibility_notify(QObject *object, int event)
+{
+    QAccessibleEvent accessibleEvent(object, toEvent(event));
+    QAccessible::updateAccessibility(&accessibleEvent);
+}
+
+} // extern "C"
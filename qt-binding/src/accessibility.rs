@@ -0,0 +1,140 @@
+//! Accessibility support
+//!
+//! Screen readers and other assistive technologies access the UI through Qt's accessibility
+//! layer. This module sets the accessible name and description of objects implementing
+//! [`Object`], and notifies assistive technologies when the state of a Rust-backed component
+//! changes, with [`notify`].
+//!
+//! [`Object`]: ../object/trait.Object.html
+//! [`notify`]: fn.notify.html
+//!
+//! # Features
+//!
+//! This module is only available with the `gui` feature.
+//!
+//! # Limitations
+//!
+//! Only objects exposed to the accessibility layer, like widgets and Qt Quick items, have an
+//! accessible name and description. [`set_name`] and [`set_description`] return `false` for
+//! other objects, like models. QML items set their accessible properties with the
+//! `Accessible` attached property instead.
+//!
+//! [`set_name`]: fn.set_name.html
+//! [`set_description`]: fn.set_description.html
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use qt_binding::accessibility::{self, Event};
+//! use qt_binding::app::Application;
+//! use qt_binding::menu::Menu;
+//! use qt_binding::object::Object;
+//!
+//! let mut app = Application::new();
+//! let mut menu = Menu::new("File");
+//! menu.set_object_name("fileMenu");
+//! accessibility::set_name(&mut menu, "File menu");
+//! accessibility::notify(&menu, Event::NameChanged);
+//! app.exec();
+//! ```
+
+use crate::object::Object;
+use std::os::raw::{c_char, c_int, c_void};
+
+/// Accessibility event
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Event {
+    /// The object received the focus
+    Focus,
+    /// The accessible name changed
+    NameChanged,
+    /// The accessible description changed
+    DescriptionChanged,
+    /// The value of the object changed
+    ValueChanged,
+    /// The selection changed
+    SelectionChanged,
+    /// The object requires the attention of the user
+    Alert,
+}
+
+impl Event {
+    fn as_c_int(self) -> c_int {
+        match self {
+            Event::Focus => 0,
+            Event::NameChanged => 1,
+            Event::DescriptionChanged => 2,
+            Event::ValueChanged => 3,
+            Event::SelectionChanged => 4,
+            Event::Alert => 5,
+        }
+    }
+}
+
+/// If an assistive technology is using the accessibility layer
+pub fn is_active() -> bool {
+    ffi_span!("accessibility", "is_active");
+    unsafe { qt_binding_accessibility_is_active() }
+}
+
+/// Set the accessible name of an object
+///
+/// Returns `false` if the object is not exposed to the accessibility layer.
+pub fn set_name<O, S>(object: &mut O, name: S) -> bool
+where
+    O: Object,
+    S: AsRef<str>,
+{
+    ffi_span!("accessibility", "set_name");
+    let name = name.as_ref();
+    unsafe {
+        qt_binding_accessibility_set_name(
+            object.as_object_ptr(),
+            name.as_ptr() as *const c_char,
+            name.len() as u32,
+        )
+    }
+}
+
+/// Set the accessible description of an object
+///
+/// Returns `false` if the object is not exposed to the accessibility layer.
+pub fn set_description<O, S>(object: &mut O, description: S) -> bool
+where
+    O: Object,
+    S: AsRef<str>,
+{
+    ffi_span!("accessibility", "set_description");
+    let description = description.as_ref();
+    unsafe {
+        qt_binding_accessibility_set_description(
+            object.as_object_ptr(),
+            description.as_ptr() as *const c_char,
+            description.len() as u32,
+        )
+    }
+}
+
+/// Notify assistive technologies of an event on an object
+pub fn notify<O>(object: &O, event: Event)
+where
+    O: Object,
+{
+    ffi_span!("accessibility", "notify");
+    unsafe { qt_binding_accessibility_notify(object.as_object_ptr(), event.as_c_int()) }
+}
+
+extern "C" {
+    fn qt_binding_accessibility_is_active() -> bool;
+    fn qt_binding_accessibility_set_name(
+        object: *mut c_void,
+        name: *const c_char,
+        size: u32,
+    ) -> bool;
+    fn qt_binding_accessibility_set_description(
+        object: *mut c_void,
+        description: *const c_char,
+        size: u32,
+    ) -> bool;
+    fn qt_binding_accessibility_notify(object: *mut c_void, event: c_int);
+}
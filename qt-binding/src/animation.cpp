@@ -0,0 +1,62 @@
+#include <QtCore/QEasingCurve>
+#include <QtCore/QPropertyAnimation>
+#include <QtCore/QVariant>
+#include <cstdint>
+
+QT_USE_NAMESPACE
+
+extern "C" {
+
+QPropertyAnimation *qt_binding_animation_create(QObject *target, const char *property,
+                                                std::uint32_t propertySize)
+{
+    return new QPropertyAnimation(target, QByteArray(property, static_cast<int>(propertySize)));
+}
+
+void qt_binding_animation_delete(QPropertyAnimation *animation)
+{
+    delete animation;
+}
+
+void qt_binding_animation_set_start_value(QPropertyAnimation *animation, const QVariant *value)
+{
+    animation->setStartValue(*value);
+}
+
+void qt_binding_animation_set_end_value(QPropertyAnimation *animation, const QVariant *value)
+{
+    animation->setEndValue(*value);
+}
+
+void qt_binding_animation_set_duration(QPropertyAnimation *animation, int msecs)
+{
+    animation->setDuration(msecs);
+}
+
+void qt_binding_animation_set_easing(QPropertyAnimation *animation, int easing)
+{
+    animation->setEasingCurve(static_cast<QEasingCurve::Type>(easing));
+}
+
+void qt_binding_animation_start(QPropertyAnimation *animation)
+{
+    animation->stop();
+    animation->start();
+}
+
+void qt_binding_animation_stop(QPropertyAnimation *animation)
+{
+    animation->stop();
+}
+
+bool qt_binding_animation_is_running(const QPropertyAnimation *animation)
+{
+    return animation->state() == QAbstractAnimation::Running;
+}
+
+QVariant *qt_binding_animation_current_value(const QPropertyAnimation *animation)
+{
+    return new QVariant(animation->currentValue());
+}
+
+} // extern "C"
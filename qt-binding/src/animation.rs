@@ -0,0 +1,255 @@
+//! Property animations driven from Rust
+//!
+//! [`Animation`] interpolates a property of a Qt object, like the `progress` of an object
+//! exposed to QML, from its current value to a target value, with an easing curve. Values
+//! computed by backend logic can then change smoothly in the UI, without duplicating
+//! animation code in QML.
+//!
+//! Animations run in the Qt event loop. Numbers, points, sizes, rectangles and colors are
+//! interpolated.
+//!
+//! [`Animation`]: struct.Animation.html
+//!
+//! # Limitations
+//!
+//! Since `Animation` is a binding over a Qt object, it is neither `Send` nor `Sync`. Dropping
+//! an `Animation` stops it.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use qt_binding::animation::{Animation, Easing};
+//! use qt_binding::object::{Object, ObjectHandle};
+//! use std::time::Duration;
+//!
+//! // The returned animation is kept alive while it runs
+//! fn animate_progress(progress: &ObjectHandle, value: f64) -> Animation {
+//!     progress.animate("value", |animation| {
+//!         animation
+//!             .to(value)
+//!             .duration(Duration::from_millis(300))
+//!             .easing(Easing::OutCubic)
+//!     })
+//! }
+//! ```
+
+use crate::app;
+use crate::object::Object;
+use crate::variant::Variant;
+use std::fmt;
+use std::os::raw::{c_char, c_int, c_void};
+use std::time::Duration;
+
+/// An easing curve, controlling the speed of an animation over time
+///
+/// See `QEasingCurve` for a representation of the curves.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Easing {
+    /// Constant speed
+    Linear,
+    /// Quadratic, accelerating from zero velocity
+    InQuad,
+    /// Quadratic, decelerating to zero velocity
+    OutQuad,
+    /// Quadratic, accelerating until halfway, then decelerating
+    InOutQuad,
+    /// Cubic, accelerating from zero velocity
+    InCubic,
+    /// Cubic, decelerating to zero velocity
+    OutCubic,
+    /// Cubic, accelerating until halfway, then decelerating
+    InOutCubic,
+    /// Sinusoidal, accelerating from zero velocity
+    InSine,
+    /// Sinusoidal, decelerating to zero velocity
+    OutSine,
+    /// Sinusoidal, accelerating until halfway, then decelerating
+    InOutSine,
+    /// Exponential, accelerating from zero velocity
+    InExpo,
+    /// Exponential, decelerating to zero velocity
+    OutExpo,
+    /// Exponential, accelerating until halfway, then decelerating
+    InOutExpo,
+    /// Overshooting backward, then accelerating
+    InBack,
+    /// Overshooting the target value, then coming back
+    OutBack,
+    /// Overshooting at both ends
+    InOutBack,
+    /// Bouncing at the start
+    InBounce,
+    /// Bouncing at the end
+    OutBounce,
+    /// Bouncing at both ends
+    InOutBounce,
+}
+
+impl Easing {
+    /// Value of `QEasingCurve::Type`
+    fn value(self) -> c_int {
+        match self {
+            Easing::Linear => 0,
+            Easing::InQuad => 1,
+            Easing::OutQuad => 2,
+            Easing::InOutQuad => 3,
+            Easing::InCubic => 5,
+            Easing::OutCubic => 6,
+            Easing::InOutCubic => 7,
+            Easing::InSine => 17,
+            Easing::OutSine => 18,
+            Easing::InOutSine => 19,
+            Easing::InExpo => 21,
+            Easing::OutExpo => 22,
+            Easing::InOutExpo => 23,
+            Easing::InBack => 33,
+            Easing::OutBack => 34,
+            Easing::InOutBack => 35,
+            Easing::InBounce => 37,
+            Easing::OutBounce => 38,
+            Easing::InOutBounce => 39,
+        }
+    }
+}
+
+/// An animation of a property of a Qt object
+///
+/// The animation starts from the value of the property when it is started, unless a start
+/// value is set with [`from`], and lasts 250 milliseconds by default. [`Object::animate`]
+/// creates and starts an animation in one call.
+///
+/// See module level documentation for more information.
+///
+/// [`from`]: #method.from
+/// [`Object::animate`]: ../object/trait.Object.html#method.animate
+pub struct Animation {
+    ptr: *mut c_void,
+}
+
+impl Animation {
+    /// Creates an `Animation` of a property of an object
+    ///
+    /// The animation is stopped when the object is destroyed.
+    pub fn new<O, S>(target: &O, property: S) -> Self
+    where
+        O: Object,
+        S: AsRef<str>,
+    {
+        ffi_span!("Animation", "new");
+        let property = property.as_ref();
+        let ptr = unsafe {
+            qt_binding_animation_create(
+                target.as_object_ptr(),
+                property.as_ptr() as *const c_char,
+                property.len() as u32,
+            )
+        };
+        track_created!(Animation);
+        Animation { ptr }
+    }
+
+    /// Value at the start of the animation
+    pub fn from<V>(&mut self, value: V) -> &mut Self
+    where
+        V: Into<Variant>,
+    {
+        ffi_span!("Animation", "from");
+        let value = value.into();
+        unsafe { qt_binding_animation_set_start_value(self.ptr, value.as_ptr()) }
+        self
+    }
+
+    /// Value at the end of the animation
+    pub fn to<V>(&mut self, value: V) -> &mut Self
+    where
+        V: Into<Variant>,
+    {
+        ffi_span!("Animation", "to");
+        let value = value.into();
+        unsafe { qt_binding_animation_set_end_value(self.ptr, value.as_ptr()) }
+        self
+    }
+
+    /// Duration of the animation
+    pub fn duration(&mut self, duration: Duration) -> &mut Self {
+        ffi_span!("Animation", "duration");
+        let msecs = duration.as_millis().min(c_int::MAX as u128) as c_int;
+        unsafe { qt_binding_animation_set_duration(self.ptr, msecs) }
+        self
+    }
+
+    /// Easing curve of the animation, `Linear` by default
+    pub fn easing(&mut self, easing: Easing) -> &mut Self {
+        ffi_span!("Animation", "easing");
+        unsafe { qt_binding_animation_set_easing(self.ptr, easing.value()) }
+        self
+    }
+
+    /// Start the animation
+    ///
+    /// Starting a running animation restarts it.
+    pub fn start(&mut self) {
+        ffi_span!("Animation", "start");
+        unsafe { qt_binding_animation_start(self.ptr) }
+    }
+
+    /// Stop the animation, leaving the property at its current value
+    pub fn stop(&mut self) {
+        ffi_span!("Animation", "stop");
+        unsafe { qt_binding_animation_stop(self.ptr) }
+    }
+
+    /// If the animation is running
+    pub fn is_running(&self) -> bool {
+        ffi_span!("Animation", "is_running");
+        unsafe { qt_binding_animation_is_running(self.ptr) }
+    }
+
+    /// Current interpolated value
+    pub fn current_value(&self) -> Variant {
+        ffi_span!("Animation", "current_value");
+        Variant::from_ptr(unsafe { qt_binding_animation_current_value(self.ptr) })
+    }
+}
+
+impl fmt::Debug for Animation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Animation")
+            .field("running", &self.is_running())
+            .finish()
+    }
+}
+
+impl Object for Animation {
+    fn as_object_ptr(&self) -> *mut c_void {
+        self.ptr
+    }
+}
+
+impl Drop for Animation {
+    fn drop(&mut self) {
+        ffi_span!("Animation", "drop");
+        if app::should_leak("Animation") {
+            return;
+        }
+        track_destroyed!(Animation);
+        unsafe { qt_binding_animation_delete(self.ptr) }
+    }
+}
+
+extern "C" {
+    fn qt_binding_animation_create(
+        target: *mut c_void,
+        property: *const c_char,
+        property_size: u32,
+    ) -> *mut c_void;
+    fn qt_binding_animation_delete(animation: *mut c_void);
+    fn qt_binding_animation_set_start_value(animation: *mut c_void, value: *const c_void);
+    fn qt_binding_animation_set_end_value(animation: *mut c_void, value: *const c_void);
+    fn qt_binding_animation_set_duration(animation: *mut c_void, msecs: c_int);
+    fn qt_binding_animation_set_easing(animation: *mut c_void, easing: c_int);
+    fn qt_binding_animation_start(animation: *mut c_void);
+    fn qt_binding_animation_stop(animation: *mut c_void);
+    fn qt_binding_animation_is_running(animation: *const c_void) -> bool;
+    fn qt_binding_animation_current_value(animation: *const c_void) -> *mut c_void;
+}
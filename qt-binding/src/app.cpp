@@ -8,8 +8,19 @@
 #endif // QT_BINDING_WITH_WIDGETS
 #endif // QT_BINDING_WITH_GUI
 
+#include <cstdint>
 #include <memory>
 
+QT_USE_NAMESPACE
+
+#ifndef QT_BINDING_QT_VERSION
+#define QT_BINDING_QT_VERSION QT_VERSION_STR
+#endif // QT_BINDING_QT_VERSION
+
+#ifndef QT_BINDING_QT_LIB_DIR
+#define QT_BINDING_QT_LIB_DIR "unknown"
+#endif // QT_BINDING_QT_LIB_DIR
+
 namespace {
 
 template <typename T>
@@ -83,6 +94,11 @@ void qt_binding_application_delete(qt_binding::AppContainer *app)
     delete app;
 }
 
+QCoreApplication *qt_binding_application_instance()
+{
+    return QCoreApplication::instance();
+}
+
 int qt_binding_application_exec(qt_binding::AppContainer *app)
 {
     return app->m_app->exec();
@@ -93,4 +109,50 @@ void qt_binding_application_exit(int code)
     qt_binding::Application::exit(code);
 }
 
+void qt_binding_application_set_organization_name(const char *name, std::uint32_t size)
+{
+    QCoreApplication::setOrganizationName(QString::fromUtf8(name, static_cast<int>(size)));
+}
+
+void qt_binding_application_set_organization_domain(const char *domain, std::uint32_t size)
+{
+    QCoreApplication::setOrganizationDomain(QString::fromUtf8(domain, static_cast<int>(size)));
+}
+
+void qt_binding_application_set_application_name(const char *name, std::uint32_t size)
+{
+    QCoreApplication::setApplicationName(QString::fromUtf8(name, static_cast<int>(size)));
+}
+
+void qt_binding_application_set_application_version(const char *version, std::uint32_t size)
+{
+    QCoreApplication::setApplicationVersion(QString::fromUtf8(version, static_cast<int>(size)));
+}
+
+void qt_binding_application_set_high_dpi_scaling(bool enabled)
+{
+    QCoreApplication::setAttribute(Qt::AA_EnableHighDpiScaling, enabled);
+    QCoreApplication::setAttribute(Qt::AA_DisableHighDpiScaling, !enabled);
+}
+
+const char *qt_binding_version_build()
+{
+    return QT_BINDING_QT_VERSION;
+}
+
+const char *qt_binding_version_build_lib_dir()
+{
+    return QT_BINDING_QT_LIB_DIR;
+}
+
+const char *qt_binding_version_headers()
+{
+    return QT_VERSION_STR;
+}
+
+const char *qt_binding_version_runtime()
+{
+    return qVersion();
+}
+
 } // extern "C"
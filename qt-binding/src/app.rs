@@ -17,6 +17,52 @@
 //! By enabling the `futures-executor` feature, the Qt event-loop can act as a mono-threaded
 //! executor to run futures.
 //!
+//! With the feature `gui` enabled, the platform plugin, like `xcb` or `wayland` on Linux, can be
+//! selected with [`set_platform`] before creating the `Application`, and detected with
+//! [`platform_name`].
+//!
+//! [`set_platform`]: struct.Application.html#method.set_platform
+//! [`platform_name`]: struct.Application.html#method.platform_name
+//!
+//! # Existing applications
+//!
+//! When Rust code is loaded in an application created by C++, like a QML plugin,
+//! [`Application::instance`] returns the running application, instead of creating a new one.
+//!
+//! [`Application::instance`]: struct.Application.html#method.instance
+//!
+//! # Foreign event loops
+//!
+//! Applications that own their main loop can service Qt from it, instead of calling [`exec`].
+//! See the [`embed`] module for more information.
+//!
+//! [`embed`]: embed/index.html
+//!
+//! # Teardown
+//!
+//! [`Application::new`] returns an [`AppGuard`], that owns the underlying Qt application:
+//! dropping the guard destroys the Qt application and the state Qt keeps alongside it. Objects
+//! that are backed by Qt, like [`Variant`], should be dropped before the guard, which happens
+//! naturally when they are declared after it in the same scope.
+//!
+//! The lifecycle of `Application` is tracked at runtime. An object backed by a `QObject`, by a
+//! Qt plugin, like SQL databases, image readers and printers, or by a `Variant`, that is dropped
+//! after the `Application` has been destroyed is leaked instead of being freed, instead of
+//! crashing the program while it exits. A warning is then reported once, with the [`log`] or
+//! [`tracing`] crate when the `log` or `tracing` feature is enabled, and on the standard error
+//! otherwise. Plain values that do not depend on the `Application`, like locales, MIME types,
+//! font metrics, Bluetooth device information, files and shared memory, are always released, so
+//! that files are cleaned up and shared memory is unlocked and detached.
+//!
+//! Futures spawned with [`spawn`] that are still pending are dropped with the `Application`.
+//!
+//! [`Application::new`]: struct.Application.html#method.new
+//! [`AppGuard`]: struct.AppGuard.html
+//! [`Variant`]: ../variant/struct.Variant.html
+//! [`log`]: https://docs.rs/log
+//! [`tracing`]: https://docs.rs/tracing
+//! [`spawn`]: struct.Application.html#method.spawn
+//!
 //! # Examples
 //!
 //! The Qt event-loop is often used as follow
@@ -33,12 +79,32 @@
 //! }
 //! ```
 
+pub mod embed;
 #[cfg(feature = "futures-executor")]
 pub mod futures;
+mod instance;
+#[cfg(feature = "gui")]
+mod platform;
+mod version;
+
+pub use self::instance::AppRef;
+#[cfg(feature = "gui")]
+pub use self::platform::Platform;
+
+#[cfg(feature = "futures-executor")]
+use self::futures::init_runtime;
+#[cfg(feature = "gui")]
+use self::platform::arguments as platform_arguments;
 
 use std::env;
 use std::ffi::{c_void, CString};
+use std::ops::{Deref, DerefMut};
 use std::os::raw::{c_char, c_int};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+static ALIVE: AtomicBool = AtomicBool::new(false);
+static DESTROYED: AtomicBool = AtomicBool::new(false);
+static LEAK_WARNED: AtomicBool = AtomicBool::new(false);
 
 /// Binding to Qt main application and event-loop
 ///
@@ -51,9 +117,23 @@ impl Application {
     /// Constructor
     ///
     /// This constructor will read all the program arguments and pass it to
-    /// the underlying `QCoreApplication` or `QGuiApplication`.
-    pub fn new() -> Self {
-        let argv_strings = env::args()
+    /// the underlying `QCoreApplication` or `QGuiApplication`. The application is owned by the
+    /// returned [`AppGuard`], and is destroyed when the guard is dropped.
+    ///
+    /// [`AppGuard`]: struct.AppGuard.html
+    ///
+    /// # Panics
+    ///
+    /// This constructor will panic with a user-friendly error message if the Qt libraries loaded
+    /// at runtime are not compatible with the Qt installation used to build `qt-binding`.
+    #[allow(clippy::new_ret_no_self)]
+    pub fn new() -> AppGuard {
+        ffi_span!("Application", "new");
+        version::check();
+        let mut args = env::args();
+        let argv_strings = (args.next().into_iter())
+            .chain(platform_arguments())
+            .chain(args)
             .map(|arg| CString::new(arg).unwrap())
             .collect::<Vec<_>>();
         let argv = (argv_strings.iter())
@@ -61,8 +141,13 @@ impl Application {
             .collect::<Vec<_>>();
 
         let ptr = unsafe { qt_binding_application_create(argv.len() as c_int, argv.as_ptr()) };
-        let app = Application { ptr };
-        app.initialized()
+        track_created!(Application);
+        ALIVE.store(true, Ordering::SeqCst);
+        DESTROYED.store(false, Ordering::SeqCst);
+        init_runtime();
+        AppGuard {
+            app: Application { ptr },
+        }
     }
 
     /// Starts the event loop
@@ -71,6 +156,7 @@ impl Application {
     ///
     /// [`exit`]: #method.exit
     pub fn exec(&mut self) -> i32 {
+        ffi_span!("Application", "exec");
         unsafe { qt_binding_application_exec(self.ptr) as i32 }
     }
 
@@ -81,6 +167,7 @@ impl Application {
     ///
     /// [`exec`]: #method.exec
     pub fn exit(code: i32) {
+        ffi_span!("Application", "exit");
         unsafe { qt_binding_application_exit(code as c_int) }
     }
 
@@ -94,24 +181,202 @@ impl Application {
         Application::exit(0)
     }
 
-    #[cfg(not(feature = "futures-executor"))]
-    fn initialized(self) -> Self {
-        self
+    /// Set the name of the organization that wrote the application
+    ///
+    /// The organization name and the application name are used by `QSettings` and
+    /// `QStandardPaths` to locate the settings and the data of the application. They can be
+    /// set before creating the `Application`.
+    pub fn set_organization_name<S>(name: S)
+    where
+        S: AsRef<str>,
+    {
+        ffi_span!("Application", "set_organization_name");
+        let name = name.as_ref();
+        unsafe {
+            qt_binding_application_set_organization_name(
+                name.as_ptr() as *const c_char,
+                name.len() as u32,
+            )
+        }
+    }
+
+    /// Set the Internet domain of the organization that wrote the application
+    ///
+    /// On macOS, `QSettings` uses the reversed domain, followed by the application name, as
+    /// identifier: it should match the bundle identifier of the application. With
+    /// `example.com` and `App`, the identifier is `com.example.App`.
+    pub fn set_organization_domain<S>(domain: S)
+    where
+        S: AsRef<str>,
+    {
+        ffi_span!("Application", "set_organization_domain");
+        let domain = domain.as_ref();
+        unsafe {
+            qt_binding_application_set_organization_domain(
+                domain.as_ptr() as *const c_char,
+                domain.len() as u32,
+            )
+        }
+    }
+
+    /// Set the name of the application
+    ///
+    /// Defaults to the name of the executable, or to the bundle name on macOS.
+    pub fn set_application_name<S>(name: S)
+    where
+        S: AsRef<str>,
+    {
+        ffi_span!("Application", "set_application_name");
+        let name = name.as_ref();
+        unsafe {
+            qt_binding_application_set_application_name(
+                name.as_ptr() as *const c_char,
+                name.len() as u32,
+            )
+        }
+    }
+
+    /// Set the version of the application
+    ///
+    /// Defaults to the bundle version on macOS.
+    pub fn set_application_version<S>(version: S)
+    where
+        S: AsRef<str>,
+    {
+        ffi_span!("Application", "set_application_version");
+        let version = version.as_ref();
+        unsafe {
+            qt_binding_application_set_application_version(
+                version.as_ptr() as *const c_char,
+                version.len() as u32,
+            )
+        }
+    }
+
+    /// Check if an `Application` is alive
+    ///
+    /// Returns `true` between the creation of an `Application` and its destruction.
+    pub fn is_alive() -> bool {
+        ALIVE.load(Ordering::SeqCst)
+    }
+
+    /// Enable or disable high DPI scaling
+    ///
+    /// When enabled, windows are scaled following the device pixel ratio of their screen.
+    /// By default, Qt 5 only scales on platforms that scale natively, like macOS.
+    ///
+    /// # Features
+    ///
+    /// This function requires the `gui` feature.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if an `Application` is alive, as scaling is configured when the
+    /// `Application` is created.
+    #[cfg(feature = "gui")]
+    pub fn set_high_dpi_scaling(enabled: bool) {
+        ffi_span!("Application", "set_high_dpi_scaling");
+        assert!(
+            !Application::is_alive(),
+            "High DPI scaling must be configured before creating the Application"
+        );
+        unsafe { qt_binding_application_set_high_dpi_scaling(enabled) }
+    }
+
+    /// Scale all the windows by a factor
+    ///
+    /// The factor is applied in addition to high DPI scaling, and is set as the
+    /// `QT_SCALE_FACTOR` environment variable.
+    ///
+    /// # Features
+    ///
+    /// This function requires the `gui` feature.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if an `Application` is alive, as scaling is configured when the
+    /// `Application` is created.
+    #[cfg(feature = "gui")]
+    pub fn set_scale_factor(factor: f64) {
+        assert!(
+            !Application::is_alive(),
+            "Scale factor must be configured before creating the Application"
+        );
+        env::set_var("QT_SCALE_FACTOR", factor.to_string());
     }
 }
 
-impl Drop for Application {
+/// Owner of the application created by [`Application::new`]
+///
+/// Dropping the `AppGuard` destroys the Qt application. The guard dereferences to the
+/// [`Application`], that cannot be moved out of it, so that the application lives exactly as
+/// long as the guard.
+///
+/// See module level documentation for more information about teardown.
+///
+/// [`Application::new`]: struct.Application.html#method.new
+/// [`Application`]: struct.Application.html
+#[must_use = "the Application is destroyed when the AppGuard is dropped"]
+pub struct AppGuard {
+    app: Application,
+}
+
+impl Deref for AppGuard {
+    type Target = Application;
+
+    fn deref(&self) -> &Application {
+        &self.app
+    }
+}
+
+impl DerefMut for AppGuard {
+    fn deref_mut(&mut self) -> &mut Application {
+        &mut self.app
+    }
+}
+
+impl Drop for AppGuard {
     fn drop(&mut self) {
+        ffi_span!("Application", "drop");
+        track_destroyed!(Application);
+        ALIVE.store(false, Ordering::SeqCst);
         unsafe {
-            qt_binding_application_delete(self.ptr);
+            qt_binding_application_delete(self.app.ptr);
         }
+        DESTROYED.store(true, Ordering::SeqCst);
+        #[cfg(feature = "diagnostics")]
+        crate::diagnostics::warn_live_objects();
     }
 }
 
-impl Default for Application {
+impl Default for AppGuard {
     fn default() -> Self {
-        Self::new()
+        Application::new()
+    }
+}
+
+#[cfg(not(feature = "futures-executor"))]
+fn init_runtime() {}
+
+#[cfg(not(feature = "gui"))]
+fn platform_arguments() -> Vec<String> {
+    Vec::new()
+}
+
+/// Check if an object backed by a `QObject` or a Qt plugin should be leaked when dropped
+///
+/// Returns `true`, and warns once, if the `Application` has already been destroyed. Objects
+/// that do not depend on the `Application` should always be released instead.
+pub(crate) fn should_leak(object: &str) -> bool {
+    let destroyed = DESTROYED.load(Ordering::SeqCst) && !ALIVE.load(Ordering::SeqCst);
+    if destroyed && !LEAK_WARNED.swap(true, Ordering::SeqCst) {
+        warning!(
+            "qt-binding: {} dropped after Application was destroyed. \
+             It will be leaked. Drop Qt-backed objects before Application.",
+            object
+        );
     }
+    destroyed
 }
 
 extern "C" {
@@ -120,4 +385,10 @@ extern "C" {
 
     fn qt_binding_application_exec(app: *mut c_void) -> c_int;
     fn qt_binding_application_exit(code: c_int);
+    fn qt_binding_application_set_organization_name(name: *const c_char, size: u32);
+    fn qt_binding_application_set_organization_domain(domain: *const c_char, size: u32);
+    fn qt_binding_application_set_application_name(name: *const c_char, size: u32);
+    fn qt_binding_application_set_application_version(version: *const c_char, size: u32);
+    #[cfg(feature = "gui")]
+    fn qt_binding_application_set_high_dpi_scaling(enabled: bool);
 }
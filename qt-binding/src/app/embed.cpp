@@ -0,0 +1,95 @@
+#include <QtCore/QAbstractEventDispatcher>
+#include <QtCore/QCoreApplication>
+#include <QtCore/QEventLoop>
+#include <QtCore/QThread>
+#include <atomic>
+#ifdef Q_OS_UNIX
+#include <fcntl.h>
+#include <unistd.h>
+#endif // Q_OS_UNIX
+
+QT_USE_NAMESPACE
+
+namespace {
+
+#ifdef Q_OS_UNIX
+// Read and write ends of the wake-up pipe, -1 until the pipe is created
+std::atomic<int> wakeUpReadFd{-1};
+std::atomic<int> wakeUpWriteFd{-1};
+
+void drainWakeUpFd()
+{
+    const auto fd = wakeUpReadFd.load();
+    if (fd < 0) {
+        return;
+    }
+
+    char buffer[64];
+    while (::read(fd, buffer, sizeof(buffer)) > 0) {
+    }
+}
+#endif // Q_OS_UNIX
+
+} // namespace
+
+extern "C" {
+
+void qt_binding_application_process_events(int maxTime)
+{
+#ifdef Q_OS_UNIX
+    drainWakeUpFd();
+#endif // Q_OS_UNIX
+
+    if (maxTime <= 0) {
+        QCoreApplication::processEvents(QEventLoop::AllEvents);
+    } else {
+        QCoreApplication::processEvents(QEventLoop::AllEvents, maxTime);
+    }
+    // Objects deleted with deleteLater are only deleted by event loops
+    QCoreApplication::sendPostedEvents(nullptr, QEvent::DeferredDelete);
+}
+
+void qt_binding_application_wake_up()
+{
+#ifdef Q_OS_UNIX
+    const auto fd = wakeUpWriteFd.load();
+    if (fd >= 0) {
+        const char byte = 0;
+        // The pipe is non-blocking: if it is full, the loop is already woken up
+        (void)::write(fd, &byte, 1);
+    }
+#endif // Q_OS_UNIX
+
+    const auto app = QCoreApplication::instance();
+    if (app != nullptr) {
+        if (auto dispatcher = QAbstractEventDispatcher::instance(app->thread())) {
+            dispatcher->wakeUp();
+        }
+    }
+}
+
+int qt_binding_application_wake_up_fd()
+{
+#ifdef Q_OS_UNIX
+    auto fd = wakeUpReadFd.load();
+    if (fd >= 0) {
+        return fd;
+    }
+
+    int fds[2];
+    if (::pipe(fds) != 0) {
+        return -1;
+    }
+    for (auto pipeFd : fds) {
+        ::fcntl(pipeFd, F_SETFL, ::fcntl(pipeFd, F_GETFL) | O_NONBLOCK);
+        ::fcntl(pipeFd, F_SETFD, FD_CLOEXEC);
+    }
+    wakeUpWriteFd.store(fds[1]);
+    wakeUpReadFd.store(fds[0]);
+    return fds[0];
+#else // Q_OS_UNIX
+    return -1;
+#endif // Q_OS_UNIX
+}
+
+} // extern "C"
@@ -0,0 +1,131 @@
+//! Foreign event loop support for `Application`
+//!
+//! Some applications, like game engines or audio hosts, own their main loop. Instead of
+//! calling [`Application::exec`], they service Qt from their own loop, by calling
+//! [`Application::process_events`] on every iteration.
+//!
+//! A foreign loop that sleeps while waiting for its own events is woken up with a [`Waker`].
+//! On Unix, the loop can also wait on the file descriptor returned by
+//! [`Application::wake_up_fd`], that becomes readable when a `Waker` is woken up, and when
+//! a future is spawned or woken, or a value is delivered, with the `futures-executor` feature.
+//!
+//! [`Application::exec`]: ../struct.Application.html#method.exec
+//! [`Application::process_events`]: ../struct.Application.html#method.process_events
+//! [`Application::wake_up_fd`]: ../struct.Application.html#method.wake_up_fd
+//! [`Waker`]: struct.Waker.html
+//!
+//! # Limitations
+//!
+//! Events posted by Qt itself, like queued signals or timers, do not wake up the foreign loop,
+//! and do not make the file descriptor readable. A foreign loop that only waits on the file
+//! descriptor stalls Qt work. It should also process events regularly, like once per frame,
+//! or wake up at the pace of the timers it uses.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use qt_binding::app::Application;
+//! use std::time::Duration;
+//!
+//! let mut app = Application::new();
+//! loop {
+//!     // Update and render a frame
+//!     app.process_events(Duration::from_millis(4));
+//! #   break;
+//! }
+//! ```
+
+use crate::app::Application;
+use std::os::raw::c_int;
+#[cfg(unix)]
+use std::os::unix::io::RawFd;
+use std::time::Duration;
+
+impl Application {
+    /// Process the pending events, without waiting for new events
+    ///
+    /// Objects deleted with `deleteLater` are also deleted.
+    pub fn process_events_once(&mut self) {
+        ffi_span!("Application", "process_events_once");
+        unsafe { qt_binding_application_process_events(0) }
+    }
+
+    /// Process events until there are no more pending events, or for at most `max_time`
+    ///
+    /// Objects deleted with `deleteLater` are also deleted.
+    pub fn process_events(&mut self, max_time: Duration) {
+        ffi_span!("Application", "process_events");
+        let milliseconds = max_time.as_millis().min(c_int::MAX as u128) as c_int;
+        unsafe { qt_binding_application_process_events(milliseconds.max(1)) }
+    }
+
+    /// Get a [`Waker`] that wakes up the event loop
+    ///
+    /// [`Waker`]: embed/struct.Waker.html
+    pub fn waker(&self) -> Waker {
+        Waker { _private: () }
+    }
+
+    /// File descriptor that becomes readable when a [`Waker`] is woken up
+    ///
+    /// The file descriptor also becomes readable when futures are spawned or woken, and when
+    /// values are delivered, with [`spawn`] and [`deliver`]. Events posted by Qt and timers do
+    /// not make it readable, see the [limitations] of foreign event loops.
+    ///
+    /// The file descriptor is drained by [`process_events`] and [`process_events_once`]. It
+    /// can be registered in `poll`, `epoll` or the loop of an asynchronous runtime. It is
+    /// created on the first call, and stays open for the lifetime of the process, as `Waker`s
+    /// might still use it from other threads: it is not closed when the `Application` is
+    /// destroyed, and is shared with the `Application`s created after.
+    ///
+    /// [`Waker`]: embed/struct.Waker.html
+    /// [`spawn`]: #method.spawn
+    /// [`deliver`]: #method.deliver
+    /// [limitations]: embed/index.html#limitations
+    /// [`process_events`]: #method.process_events
+    /// [`process_events_once`]: #method.process_events_once
+    ///
+    /// # Panics
+    ///
+    /// Panics if the file descriptor cannot be created.
+    #[cfg(unix)]
+    pub fn wake_up_fd(&self) -> RawFd {
+        ffi_span!("Application", "wake_up_fd");
+        let fd = unsafe { qt_binding_application_wake_up_fd() };
+        if fd < 0 {
+            panic!("Cannot create the wake-up file descriptor");
+        }
+        fd as RawFd
+    }
+}
+
+/// Wakes up the event loop
+///
+/// Waking up interrupts Qt's event loop if it is waiting for events, and makes the file
+/// descriptor returned by [`Application::wake_up_fd`] readable. `Waker` can be used from any
+/// thread.
+///
+/// [`Application::wake_up_fd`]: ../struct.Application.html#method.wake_up_fd
+#[derive(Clone, Debug)]
+pub struct Waker {
+    _private: (),
+}
+
+impl Waker {
+    /// Wake up the event loop
+    pub fn wake(&self) {
+        wake_up();
+    }
+}
+
+pub(in crate::app) fn wake_up() {
+    ffi_span!("Waker", "wake");
+    unsafe { qt_binding_application_wake_up() }
+}
+
+extern "C" {
+    fn qt_binding_application_process_events(max_time: c_int);
+    fn qt_binding_application_wake_up();
+    #[cfg(unix)]
+    fn qt_binding_application_wake_up_fd() -> c_int;
+}
@@ -1,28 +1,73 @@
 #include "futures.h"
 
 #include <QtCore/QCoreApplication>
+#include <QtCore/QMutex>
+#include <QtCore/QMutexLocker>
 
 namespace qt_binding {
 
-QtRuntime::QtRuntime(ExecTaskFunc execTask, QObject *parent)
+namespace {
+
+// Tasks are queued from any thread, while the runtime is created and destroyed with the
+// application in the main thread
+QMutex runtimeMutex;
+QtRuntime *runtime{nullptr};
+
+} // namespace
+
+QtRuntime::QtRuntime(ExecTaskFunc execTask, DropTaskFunc dropTask, QObject *parent)
     : QObject(parent)
     , m_execTask(execTask)
+    , m_dropTask(dropTask)
 {
     connect(this, &QtRuntime::queueTask, this, &QtRuntime::executeTask, Qt::QueuedConnection);
+
+    QMutexLocker locker(&runtimeMutex);
+    runtime = this;
 }
 
-QtRuntime *QtRuntime::instance()
+QtRuntime::~QtRuntime()
 {
-    auto app = QCoreApplication::instance();
-    if (app == nullptr) {
-        return nullptr;
+    QHash<const void *, int> pending;
+    {
+        QMutexLocker locker(&runtimeMutex);
+        runtime = nullptr;
+        pending.swap(m_pending);
+    }
+
+    // Queued tasks are discarded with the pending events of the application, and are dropped
+    // here. Dropping a future might wake other tasks, that are dropped as they can't be queued.
+    for (auto it = pending.constBegin(); it != pending.constEnd(); ++it) {
+        for (int i = 0; i < it.value(); ++i) {
+            m_dropTask(it.key());
+        }
+    }
+}
+
+bool QtRuntime::queue(const void *task)
+{
+    QMutexLocker locker(&runtimeMutex);
+    if (runtime == nullptr) {
+        return false;
     }
 
-    return app->findChild<QtRuntime *>(QString(), Qt::FindDirectChildrenOnly);
+    ++runtime->m_pending[task];
+    emit runtime->queueTask(task);
+    return true;
 }
 
 void QtRuntime::executeTask(const void *task)
 {
+    {
+        QMutexLocker locker(&runtimeMutex);
+        auto it = m_pending.find(task);
+        if (it == m_pending.end()) {
+            return;
+        }
+        if (--it.value() == 0) {
+            m_pending.erase(it);
+        }
+    }
     m_execTask(task);
 }
 
@@ -30,21 +75,23 @@ void QtRuntime::executeTask(const void *task)
 
 extern "C" {
 
-void qt_binding_futures_runtime_init(qt_binding::ExecTaskFunc execTask)
+void qt_binding_futures_runtime_init(qt_binding::ExecTaskFunc execTask,
+                                     qt_binding::DropTaskFunc dropTask)
 {
+    {
+        QMutexLocker locker(&qt_binding::runtimeMutex);
+        if (qt_binding::runtime != nullptr) {
+            return;
+        }
+    }
+
     auto app = QCoreApplication::instance();
-    new qt_binding::QtRuntime(execTask, app);
+    new qt_binding::QtRuntime(execTask, dropTask, app);
 }
 
 bool qt_binding_futures_task_queue(const void *task)
 {
-    auto runtime = qt_binding::QtRuntime::instance();
-    if (runtime == nullptr) {
-        return false;
-    }
-
-    emit runtime->queueTask(task);
-    return true;
+    return qt_binding::QtRuntime::queue(task);
 }
 
 } // extern "C"
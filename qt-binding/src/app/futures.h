@@ -1,18 +1,25 @@
 #ifndef QT_BINDING_APP_FUTURES_H
 #define QT_BINDING_APP_FUTURES_H
 
+#include <QtCore/QHash>
 #include <QtCore/QObject>
 
+QT_USE_NAMESPACE
+
 namespace qt_binding {
 
 using ExecTaskFunc = void (*)(const void *task);
+using DropTaskFunc = void (*)(const void *task);
 
 class QtRuntime : public QObject
 {
     Q_OBJECT
 public:
-    explicit QtRuntime(ExecTaskFunc execTask, QObject *parent);
-    static QtRuntime *instance();
+    explicit QtRuntime(ExecTaskFunc execTask, DropTaskFunc dropTask, QObject *parent);
+    ~QtRuntime() override;
+
+    // Queue a task, returns false if there is no runtime
+    static bool queue(const void *task);
 
 signals:
     void queueTask(const void *task);
@@ -22,6 +29,9 @@ private slots:
 
 private:
     ExecTaskFunc m_execTask{nullptr};
+    DropTaskFunc m_dropTask{nullptr};
+    // Tasks that are queued, but not executed yet, with the number of times they are queued
+    QHash<const void *, int> m_pending;
 };
 
 } // namespace qt_binding
@@ -16,8 +16,13 @@
 //! - `QtRuntime`, a QObject based class, uses a Qt signal to queue a `Task`
 //!   and a Qt slot to execute it in the Qt event-loop
 //! - `exec_task`, a Rust function called from `QtRuntime
+//!
+//! `QtRuntime` keeps track of the tasks that are queued but not executed yet. It is destroyed
+//! with the application, and drops the pending tasks with `drop_task`, as the queued signals
+//! holding them are discarded.
 
-use crate::app::Application;
+use crate::app::{embed, Application};
+use crate::variant::{Variant, VariantValue};
 use futures_core::future::BoxFuture;
 use futures_util::task::waker_ref;
 use futures_util::task::ArcWake;
@@ -25,7 +30,7 @@ use futures_util::FutureExt;
 use std::future::Future;
 use std::os::raw::c_void;
 use std::sync::{Arc, Mutex};
-use std::task::{Context, Poll};
+use std::task::Context;
 
 impl Application {
     /// Spawn a future in Qt event-loop
@@ -37,24 +42,64 @@ impl Application {
     /// Since this method integrates into Qt main event-loop, only use it to execute GUI-related
     /// futures. Prefer using another runtime for other futures.
     ///
+    /// Futures that are still pending when the `Application` is destroyed are dropped with
+    /// it, and futures that are woken after are dropped instead of being polled.
+    ///
     /// # Panics
     ///
-    /// This method will panic if a Qt event-loop is not present. `Application::new` should
-    /// always be called before calling `Application::spawn`.
+    /// This method will panic if a Qt event-loop is not present. `Application::new`, or
+    /// `Application::instance` when the application is created by C++, should always be called
+    /// before calling `Application::spawn`.
     pub fn spawn<F>(future: F)
     where
         F: Future<Output = ()> + 'static + Send,
     {
         let task = Task::new(future);
-        task.queue();
+        assert!(task.queue(), "No Application to run the future")
     }
 
-    pub(in crate::app) fn initialized(self) -> Self {
-        unsafe { qt_binding_futures_runtime_init(Some(exec_task)) };
-        self
+    /// Convert a value to a `Variant` in Qt event-loop, and call a closure with it
+    ///
+    /// This method can be called from any thread, to deliver a [`VariantValue`] prepared in a
+    /// worker thread. The closure is called later, in the main thread, like futures spawned with
+    /// [`spawn`].
+    ///
+    /// [`VariantValue`]: ../../variant/enum.VariantValue.html
+    /// [`spawn`]: #method.spawn
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if a Qt event-loop is not present.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use qt_binding::app::Application;
+    /// use qt_binding::variant::VariantValue;
+    /// use std::thread;
+    ///
+    /// let mut app = Application::new();
+    /// thread::spawn(|| {
+    ///     let files = vec![VariantValue::from("a.txt"), VariantValue::from("b.txt")];
+    ///     Application::deliver(VariantValue::List(files), |variant| {
+    ///         println!("Received {:?}", variant);
+    ///     });
+    /// });
+    /// app.exec();
+    /// ```
+    pub fn deliver<F>(value: VariantValue, callback: F)
+    where
+        F: FnOnce(Variant) + 'static + Send,
+    {
+        Application::spawn(async move { callback(Variant::from(value)) })
     }
 }
 
+/// Create the runtime in the running application, if it does not have one
+pub(in crate::app) fn init_runtime() {
+    unsafe { qt_binding_futures_runtime_init(Some(exec_task), Some(drop_task)) };
+}
+
 struct Task {
     future: Mutex<Option<BoxFuture<'static, ()>>>,
 }
@@ -65,24 +110,34 @@ impl Task {
         F: Future<Output = ()> + 'static + Send,
     {
         let future = future.boxed();
+        track_created!(Task);
         Arc::new(Task {
             future: Mutex::new(Some(future)),
         })
     }
 
-    fn queue(self: Arc<Self>) {
+    /// Queue the task in the Qt event-loop, or drop it if there is no runtime
+    fn queue(self: Arc<Self>) -> bool {
+        ffi_span!("Task", "queue");
         let task = Arc::into_raw(self);
         let result = unsafe { qt_binding_futures_task_queue(task as *const c_void) };
-        assert!(result, "No Application to run the future")
+        if result {
+            // Foreign event loops waiting on the wake-up file descriptor should run the task
+            embed::wake_up();
+        } else {
+            drop(unsafe { Arc::from_raw(task) });
+        }
+        result
     }
 
     fn execute(self: Arc<Self>) {
+        ffi_span!("Task", "execute");
         if let Ok(mut future_slot) = self.future.lock() {
             if let Some(mut future) = future_slot.take() {
                 let waker = waker_ref(&self);
-                let mut context = Context::from_waker(&*waker);
+                let mut context = Context::from_waker(&waker);
 
-                if let Poll::Pending = future.as_mut().poll(&mut context) {
+                if future.as_mut().poll(&mut context).is_pending() {
                     *future_slot = Some(future);
                 }
             }
@@ -90,9 +145,15 @@ impl Task {
     }
 }
 
+impl Drop for Task {
+    fn drop(&mut self) {
+        track_destroyed!(Task);
+    }
+}
+
 impl ArcWake for Task {
     fn wake_by_ref(arc_self: &Arc<Self>) {
-        arc_self.clone().queue()
+        arc_self.clone().queue();
     }
 }
 
@@ -101,9 +162,17 @@ extern "C" fn exec_task(task: *const c_void) {
     task.execute()
 }
 
+extern "C" fn drop_task(task: *const c_void) {
+    drop(unsafe { Arc::from_raw(task as *const Task) });
+}
+
 type ExecTaskFunc = extern "C" fn(task: *const c_void);
+type DropTaskFunc = extern "C" fn(task: *const c_void);
 
 extern "C" {
-    fn qt_binding_futures_runtime_init(exec_task: Option<ExecTaskFunc>);
+    fn qt_binding_futures_runtime_init(
+        exec_task: Option<ExecTaskFunc>,
+        drop_task: Option<DropTaskFunc>,
+    );
     fn qt_binding_futures_task_queue(task: *const c_void) -> bool;
 }
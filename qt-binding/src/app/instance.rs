@@ -0,0 +1,82 @@
+//! Access to an existing application
+//!
+//! When Rust code is loaded in an application created by C++, like a QML plugin, the
+//! `QCoreApplication` already exists and must not be created again.
+//!
+//! See documentation of [`Application::instance`] for more information.
+//!
+//! [`Application::instance`]: ../struct.Application.html#method.instance
+
+use crate::app::{init_runtime, Application};
+use crate::object::Object;
+use std::os::raw::c_void;
+
+/// Reference to the running `QCoreApplication`
+///
+/// `AppRef` is returned by [`Application::instance`], and does not own the application. The
+/// application might have been created by an [`Application`], or by C++ code.
+///
+/// [`Application::instance`]: struct.Application.html#method.instance
+/// [`Application`]: struct.Application.html
+#[derive(Clone, Copy, Debug)]
+pub struct AppRef {
+    ptr: *mut c_void,
+}
+
+impl AppRef {
+    /// If the application was created by an [`Application`]
+    ///
+    /// [`Application`]: struct.Application.html
+    pub fn is_owned(&self) -> bool {
+        Application::is_alive()
+    }
+}
+
+impl Object for AppRef {
+    fn as_object_ptr(&self) -> *mut c_void {
+        self.ptr
+    }
+}
+
+impl Application {
+    /// Get the running application, if any
+    ///
+    /// Returns the application created with [`new`], or by C++ code, when Rust code is loaded
+    /// in an existing Qt application, like a QML plugin. Applications created by C++ are not
+    /// owned, and are not destroyed by Rust code.
+    ///
+    /// APIs depending on the event loop, like [`spawn`], [`exit`] and [`quit`], work with
+    /// applications created by C++ once this method has been called. It should be called from
+    /// the main thread, for example when registering the plugin.
+    ///
+    /// [`new`]: #method.new
+    /// [`spawn`]: #method.spawn
+    /// [`exit`]: #method.exit
+    /// [`quit`]: #method.quit
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use qt_binding::app::Application;
+    ///
+    /// let app = match Application::instance() {
+    ///     Some(app) => app,
+    ///     None => panic!("The plugin must be loaded in a Qt application"),
+    /// };
+    /// assert!(!app.is_owned());
+    /// ```
+    pub fn instance() -> Option<AppRef> {
+        ffi_span!("Application", "instance");
+        let ptr = unsafe { qt_binding_application_instance() };
+        if ptr.is_null() {
+            return None;
+        }
+
+        init_runtime();
+        Some(AppRef { ptr })
+    }
+}
+
+extern "C" {
+    fn qt_binding_application_instance() -> *mut c_void;
+}
@@ -0,0 +1,26 @@
+#include <QtGui/QGuiApplication>
+#include <cstdint>
+
+QT_USE_NAMESPACE
+
+using RsStringFillFunc = void (*)(void *output, const char *input, std::uint32_t inputSize);
+
+static void fillString(const QString &value, void *output, RsStringFillFunc fill)
+{
+    const auto byteArray = value.toUtf8();
+    fill(output, byteArray.constData(), static_cast<std::uint32_t>(byteArray.size()));
+}
+
+extern "C" {
+
+void qt_binding_application_platform_name(void *output, RsStringFillFunc fill)
+{
+    fillString(QGuiApplication::platformName(), output, fill);
+}
+
+void qt_binding_application_set_desktop_file_name(const char *name, std::uint32_t size)
+{
+    QGuiApplication::setDesktopFileName(QString::fromUtf8(name, static_cast<int>(size)));
+}
+
+} // extern "C"
@@ -0,0 +1,170 @@
+//! Platform selection and detection for `Application`
+//!
+//! Qt draws windows through a platform plugin, selected when the `Application` is created. On
+//! Linux, it is usually `xcb`, for X11, or `wayland`.
+//!
+//! See documentation of [`Application::set_platform`] for more information.
+//!
+//! [`Application::set_platform`]: ../struct.Application.html#method.set_platform
+
+use crate::app::Application;
+use crate::variant::convert::{rs_string_fill, RsStringFillFunc};
+use std::os::raw::{c_char, c_void};
+use std::sync::Mutex;
+
+static PLATFORM: Mutex<Option<String>> = Mutex::new(None);
+static X11_INSTANCE: Mutex<Option<String>> = Mutex::new(None);
+
+/// A Qt platform plugin
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Platform {
+    /// X11, through XCB
+    Xcb,
+    /// Wayland
+    Wayland,
+    /// No display, windows are drawn in memory
+    Offscreen,
+    /// A platform plugin, by name, like `eglfs`
+    Custom(String),
+}
+
+impl Platform {
+    fn name(&self) -> &str {
+        match self {
+            Platform::Xcb => "xcb",
+            Platform::Wayland => "wayland",
+            Platform::Offscreen => "offscreen",
+            Platform::Custom(name) => name,
+        }
+    }
+}
+
+impl Application {
+    /// Select the platform plugin
+    ///
+    /// By default, Qt uses the platform plugin set in the `QT_QPA_PLATFORM` environment
+    /// variable, or the default plugin of the Qt build. The platform is passed to Qt with the
+    /// `-platform` argument when the `Application` is created. It takes precedence over
+    /// `QT_QPA_PLATFORM`, that is left untouched, but a `-platform` argument given on the
+    /// command line still takes precedence over it.
+    ///
+    /// # Features
+    ///
+    /// This function requires the `gui` feature.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if an `Application` is alive, as the platform is selected when the
+    /// `Application` is created.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use qt_binding::app::{Application, Platform};
+    ///
+    /// Application::set_platform(Platform::Xcb);
+    /// let mut app = Application::new();
+    /// assert_eq!(Application::platform_name(), "xcb");
+    /// ```
+    pub fn set_platform(platform: Platform) {
+        assert!(
+            !Application::is_alive(),
+            "Platform must be selected before creating the Application"
+        );
+        *PLATFORM.lock().unwrap() = Some(platform.name().to_string());
+    }
+
+    /// Name of the platform plugin in use, like `xcb` or `wayland`
+    ///
+    /// The name is empty if no `Application` is alive.
+    ///
+    /// # Features
+    ///
+    /// This function requires the `gui` feature.
+    pub fn platform_name() -> String {
+        ffi_span!("Application", "platform_name");
+        let mut name = String::new();
+        unsafe {
+            let data: *mut String = &mut name;
+            qt_binding_application_platform_name(data as *mut c_void, rs_string_fill);
+        }
+        name
+    }
+
+    /// Set the Wayland application id
+    ///
+    /// The application id is used by Wayland compositors to group windows, and to find the
+    /// icon and name of the application. It should be the name of the `.desktop` file of the
+    /// application, without the extension, like `com.example.App`. It should be set before
+    /// showing windows.
+    ///
+    /// # Features
+    ///
+    /// This function requires the `gui` feature.
+    pub fn set_wayland_app_id<S>(app_id: S)
+    where
+        S: AsRef<str>,
+    {
+        ffi_span!("Application", "set_wayland_app_id");
+        let app_id = app_id.as_ref();
+        unsafe {
+            qt_binding_application_set_desktop_file_name(
+                app_id.as_ptr() as *const c_char,
+                app_id.len() as u32,
+            )
+        }
+    }
+
+    /// Set the instance name of the X11 `WM_CLASS` property
+    ///
+    /// `WM_CLASS` is used by X11 window managers to group windows, and to match them with the
+    /// `StartupWMClass` of `.desktop` files. It is made of an instance name, that defaults to
+    /// the name of the executable, and a class name, that is the application name set with
+    /// [`set_application_name`]. The instance name is passed to Qt with the `-name` argument
+    /// when the `Application` is created. It takes precedence over the `RESOURCE_NAME`
+    /// environment variable, that is left untouched, but a `-name` argument given on the
+    /// command line still takes precedence over it.
+    ///
+    /// [`set_application_name`]: #method.set_application_name
+    ///
+    /// # Features
+    ///
+    /// This function requires the `gui` feature.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if an `Application` is alive, as the instance name is read when
+    /// the `Application` is created.
+    pub fn set_x11_wm_class<S>(instance: S)
+    where
+        S: AsRef<str>,
+    {
+        assert!(
+            !Application::is_alive(),
+            "WM_CLASS must be configured before creating the Application"
+        );
+        *X11_INSTANCE.lock().unwrap() = Some(instance.as_ref().to_string());
+    }
+}
+
+/// Arguments selecting the platform and the `WM_CLASS` instance name
+///
+/// They are inserted after the program name, before the arguments of the program, so that
+/// arguments given on the command line, that Qt reads last, take precedence.
+pub(in crate::app) fn arguments() -> Vec<String> {
+    let mut arguments = Vec::new();
+    if let Some(platform) = &*PLATFORM.lock().unwrap() {
+        arguments.push("-platform".to_string());
+        arguments.push(platform.clone());
+    }
+    if let Some(instance) = &*X11_INSTANCE.lock().unwrap() {
+        arguments.push("-name".to_string());
+        arguments.push(instance.clone());
+    }
+    arguments
+}
+
+extern "C" {
+    fn qt_binding_application_platform_name(output: *mut c_void, fill: RsStringFillFunc);
+    fn qt_binding_application_set_desktop_file_name(name: *const c_char, size: u32);
+}
@@ -0,0 +1,102 @@
+//! Qt version checks
+//!
+//! `qt-binding` is compiled against the Qt installation located by `qt-sys`, but the Qt
+//! libraries loaded at runtime might come from another installation, for example when
+//! `QT_INSTALL_DIR` changed between builds. This usually leads to crashes that are hard to
+//! understand, so versions are checked when creating the `Application`.
+
+use std::ffi::CStr;
+use std::os::raw::c_char;
+
+pub(in crate::app) fn check() {
+    let (build, lib_dir, headers, runtime) = unsafe {
+        (
+            to_str(qt_binding_version_build()),
+            to_str(qt_binding_version_build_lib_dir()),
+            to_str(qt_binding_version_headers()),
+            to_str(qt_binding_version_runtime()),
+        )
+    };
+
+    if let Err(message) = check_versions(&build, &lib_dir, &headers, &runtime) {
+        panic!("{}", message)
+    }
+}
+
+fn check_versions(build: &str, lib_dir: &str, headers: &str, runtime: &str) -> Result<(), String> {
+    if build != headers {
+        return Err(format!(
+            "qt-binding was built against Qt {} ({}), but compiled with headers from Qt {}. \
+             Make sure that qt-sys and qt-binding-build use the same Qt installation.",
+            build, lib_dir, headers
+        ));
+    }
+
+    let compatible = match (major_minor(build), major_minor(runtime)) {
+        (Some((build_major, build_minor)), Some((runtime_major, runtime_minor))) => {
+            build_major == runtime_major && build_minor <= runtime_minor
+        }
+        _ => false,
+    };
+
+    if compatible {
+        Ok(())
+    } else {
+        Err(format!(
+            "qt-binding was built against Qt {} ({}), but Qt {} was loaded at runtime. \
+             Make sure that the Qt libraries used at runtime match the ones used to build, \
+             and rebuild qt-sys if `QT_INSTALL_DIR` changed.",
+            build, lib_dir, runtime
+        ))
+    }
+}
+
+fn major_minor(version: &str) -> Option<(u32, u32)> {
+    let mut parts = version.split('.').map(str::parse::<u32>);
+    match (parts.next(), parts.next()) {
+        (Some(Ok(major)), Some(Ok(minor))) => Some((major, minor)),
+        _ => None,
+    }
+}
+
+unsafe fn to_str(value: *const c_char) -> String {
+    CStr::from_ptr(value).to_string_lossy().into_owned()
+}
+
+extern "C" {
+    fn qt_binding_version_build() -> *const c_char;
+    fn qt_binding_version_build_lib_dir() -> *const c_char;
+    fn qt_binding_version_headers() -> *const c_char;
+    fn qt_binding_version_runtime() -> *const c_char;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_major_minor() {
+        assert_eq!(major_minor("5.11.1"), Some((5, 11)));
+        assert_eq!(major_minor("5.9"), Some((5, 9)));
+        assert_eq!(major_minor("5"), None);
+        assert_eq!(major_minor("abc"), None);
+    }
+
+    #[test]
+    fn test_check_versions() {
+        assert!(check_versions("5.11.1", "/qt/lib", "5.11.1", "5.11.1").is_ok());
+        assert!(check_versions("5.11.1", "/qt/lib", "5.11.1", "5.11.3").is_ok());
+        assert!(check_versions("5.11.1", "/qt/lib", "5.11.1", "5.12.0").is_ok());
+        assert!(check_versions("5.11.1", "/qt/lib", "5.11.1", "5.9.5").is_err());
+        assert!(check_versions("5.11.1", "/qt/lib", "5.11.1", "6.0.0").is_err());
+        assert!(check_versions("5.11.1", "/qt/lib", "5.9.5", "5.11.1").is_err());
+    }
+
+    #[test]
+    fn test_check_versions_message() {
+        let message = check_versions("5.11.1", "/qt/lib", "5.11.1", "5.9.5").unwrap_err();
+        assert!(message.contains("5.11.1"));
+        assert!(message.contains("/qt/lib"));
+        assert!(message.contains("5.9.5"));
+    }
+}
@@ -0,0 +1,293 @@
+#include <QtBluetooth/QBluetoothDeviceDiscoveryAgent>
+#include <QtBluetooth/QBluetoothDeviceInfo>
+#include <QtBluetooth/QLowEnergyController>
+#include <QtBluetooth/QLowEnergyService>
+#include <cstdint>
+
+QT_USE_NAMESPACE
+
+using RsStringFillFunc = void (*)(void *output, const char *input, std::uint32_t inputSize);
+using RsSignalFunc = void (*)(void *data);
+using RsMessageFunc = void (*)(void *data, const char *input, std::uint32_t size);
+using RsDeviceFunc = void (*)(void *data, QBluetoothDeviceInfo *device);
+using RsValueFunc = void (*)(void *data, const char *uuid, std::uint32_t uuidSize,
+                             const char *value, std::uint32_t valueSize);
+
+static void fillString(const QString &value, void *output, RsStringFillFunc fill)
+{
+    const auto byteArray = value.toUtf8();
+    fill(output, byteArray.constData(), static_cast<std::uint32_t>(byteArray.size()));
+}
+
+static QString uuidToString(const QBluetoothUuid &uuid)
+{
+    // QUuid::toString wraps the UUID in braces
+    return uuid.toString().mid(1, 36);
+}
+
+static QBluetoothUuid toUuid(const char *uuid, std::uint32_t size)
+{
+    auto string = QString::fromUtf8(uuid, static_cast<int>(size));
+    if (!string.startsWith(QLatin1Char('{'))) {
+        string = QLatin1Char('{') + string + QLatin1Char('}');
+    }
+    return QBluetoothUuid(string);
+}
+
+static void sendValue(void *data, RsValueFunc func, const QLowEnergyCharacteristic &characteristic,
+                      const QByteArray &value)
+{
+    const auto uuid = uuidToString(characteristic.uuid()).toUtf8();
+    func(data, uuid.constData(), static_cast<std::uint32_t>(uuid.size()), value.constData(),
+         static_cast<std::uint32_t>(value.size()));
+}
+
+static void sendMessage(void *data, RsMessageFunc func, const QString &message)
+{
+    const auto byteArray = message.toUtf8();
+    func(data, byteArray.constData(), static_cast<std::uint32_t>(byteArray.size()));
+}
+
+static QString serviceErrorString(QLowEnergyService::ServiceError error)
+{
+    switch (error) {
+    case QLowEnergyService::OperationError:
+        return QStringLiteral("Operation error");
+    case QLowEnergyService::CharacteristicReadError:
+        return QStringLiteral("Characteristic read error");
+    case QLowEnergyService::CharacteristicWriteError:
+        return QStringLiteral("Characteristic write error");
+    case QLowEnergyService::DescriptorReadError:
+        return QStringLiteral("Descriptor read error");
+    case QLowEnergyService::DescriptorWriteError:
+        return QStringLiteral("Descriptor write error");
+    default:
+        return QStringLiteral("Unknown error");
+    }
+}
+
+extern "C" {
+
+QBluetoothDeviceInfo *qt_binding_bluetooth_device_info_clone(const QBluetoothDeviceInfo *device)
+{
+    return new QBluetoothDeviceInfo(*device);
+}
+
+void qt_binding_bluetooth_device_info_delete(QBluetoothDeviceInfo *device)
+{
+    delete device;
+}
+
+void qt_binding_bluetooth_device_info_name(const QBluetoothDeviceInfo *device, void *output,
+                                           RsStringFillFunc fill)
+{
+    fillString(device->name(), output, fill);
+}
+
+void qt_binding_bluetooth_device_info_address(const QBluetoothDeviceInfo *device, void *output,
+                                              RsStringFillFunc fill)
+{
+    if (device->address().isNull()) {
+        fillString(uuidToString(device->deviceUuid()), output, fill);
+    } else {
+        fillString(device->address().toString(), output, fill);
+    }
+}
+
+std::int16_t qt_binding_bluetooth_device_info_rssi(const QBluetoothDeviceInfo *device)
+{
+    return device->rssi();
+}
+
+QBluetoothDeviceDiscoveryAgent *qt_binding_bluetooth_discovery_create(void *data,
+                                                                      RsDeviceFunc deviceDiscovered,
+                                                                      RsSignalFunc finished,
+                                                                      RsMessageFunc error)
+{
+    auto discovery = new QBluetoothDeviceDiscoveryAgent();
+    QObject::connect(discovery, &QBluetoothDeviceDiscoveryAgent::deviceDiscovered,
+                     [data, deviceDiscovered](const QBluetoothDeviceInfo &device) {
+                         if (device.coreConfigurations()
+                             & QBluetoothDeviceInfo::LowEnergyCoreConfiguration) {
+                             deviceDiscovered(data, new QBluetoothDeviceInfo(device));
+                         }
+                     });
+    QObject::connect(discovery, &QBluetoothDeviceDiscoveryAgent::finished,
+                     [data, finished]() { finished(data); });
+    QObject::connect(
+        discovery,
+        static_cast<void (QBluetoothDeviceDiscoveryAgent::*)(
+            QBluetoothDeviceDiscoveryAgent::Error)>(&QBluetoothDeviceDiscoveryAgent::error),
+        [discovery, data, error](QBluetoothDeviceDiscoveryAgent::Error) {
+            sendMessage(data, error, discovery->errorString());
+        });
+    return discovery;
+}
+
+void qt_binding_bluetooth_discovery_delete(QBluetoothDeviceDiscoveryAgent *discovery)
+{
+    delete discovery;
+}
+
+void qt_binding_bluetooth_discovery_start(QBluetoothDeviceDiscoveryAgent *discovery)
+{
+    discovery->start(QBluetoothDeviceDiscoveryAgent::LowEnergyMethod);
+}
+
+void qt_binding_bluetooth_discovery_stop(QBluetoothDeviceDiscoveryAgent *discovery)
+{
+    discovery->stop();
+}
+
+bool qt_binding_bluetooth_discovery_is_active(const QBluetoothDeviceDiscoveryAgent *discovery)
+{
+    return discovery->isActive();
+}
+
+QLowEnergyController *qt_binding_bluetooth_controller_create(
+    const QBluetoothDeviceInfo *device, void *data, RsSignalFunc connected,
+    RsSignalFunc disconnected, RsMessageFunc serviceDiscovered, RsSignalFunc discoveryFinished,
+    RsMessageFunc error)
+{
+    auto controller = QLowEnergyController::createCentral(*device);
+    QObject::connect(controller, &QLowEnergyController::connected,
+                     [data, connected]() { connected(data); });
+    QObject::connect(controller, &QLowEnergyController::disconnected,
+                     [data, disconnected]() { disconnected(data); });
+    QObject::connect(controller, &QLowEnergyController::serviceDiscovered,
+                     [data, serviceDiscovered](const QBluetoothUuid &uuid) {
+                         sendMessage(data, serviceDiscovered, uuidToString(uuid));
+                     });
+    QObject::connect(controller, &QLowEnergyController::discoveryFinished,
+                     [data, discoveryFinished]() { discoveryFinished(data); });
+    QObject::connect(
+        controller,
+        static_cast<void (QLowEnergyController::*)(QLowEnergyController::Error)>(
+            &QLowEnergyController::error),
+        [controller, data, error](QLowEnergyController::Error) {
+            sendMessage(data, error, controller->errorString());
+        });
+    return controller;
+}
+
+void qt_binding_bluetooth_controller_delete(QLowEnergyController *controller)
+{
+    delete controller;
+}
+
+void qt_binding_bluetooth_controller_connect_to_device(QLowEnergyController *controller)
+{
+    controller->connectToDevice();
+}
+
+void qt_binding_bluetooth_controller_disconnect_from_device(QLowEnergyController *controller)
+{
+    controller->disconnectFromDevice();
+}
+
+void qt_binding_bluetooth_controller_discover_services(QLowEnergyController *controller)
+{
+    controller->discoverServices();
+}
+
+QLowEnergyService *qt_binding_bluetooth_controller_create_service(QLowEnergyController *controller,
+                                                                  const char *uuid,
+                                                                  std::uint32_t size)
+{
+    // Services are owned by Rust, and are created without parent
+    return controller->createServiceObject(toUuid(uuid, size));
+}
+
+void qt_binding_bluetooth_service_connect(QLowEnergyService *service, void *data,
+                                          RsSignalFunc detailsDiscovered,
+                                          RsValueFunc characteristicChanged,
+                                          RsValueFunc characteristicRead, RsMessageFunc error)
+{
+    QObject::connect(service, &QLowEnergyService::stateChanged,
+                     [data, detailsDiscovered](QLowEnergyService::ServiceState state) {
+                         if (state == QLowEnergyService::ServiceDiscovered) {
+                             detailsDiscovered(data);
+                         }
+                     });
+    QObject::connect(service, &QLowEnergyService::characteristicChanged,
+                     [data, characteristicChanged](const QLowEnergyCharacteristic &characteristic,
+                                                   const QByteArray &value) {
+                         sendValue(data, characteristicChanged, characteristic, value);
+                     });
+    QObject::connect(service, &QLowEnergyService::characteristicRead,
+                     [data, characteristicRead](const QLowEnergyCharacteristic &characteristic,
+                                                const QByteArray &value) {
+                         sendValue(data, characteristicRead, characteristic, value);
+                     });
+    QObject::connect(service,
+                     static_cast<void (QLowEnergyService::*)(QLowEnergyService::ServiceError)>(
+                         &QLowEnergyService::error),
+                     [data, error](QLowEnergyService::ServiceError serviceError) {
+                         sendMessage(data, error, serviceErrorString(serviceError));
+                     });
+}
+
+void qt_binding_bluetooth_service_delete(QLowEnergyService *service)
+{
+    delete service;
+}
+
+void qt_binding_bluetooth_service_discover_details(QLowEnergyService *service)
+{
+    service->discoverDetails();
+}
+
+int qt_binding_bluetooth_service_characteristic_count(const QLowEnergyService *service)
+{
+    return service->characteristics().count();
+}
+
+void qt_binding_bluetooth_service_characteristic_uuid(const QLowEnergyService *service, int index,
+                                                      void *output, RsStringFillFunc fill)
+{
+    fillString(uuidToString(service->characteristics().at(index).uuid()), output, fill);
+}
+
+bool qt_binding_bluetooth_service_read_characteristic(QLowEnergyService *service,
+                                                      const char *uuid, std::uint32_t size)
+{
+    const auto characteristic = service->characteristic(toUuid(uuid, size));
+    if (!characteristic.isValid()) {
+        return false;
+    }
+    service->readCharacteristic(characteristic);
+    return true;
+}
+
+bool qt_binding_bluetooth_service_write_characteristic(QLowEnergyService *service,
+                                                       const char *uuid, std::uint32_t uuidSize,
+                                                       const char *value, std::uint32_t valueSize,
+                                                       bool withResponse)
+{
+    const auto characteristic = service->characteristic(toUuid(uuid, uuidSize));
+    if (!characteristic.isValid()) {
+        return false;
+    }
+    const auto mode = withResponse ? QLowEnergyService::WriteWithResponse
+                                   : QLowEnergyService::WriteWithoutResponse;
+    service->writeCharacteristic(characteristic, QByteArray(value, static_cast<int>(valueSize)),
+                                 mode);
+    return true;
+}
+
+bool qt_binding_bluetooth_service_set_notifications_enabled(QLowEnergyService *service,
+                                                            const char *uuid, std::uint32_t size,
+                                                            bool enabled)
+{
+    const auto characteristic = service->characteristic(toUuid(uuid, size));
+    const auto descriptor = characteristic.descriptor(
+        QBluetoothUuid::DescriptorType::ClientCharacteristicConfiguration);
+    if (!characteristic.isValid() || !descriptor.isValid()) {
+        return false;
+    }
+    const auto value = enabled ? QByteArray::fromHex("0100") : QByteArray::fromHex("0000");
+    service->writeDescriptor(descriptor, value);
+    return true;
+}
+
+} // extern "C"
@@ -0,0 +1,967 @@
+//! Bindings to Qt Bluetooth Low Energy
+//!
+//! [`DeviceDiscovery`] is a binding to `QBluetoothDeviceDiscoveryAgent`, that discovers Bluetooth
+//! Low Energy devices. A discovered device is described by a [`DeviceInfo`], and a connection
+//! to it is established with a [`Controller`], a binding to `QLowEnergyController`.
+//!
+//! Once connected, GATT services offered by the device are discovered, and a [`Service`] is
+//! created for each of them. Characteristics of a service can be read, written, and notify
+//! changes to their value. All operations are asynchronous and integrated with Qt's event-loop,
+//! and results are passed to handlers.
+//!
+//! UUIDs of services and characteristics are strings, like
+//! `0000180d-0000-1000-8000-00805f9b34fb`.
+//!
+//! [`DeviceDiscovery`]: struct.DeviceDiscovery.html
+//! [`DeviceInfo`]: struct.DeviceInfo.html
+//! [`Controller`]: struct.Controller.html
+//! [`Service`]: struct.Service.html
+//!
+//! # Features
+//!
+//! This module is only available with the `bluetooth` feature.
+//!
+//! With the `futures-executor` feature, notifications can also be received as a `Stream`, with
+//! [`Service::notifications`], and processed by futures spawned with [`Application::spawn`].
+//!
+//! [`Service::notifications`]: struct.Service.html#method.notifications
+//! [`Application::spawn`]: ../app/struct.Application.html#method.spawn
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use qt_binding::app::Application;
+//! use qt_binding::bluetooth::{Controller, DeviceDiscovery};
+//!
+//! let mut app = Application::new();
+//!
+//! let mut controllers = Vec::new();
+//! let mut discovery = DeviceDiscovery::new();
+//! discovery.on_device_discovered(move |device| {
+//!     println!("Found {} ({})", device.name(), device.address());
+//!     let mut controller = Controller::new(&device);
+//!     controller.connect_to_device();
+//!     controllers.push(controller);
+//! });
+//! discovery.start();
+//!
+//! app.exec();
+//! ```
+//!
+//! Once a controller has discovered services
+//!
+//! ```no_run
+//! # use qt_binding::bluetooth::Controller;
+//! # const HEART_RATE: &str = "0000180d-0000-1000-8000-00805f9b34fb";
+//! # fn discovered(controller: &Controller) {
+//! let mut service = controller.service(HEART_RATE).unwrap();
+//! service.on_characteristic_changed(|uuid, value| println!("{}: {:?}", uuid, value));
+//! service.on_details_discovered(|| println!("Details discovered"));
+//! service.discover_details();
+//! # }
+//! ```
+
+use crate::app;
+use crate::variant::convert::{rs_string_fill, RsStringFillFunc};
+use std::error;
+use std::fmt;
+use std::os::raw::{c_char, c_int, c_void};
+use std::slice;
+
+#[cfg(feature = "futures-executor")]
+use crate::channel;
+#[cfg(feature = "futures-executor")]
+use futures_core::stream::Stream;
+#[cfg(feature = "futures-executor")]
+use std::pin::Pin;
+#[cfg(feature = "futures-executor")]
+use std::task::{Context, Poll};
+
+type SignalHandler = Option<Box<dyn FnMut()>>;
+type ErrorHandler = Option<Box<dyn FnMut(Error)>>;
+type DeviceHandler = Option<Box<dyn FnMut(DeviceInfo)>>;
+type UuidHandler = Option<Box<dyn FnMut(&str)>>;
+type ValueHandler = Option<Box<dyn FnMut(&str, &[u8])>>;
+
+/// Error reported by Qt Bluetooth
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Error {
+    message: String,
+}
+
+impl Error {
+    /// Error message
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl error::Error for Error {}
+
+/// Information about a discovered device
+///
+/// Binding to `QBluetoothDeviceInfo`.
+pub struct DeviceInfo {
+    ptr: *mut c_void,
+}
+
+impl DeviceInfo {
+    /// Name of the device
+    pub fn name(&self) -> String {
+        ffi_span!("DeviceInfo", "name");
+        let mut value = String::new();
+        unsafe {
+            let data: *mut String = &mut value;
+            qt_binding_bluetooth_device_info_name(self.ptr, data as *mut c_void, rs_string_fill);
+        }
+        value
+    }
+
+    /// Address of the device
+    ///
+    /// On macOS and iOS, device addresses are not available, and an UUID identifying the device
+    /// is returned instead.
+    pub fn address(&self) -> String {
+        ffi_span!("DeviceInfo", "address");
+        let mut value = String::new();
+        unsafe {
+            let data: *mut String = &mut value;
+            qt_binding_bluetooth_device_info_address(self.ptr, data as *mut c_void, rs_string_fill);
+        }
+        value
+    }
+
+    /// Signal strength, in dBm
+    pub fn rssi(&self) -> i16 {
+        ffi_span!("DeviceInfo", "rssi");
+        unsafe { qt_binding_bluetooth_device_info_rssi(self.ptr) }
+    }
+}
+
+impl Clone for DeviceInfo {
+    fn clone(&self) -> Self {
+        ffi_span!("DeviceInfo", "clone");
+        track_created!(BluetoothDeviceInfo);
+        DeviceInfo {
+            ptr: unsafe { qt_binding_bluetooth_device_info_clone(self.ptr) },
+        }
+    }
+}
+
+impl fmt::Debug for DeviceInfo {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("DeviceInfo")
+            .field("name", &self.name())
+            .field("address", &self.address())
+            .field("rssi", &self.rssi())
+            .finish()
+    }
+}
+
+impl Drop for DeviceInfo {
+    fn drop(&mut self) {
+        ffi_span!("DeviceInfo", "drop");
+        track_destroyed!(BluetoothDeviceInfo);
+        unsafe { qt_binding_bluetooth_device_info_delete(self.ptr) }
+    }
+}
+
+#[derive(Default)]
+struct DiscoveryHandlers {
+    device_discovered: DeviceHandler,
+    finished: SignalHandler,
+    error: ErrorHandler,
+}
+
+/// Binding to `QBluetoothDeviceDiscoveryAgent`
+///
+/// Only Bluetooth Low Energy devices are discovered.
+///
+/// See module level documentation for more information.
+pub struct DeviceDiscovery {
+    ptr: *mut c_void,
+    handlers: Box<DiscoveryHandlers>,
+}
+
+impl DeviceDiscovery {
+    /// Creates a `DeviceDiscovery` using the default Bluetooth adapter
+    pub fn new() -> Self {
+        ffi_span!("DeviceDiscovery", "new");
+        let mut handlers = Box::new(DiscoveryHandlers::default());
+        let data: *mut DiscoveryHandlers = &mut *handlers;
+        let ptr = unsafe {
+            qt_binding_bluetooth_discovery_create(
+                data as *mut c_void,
+                rs_bluetooth_discovery_device_discovered,
+                rs_bluetooth_discovery_finished,
+                rs_bluetooth_discovery_error,
+            )
+        };
+        track_created!(BluetoothDeviceDiscovery);
+        DeviceDiscovery { ptr, handlers }
+    }
+
+    /// Start discovering devices
+    ///
+    /// Discovery runs while the event-loop runs, until [`stop`] is called, or until it
+    /// times out.
+    ///
+    /// [`stop`]: #method.stop
+    pub fn start(&mut self) {
+        ffi_span!("DeviceDiscovery", "start");
+        unsafe { qt_binding_bluetooth_discovery_start(self.ptr) }
+    }
+
+    /// Stop discovering devices
+    pub fn stop(&mut self) {
+        ffi_span!("DeviceDiscovery", "stop");
+        unsafe { qt_binding_bluetooth_discovery_stop(self.ptr) }
+    }
+
+    /// Returns `true` if devices are being discovered
+    pub fn is_active(&self) -> bool {
+        ffi_span!("DeviceDiscovery", "is_active");
+        unsafe { qt_binding_bluetooth_discovery_is_active(self.ptr) }
+    }
+
+    /// Handle discovered devices
+    ///
+    /// Setting a new handler replaces the previous one.
+    pub fn on_device_discovered<F>(&mut self, handler: F) -> &mut Self
+    where
+        F: FnMut(DeviceInfo) + 'static,
+    {
+        self.handlers.device_discovered = Some(Box::new(handler));
+        self
+    }
+
+    /// Handle the end of the discovery
+    ///
+    /// Setting a new handler replaces the previous one.
+    pub fn on_finished<F>(&mut self, handler: F) -> &mut Self
+    where
+        F: FnMut() + 'static,
+    {
+        self.handlers.finished = Some(Box::new(handler));
+        self
+    }
+
+    /// Handle errors
+    ///
+    /// Setting a new handler replaces the previous one.
+    pub fn on_error<F>(&mut self, handler: F) -> &mut Self
+    where
+        F: FnMut(Error) + 'static,
+    {
+        self.handlers.error = Some(Box::new(handler));
+        self
+    }
+}
+
+impl Default for DeviceDiscovery {
+    fn default() -> Self {
+        DeviceDiscovery::new()
+    }
+}
+
+impl fmt::Debug for DeviceDiscovery {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("DeviceDiscovery")
+            .field("is_active", &self.is_active())
+            .finish()
+    }
+}
+
+impl Drop for DeviceDiscovery {
+    fn drop(&mut self) {
+        ffi_span!("DeviceDiscovery", "drop");
+        if app::should_leak("DeviceDiscovery") {
+            return;
+        }
+        track_destroyed!(BluetoothDeviceDiscovery);
+        unsafe { qt_binding_bluetooth_discovery_delete(self.ptr) }
+    }
+}
+
+#[derive(Default)]
+struct ControllerHandlers {
+    connected: SignalHandler,
+    disconnected: SignalHandler,
+    service_discovered: UuidHandler,
+    discovery_finished: SignalHandler,
+    error: ErrorHandler,
+}
+
+/// Binding to `QLowEnergyController`
+///
+/// See module level documentation for more information.
+pub struct Controller {
+    ptr: *mut c_void,
+    handlers: Box<ControllerHandlers>,
+}
+
+impl Controller {
+    /// Creates a `Controller` for a device
+    ///
+    /// The device is not connected, see [`connect_to_device`].
+    ///
+    /// [`connect_to_device`]: #method.connect_to_device
+    pub fn new(device: &DeviceInfo) -> Self {
+        ffi_span!("Controller", "new");
+        let mut handlers = Box::new(ControllerHandlers::default());
+        let data: *mut ControllerHandlers = &mut *handlers;
+        let ptr = unsafe {
+            qt_binding_bluetooth_controller_create(
+                device.ptr,
+                data as *mut c_void,
+                rs_bluetooth_controller_connected,
+                rs_bluetooth_controller_disconnected,
+                rs_bluetooth_controller_service_discovered,
+                rs_bluetooth_controller_discovery_finished,
+                rs_bluetooth_controller_error,
+            )
+        };
+        track_created!(BluetoothController);
+        Controller { ptr, handlers }
+    }
+
+    /// Connect to the device
+    ///
+    /// The handler set with [`on_connected`] is called once connected. Services are then
+    /// discovered with [`discover_services`].
+    ///
+    /// [`on_connected`]: #method.on_connected
+    /// [`discover_services`]: #method.discover_services
+    pub fn connect_to_device(&mut self) {
+        ffi_span!("Controller", "connect_to_device");
+        unsafe { qt_binding_bluetooth_controller_connect_to_device(self.ptr) }
+    }
+
+    /// Disconnect from the device
+    pub fn disconnect_from_device(&mut self) {
+        ffi_span!("Controller", "disconnect_from_device");
+        unsafe { qt_binding_bluetooth_controller_disconnect_from_device(self.ptr) }
+    }
+
+    /// Discover the services offered by the device
+    ///
+    /// The handler set with [`on_service_discovered`] is called for each service, and the one
+    /// set with [`on_discovery_finished`] is called once all services are discovered.
+    ///
+    /// [`on_service_discovered`]: #method.on_service_discovered
+    /// [`on_discovery_finished`]: #method.on_discovery_finished
+    pub fn discover_services(&mut self) {
+        ffi_span!("Controller", "discover_services");
+        unsafe { qt_binding_bluetooth_controller_discover_services(self.ptr) }
+    }
+
+    /// Creates a `Service` for a discovered service
+    ///
+    /// Returns `None` if the service was not discovered. The service should not outlive the
+    /// controller.
+    pub fn service<S>(&self, uuid: S) -> Option<Service>
+    where
+        S: AsRef<str>,
+    {
+        ffi_span!("Controller", "service");
+        let uuid = uuid.as_ref();
+        let ptr = unsafe {
+            qt_binding_bluetooth_controller_create_service(
+                self.ptr,
+                uuid.as_ptr() as *const c_char,
+                uuid.len() as u32,
+            )
+        };
+        if ptr.is_null() {
+            None
+        } else {
+            Some(Service::from_ptr(ptr))
+        }
+    }
+
+    /// Handle the connection being established
+    ///
+    /// Setting a new handler replaces the previous one.
+    pub fn on_connected<F>(&mut self, handler: F) -> &mut Self
+    where
+        F: FnMut() + 'static,
+    {
+        self.handlers.connected = Some(Box::new(handler));
+        self
+    }
+
+    /// Handle the connection being closed
+    ///
+    /// Setting a new handler replaces the previous one.
+    pub fn on_disconnected<F>(&mut self, handler: F) -> &mut Self
+    where
+        F: FnMut() + 'static,
+    {
+        self.handlers.disconnected = Some(Box::new(handler));
+        self
+    }
+
+    /// Handle discovered services
+    ///
+    /// The handler is called with the UUID of the service. Setting a new handler replaces the
+    /// previous one.
+    pub fn on_service_discovered<F>(&mut self, handler: F) -> &mut Self
+    where
+        F: FnMut(&str) + 'static,
+    {
+        self.handlers.service_discovered = Some(Box::new(handler));
+        self
+    }
+
+    /// Handle the end of the service discovery
+    ///
+    /// Setting a new handler replaces the previous one.
+    pub fn on_discovery_finished<F>(&mut self, handler: F) -> &mut Self
+    where
+        F: FnMut() + 'static,
+    {
+        self.handlers.discovery_finished = Some(Box::new(handler));
+        self
+    }
+
+    /// Handle errors
+    ///
+    /// Setting a new handler replaces the previous one.
+    pub fn on_error<F>(&mut self, handler: F) -> &mut Self
+    where
+        F: FnMut(Error) + 'static,
+    {
+        self.handlers.error = Some(Box::new(handler));
+        self
+    }
+}
+
+impl fmt::Debug for Controller {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Controller").finish()
+    }
+}
+
+impl Drop for Controller {
+    fn drop(&mut self) {
+        ffi_span!("Controller", "drop");
+        if app::should_leak("Controller") {
+            return;
+        }
+        track_destroyed!(BluetoothController);
+        unsafe { qt_binding_bluetooth_controller_delete(self.ptr) }
+    }
+}
+
+#[derive(Default)]
+struct ServiceHandlers {
+    details_discovered: SignalHandler,
+    characteristic_changed: ValueHandler,
+    characteristic_read: ValueHandler,
+    error: ErrorHandler,
+    #[cfg(feature = "futures-executor")]
+    notifications: Vec<(String, channel::Sender<Vec<u8>>)>,
+}
+
+/// Binding to `QLowEnergyService`
+///
+/// See module level documentation for more information.
+pub struct Service {
+    ptr: *mut c_void,
+    handlers: Box<ServiceHandlers>,
+}
+
+impl Service {
+    fn from_ptr(ptr: *mut c_void) -> Self {
+        let mut handlers = Box::new(ServiceHandlers::default());
+        let data: *mut ServiceHandlers = &mut *handlers;
+        unsafe {
+            qt_binding_bluetooth_service_connect(
+                ptr,
+                data as *mut c_void,
+                rs_bluetooth_service_details_discovered,
+                rs_bluetooth_service_characteristic_changed,
+                rs_bluetooth_service_characteristic_read,
+                rs_bluetooth_service_error,
+            )
+        }
+        track_created!(BluetoothService);
+        Service { ptr, handlers }
+    }
+
+    /// Discover the characteristics of the service
+    ///
+    /// Characteristics can only be accessed once discovered. The handler set with
+    /// [`on_details_discovered`] is called once they are discovered.
+    ///
+    /// [`on_details_discovered`]: #method.on_details_discovered
+    pub fn discover_details(&mut self) {
+        ffi_span!("Service", "discover_details");
+        unsafe { qt_binding_bluetooth_service_discover_details(self.ptr) }
+    }
+
+    /// UUIDs of the discovered characteristics
+    pub fn characteristics(&self) -> Vec<String> {
+        ffi_span!("Service", "characteristics");
+        let count = unsafe { qt_binding_bluetooth_service_characteristic_count(self.ptr) };
+        (0..count)
+            .map(|index| {
+                let mut value = String::new();
+                unsafe {
+                    let data: *mut String = &mut value;
+                    qt_binding_bluetooth_service_characteristic_uuid(
+                        self.ptr,
+                        index,
+                        data as *mut c_void,
+                        rs_string_fill,
+                    );
+                }
+                value
+            })
+            .collect()
+    }
+
+    /// Read the value of a characteristic
+    ///
+    /// The value is passed to the handler set with [`on_characteristic_read`]. Returns an
+    /// error if the characteristic was not discovered.
+    ///
+    /// [`on_characteristic_read`]: #method.on_characteristic_read
+    pub fn read<S>(&mut self, uuid: S) -> Result<(), Error>
+    where
+        S: AsRef<str>,
+    {
+        ffi_span!("Service", "read");
+        let uuid = uuid.as_ref();
+        let found = unsafe {
+            qt_binding_bluetooth_service_read_characteristic(
+                self.ptr,
+                uuid.as_ptr() as *const c_char,
+                uuid.len() as u32,
+            )
+        };
+        check_characteristic(found, uuid)
+    }
+
+    /// Write the value of a characteristic, expecting a response from the device
+    ///
+    /// Returns an error if the characteristic was not discovered. Errors raised by the device
+    /// are passed to the handler set with [`on_error`].
+    ///
+    /// [`on_error`]: #method.on_error
+    pub fn write<S>(&mut self, uuid: S, value: &[u8]) -> Result<(), Error>
+    where
+        S: AsRef<str>,
+    {
+        ffi_span!("Service", "write");
+        self.write_characteristic(uuid.as_ref(), value, true)
+    }
+
+    /// Write the value of a characteristic, without response from the device
+    ///
+    /// Returns an error if the characteristic was not discovered.
+    pub fn write_without_response<S>(&mut self, uuid: S, value: &[u8]) -> Result<(), Error>
+    where
+        S: AsRef<str>,
+    {
+        ffi_span!("Service", "write_without_response");
+        self.write_characteristic(uuid.as_ref(), value, false)
+    }
+
+    /// Enable or disable notifications for a characteristic
+    ///
+    /// Once enabled, changes to the value of the characteristic are passed to the handler set
+    /// with [`on_characteristic_changed`]. Returns an error if the characteristic was not
+    /// discovered, or does not support notifications.
+    ///
+    /// [`on_characteristic_changed`]: #method.on_characteristic_changed
+    pub fn set_notifications_enabled<S>(&mut self, uuid: S, enabled: bool) -> Result<(), Error>
+    where
+        S: AsRef<str>,
+    {
+        ffi_span!("Service", "set_notifications_enabled");
+        let uuid = uuid.as_ref();
+        let found = unsafe {
+            qt_binding_bluetooth_service_set_notifications_enabled(
+                self.ptr,
+                uuid.as_ptr() as *const c_char,
+                uuid.len() as u32,
+                enabled,
+            )
+        };
+        check_characteristic(found, uuid)
+    }
+
+    /// Receive notifications for a characteristic as a `Stream`
+    ///
+    /// Notifications are enabled for the characteristic, and each new value is yielded by the
+    /// stream. The stream ends when the service is dropped.
+    ///
+    /// # Features
+    ///
+    /// This method is only available with the `futures-executor` feature.
+    #[cfg(feature = "futures-executor")]
+    pub fn notifications<S>(&mut self, uuid: S) -> Result<Notifications, Error>
+    where
+        S: AsRef<str>,
+    {
+        let uuid = uuid.as_ref();
+        self.set_notifications_enabled(uuid, true)?;
+        let (sender, receiver) = channel::channel();
+        self.handlers
+            .notifications
+            .push((uuid.to_lowercase(), sender));
+        Ok(Notifications { receiver })
+    }
+
+    /// Handle the end of the characteristics discovery
+    ///
+    /// Setting a new handler replaces the previous one.
+    pub fn on_details_discovered<F>(&mut self, handler: F) -> &mut Self
+    where
+        F: FnMut() + 'static,
+    {
+        self.handlers.details_discovered = Some(Box::new(handler));
+        self
+    }
+
+    /// Handle notified changes to characteristics
+    ///
+    /// The handler is called with the UUID of the characteristic and its new value. Setting a
+    /// new handler replaces the previous one.
+    pub fn on_characteristic_changed<F>(&mut self, handler: F) -> &mut Self
+    where
+        F: FnMut(&str, &[u8]) + 'static,
+    {
+        self.handlers.characteristic_changed = Some(Box::new(handler));
+        self
+    }
+
+    /// Handle characteristics being read
+    ///
+    /// The handler is called with the UUID of the characteristic and its value. Setting a new
+    /// handler replaces the previous one.
+    pub fn on_characteristic_read<F>(&mut self, handler: F) -> &mut Self
+    where
+        F: FnMut(&str, &[u8]) + 'static,
+    {
+        self.handlers.characteristic_read = Some(Box::new(handler));
+        self
+    }
+
+    /// Handle errors
+    ///
+    /// Setting a new handler replaces the previous one.
+    pub fn on_error<F>(&mut self, handler: F) -> &mut Self
+    where
+        F: FnMut(Error) + 'static,
+    {
+        self.handlers.error = Some(Box::new(handler));
+        self
+    }
+
+    fn write_characteristic(
+        &mut self,
+        uuid: &str,
+        value: &[u8],
+        with_response: bool,
+    ) -> Result<(), Error> {
+        let found = unsafe {
+            qt_binding_bluetooth_service_write_characteristic(
+                self.ptr,
+                uuid.as_ptr() as *const c_char,
+                uuid.len() as u32,
+                value.as_ptr() as *const c_char,
+                value.len() as u32,
+                with_response,
+            )
+        };
+        check_characteristic(found, uuid)
+    }
+}
+
+impl fmt::Debug for Service {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Service")
+            .field("characteristics", &self.characteristics())
+            .finish()
+    }
+}
+
+impl Drop for Service {
+    fn drop(&mut self) {
+        ffi_span!("Service", "drop");
+        if !app::should_leak("Service") {
+            track_destroyed!(BluetoothService);
+            unsafe { qt_binding_bluetooth_service_delete(self.ptr) }
+        }
+        #[cfg(feature = "futures-executor")]
+        {
+            for (_, sender) in &self.handlers.notifications {
+                sender.close();
+            }
+        }
+    }
+}
+
+/// Stream of notified values of a characteristic
+///
+/// See [`Service::notifications`] for more information.
+///
+/// [`Service::notifications`]: struct.Service.html#method.notifications
+#[cfg(feature = "futures-executor")]
+pub struct Notifications {
+    receiver: channel::Receiver<Vec<u8>>,
+}
+
+#[cfg(feature = "futures-executor")]
+impl Stream for Notifications {
+    type Item = Vec<u8>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        Pin::new(&mut self.receiver).poll_next(cx)
+    }
+}
+
+fn check_characteristic(found: bool, uuid: &str) -> Result<(), Error> {
+    if found {
+        Ok(())
+    } else {
+        Err(Error {
+            message: format!("Characteristic {} is not available", uuid),
+        })
+    }
+}
+
+fn to_string(input: *const c_char, size: u32) -> String {
+    let mut value = String::new();
+    let output: *mut String = &mut value;
+    rs_string_fill(output as *mut c_void, input, size);
+    value
+}
+
+unsafe fn to_slice<'a>(input: *const c_char, size: u32) -> &'a [u8] {
+    slice::from_raw_parts(input as *const u8, size as usize)
+}
+
+extern "C" fn rs_bluetooth_discovery_device_discovered(data: *mut c_void, device: *mut c_void) {
+    let handlers = unsafe { &mut *(data as *mut DiscoveryHandlers) };
+    track_created!(BluetoothDeviceInfo);
+    let device = DeviceInfo { ptr: device };
+    if let Some(handler) = &mut handlers.device_discovered {
+        handler(device);
+    }
+}
+
+extern "C" fn rs_bluetooth_discovery_finished(data: *mut c_void) {
+    let handlers = unsafe { &mut *(data as *mut DiscoveryHandlers) };
+    if let Some(handler) = &mut handlers.finished {
+        handler();
+    }
+}
+
+extern "C" fn rs_bluetooth_discovery_error(data: *mut c_void, message: *const c_char, size: u32) {
+    let handlers = unsafe { &mut *(data as *mut DiscoveryHandlers) };
+    if let Some(handler) = &mut handlers.error {
+        handler(Error {
+            message: to_string(message, size),
+        });
+    }
+}
+
+extern "C" fn rs_bluetooth_controller_connected(data: *mut c_void) {
+    let handlers = unsafe { &mut *(data as *mut ControllerHandlers) };
+    if let Some(handler) = &mut handlers.connected {
+        handler();
+    }
+}
+
+extern "C" fn rs_bluetooth_controller_disconnected(data: *mut c_void) {
+    let handlers = unsafe { &mut *(data as *mut ControllerHandlers) };
+    if let Some(handler) = &mut handlers.disconnected {
+        handler();
+    }
+}
+
+extern "C" fn rs_bluetooth_controller_service_discovered(
+    data: *mut c_void,
+    uuid: *const c_char,
+    size: u32,
+) {
+    let handlers = unsafe { &mut *(data as *mut ControllerHandlers) };
+    if let Some(handler) = &mut handlers.service_discovered {
+        handler(&to_string(uuid, size));
+    }
+}
+
+extern "C" fn rs_bluetooth_controller_discovery_finished(data: *mut c_void) {
+    let handlers = unsafe { &mut *(data as *mut ControllerHandlers) };
+    if let Some(handler) = &mut handlers.discovery_finished {
+        handler();
+    }
+}
+
+extern "C" fn rs_bluetooth_controller_error(data: *mut c_void, message: *const c_char, size: u32) {
+    let handlers = unsafe { &mut *(data as *mut ControllerHandlers) };
+    if let Some(handler) = &mut handlers.error {
+        handler(Error {
+            message: to_string(message, size),
+        });
+    }
+}
+
+extern "C" fn rs_bluetooth_service_details_discovered(data: *mut c_void) {
+    let handlers = unsafe { &mut *(data as *mut ServiceHandlers) };
+    if let Some(handler) = &mut handlers.details_discovered {
+        handler();
+    }
+}
+
+extern "C" fn rs_bluetooth_service_characteristic_changed(
+    data: *mut c_void,
+    uuid: *const c_char,
+    uuid_size: u32,
+    value: *const c_char,
+    value_size: u32,
+) {
+    let handlers = unsafe { &mut *(data as *mut ServiceHandlers) };
+    let uuid = to_string(uuid, uuid_size);
+    let value = unsafe { to_slice(value, value_size) };
+    if let Some(handler) = &mut handlers.characteristic_changed {
+        handler(&uuid, value);
+    }
+    #[cfg(feature = "futures-executor")]
+    {
+        handlers
+            .notifications
+            .retain(|(_, sender)| !sender.is_dropped());
+        for (notified, sender) in &handlers.notifications {
+            if *notified == uuid {
+                sender.send(value.to_vec());
+            }
+        }
+    }
+}
+
+extern "C" fn rs_bluetooth_service_characteristic_read(
+    data: *mut c_void,
+    uuid: *const c_char,
+    uuid_size: u32,
+    value: *const c_char,
+    value_size: u32,
+) {
+    let handlers = unsafe { &mut *(data as *mut ServiceHandlers) };
+    if let Some(handler) = &mut handlers.characteristic_read {
+        let value = unsafe { to_slice(value, value_size) };
+        handler(&to_string(uuid, uuid_size), value);
+    }
+}
+
+extern "C" fn rs_bluetooth_service_error(data: *mut c_void, message: *const c_char, size: u32) {
+    let handlers = unsafe { &mut *(data as *mut ServiceHandlers) };
+    if let Some(handler) = &mut handlers.error {
+        handler(Error {
+            message: to_string(message, size),
+        });
+    }
+}
+
+type RsSignalFunc = extern "C" fn(data: *mut c_void);
+type RsMessageFunc = extern "C" fn(data: *mut c_void, input: *const c_char, size: u32);
+type RsDeviceFunc = extern "C" fn(data: *mut c_void, device: *mut c_void);
+type RsValueFunc = extern "C" fn(
+    data: *mut c_void,
+    uuid: *const c_char,
+    uuid_size: u32,
+    value: *const c_char,
+    value_size: u32,
+);
+
+extern "C" {
+    fn qt_binding_bluetooth_device_info_clone(device: *const c_void) -> *mut c_void;
+    fn qt_binding_bluetooth_device_info_delete(device: *mut c_void);
+    fn qt_binding_bluetooth_device_info_name(
+        device: *const c_void,
+        output: *mut c_void,
+        fill: RsStringFillFunc,
+    );
+    fn qt_binding_bluetooth_device_info_address(
+        device: *const c_void,
+        output: *mut c_void,
+        fill: RsStringFillFunc,
+    );
+    fn qt_binding_bluetooth_device_info_rssi(device: *const c_void) -> i16;
+
+    fn qt_binding_bluetooth_discovery_create(
+        data: *mut c_void,
+        device_discovered: RsDeviceFunc,
+        finished: RsSignalFunc,
+        error: RsMessageFunc,
+    ) -> *mut c_void;
+    fn qt_binding_bluetooth_discovery_delete(discovery: *mut c_void);
+    fn qt_binding_bluetooth_discovery_start(discovery: *mut c_void);
+    fn qt_binding_bluetooth_discovery_stop(discovery: *mut c_void);
+    fn qt_binding_bluetooth_discovery_is_active(discovery: *const c_void) -> bool;
+
+    fn qt_binding_bluetooth_controller_create(
+        device: *const c_void,
+        data: *mut c_void,
+        connected: RsSignalFunc,
+        disconnected: RsSignalFunc,
+        service_discovered: RsMessageFunc,
+        discovery_finished: RsSignalFunc,
+        error: RsMessageFunc,
+    ) -> *mut c_void;
+    fn qt_binding_bluetooth_controller_delete(controller: *mut c_void);
+    fn qt_binding_bluetooth_controller_connect_to_device(controller: *mut c_void);
+    fn qt_binding_bluetooth_controller_disconnect_from_device(controller: *mut c_void);
+    fn qt_binding_bluetooth_controller_discover_services(controller: *mut c_void);
+    fn qt_binding_bluetooth_controller_create_service(
+        controller: *mut c_void,
+        uuid: *const c_char,
+        size: u32,
+    ) -> *mut c_void;
+
+    fn qt_binding_bluetooth_service_connect(
+        service: *mut c_void,
+        data: *mut c_void,
+        details_discovered: RsSignalFunc,
+        characteristic_changed: RsValueFunc,
+        characteristic_read: RsValueFunc,
+        error: RsMessageFunc,
+    );
+    fn qt_binding_bluetooth_service_delete(service: *mut c_void);
+    fn qt_binding_bluetooth_service_discover_details(service: *mut c_void);
+    fn qt_binding_bluetooth_service_characteristic_count(service: *const c_void) -> c_int;
+    fn qt_binding_bluetooth_service_characteristic_uuid(
+        service: *const c_void,
+        index: c_int,
+        output: *mut c_void,
+        fill: RsStringFillFunc,
+    );
+    fn qt_binding_bluetooth_service_read_characteristic(
+        service: *mut c_void,
+        uuid: *const c_char,
+        size: u32,
+    ) -> bool;
+    fn qt_binding_bluetooth_service_write_characteristic(
+        service: *mut c_void,
+        uuid: *const c_char,
+        uuid_size: u32,
+        value: *const c_char,
+        value_size: u32,
+        with_response: bool,
+    ) -> bool;
+    fn qt_binding_bluetooth_service_set_notifications_enabled(
+        service: *mut c_void,
+        uuid: *const c_char,
+        size: u32,
+        enabled: bool,
+    ) -> bool;
+}
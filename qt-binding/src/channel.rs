@@ -0,0 +1,124 @@
+//! Unbounded channel received as a `Stream`
+//!
+//! Bindings that forward Qt signals to a `Stream` keep a [`Sender`] per stream, send values
+//! from the signal handler, and close the senders when the bound object is dropped. Values are
+//! queued until the stream is polled, and the stream ends once the channel is closed and all
+//! queued values have been yielded.
+//!
+//! [`Sender`]: struct.Sender.html
+
+use futures_core::stream::Stream;
+use std::collections::VecDeque;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
+
+struct Shared<T> {
+    values: VecDeque<T>,
+    waker: Option<Waker>,
+    closed: bool,
+}
+
+/// Sending half of a channel
+pub(crate) struct Sender<T> {
+    shared: Arc<Mutex<Shared<T>>>,
+}
+
+impl<T> Sender<T> {
+    /// Check if the receiving half has been dropped
+    pub(crate) fn is_dropped(&self) -> bool {
+        Arc::strong_count(&self.shared) == 1
+    }
+
+    pub(crate) fn send(&self, value: T) {
+        let mut shared = self.shared.lock().unwrap();
+        shared.values.push_back(value);
+        if let Some(waker) = shared.waker.take() {
+            waker.wake();
+        }
+    }
+
+    /// End the stream after the values that are already queued
+    pub(crate) fn close(&self) {
+        let mut shared = self.shared.lock().unwrap();
+        shared.closed = true;
+        if let Some(waker) = shared.waker.take() {
+            waker.wake();
+        }
+    }
+}
+
+/// Receiving half of a channel
+pub(crate) struct Receiver<T> {
+    shared: Arc<Mutex<Shared<T>>>,
+}
+
+impl<T> Stream for Receiver<T> {
+    type Item = T;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut shared = self.shared.lock().unwrap();
+        if let Some(value) = shared.values.pop_front() {
+            Poll::Ready(Some(value))
+        } else if shared.closed {
+            Poll::Ready(None)
+        } else {
+            shared.waker = Some(cx.waker().clone());
+            Poll::Pending
+        }
+    }
+}
+
+pub(crate) fn channel<T>() -> (Sender<T>, Receiver<T>) {
+    let shared = Arc::new(Mutex::new(Shared {
+        values: VecDeque::new(),
+        waker: None,
+        closed: false,
+    }));
+    let sender = Sender {
+        shared: shared.clone(),
+    };
+    (sender, Receiver { shared })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures_util::future::FutureExt;
+    use futures_util::StreamExt;
+
+    #[test]
+    fn test_channel() {
+        let (sender, mut receiver) = channel();
+        assert!(!sender.is_dropped());
+
+        sender.send(1);
+        sender.send(2);
+        sender.close();
+
+        let values = async {
+            let mut values = Vec::new();
+            while let Some(value) = receiver.next().await {
+                values.push(value);
+            }
+            values
+        }
+        .now_or_never();
+        assert_eq!(values, Some(vec![1, 2]));
+
+        drop(receiver);
+        assert!(sender.is_dropped());
+    }
+
+    #[test]
+    fn test_pending() {
+        let (sender, mut receiver) = channel::<u8>();
+        assert_eq!(receiver.next().now_or_never(), None);
+
+        sender.send(3);
+        assert_eq!(receiver.next().now_or_never(), Some(Some(3)));
+
+        sender.close();
+        assert_eq!(receiver.next().now_or_never(), Some(None));
+    }
+}
@@ -0,0 +1,33 @@
+#include <QtCore/QCoreApplication>
+#include <QtCore/QThread>
+#include <QtWidgets/QMessageBox>
+#include <cstdint>
+
+QT_USE_NAMESPACE
+
+static QString toString(const char *value, std::uint32_t size)
+{
+    return QString::fromUtf8(value, static_cast<int>(size));
+}
+
+extern "C" {
+
+bool qt_binding_crash_dialog_is_main_thread()
+{
+    auto app = QCoreApplication::instance();
+    return app != nullptr && QThread::currentThread() == app->thread();
+}
+
+void qt_binding_crash_dialog_show(const char *title, std::uint32_t titleSize, const char *text,
+                                  std::uint32_t textSize, const char *message,
+                                  std::uint32_t messageSize, const char *details,
+                                  std::uint32_t detailsSize)
+{
+    QMessageBox box(QMessageBox::Critical, toString(title, titleSize), toString(text, textSize),
+                    QMessageBox::Close);
+    box.setInformativeText(toString(message, messageSize));
+    box.setDetailedText(toString(details, detailsSize));
+    box.exec();
+}
+
+} // extern "C"
@@ -0,0 +1,152 @@
+//! Crash reports for end users
+//!
+//! When a Rust panic happens in the main thread of a GUI application, the application usually
+//! vanishes, or is left in an inconsistent state, and end users only see a closed window.
+//! [`CrashDialog`] installs an opt-in panic hook that shows a native error dialog with the
+//! panic message, before aborting the application.
+//!
+//! [`CrashDialog`]: struct.CrashDialog.html
+//!
+//! # Features
+//!
+//! This module is only available with the `widgets` feature.
+//!
+//! # Limitations
+//!
+//! The dialog is only shown for panics in the main thread, while the [`Application`] is alive.
+//! Panics in other threads are handled by the previous panic hook only. Since the application
+//! is aborted after the dialog is closed, panics in the main thread can't be caught with
+//! `std::panic::catch_unwind` anymore.
+//!
+//! [`Application`]: ../app/struct.Application.html
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use qt_binding::app::Application;
+//! use qt_binding::crash::CrashDialog;
+//!
+//! let mut app = Application::new();
+//! if !cfg!(debug_assertions) {
+//!     CrashDialog::new()
+//!         .title("Editor crashed")
+//!         .hint("Please report this error at https://example.com/issues")
+//!         .install();
+//! }
+//! app.exec();
+//! ```
+
+use crate::app::Application;
+use std::os::raw::c_char;
+use std::panic;
+use std::process;
+
+/// Panic hook showing an error dialog
+///
+/// See module level documentation for more information.
+#[derive(Clone, Debug)]
+pub struct CrashDialog {
+    title: String,
+    text: String,
+    hint: String,
+}
+
+impl CrashDialog {
+    /// Creates a `CrashDialog`
+    ///
+    /// By default, the dialog has a generic title and text, and hints at running the
+    /// application with `RUST_BACKTRACE=1` to get a backtrace.
+    pub fn new() -> Self {
+        CrashDialog {
+            title: "Unexpected error".to_string(),
+            text: "The application encountered an unexpected error and will close.".to_string(),
+            hint: "Run the application with the RUST_BACKTRACE=1 environment variable to get \
+                   a backtrace."
+                .to_string(),
+        }
+    }
+
+    /// Title of the dialog
+    pub fn title<S>(&mut self, title: S) -> &mut Self
+    where
+        S: Into<String>,
+    {
+        self.title = title.into();
+        self
+    }
+
+    /// Main text of the dialog, displayed above the panic message
+    pub fn text<S>(&mut self, text: S) -> &mut Self
+    where
+        S: Into<String>,
+    {
+        self.text = text.into();
+        self
+    }
+
+    /// Hint displayed with the details of the panic, like where to report errors
+    pub fn hint<S>(&mut self, hint: S) -> &mut Self
+    where
+        S: Into<String>,
+    {
+        self.hint = hint.into();
+        self
+    }
+
+    /// Install the panic hook
+    ///
+    /// The previous panic hook is called before showing the dialog, so panic messages are
+    /// still printed on the standard error.
+    pub fn install(&self) {
+        ffi_span!("CrashDialog", "install");
+        let dialog = self.clone();
+        let previous = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            previous(info);
+            if Application::is_alive() && unsafe { qt_binding_crash_dialog_is_main_thread() } {
+                let payload = info.payload();
+                let message = (payload.downcast_ref::<&str>().copied())
+                    .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+                    .unwrap_or("Unknown error");
+                dialog.show(message, &info.to_string());
+                process::abort();
+            }
+        }));
+    }
+
+    fn show(&self, message: &str, info: &str) {
+        let details = format!("{}\n\n{}", info, self.hint);
+        unsafe {
+            qt_binding_crash_dialog_show(
+                self.title.as_ptr() as *const c_char,
+                self.title.len() as u32,
+                self.text.as_ptr() as *const c_char,
+                self.text.len() as u32,
+                message.as_ptr() as *const c_char,
+                message.len() as u32,
+                details.as_ptr() as *const c_char,
+                details.len() as u32,
+            )
+        }
+    }
+}
+
+impl Default for CrashDialog {
+    fn default() -> Self {
+        CrashDialog::new()
+    }
+}
+
+extern "C" {
+    fn qt_binding_crash_dialog_is_main_thread() -> bool;
+    fn qt_binding_crash_dialog_show(
+        title: *const c_char,
+        title_size: u32,
+        text: *const c_char,
+        text_size: u32,
+        message: *const c_char,
+        message_size: u32,
+        details: *const c_char,
+        details_size: u32,
+    );
+}
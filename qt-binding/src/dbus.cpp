@@ -0,0 +1,240 @@
+#include "dbus.h"
+
+#include <QtDBus/QDBusConnection>
+#include <QtDBus/QDBusConnectionInterface>
+#include <QtDBus/QDBusPendingCallWatcher>
+#include <QtDBus/QDBusVirtualObject>
+#include <cstdint>
+
+QT_USE_NAMESPACE
+
+using RsStringFillFunc = void (*)(void *output, const char *input, std::uint32_t inputSize);
+using RsReplyFunc = void (*)(void *data, QVariant *arguments, const char *errorName,
+                             std::uint32_t errorNameSize, const char *errorMessage,
+                             std::uint32_t errorMessageSize);
+using RsCallFunc = void (*)(void *data, const char *interface, std::uint32_t interfaceSize,
+                            const char *member, std::uint32_t memberSize, QVariant *arguments,
+                            void *result);
+
+static QString toString(const char *value, std::uint32_t size)
+{
+    return QString::fromUtf8(value, static_cast<int>(size));
+}
+
+static void sendReply(void *data, RsReplyFunc reply, const QDBusMessage &message)
+{
+    if (message.type() == QDBusMessage::ErrorMessage) {
+        const auto name = message.errorName().toUtf8();
+        const auto errorMessage = message.errorMessage().toUtf8();
+        reply(data, nullptr, name.constData(), static_cast<std::uint32_t>(name.size()),
+              errorMessage.constData(), static_cast<std::uint32_t>(errorMessage.size()));
+    } else {
+        reply(data, new QVariant(message.arguments()), nullptr, 0, nullptr, 0);
+    }
+}
+
+namespace qt_binding {
+
+DBusSignalReceiver::DBusSignalReceiver(void *data, RsSignalFunc signal)
+    : m_data(data)
+    , m_signal(signal)
+{
+}
+
+void DBusSignalReceiver::handleSignal(const QDBusMessage &message)
+{
+    m_signal(m_data, new QVariant(message.arguments()));
+}
+
+struct DBusCallResult
+{
+    QVariantList arguments;
+    QString errorName;
+    QString errorMessage;
+};
+
+class DBusObject : public QDBusVirtualObject
+{
+public:
+    DBusObject(QString introspection, void *data, RsCallFunc call)
+        : m_introspection(std::move(introspection))
+        , m_data(data)
+        , m_call(call)
+    {
+    }
+
+    QString introspect(const QString &path) const override
+    {
+        Q_UNUSED(path)
+        return m_introspection;
+    }
+
+    bool handleMessage(const QDBusMessage &message, const QDBusConnection &connection) override
+    {
+        if (message.type() != QDBusMessage::MethodCallMessage) {
+            return false;
+        }
+
+        const auto interface = message.interface().toUtf8();
+        const auto member = message.member().toUtf8();
+        auto result = DBusCallResult();
+        m_call(m_data, interface.constData(), static_cast<std::uint32_t>(interface.size()),
+               member.constData(), static_cast<std::uint32_t>(member.size()),
+               new QVariant(message.arguments()), &result);
+
+        if (!result.errorName.isEmpty()) {
+            connection.send(message.createErrorReply(result.errorName, result.errorMessage));
+        } else {
+            connection.send(message.createReply(result.arguments));
+        }
+        return true;
+    }
+
+private:
+    QString m_introspection;
+    void *m_data;
+    RsCallFunc m_call;
+};
+
+} // namespace qt_binding
+
+extern "C" {
+
+QDBusConnection *qt_binding_dbus_connection_create(bool system)
+{
+    return new QDBusConnection(system ? QDBusConnection::systemBus()
+                                      : QDBusConnection::sessionBus());
+}
+
+void qt_binding_dbus_connection_delete(QDBusConnection *connection)
+{
+    delete connection;
+}
+
+bool qt_binding_dbus_connection_is_connected(const QDBusConnection *connection)
+{
+    return connection->isConnected();
+}
+
+void qt_binding_dbus_connection_last_error(const QDBusConnection *connection, void *name,
+                                           void *message, RsStringFillFunc fill)
+{
+    const auto error = connection->lastError();
+    const auto errorName = error.name().toUtf8();
+    const auto errorMessage = error.message().toUtf8();
+    fill(name, errorName.constData(), static_cast<std::uint32_t>(errorName.size()));
+    fill(message, errorMessage.constData(), static_cast<std::uint32_t>(errorMessage.size()));
+}
+
+bool qt_binding_dbus_connection_register_service(QDBusConnection *connection, const char *name,
+                                                 std::uint32_t size)
+{
+    return connection->registerService(toString(name, size));
+}
+
+bool qt_binding_dbus_connection_unregister_service(QDBusConnection *connection,
+                                                   const char *name, std::uint32_t size)
+{
+    return connection->unregisterService(toString(name, size));
+}
+
+void qt_binding_dbus_connection_call(QDBusConnection *connection, const char *service,
+                                     std::uint32_t serviceSize, const char *path,
+                                     std::uint32_t pathSize, const char *interface,
+                                     std::uint32_t interfaceSize, const char *method,
+                                     std::uint32_t methodSize, const QVariant *arguments,
+                                     bool async, void *data, RsReplyFunc reply)
+{
+    auto message = QDBusMessage::createMethodCall(
+        toString(service, serviceSize), toString(path, pathSize),
+        toString(interface, interfaceSize), toString(method, methodSize));
+    message.setArguments(arguments->toList());
+
+    if (!async) {
+        sendReply(data, reply, connection->call(message));
+        return;
+    }
+
+    auto watcher = new QDBusPendingCallWatcher(connection->asyncCall(message));
+    QObject::connect(watcher, &QDBusPendingCallWatcher::finished,
+                     [data, reply](QDBusPendingCallWatcher *watcher) {
+                         sendReply(data, reply, watcher->reply());
+                         watcher->deleteLater();
+                     });
+}
+
+bool qt_binding_dbus_connection_emit_signal(QDBusConnection *connection, const char *path,
+                                            std::uint32_t pathSize, const char *interface,
+                                            std::uint32_t interfaceSize, const char *name,
+                                            std::uint32_t nameSize, const QVariant *arguments)
+{
+    auto message = QDBusMessage::createSignal(toString(path, pathSize),
+                                              toString(interface, interfaceSize),
+                                              toString(name, nameSize));
+    message.setArguments(arguments->toList());
+    return connection->send(message);
+}
+
+qt_binding::DBusObject *qt_binding_dbus_object_create(const char *introspection,
+                                                      std::uint32_t size, void *data,
+                                                      RsCallFunc call)
+{
+    return new qt_binding::DBusObject(toString(introspection, size), data, call);
+}
+
+void qt_binding_dbus_object_delete(qt_binding::DBusObject *object)
+{
+    delete object;
+}
+
+void qt_binding_dbus_call_result_set_arguments(qt_binding::DBusCallResult *result,
+                                               const QVariant *arguments)
+{
+    result->arguments = arguments->toList();
+}
+
+void qt_binding_dbus_call_result_set_error(qt_binding::DBusCallResult *result, const char *name,
+                                           std::uint32_t nameSize, const char *message,
+                                           std::uint32_t messageSize)
+{
+    result->errorName = toString(name, nameSize);
+    result->errorMessage = toString(message, messageSize);
+}
+
+bool qt_binding_dbus_connection_register_object(QDBusConnection *connection, const char *path,
+                                                std::uint32_t size,
+                                                qt_binding::DBusObject *object)
+{
+    return connection->registerVirtualObject(toString(path, size), object);
+}
+
+void qt_binding_dbus_connection_unregister_object(QDBusConnection *connection, const char *path,
+                                                  std::uint32_t size)
+{
+    connection->unregisterObject(toString(path, size));
+}
+
+qt_binding::DBusSignalReceiver *qt_binding_dbus_connection_subscribe(
+    QDBusConnection *connection, const char *service, std::uint32_t serviceSize,
+    const char *path, std::uint32_t pathSize, const char *interface, std::uint32_t interfaceSize,
+    const char *name, std::uint32_t nameSize, void *data, qt_binding::RsSignalFunc signal)
+{
+    auto receiver = new qt_binding::DBusSignalReceiver(data, signal);
+    const auto connected = connection->connect(
+        toString(service, serviceSize), toString(path, pathSize),
+        toString(interface, interfaceSize), toString(name, nameSize), receiver,
+        SLOT(handleSignal(QDBusMessage)));
+    if (!connected) {
+        delete receiver;
+        return nullptr;
+    }
+    return receiver;
+}
+
+void qt_binding_dbus_signal_receiver_delete(qt_binding::DBusSignalReceiver *receiver)
+{
+    // QtDBus disconnects the signal when the receiver is destroyed
+    delete receiver;
+}
+
+} // extern "C"
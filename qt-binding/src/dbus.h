@@ -0,0 +1,29 @@
+#ifndef QT_BINDING_DBUS_H
+#define QT_BINDING_DBUS_H
+
+#include <QtCore/QObject>
+#include <QtDBus/QDBusMessage>
+
+QT_USE_NAMESPACE
+
+namespace qt_binding {
+
+using RsSignalFunc = void (*)(void *data, QVariant *arguments);
+
+class DBusSignalReceiver : public QObject
+{
+    Q_OBJECT
+public:
+    explicit DBusSignalReceiver(void *data, RsSignalFunc signal);
+
+public slots:
+    void handleSignal(const QDBusMessage &message);
+
+private:
+    void *m_data{nullptr};
+    RsSignalFunc m_signal{nullptr};
+};
+
+} // namespace qt_binding
+
+#endif // QT_BINDING_DBUS_H
@@ -0,0 +1,723 @@
+//! Bindings to Qt D-Bus
+//!
+//! [`Connection`] is a binding to `QDBusConnection`, a connection to the session or system bus.
+//! It can call methods of remote objects, emit and subscribe to signals, and register objects
+//! implemented in Rust, with [`Object`].
+//!
+//! Arguments and return values are marshalled as [`Variant`]s. Basic D-Bus types are converted
+//! to and from the corresponding Qt types, like `QString` or `int`. Complex types, like structs
+//! or dictionaries, are passed as `QDBusArgument`.
+//!
+//! D-Bus calls and signals are dispatched by Qt's event-loop, so that there is no need to run
+//! a separate D-Bus event-loop.
+//!
+//! [`Connection`]: struct.Connection.html
+//! [`Object`]: struct.Object.html
+//! [`Variant`]: ../variant/struct.Variant.html
+//!
+//! # Features
+//!
+//! This module is only available with the `dbus` feature.
+//!
+//! # Examples
+//!
+//! Calling a method
+//!
+//! ```no_run
+//! use qt_binding::app::Application;
+//! use qt_binding::dbus::Connection;
+//! use qt_binding::variant::Variant;
+//! use std::convert::TryFrom;
+//!
+//! let _app = Application::new();
+//!
+//! let connection = Connection::session();
+//! let reply = connection
+//!     .call(
+//!         "org.freedesktop.DBus",
+//!         "/org/freedesktop/DBus",
+//!         "org.freedesktop.DBus",
+//!         "ListNames",
+//!         &[],
+//!     )
+//!     .unwrap();
+//! let names = Vec::<Variant>::try_from(&reply[0]).unwrap();
+//! println!("{} names on the bus", names.len());
+//! ```
+//!
+//! Registering an object
+//!
+//! ```no_run
+//! use qt_binding::app::Application;
+//! use qt_binding::dbus::{Connection, Error, Object};
+//! use qt_binding::variant::Variant;
+//! use std::convert::TryFrom;
+//!
+//! const INTROSPECTION: &str = r#"
+//! <interface name="org.example.Greeter">
+//!   <method name="Greet">
+//!     <arg name="name" type="s" direction="in"/>
+//!     <arg name="greeting" type="s" direction="out"/>
+//!   </method>
+//! </interface>"#;
+//!
+//! let mut app = Application::new();
+//!
+//! let object = Object::new(INTROSPECTION, |_, member, args| match member {
+//!     "Greet" => {
+//!         let name = String::try_from(&args[0]).unwrap_or_default();
+//!         Ok(vec![Variant::from(format!("Hello {}", name).as_str())])
+//!     }
+//!     _ => Err(Error::new("org.freedesktop.DBus.Error.UnknownMethod", member)),
+//! });
+//!
+//! let mut connection = Connection::session();
+//! connection.register_service("org.example.Greeter").unwrap();
+//! connection.register_object("/greeter", &object).unwrap();
+//!
+//! app.exec();
+//! ```
+
+use crate::app;
+use crate::variant::convert::{rs_string_fill, RsStringFillFunc};
+use crate::variant::Variant;
+use std::convert::TryFrom;
+use std::error;
+use std::fmt;
+use std::os::raw::{c_char, c_void};
+
+type ReplyHandler = Box<dyn FnOnce(Result<Vec<Variant>, Error>)>;
+type CallHandler = Box<dyn FnMut(&str, &str, Vec<Variant>) -> Result<Vec<Variant>, Error>>;
+type SignalHandler = Box<dyn FnMut(Vec<Variant>)>;
+
+/// D-Bus error
+///
+/// Errors have a name, like `org.freedesktop.DBus.Error.UnknownMethod`, and a message.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Error {
+    name: String,
+    message: String,
+}
+
+impl Error {
+    /// Creates an `Error`
+    ///
+    /// This is used to return errors from objects implemented in Rust.
+    pub fn new<N, M>(name: N, message: M) -> Self
+    where
+        N: Into<String>,
+        M: Into<String>,
+    {
+        Error {
+            name: name.into(),
+            message: message.into(),
+        }
+    }
+
+    /// Error name
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Error message
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {}", self.name, self.message)
+    }
+}
+
+impl error::Error for Error {}
+
+/// Binding to `QDBusConnection`
+///
+/// See module level documentation for more information.
+pub struct Connection {
+    ptr: *mut c_void,
+}
+
+impl Connection {
+    /// Connection to the session bus
+    pub fn session() -> Self {
+        ffi_span!("Connection", "session");
+        track_created!(DBusConnection);
+        Connection {
+            ptr: unsafe { qt_binding_dbus_connection_create(false) },
+        }
+    }
+
+    /// Connection to the system bus
+    pub fn system() -> Self {
+        ffi_span!("Connection", "system");
+        track_created!(DBusConnection);
+        Connection {
+            ptr: unsafe { qt_binding_dbus_connection_create(true) },
+        }
+    }
+
+    /// Returns `true` if connected to the bus
+    pub fn is_connected(&self) -> bool {
+        ffi_span!("Connection", "is_connected");
+        unsafe { qt_binding_dbus_connection_is_connected(self.ptr) }
+    }
+
+    /// Request a well-known service name, like `org.example.App`
+    pub fn register_service<S>(&mut self, name: S) -> Result<(), Error>
+    where
+        S: AsRef<str>,
+    {
+        ffi_span!("Connection", "register_service");
+        let name = name.as_ref();
+        self.check(unsafe {
+            qt_binding_dbus_connection_register_service(
+                self.ptr,
+                name.as_ptr() as *const c_char,
+                name.len() as u32,
+            )
+        })
+    }
+
+    /// Release a well-known service name
+    pub fn unregister_service<S>(&mut self, name: S) -> Result<(), Error>
+    where
+        S: AsRef<str>,
+    {
+        ffi_span!("Connection", "unregister_service");
+        let name = name.as_ref();
+        self.check(unsafe {
+            qt_binding_dbus_connection_unregister_service(
+                self.ptr,
+                name.as_ptr() as *const c_char,
+                name.len() as u32,
+            )
+        })
+    }
+
+    /// Call a method and wait for the reply
+    ///
+    /// This call blocks until the reply is received. Prefer [`call_async`] in GUI
+    /// applications, to keep the event-loop running.
+    ///
+    /// [`call_async`]: #method.call_async
+    pub fn call(
+        &self,
+        service: &str,
+        path: &str,
+        interface: &str,
+        method: &str,
+        args: &[Variant],
+    ) -> Result<Vec<Variant>, Error> {
+        ffi_span!("Connection", "call");
+        let mut reply: Option<Result<Vec<Variant>, Error>> = None;
+        let data: *mut Option<Result<Vec<Variant>, Error>> = &mut reply;
+        self.send_call(
+            service,
+            path,
+            interface,
+            method,
+            args,
+            false,
+            data as *mut c_void,
+            rs_dbus_call_reply,
+        );
+        reply.unwrap_or_else(|| Err(Error::new("org.freedesktop.DBus.Error.NoReply", "")))
+    }
+
+    /// Call a method without blocking
+    ///
+    /// The handler is called from the event-loop with the reply.
+    pub fn call_async<F>(
+        &self,
+        service: &str,
+        path: &str,
+        interface: &str,
+        method: &str,
+        args: &[Variant],
+        handler: F,
+    ) where
+        F: FnOnce(Result<Vec<Variant>, Error>) + 'static,
+    {
+        ffi_span!("Connection", "call_async");
+        let handler: Box<ReplyHandler> = Box::new(Box::new(handler));
+        self.send_call(
+            service,
+            path,
+            interface,
+            method,
+            args,
+            true,
+            Box::into_raw(handler) as *mut c_void,
+            rs_dbus_call_async_reply,
+        );
+    }
+
+    /// Emit a signal
+    pub fn emit_signal(
+        &mut self,
+        path: &str,
+        interface: &str,
+        name: &str,
+        args: &[Variant],
+    ) -> Result<(), Error> {
+        ffi_span!("Connection", "emit_signal");
+        let args = args.iter().collect::<Variant>();
+        self.check(unsafe {
+            qt_binding_dbus_connection_emit_signal(
+                self.ptr,
+                path.as_ptr() as *const c_char,
+                path.len() as u32,
+                interface.as_ptr() as *const c_char,
+                interface.len() as u32,
+                name.as_ptr() as *const c_char,
+                name.len() as u32,
+                args.as_ptr(),
+            )
+        })
+    }
+
+    /// Register an object at a path
+    ///
+    /// The object should outlive its registration. It is unregistered when dropped.
+    pub fn register_object(&mut self, path: &str, object: &Object) -> Result<(), Error> {
+        ffi_span!("Connection", "register_object");
+        self.check(unsafe {
+            qt_binding_dbus_connection_register_object(
+                self.ptr,
+                path.as_ptr() as *const c_char,
+                path.len() as u32,
+                object.ptr,
+            )
+        })
+    }
+
+    /// Unregister the object at a path
+    pub fn unregister_object(&mut self, path: &str) {
+        ffi_span!("Connection", "unregister_object");
+        unsafe {
+            qt_binding_dbus_connection_unregister_object(
+                self.ptr,
+                path.as_ptr() as *const c_char,
+                path.len() as u32,
+            )
+        }
+    }
+
+    /// Subscribe to a signal
+    ///
+    /// The handler is called from the event-loop with the arguments of the signal. An empty
+    /// service, path or interface matches any sender, path or interface. The subscription
+    /// ends when the returned [`Subscription`] is dropped.
+    ///
+    /// [`Subscription`]: struct.Subscription.html
+    pub fn subscribe<F>(
+        &mut self,
+        service: &str,
+        path: &str,
+        interface: &str,
+        name: &str,
+        handler: F,
+    ) -> Result<Subscription, Error>
+    where
+        F: FnMut(Vec<Variant>) + 'static,
+    {
+        ffi_span!("Connection", "subscribe");
+        let mut handler: Box<SignalHandler> = Box::new(Box::new(handler));
+        let data: *mut SignalHandler = &mut *handler;
+        let ptr = unsafe {
+            qt_binding_dbus_connection_subscribe(
+                self.ptr,
+                service.as_ptr() as *const c_char,
+                service.len() as u32,
+                path.as_ptr() as *const c_char,
+                path.len() as u32,
+                interface.as_ptr() as *const c_char,
+                interface.len() as u32,
+                name.as_ptr() as *const c_char,
+                name.len() as u32,
+                data as *mut c_void,
+                rs_dbus_signal,
+            )
+        };
+        if ptr.is_null() {
+            Err(self.last_error())
+        } else {
+            track_created!(DBusSubscription);
+            Ok(Subscription {
+                ptr,
+                _handler: handler,
+            })
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn send_call(
+        &self,
+        service: &str,
+        path: &str,
+        interface: &str,
+        method: &str,
+        args: &[Variant],
+        is_async: bool,
+        data: *mut c_void,
+        reply: RsReplyFunc,
+    ) {
+        let args = args.iter().collect::<Variant>();
+        unsafe {
+            qt_binding_dbus_connection_call(
+                self.ptr,
+                service.as_ptr() as *const c_char,
+                service.len() as u32,
+                path.as_ptr() as *const c_char,
+                path.len() as u32,
+                interface.as_ptr() as *const c_char,
+                interface.len() as u32,
+                method.as_ptr() as *const c_char,
+                method.len() as u32,
+                args.as_ptr(),
+                is_async,
+                data,
+                reply,
+            )
+        }
+    }
+
+    fn check(&self, result: bool) -> Result<(), Error> {
+        if result {
+            Ok(())
+        } else {
+            Err(self.last_error())
+        }
+    }
+
+    fn last_error(&self) -> Error {
+        let mut name = String::new();
+        let mut message = String::new();
+        unsafe {
+            let name_data: *mut String = &mut name;
+            let message_data: *mut String = &mut message;
+            qt_binding_dbus_connection_last_error(
+                self.ptr,
+                name_data as *mut c_void,
+                message_data as *mut c_void,
+                rs_string_fill,
+            );
+        }
+        Error { name, message }
+    }
+}
+
+impl fmt::Debug for Connection {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Connection")
+            .field("is_connected", &self.is_connected())
+            .finish()
+    }
+}
+
+impl Drop for Connection {
+    fn drop(&mut self) {
+        ffi_span!("Connection", "drop");
+        if app::should_leak("Connection") {
+            return;
+        }
+        track_destroyed!(DBusConnection);
+        unsafe { qt_binding_dbus_connection_delete(self.ptr) }
+    }
+}
+
+/// An object implemented in Rust
+///
+/// Binding to `QDBusVirtualObject`. Method calls are passed to a handler, that returns either
+/// the output arguments or an error. The object is described by introspection data, the XML
+/// description of its interfaces.
+///
+/// See module level documentation for more information.
+pub struct Object {
+    ptr: *mut c_void,
+    _handler: Box<CallHandler>,
+}
+
+impl Object {
+    /// Creates an `Object`
+    ///
+    /// The handler is called with the interface and name of the called method, and its input
+    /// arguments. Properties are implemented by handling methods of the
+    /// `org.freedesktop.DBus.Properties` interface.
+    pub fn new<S, F>(introspection: S, handler: F) -> Self
+    where
+        S: AsRef<str>,
+        F: FnMut(&str, &str, Vec<Variant>) -> Result<Vec<Variant>, Error> + 'static,
+    {
+        ffi_span!("Object", "new");
+        let introspection = introspection.as_ref();
+        let mut handler: Box<CallHandler> = Box::new(Box::new(handler));
+        let data: *mut CallHandler = &mut *handler;
+        let ptr = unsafe {
+            qt_binding_dbus_object_create(
+                introspection.as_ptr() as *const c_char,
+                introspection.len() as u32,
+                data as *mut c_void,
+                rs_dbus_object_call,
+            )
+        };
+        track_created!(DBusObject);
+        Object {
+            ptr,
+            _handler: handler,
+        }
+    }
+}
+
+impl fmt::Debug for Object {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Object").finish()
+    }
+}
+
+impl Drop for Object {
+    fn drop(&mut self) {
+        ffi_span!("Object", "drop");
+        if app::should_leak("Object") {
+            return;
+        }
+        track_destroyed!(DBusObject);
+        unsafe { qt_binding_dbus_object_delete(self.ptr) }
+    }
+}
+
+/// Subscription to a signal
+///
+/// See [`Connection::subscribe`] for more information.
+///
+/// [`Connection::subscribe`]: struct.Connection.html#method.subscribe
+pub struct Subscription {
+    ptr: *mut c_void,
+    _handler: Box<SignalHandler>,
+}
+
+impl fmt::Debug for Subscription {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Subscription").finish()
+    }
+}
+
+impl Drop for Subscription {
+    fn drop(&mut self) {
+        ffi_span!("Subscription", "drop");
+        if app::should_leak("Subscription") {
+            return;
+        }
+        track_destroyed!(DBusSubscription);
+        unsafe { qt_binding_dbus_signal_receiver_delete(self.ptr) }
+    }
+}
+
+fn to_string(input: *const c_char, size: u32) -> String {
+    let mut value = String::new();
+    let output: *mut String = &mut value;
+    rs_string_fill(output as *mut c_void, input, size);
+    value
+}
+
+fn to_arguments(arguments: *mut c_void) -> Vec<Variant> {
+    Vec::<Variant>::try_from(Variant::from_ptr(arguments)).unwrap_or_default()
+}
+
+fn to_reply(
+    arguments: *mut c_void,
+    error_name: *const c_char,
+    error_name_size: u32,
+    error_message: *const c_char,
+    error_message_size: u32,
+) -> Result<Vec<Variant>, Error> {
+    if arguments.is_null() {
+        Err(Error {
+            name: to_string(error_name, error_name_size),
+            message: to_string(error_message, error_message_size),
+        })
+    } else {
+        Ok(to_arguments(arguments))
+    }
+}
+
+extern "C" fn rs_dbus_call_reply(
+    data: *mut c_void,
+    arguments: *mut c_void,
+    error_name: *const c_char,
+    error_name_size: u32,
+    error_message: *const c_char,
+    error_message_size: u32,
+) {
+    let reply = unsafe { &mut *(data as *mut Option<Result<Vec<Variant>, Error>>) };
+    *reply = Some(to_reply(
+        arguments,
+        error_name,
+        error_name_size,
+        error_message,
+        error_message_size,
+    ));
+}
+
+extern "C" fn rs_dbus_call_async_reply(
+    data: *mut c_void,
+    arguments: *mut c_void,
+    error_name: *const c_char,
+    error_name_size: u32,
+    error_message: *const c_char,
+    error_message_size: u32,
+) {
+    let handler = unsafe { Box::from_raw(data as *mut ReplyHandler) };
+    handler(to_reply(
+        arguments,
+        error_name,
+        error_name_size,
+        error_message,
+        error_message_size,
+    ));
+}
+
+extern "C" fn rs_dbus_object_call(
+    data: *mut c_void,
+    interface: *const c_char,
+    interface_size: u32,
+    member: *const c_char,
+    member_size: u32,
+    arguments: *mut c_void,
+    result: *mut c_void,
+) {
+    let handler = unsafe { &mut *(data as *mut CallHandler) };
+    let interface = to_string(interface, interface_size);
+    let member = to_string(member, member_size);
+    match handler(&interface, &member, to_arguments(arguments)) {
+        Ok(arguments) => {
+            let arguments = arguments.iter().collect::<Variant>();
+            unsafe { qt_binding_dbus_call_result_set_arguments(result, arguments.as_ptr()) }
+        }
+        Err(error) => unsafe {
+            qt_binding_dbus_call_result_set_error(
+                result,
+                error.name.as_ptr() as *const c_char,
+                error.name.len() as u32,
+                error.message.as_ptr() as *const c_char,
+                error.message.len() as u32,
+            )
+        },
+    }
+}
+
+extern "C" fn rs_dbus_signal(data: *mut c_void, arguments: *mut c_void) {
+    let handler = unsafe { &mut *(data as *mut SignalHandler) };
+    handler(to_arguments(arguments));
+}
+
+type RsReplyFunc = extern "C" fn(
+    data: *mut c_void,
+    arguments: *mut c_void,
+    error_name: *const c_char,
+    error_name_size: u32,
+    error_message: *const c_char,
+    error_message_size: u32,
+);
+type RsCallFunc = extern "C" fn(
+    data: *mut c_void,
+    interface: *const c_char,
+    interface_size: u32,
+    member: *const c_char,
+    member_size: u32,
+    arguments: *mut c_void,
+    result: *mut c_void,
+);
+type RsSignalFunc = extern "C" fn(data: *mut c_void, arguments: *mut c_void);
+
+extern "C" {
+    fn qt_binding_dbus_connection_create(system: bool) -> *mut c_void;
+    fn qt_binding_dbus_connection_delete(connection: *mut c_void);
+    fn qt_binding_dbus_connection_is_connected(connection: *const c_void) -> bool;
+    fn qt_binding_dbus_connection_last_error(
+        connection: *const c_void,
+        name: *mut c_void,
+        message: *mut c_void,
+        fill: RsStringFillFunc,
+    );
+    fn qt_binding_dbus_connection_register_service(
+        connection: *mut c_void,
+        name: *const c_char,
+        size: u32,
+    ) -> bool;
+    fn qt_binding_dbus_connection_unregister_service(
+        connection: *mut c_void,
+        name: *const c_char,
+        size: u32,
+    ) -> bool;
+    fn qt_binding_dbus_connection_call(
+        connection: *mut c_void,
+        service: *const c_char,
+        service_size: u32,
+        path: *const c_char,
+        path_size: u32,
+        interface: *const c_char,
+        interface_size: u32,
+        method: *const c_char,
+        method_size: u32,
+        arguments: *const c_void,
+        is_async: bool,
+        data: *mut c_void,
+        reply: RsReplyFunc,
+    );
+    fn qt_binding_dbus_connection_emit_signal(
+        connection: *mut c_void,
+        path: *const c_char,
+        path_size: u32,
+        interface: *const c_char,
+        interface_size: u32,
+        name: *const c_char,
+        name_size: u32,
+        arguments: *const c_void,
+    ) -> bool;
+    fn qt_binding_dbus_connection_register_object(
+        connection: *mut c_void,
+        path: *const c_char,
+        size: u32,
+        object: *mut c_void,
+    ) -> bool;
+    fn qt_binding_dbus_connection_unregister_object(
+        connection: *mut c_void,
+        path: *const c_char,
+        size: u32,
+    );
+    fn qt_binding_dbus_connection_subscribe(
+        connection: *mut c_void,
+        service: *const c_char,
+        service_size: u32,
+        path: *const c_char,
+        path_size: u32,
+        interface: *const c_char,
+        interface_size: u32,
+        name: *const c_char,
+        name_size: u32,
+        data: *mut c_void,
+        signal: RsSignalFunc,
+    ) -> *mut c_void;
+
+    fn qt_binding_dbus_object_create(
+        introspection: *const c_char,
+        size: u32,
+        data: *mut c_void,
+        call: RsCallFunc,
+    ) -> *mut c_void;
+    fn qt_binding_dbus_object_delete(object: *mut c_void);
+    fn qt_binding_dbus_call_result_set_arguments(result: *mut c_void, arguments: *const c_void);
+    fn qt_binding_dbus_call_result_set_error(
+        result: *mut c_void,
+        name: *const c_char,
+        name_size: u32,
+        message: *const c_char,
+        message_size: u32,
+    );
+
+    fn qt_binding_dbus_signal_receiver_delete(receiver: *mut c_void);
+}
@@ -0,0 +1,304 @@
+//! Memory diagnostics
+//!
+//! This module counts how many binding objects were created and destroyed, to help catching
+//! leaks, where Qt keeps Rust objects alive or where Rust keeps Qt objects alive.
+//!
+//! Counters are global and can be queried at any time with [`counter`]. [`report`] returns a
+//! summary of all counters, that can be printed, for example when exiting the application.
+//!
+//! When the [`Application`] is destroyed, a warning lists the kinds of objects that are still
+//! alive. They are leaked when they are dropped.
+//!
+//! [`counter`]: fn.counter.html
+//! [`report`]: fn.report.html
+//! [`Application`]: ../app/struct.Application.html
+//!
+//! # Features
+//!
+//! This module is only available with the `diagnostics` feature. Counting objects is cheap but
+//! not free, so this feature should only be enabled in debug builds.
+//!
+//! # Examples
+//!
+//! ```
+//! use qt_binding::diagnostics::{counter, report, Kind};
+//! use qt_binding::variant::Variant;
+//!
+//! let live = counter(Kind::Variant).live();
+//! let variant = Variant::from(123);
+//! assert_eq!(counter(Kind::Variant).live(), live + 1);
+//!
+//! drop(variant);
+//! assert_eq!(counter(Kind::Variant).live(), live);
+//!
+//! eprintln!("{}", report());
+//! ```
+
+use std::fmt;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+macro_rules! kinds {
+    ($($(#[$doc:meta])* $kind:ident,)*) => {
+        /// Kind of counted object
+        ///
+        /// Each binding object backed by Qt has its own kind. More kinds might be added when
+        /// new bindings are added.
+        #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+        #[non_exhaustive]
+        pub enum Kind {
+            $($(#[$doc])* $kind,)*
+        }
+
+        const KINDS: &[Kind] = &[$(Kind::$kind,)*];
+
+        impl fmt::Display for Kind {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                match self {
+                    $(Kind::$kind => write!(f, stringify!($kind)),)*
+                }
+            }
+        }
+    };
+}
+
+kinds! {
+    /// [`Application`](../app/struct.Application.html) instances
+    Application,
+    /// [`Variant`](../variant/struct.Variant.html) instances
+    Variant,
+    /// Futures spawned in the Qt event-loop, that are held by Qt until they complete
+    Task,
+    /// `menu::Action` instances
+    Action,
+    /// `animation::Animation` instances
+    Animation,
+    /// `bluetooth::Controller` instances
+    BluetoothController,
+    /// `bluetooth::DeviceDiscovery` instances
+    BluetoothDeviceDiscovery,
+    /// `bluetooth::DeviceInfo` instances
+    BluetoothDeviceInfo,
+    /// `bluetooth::Service` instances
+    BluetoothService,
+    /// `multimedia::Camera` instances
+    Camera,
+    /// `dbus::Connection` instances
+    DBusConnection,
+    /// `dbus::Object` instances
+    DBusObject,
+    /// `dbus::Subscription` instances
+    DBusSubscription,
+    /// `model::FetchModel` instances
+    FetchModel,
+    /// `font::FontMetrics` instances
+    FontMetrics,
+    /// `image::ImageReader` instances
+    ImageReader,
+    /// `locale::Locale` instances
+    Locale,
+    /// `multimedia::MediaPlayer` instances
+    MediaPlayer,
+    /// `menu::Menu` instances
+    Menu,
+    /// `menu::MenuBar` instances
+    MenuBar,
+    /// `mime::MimeType` instances
+    MimeType,
+    /// `notification::Notifier` instances
+    Notifier,
+    /// `object::ObjectHandle` instances
+    ObjectHandle,
+    /// `pdf::PdfDocument` instances
+    PdfDocument,
+    /// `model::PointListModel` instances
+    PointListModel,
+    /// `positioning::PositionSource` instances
+    PositionSource,
+    /// `print::Printer` instances
+    Printer,
+    /// `qml::Component` instances
+    QmlComponent,
+    /// `qml::Config` instances
+    QmlConfig,
+    /// `qml::Engine` instances
+    QmlEngine,
+    /// `file::SaveFile` instances
+    SaveFile,
+    /// `serialport::SerialPort` instances
+    SerialPort,
+    /// `sharedmem::SharedMemory` instances
+    SharedMemory,
+    /// `shortcut::Shortcut` instances
+    Shortcut,
+    /// `sql::Database` instances
+    SqlDatabase,
+    /// `sql::Query` instances
+    SqlQuery,
+    /// `sql::QueryModel` instances
+    SqlQueryModel,
+    /// `state::StateMachine` instances
+    StateMachine,
+    /// `model::StringListModel` instances
+    StringListModel,
+    /// `file::TemporaryFile` instances
+    TemporaryFile,
+    /// `text::TextDocument` instances
+    TextDocument,
+    /// `theme::Watcher` instances
+    ThemeWatcher,
+    /// `undo::UndoStack` instances
+    UndoStack,
+    /// `validator::Validator` instances
+    Validator,
+    /// `websocket::WebSocket` instances
+    WebSocket,
+    /// `websocket::WebSocketServer` instances
+    WebSocketServer,
+    /// `window::Window` instances created from Rust
+    Window,
+}
+
+impl Kind {
+    fn index(self) -> usize {
+        self as usize
+    }
+}
+
+/// Created and destroyed object counts
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Counter {
+    created: usize,
+    destroyed: usize,
+}
+
+impl Counter {
+    /// Number of objects created
+    pub fn created(&self) -> usize {
+        self.created
+    }
+
+    /// Number of objects destroyed
+    pub fn destroyed(&self) -> usize {
+        self.destroyed
+    }
+
+    /// Number of objects still alive
+    pub fn live(&self) -> usize {
+        self.created.saturating_sub(self.destroyed)
+    }
+}
+
+/// Summary of all counters
+///
+/// A `Report` is a snapshot of all counters taken by [`report`]. It can be printed with
+/// `Display`, that lists the kinds of objects that were created at least once.
+///
+/// [`report`]: fn.report.html
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Report {
+    counters: Vec<(Kind, Counter)>,
+}
+
+impl Report {
+    /// Returns `true` if some objects are still alive
+    pub fn has_live_objects(&self) -> bool {
+        self.counters.iter().any(|(_, counter)| counter.live() > 0)
+    }
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let counters = self
+            .counters
+            .iter()
+            .filter(|(_, counter)| counter.created() > 0);
+        for (kind, counter) in counters {
+            writeln!(
+                f,
+                "{}: {} created, {} destroyed, {} live",
+                kind,
+                counter.created(),
+                counter.destroyed(),
+                counter.live()
+            )?;
+        }
+        Ok(())
+    }
+}
+
+const KIND_COUNT: usize = KINDS.len();
+#[allow(clippy::declare_interior_mutable_const)]
+const ZERO: AtomicUsize = AtomicUsize::new(0);
+
+static CREATED: [AtomicUsize; KIND_COUNT] = [ZERO; KIND_COUNT];
+static DESTROYED: [AtomicUsize; KIND_COUNT] = [ZERO; KIND_COUNT];
+
+/// Counter for a kind of object
+pub fn counter(kind: Kind) -> Counter {
+    Counter {
+        created: CREATED[kind.index()].load(Ordering::Relaxed),
+        destroyed: DESTROYED[kind.index()].load(Ordering::Relaxed),
+    }
+}
+
+/// Snapshot of all counters
+pub fn report() -> Report {
+    let counters = KINDS.iter().map(|kind| (*kind, counter(*kind))).collect();
+    Report { counters }
+}
+
+pub(crate) fn created(kind: Kind) {
+    CREATED[kind.index()].fetch_add(1, Ordering::Relaxed);
+}
+
+pub(crate) fn destroyed(kind: Kind) {
+    DESTROYED[kind.index()].fetch_add(1, Ordering::Relaxed);
+}
+
+#[cfg(not(feature = "mock"))]
+pub(crate) fn warn_live_objects() {
+    let live = KINDS
+        .iter()
+        .map(|kind| (*kind, counter(*kind).live()))
+        .filter(|(_, live)| *live > 0)
+        .map(|(kind, live)| format!("{} {}", live, kind))
+        .collect::<Vec<_>>();
+    if !live.is_empty() {
+        warning!(
+            "Application destroyed with live objects: {}",
+            live.join(", ")
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn report_lists_created_kinds() {
+        let report = Report {
+            counters: vec![
+                (
+                    Kind::Variant,
+                    Counter {
+                        created: 2,
+                        destroyed: 1,
+                    },
+                ),
+                (
+                    Kind::Task,
+                    Counter {
+                        created: 0,
+                        destroyed: 0,
+                    },
+                ),
+            ],
+        };
+        assert!(report.has_live_objects());
+        assert_eq!(
+            report.to_string(),
+            "Variant: 2 created, 1 destroyed, 1 live\n"
+        );
+    }
+}
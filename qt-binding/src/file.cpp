@@ -0,0 +1,110 @@
+#include <QtCore/QSaveFile>
+#include <QtCore/QTemporaryFile>
+#include <cstdint>
+
+QT_USE_NAMESPACE
+
+using RsStringFillFunc = void (*)(void *output, const char *input, std::uint32_t inputSize);
+
+static QString toString(const char *value, std::uint32_t size)
+{
+    return QString::fromUtf8(value, static_cast<int>(size));
+}
+
+static void fillString(const QString &value, void *output, RsStringFillFunc fill)
+{
+    const auto byteArray = value.toUtf8();
+    fill(output, byteArray.constData(), static_cast<std::uint32_t>(byteArray.size()));
+}
+
+extern "C" {
+
+void qt_binding_file_device_delete(QFileDevice *device)
+{
+    delete device;
+}
+
+std::int64_t qt_binding_file_device_write(QFileDevice *device, const char *data,
+                                          std::uint64_t size)
+{
+    return device->write(data, static_cast<qint64>(size));
+}
+
+std::int64_t qt_binding_file_device_read(QFileDevice *device, char *data, std::uint64_t size)
+{
+    return device->read(data, static_cast<qint64>(size));
+}
+
+bool qt_binding_file_device_flush(QFileDevice *device)
+{
+    return device->flush();
+}
+
+bool qt_binding_file_device_seek(QFileDevice *device, std::int64_t position)
+{
+    return device->seek(position);
+}
+
+std::int64_t qt_binding_file_device_pos(const QFileDevice *device)
+{
+    return device->pos();
+}
+
+std::int64_t qt_binding_file_device_size(const QFileDevice *device)
+{
+    return device->size();
+}
+
+void qt_binding_file_device_file_name(const QFileDevice *device, void *output,
+                                      RsStringFillFunc fill)
+{
+    fillString(device->fileName(), output, fill);
+}
+
+int qt_binding_file_device_error(const QFileDevice *device, void *output, RsStringFillFunc fill)
+{
+    fillString(device->errorString(), output, fill);
+    return static_cast<int>(device->error());
+}
+
+QSaveFile *qt_binding_save_file_create(const char *path, std::uint32_t size)
+{
+    return new QSaveFile(toString(path, size));
+}
+
+void qt_binding_save_file_set_direct_write_fallback(QSaveFile *file, bool enabled)
+{
+    file->setDirectWriteFallback(enabled);
+}
+
+bool qt_binding_save_file_open(QSaveFile *file)
+{
+    return file->open(QIODevice::WriteOnly);
+}
+
+bool qt_binding_save_file_commit(QSaveFile *file)
+{
+    return file->commit();
+}
+
+void qt_binding_save_file_cancel(QSaveFile *file)
+{
+    file->cancelWriting();
+}
+
+QTemporaryFile *qt_binding_temporary_file_create(const char *templateName, std::uint32_t size)
+{
+    return size > 0 ? new QTemporaryFile(toString(templateName, size)) : new QTemporaryFile();
+}
+
+bool qt_binding_temporary_file_open(QTemporaryFile *file)
+{
+    return file->open();
+}
+
+void qt_binding_temporary_file_set_auto_remove(QTemporaryFile *file, bool autoRemove)
+{
+    file->setAutoRemove(autoRemove);
+}
+
+} // extern "C"
@@ -0,0 +1,419 @@
+//! Bindings to `QSaveFile` and `QTemporaryFile`
+//!
+//! [`SaveFile`] is a binding on Qt's `QSaveFile`, that saves a file safely. Data is written to
+//! a temporary file, that replaces the target file atomically when [`commit`] is called. If
+//! writing fails, or if the `SaveFile` is dropped without being committed, the target file is
+//! left untouched.
+//!
+//! [`TemporaryFile`] is a binding on Qt's `QTemporaryFile`, a file with a unique name, that is
+//! removed when dropped.
+//!
+//! Both implement the [`Write`] trait, so that they can be used with any Rust serializer.
+//!
+//! [`SaveFile`]: struct.SaveFile.html
+//! [`commit`]: struct.SaveFile.html#method.commit
+//! [`TemporaryFile`]: struct.TemporaryFile.html
+//! [`Write`]: https://doc.rust-lang.org/std/io/trait.Write.html
+//!
+//! # Limitations
+//!
+//! Since `SaveFile` and `TemporaryFile` are bindings over Qt objects, they are neither `Send`
+//! nor `Sync`.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use qt_binding::file::SaveFile;
+//! use std::io::Write;
+//!
+//! let mut file = SaveFile::new("document.json");
+//! file.open().unwrap();
+//! file.write_all(b"{\"title\": \"Notes\"}").unwrap();
+//! file.commit().unwrap();
+//! ```
+
+use crate::variant::convert::{rs_string_fill, RsStringFillFunc};
+use std::fmt;
+use std::io::{self, Read, Seek, SeekFrom, Write};
+use std::os::raw::{c_char, c_int, c_void};
+use std::path::{Path, PathBuf};
+
+/// Binding to `QSaveFile`
+///
+/// See module level documentation for more information.
+pub struct SaveFile {
+    ptr: *mut c_void,
+}
+
+impl SaveFile {
+    /// Creates a `SaveFile` that will replace the file at `path`
+    ///
+    /// The `SaveFile` needs to be opened with [`open`] before writing.
+    ///
+    /// [`open`]: #method.open
+    pub fn new<P>(path: P) -> Self
+    where
+        P: AsRef<Path>,
+    {
+        ffi_span!("SaveFile", "new");
+        let path = path.as_ref().to_string_lossy();
+        track_created!(SaveFile);
+        SaveFile {
+            ptr: unsafe {
+                qt_binding_save_file_create(path.as_ptr() as *const c_char, path.len() as u32)
+            },
+        }
+    }
+
+    /// Allow writing directly to the target file when a temporary file cannot be used
+    ///
+    /// On Windows, the target file cannot be replaced atomically in some directories, like
+    /// some network drives. With this fallback enabled, the target file is written directly
+    /// instead of failing, losing the crash-safety. This is disabled by default.
+    ///
+    /// This needs to be set before calling [`open`].
+    ///
+    /// [`open`]: #method.open
+    pub fn set_direct_write_fallback(&mut self, enabled: bool) -> &mut Self {
+        ffi_span!("SaveFile", "set_direct_write_fallback");
+        unsafe { qt_binding_save_file_set_direct_write_fallback(self.ptr, enabled) }
+        self
+    }
+
+    /// Open the file for writing
+    ///
+    /// The temporary file is created next to the target file.
+    pub fn open(&mut self) -> io::Result<()> {
+        ffi_span!("SaveFile", "open");
+        let result = unsafe { qt_binding_save_file_open(self.ptr) };
+        check(self.ptr, result)
+    }
+
+    /// Path of the target file
+    pub fn path(&self) -> PathBuf {
+        ffi_span!("SaveFile", "path");
+        file_name(self.ptr)
+    }
+
+    /// Replace the target file with the written data
+    ///
+    /// Returns an error if writing failed, or if [`cancel`] was called. In this case, the
+    /// target file is left untouched.
+    ///
+    /// [`cancel`]: #method.cancel
+    pub fn commit(self) -> io::Result<()> {
+        ffi_span!("SaveFile", "commit");
+        let result = unsafe { qt_binding_save_file_commit(self.ptr) };
+        check(self.ptr, result)
+    }
+
+    /// Cancel writing
+    ///
+    /// The target file will be left untouched, even if [`commit`] is called.
+    ///
+    /// [`commit`]: #method.commit
+    pub fn cancel(&mut self) {
+        ffi_span!("SaveFile", "cancel");
+        unsafe { qt_binding_save_file_cancel(self.ptr) }
+    }
+}
+
+impl Write for SaveFile {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        ffi_span!("SaveFile", "write");
+        write(self.ptr, buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        ffi_span!("SaveFile", "flush");
+        flush(self.ptr)
+    }
+}
+
+impl fmt::Debug for SaveFile {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("SaveFile")
+            .field("path", &self.path())
+            .finish()
+    }
+}
+
+impl Drop for SaveFile {
+    fn drop(&mut self) {
+        ffi_span!("SaveFile", "drop");
+        track_destroyed!(SaveFile);
+        unsafe { qt_binding_file_device_delete(self.ptr) }
+    }
+}
+
+/// Binding to `QTemporaryFile`
+///
+/// See module level documentation for more information.
+pub struct TemporaryFile {
+    ptr: *mut c_void,
+}
+
+impl TemporaryFile {
+    /// Creates and open a `TemporaryFile` in the temporary directory of the system
+    pub fn new() -> io::Result<Self> {
+        TemporaryFile::create("")
+    }
+
+    /// Creates and open a `TemporaryFile` from a template
+    ///
+    /// The template is a path, where the last `XXXXXX` is replaced by random characters to
+    /// create a unique name. If the template does not contain `XXXXXX`, it is appended to the
+    /// template. Relative paths are relative to the current directory.
+    pub fn with_template<P>(template: P) -> io::Result<Self>
+    where
+        P: AsRef<Path>,
+    {
+        TemporaryFile::create(&template.as_ref().to_string_lossy())
+    }
+
+    fn create(template: &str) -> io::Result<Self> {
+        ffi_span!("TemporaryFile", "new");
+        track_created!(TemporaryFile);
+        let file = TemporaryFile {
+            ptr: unsafe {
+                qt_binding_temporary_file_create(
+                    template.as_ptr() as *const c_char,
+                    template.len() as u32,
+                )
+            },
+        };
+        let result = unsafe { qt_binding_temporary_file_open(file.ptr) };
+        check(file.ptr, result)?;
+        Ok(file)
+    }
+
+    /// Path of the temporary file
+    pub fn path(&self) -> PathBuf {
+        ffi_span!("TemporaryFile", "path");
+        file_name(self.ptr)
+    }
+
+    /// Set if the file is removed when dropped
+    ///
+    /// Files are removed by default. Disabling this is useful to keep a file that was
+    /// written, for example by renaming it.
+    pub fn set_auto_remove(&mut self, auto_remove: bool) -> &mut Self {
+        ffi_span!("TemporaryFile", "set_auto_remove");
+        unsafe { qt_binding_temporary_file_set_auto_remove(self.ptr, auto_remove) }
+        self
+    }
+
+    /// Size of the file in bytes
+    pub fn len(&self) -> u64 {
+        ffi_span!("TemporaryFile", "len");
+        unsafe { qt_binding_file_device_size(self.ptr).max(0) as u64 }
+    }
+
+    /// Returns `true` if the file is empty
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl Write for TemporaryFile {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        ffi_span!("TemporaryFile", "write");
+        write(self.ptr, buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        ffi_span!("TemporaryFile", "flush");
+        flush(self.ptr)
+    }
+}
+
+impl Read for TemporaryFile {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        ffi_span!("TemporaryFile", "read");
+        let result = unsafe {
+            qt_binding_file_device_read(self.ptr, buf.as_mut_ptr() as *mut c_char, buf.len() as u64)
+        };
+        if result < 0 {
+            Err(error(self.ptr))
+        } else {
+            Ok(result as usize)
+        }
+    }
+}
+
+impl Seek for TemporaryFile {
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        ffi_span!("TemporaryFile", "seek");
+        let position = match pos {
+            SeekFrom::Start(offset) => offset as i64,
+            SeekFrom::End(offset) => self.len() as i64 + offset,
+            SeekFrom::Current(offset) => {
+                let current = unsafe { qt_binding_file_device_pos(self.ptr) };
+                current + offset
+            }
+        };
+        if position < 0 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "Cannot seek before the beginning of the file",
+            ));
+        }
+
+        let result = unsafe { qt_binding_file_device_seek(self.ptr, position) };
+        check(self.ptr, result)?;
+        Ok(position as u64)
+    }
+}
+
+impl fmt::Debug for TemporaryFile {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("TemporaryFile")
+            .field("path", &self.path())
+            .finish()
+    }
+}
+
+impl Drop for TemporaryFile {
+    fn drop(&mut self) {
+        ffi_span!("TemporaryFile", "drop");
+        track_destroyed!(TemporaryFile);
+        unsafe { qt_binding_file_device_delete(self.ptr) }
+    }
+}
+
+fn write(ptr: *mut c_void, buf: &[u8]) -> io::Result<usize> {
+    let result = unsafe {
+        qt_binding_file_device_write(ptr, buf.as_ptr() as *const c_char, buf.len() as u64)
+    };
+    if result < 0 {
+        Err(error(ptr))
+    } else {
+        Ok(result as usize)
+    }
+}
+
+fn flush(ptr: *mut c_void) -> io::Result<()> {
+    let result = unsafe { qt_binding_file_device_flush(ptr) };
+    check(ptr, result)
+}
+
+fn file_name(ptr: *const c_void) -> PathBuf {
+    let mut name = String::new();
+    unsafe {
+        let data: *mut String = &mut name;
+        qt_binding_file_device_file_name(ptr, data as *mut c_void, rs_string_fill);
+    }
+    PathBuf::from(name)
+}
+
+fn check(ptr: *const c_void, result: bool) -> io::Result<()> {
+    if result {
+        Ok(())
+    } else {
+        Err(error(ptr))
+    }
+}
+
+fn error(ptr: *const c_void) -> io::Error {
+    let mut message = String::new();
+    let error = unsafe {
+        let data: *mut String = &mut message;
+        qt_binding_file_device_error(ptr, data as *mut c_void, rs_string_fill)
+    };
+    // See QFileDevice::FileError
+    let kind = match error {
+        7 => io::ErrorKind::TimedOut,
+        13 => io::ErrorKind::PermissionDenied,
+        _ => io::ErrorKind::Other,
+    };
+    io::Error::new(kind, message)
+}
+
+extern "C" {
+    fn qt_binding_file_device_delete(device: *mut c_void);
+    fn qt_binding_file_device_write(device: *mut c_void, data: *const c_char, size: u64) -> i64;
+    fn qt_binding_file_device_read(device: *mut c_void, data: *mut c_char, size: u64) -> i64;
+    fn qt_binding_file_device_flush(device: *mut c_void) -> bool;
+    fn qt_binding_file_device_seek(device: *mut c_void, position: i64) -> bool;
+    fn qt_binding_file_device_pos(device: *const c_void) -> i64;
+    fn qt_binding_file_device_size(device: *const c_void) -> i64;
+    fn qt_binding_file_device_file_name(
+        device: *const c_void,
+        output: *mut c_void,
+        fill: RsStringFillFunc,
+    );
+    fn qt_binding_file_device_error(
+        device: *const c_void,
+        output: *mut c_void,
+        fill: RsStringFillFunc,
+    ) -> c_int;
+
+    fn qt_binding_save_file_create(path: *const c_char, size: u32) -> *mut c_void;
+    fn qt_binding_save_file_set_direct_write_fallback(file: *mut c_void, enabled: bool);
+    fn qt_binding_save_file_open(file: *mut c_void) -> bool;
+    fn qt_binding_save_file_commit(file: *mut c_void) -> bool;
+    fn qt_binding_save_file_cancel(file: *mut c_void);
+
+    fn qt_binding_temporary_file_create(template: *const c_char, size: u32) -> *mut c_void;
+    fn qt_binding_temporary_file_open(file: *mut c_void) -> bool;
+    fn qt_binding_temporary_file_set_auto_remove(file: *mut c_void, auto_remove: bool);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn save_file() {
+        let directory =
+            std::env::temp_dir().join(format!("qt-binding-save-file-{}", std::process::id()));
+        fs::create_dir_all(&directory).unwrap();
+        let path = directory.join("document.txt");
+        fs::write(&path, "old").unwrap();
+
+        let mut file = SaveFile::new(&path);
+        file.open().unwrap();
+        file.write_all(b"new").unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), "old");
+        drop(file);
+        assert_eq!(fs::read_to_string(&path).unwrap(), "old");
+
+        let mut file = SaveFile::new(&path);
+        file.open().unwrap();
+        file.write_all(b"new").unwrap();
+        file.cancel();
+        assert!(file.commit().is_err());
+        assert_eq!(fs::read_to_string(&path).unwrap(), "old");
+
+        let mut file = SaveFile::new(&path);
+        file.open().unwrap();
+        file.write_all(b"new").unwrap();
+        file.commit().unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), "new");
+
+        fs::remove_dir_all(&directory).unwrap();
+    }
+
+    #[test]
+    fn temporary_file() {
+        let mut file = TemporaryFile::new().unwrap();
+        let path = file.path();
+        assert!(path.exists());
+        assert!(file.is_empty());
+
+        file.write_all(b"hello world").unwrap();
+        file.flush().unwrap();
+        assert_eq!(file.len(), 11);
+
+        file.seek(SeekFrom::Start(6)).unwrap();
+        let mut content = String::new();
+        file.read_to_string(&mut content).unwrap();
+        assert_eq!(content, "world");
+
+        assert!(file.seek(SeekFrom::Current(-20)).is_err());
+
+        drop(file);
+        assert!(!path.exists());
+    }
+}
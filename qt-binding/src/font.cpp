@@ -0,0 +1,114 @@
+#include <QtCore/QByteArray>
+#include <QtGui/QFont>
+#include <QtGui/QFontDatabase>
+#include <QtGui/QFontMetricsF>
+#include <cstdint>
+
+QT_USE_NAMESPACE
+
+using RsStringFillFunc = void (*)(void *output, const char *input, std::uint32_t inputSize);
+
+static QString toString(const char *value, std::uint32_t size)
+{
+    return QString::fromUtf8(value, static_cast<int>(size));
+}
+
+static void fillString(const QString &value, void *output, RsStringFillFunc fill)
+{
+    const auto byteArray = value.toUtf8();
+    fill(output, byteArray.constData(), static_cast<std::uint32_t>(byteArray.size()));
+}
+
+static void fillStringList(const QStringList &values, void *output, RsStringFillFunc append)
+{
+    for (const auto &value : values) {
+        fillString(value, output, append);
+    }
+}
+
+extern "C" {
+
+void qt_binding_font_database_families(void *output, RsStringFillFunc append)
+{
+    fillStringList(QFontDatabase().families(), output, append);
+}
+
+void qt_binding_font_database_styles(const char *family, std::uint32_t size, void *output,
+                                     RsStringFillFunc append)
+{
+    fillStringList(QFontDatabase().styles(toString(family, size)), output, append);
+}
+
+int qt_binding_font_database_add_application_font(const char *path, std::uint32_t size)
+{
+    return QFontDatabase::addApplicationFont(toString(path, size));
+}
+
+int qt_binding_font_database_add_application_font_from_data(const char *data, std::uint64_t size)
+{
+    return QFontDatabase::addApplicationFontFromData(
+        QByteArray(data, static_cast<int>(size)));
+}
+
+void qt_binding_font_database_application_font_families(int id, void *output,
+                                                        RsStringFillFunc append)
+{
+    fillStringList(QFontDatabase::applicationFontFamilies(id), output, append);
+}
+
+bool qt_binding_font_database_remove_application_font(int id)
+{
+    return QFontDatabase::removeApplicationFont(id);
+}
+
+QFontMetricsF *qt_binding_font_metrics_create(const char *family, std::uint32_t size,
+                                              double pointSize, int weight, bool italic)
+{
+    QFont font(toString(family, size));
+    font.setPointSizeF(pointSize);
+    font.setWeight(weight);
+    font.setItalic(italic);
+    return new QFontMetricsF(font);
+}
+
+void qt_binding_font_metrics_delete(QFontMetricsF *metrics)
+{
+    delete metrics;
+}
+
+double qt_binding_font_metrics_width(const QFontMetricsF *metrics, const char *text,
+                                     std::uint32_t size)
+{
+    return metrics->horizontalAdvance(toString(text, size));
+}
+
+double qt_binding_font_metrics_height(const QFontMetricsF *metrics)
+{
+    return metrics->height();
+}
+
+double qt_binding_font_metrics_ascent(const QFontMetricsF *metrics)
+{
+    return metrics->ascent();
+}
+
+double qt_binding_font_metrics_descent(const QFontMetricsF *metrics)
+{
+    return metrics->descent();
+}
+
+double qt_binding_font_metrics_line_spacing(const QFontMetricsF *metrics)
+{
+    return metrics->lineSpacing();
+}
+
+void qt_binding_font_metrics_elided_text(const QFontMetricsF *metrics, const char *text,
+                                         std::uint32_t size, int mode, double width, void *output,
+                                         RsStringFillFunc fill)
+{
+    const auto elided =
+        metrics->elidedText(toString(text, size), static_cast<Qt::TextElideMode>(mode), width);
+    fillString(elided, output, fill);
+}
+
+} // extern "C"
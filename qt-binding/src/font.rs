@@ -0,0 +1,370 @@
+//! Fonts and text metrics
+//!
+//! [`FontDatabase`] is a binding on Qt's `QFontDatabase`. It lists the font families
+//! available on the system, and adds application fonts, from files, Qt resources or bytes.
+//!
+//! [`FontMetrics`] is a binding on Qt's `QFontMetricsF`. It measures and elides text for a
+//! [`Font`] exactly like Qt renders it, so that layouts or images generated by Rust code match
+//! the UI.
+//!
+//! [`FontDatabase`]: struct.FontDatabase.html
+//! [`FontMetrics`]: struct.FontMetrics.html
+//! [`Font`]: struct.Font.html
+//!
+//! # Features
+//!
+//! This module is only available with the `gui` feature.
+//!
+//! # Limitations
+//!
+//! Fonts can only be used after the [`Application`] is created. Since `FontMetrics` is a
+//! binding over `QFontMetricsF`, it is neither `Send` nor `Sync`.
+//!
+//! [`Application`]: ../app/struct.Application.html
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use qt_binding::app::Application;
+//! use qt_binding::font::{Elide, Font, FontDatabase, FontMetrics};
+//!
+//! let mut app = Application::new();
+//!
+//! let database = FontDatabase::new();
+//! let font = database
+//!     .add_application_font(":/fonts/Inter.ttf")
+//!     .expect("Cannot load font");
+//!
+//! let metrics = FontMetrics::new(&Font::new(&font.families()[0], 12.));
+//! let title = metrics.elided_text("A very long chart title", Elide::Right, 120.);
+//! println!("{} is {} wide", title, metrics.width(&title));
+//! app.exec();
+//! ```
+
+use crate::variant::convert::{rs_string_fill, RsStringFillFunc};
+use std::os::raw::{c_char, c_int, c_void};
+use std::path::Path;
+use std::slice;
+
+/// Binding to `QFontDatabase`
+///
+/// See module level documentation for more information.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct FontDatabase;
+
+impl FontDatabase {
+    /// Creates a `FontDatabase`
+    pub fn new() -> Self {
+        FontDatabase
+    }
+
+    /// Font families available to the application, including application fonts
+    pub fn families(&self) -> Vec<String> {
+        ffi_span!("FontDatabase", "families");
+        let mut families = Vec::new();
+        unsafe {
+            let data: *mut Vec<String> = &mut families;
+            qt_binding_font_database_families(data as *mut c_void, rs_string_list_append);
+        }
+        families
+    }
+
+    /// Styles of a font family, like `Regular` or `Bold Italic`
+    pub fn styles<S>(&self, family: S) -> Vec<String>
+    where
+        S: AsRef<str>,
+    {
+        ffi_span!("FontDatabase", "styles");
+        let family = family.as_ref();
+        let mut styles = Vec::new();
+        unsafe {
+            let data: *mut Vec<String> = &mut styles;
+            qt_binding_font_database_styles(
+                family.as_ptr() as *const c_char,
+                family.len() as u32,
+                data as *mut c_void,
+                rs_string_list_append,
+            );
+        }
+        styles
+    }
+
+    /// Add an application font from a file
+    ///
+    /// Paths starting with `:/` are loaded from Qt resources. Returns `None` if the font
+    /// cannot be loaded.
+    pub fn add_application_font<P>(&self, path: P) -> Option<ApplicationFont>
+    where
+        P: AsRef<Path>,
+    {
+        ffi_span!("FontDatabase", "add_application_font");
+        let path = path.as_ref().to_string_lossy();
+        let id = unsafe {
+            qt_binding_font_database_add_application_font(
+                path.as_ptr() as *const c_char,
+                path.len() as u32,
+            )
+        };
+        ApplicationFont::from_id(id)
+    }
+
+    /// Add an application font from the content of a font file
+    ///
+    /// Returns `None` if the font cannot be loaded.
+    pub fn add_application_font_from_data(&self, data: &[u8]) -> Option<ApplicationFont> {
+        ffi_span!("FontDatabase", "add_application_font_from_data");
+        let id = unsafe {
+            qt_binding_font_database_add_application_font_from_data(
+                data.as_ptr() as *const c_char,
+                data.len() as u64,
+            )
+        };
+        ApplicationFont::from_id(id)
+    }
+
+    /// Remove an application font
+    ///
+    /// Returns `false` if the font was already removed.
+    pub fn remove_application_font(&self, font: ApplicationFont) -> bool {
+        ffi_span!("FontDatabase", "remove_application_font");
+        unsafe { qt_binding_font_database_remove_application_font(font.id) }
+    }
+}
+
+/// A font added to the application with a [`FontDatabase`]
+///
+/// [`FontDatabase`]: struct.FontDatabase.html
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ApplicationFont {
+    id: c_int,
+    families: Vec<String>,
+}
+
+impl ApplicationFont {
+    fn from_id(id: c_int) -> Option<Self> {
+        if id < 0 {
+            return None;
+        }
+
+        let mut families = Vec::new();
+        unsafe {
+            let data: *mut Vec<String> = &mut families;
+            qt_binding_font_database_application_font_families(
+                id,
+                data as *mut c_void,
+                rs_string_list_append,
+            );
+        }
+        Some(ApplicationFont { id, families })
+    }
+
+    /// Font families provided by the font
+    pub fn families(&self) -> &[String] {
+        &self.families
+    }
+}
+
+/// Description of a font
+#[derive(Clone, Debug, PartialEq)]
+pub struct Font {
+    /// Font family
+    pub family: String,
+    /// Size in points
+    pub point_size: f64,
+    /// Weight, between 0 and 99, `50` being normal and `75` bold
+    pub weight: i32,
+    /// If the font is italic
+    pub italic: bool,
+}
+
+impl Font {
+    /// Creates a `Font` with normal weight
+    pub fn new<S>(family: S, point_size: f64) -> Self
+    where
+        S: Into<String>,
+    {
+        Font {
+            family: family.into(),
+            point_size,
+            weight: 50,
+            italic: false,
+        }
+    }
+}
+
+/// Where text is elided
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Elide {
+    /// Elide the beginning of the text, like `…long title`
+    Left,
+    /// Elide the end of the text, like `A very…`
+    Right,
+    /// Elide the middle of the text, like `A ve…title`
+    Middle,
+}
+
+impl Elide {
+    fn as_c_int(self) -> c_int {
+        // Values of Qt::TextElideMode
+        match self {
+            Elide::Left => 0,
+            Elide::Right => 1,
+            Elide::Middle => 2,
+        }
+    }
+}
+
+/// Binding to `QFontMetricsF`
+///
+/// All measures are in pixels.
+///
+/// See module level documentation for more information.
+pub struct FontMetrics {
+    ptr: *mut c_void,
+}
+
+impl FontMetrics {
+    /// Creates `FontMetrics` for a font
+    ///
+    /// The closest matching font is used if the font is not available.
+    pub fn new(font: &Font) -> Self {
+        ffi_span!("FontMetrics", "new");
+        track_created!(FontMetrics);
+        FontMetrics {
+            ptr: unsafe {
+                qt_binding_font_metrics_create(
+                    font.family.as_ptr() as *const c_char,
+                    font.family.len() as u32,
+                    font.point_size,
+                    font.weight,
+                    font.italic,
+                )
+            },
+        }
+    }
+
+    /// Horizontal advance of a text, where the next text should be drawn
+    pub fn width<S>(&self, text: S) -> f64
+    where
+        S: AsRef<str>,
+    {
+        ffi_span!("FontMetrics", "width");
+        let text = text.as_ref();
+        unsafe {
+            qt_binding_font_metrics_width(
+                self.ptr,
+                text.as_ptr() as *const c_char,
+                text.len() as u32,
+            )
+        }
+    }
+
+    /// Height of the font
+    pub fn height(&self) -> f64 {
+        ffi_span!("FontMetrics", "height");
+        unsafe { qt_binding_font_metrics_height(self.ptr) }
+    }
+
+    /// Distance from the baseline to the top of the highest characters
+    pub fn ascent(&self) -> f64 {
+        ffi_span!("FontMetrics", "ascent");
+        unsafe { qt_binding_font_metrics_ascent(self.ptr) }
+    }
+
+    /// Distance from the baseline to the bottom of the lowest characters
+    pub fn descent(&self) -> f64 {
+        ffi_span!("FontMetrics", "descent");
+        unsafe { qt_binding_font_metrics_descent(self.ptr) }
+    }
+
+    /// Distance between two baselines
+    pub fn line_spacing(&self) -> f64 {
+        ffi_span!("FontMetrics", "line_spacing");
+        unsafe { qt_binding_font_metrics_line_spacing(self.ptr) }
+    }
+
+    /// Elide a text so that it fits in a width
+    ///
+    /// The text is returned unchanged if it already fits.
+    pub fn elided_text<S>(&self, text: S, elide: Elide, width: f64) -> String
+    where
+        S: AsRef<str>,
+    {
+        ffi_span!("FontMetrics", "elided_text");
+        let text = text.as_ref();
+        let mut elided = String::new();
+        unsafe {
+            let data: *mut String = &mut elided;
+            qt_binding_font_metrics_elided_text(
+                self.ptr,
+                text.as_ptr() as *const c_char,
+                text.len() as u32,
+                elide.as_c_int(),
+                width,
+                data as *mut c_void,
+                rs_string_fill,
+            );
+        }
+        elided
+    }
+}
+
+impl Drop for FontMetrics {
+    fn drop(&mut self) {
+        ffi_span!("FontMetrics", "drop");
+        track_destroyed!(FontMetrics);
+        unsafe { qt_binding_font_metrics_delete(self.ptr) }
+    }
+}
+
+extern "C" fn rs_string_list_append(output: *mut c_void, input: *const c_char, input_size: u32) {
+    let output = unsafe { &mut *(output as *mut Vec<String>) };
+    unsafe {
+        let input = slice::from_raw_parts(input as *const u8, input_size as usize);
+        output.push(String::from_utf8_unchecked(Vec::from(input)));
+    }
+}
+
+extern "C" {
+    fn qt_binding_font_database_families(output: *mut c_void, append: RsStringFillFunc);
+    fn qt_binding_font_database_styles(
+        family: *const c_char,
+        size: u32,
+        output: *mut c_void,
+        append: RsStringFillFunc,
+    );
+    fn qt_binding_font_database_add_application_font(path: *const c_char, size: u32) -> c_int;
+    fn qt_binding_font_database_add_application_font_from_data(
+        data: *const c_char,
+        size: u64,
+    ) -> c_int;
+    fn qt_binding_font_database_application_font_families(
+        id: c_int,
+        output: *mut c_void,
+        append: RsStringFillFunc,
+    );
+    fn qt_binding_font_database_remove_application_font(id: c_int) -> bool;
+    fn qt_binding_font_metrics_create(
+        family: *const c_char,
+        size: u32,
+        point_size: f64,
+        weight: c_int,
+        italic: bool,
+    ) -> *mut c_void;
+    fn qt_binding_font_metrics_delete(metrics: *mut c_void);
+    fn qt_binding_font_metrics_width(metrics: *const c_void, text: *const c_char, size: u32)
+        -> f64;
+    fn qt_binding_font_metrics_height(metrics: *const c_void) -> f64;
+    fn qt_binding_font_metrics_ascent(metrics: *const c_void) -> f64;
+    fn qt_binding_font_metrics_descent(metrics: *const c_void) -> f64;
+    fn qt_binding_font_metrics_line_spacing(metrics: *const c_void) -> f64;
+    fn qt_binding_font_metrics_elided_text(
+        metrics: *const c_void,
+        text: *const c_char,
+        size: u32,
+        mode: c_int,
+        width: f64,
+        output: *mut c_void,
+        fill: RsStringFillFunc,
+    );
+}
@@ -0,0 +1,137 @@
+#include <QtCore/QBuffer>
+#include <QtCore/QByteArray>
+#include <QtGui/QImage>
+#include <QtGui/QImageReader>
+#include <cstdint>
+#include <memory>
+
+QT_USE_NAMESPACE
+
+using RsStringFillFunc = void (*)(void *output, const char *input, std::uint32_t inputSize);
+using RsImageFillFunc = void (*)(void *output, std::uint32_t width, std::uint32_t height,
+                                 const unsigned char *data, std::uint32_t bytesPerLine);
+
+static void fillString(const QByteArray &value, void *output, RsStringFillFunc fill)
+{
+    fill(output, value.constData(), static_cast<std::uint32_t>(value.size()));
+}
+
+namespace qt_binding {
+
+class ImageReader
+{
+public:
+    explicit ImageReader(const QString &fileName)
+        : m_reader(fileName)
+    {
+    }
+
+    explicit ImageReader(QByteArray data)
+        : m_buffer(new QBuffer())
+    {
+        m_buffer->setData(std::move(data));
+        m_buffer->open(QIODevice::ReadOnly);
+        m_reader.setDevice(m_buffer.get());
+    }
+
+    QImageReader &reader()
+    {
+        return m_reader;
+    }
+
+    const QImageReader &reader() const
+    {
+        return m_reader;
+    }
+
+private:
+    // The buffer must outlive the reader
+    std::unique_ptr<QBuffer> m_buffer;
+    QImageReader m_reader;
+};
+
+} // namespace qt_binding
+
+extern "C" {
+
+void qt_binding_image_reader_supported_formats(void *output, RsStringFillFunc append)
+{
+    for (const auto &format : QImageReader::supportedImageFormats()) {
+        fillString(format, output, append);
+    }
+}
+
+qt_binding::ImageReader *qt_binding_image_reader_create_from_path(const char *path,
+                                                                  std::uint32_t size)
+{
+    return new qt_binding::ImageReader(QString::fromUtf8(path, static_cast<int>(size)));
+}
+
+qt_binding::ImageReader *qt_binding_image_reader_create_from_data(const char *data,
+                                                                  std::uint64_t size)
+{
+    return new qt_binding::ImageReader(QByteArray(data, static_cast<int>(size)));
+}
+
+void qt_binding_image_reader_delete(qt_binding::ImageReader *reader)
+{
+    delete reader;
+}
+
+void qt_binding_image_reader_format(const qt_binding::ImageReader *reader, void *output,
+                                    RsStringFillFunc fill)
+{
+    // QImageReader::format is not const, as it might read the header of the image
+    fillString(const_cast<QImageReader &>(reader->reader()).format(), output, fill);
+}
+
+bool qt_binding_image_reader_size(const qt_binding::ImageReader *reader, std::uint32_t *width,
+                                  std::uint32_t *height)
+{
+    const auto size = reader->reader().size();
+    if (!size.isValid()) {
+        return false;
+    }
+    *width = static_cast<std::uint32_t>(size.width());
+    *height = static_cast<std::uint32_t>(size.height());
+    return true;
+}
+
+void qt_binding_image_reader_set_scaled_size(qt_binding::ImageReader *reader, std::uint32_t width,
+                                             std::uint32_t height)
+{
+    reader->reader().setScaledSize(QSize(static_cast<int>(width), static_cast<int>(height)));
+}
+
+int qt_binding_image_reader_image_count(const qt_binding::ImageReader *reader)
+{
+    return reader->reader().imageCount();
+}
+
+bool qt_binding_image_reader_can_read(const qt_binding::ImageReader *reader)
+{
+    return reader->reader().canRead();
+}
+
+bool qt_binding_image_reader_read(qt_binding::ImageReader *reader, void *output,
+                                  RsImageFillFunc fill)
+{
+    const auto image = reader->reader().read();
+    if (image.isNull()) {
+        return false;
+    }
+
+    const auto converted = image.convertToFormat(QImage::Format_RGBA8888);
+    fill(output, static_cast<std::uint32_t>(converted.width()),
+         static_cast<std::uint32_t>(converted.height()), converted.constBits(),
+         static_cast<std::uint32_t>(converted.bytesPerLine()));
+    return true;
+}
+
+void qt_binding_image_reader_error(const qt_binding::ImageReader *reader, void *output,
+                                   RsStringFillFunc fill)
+{
+    fillString(reader->reader().errorString().toUtf8(), output, fill);
+}
+
+} // extern "C"
@@ -0,0 +1,368 @@
+//! Image decoding
+//!
+//! [`ImageReader`] is a binding on Qt's `QImageReader`. It decodes images from files, Qt
+//! resources or bytes, with every format supported by Qt's image plugins, like SVG or WebP
+//! when the plugins are installed. The list of supported formats is returned by
+//! [`supported_formats`].
+//!
+//! The size and the format of an image are probed from its header, without decoding it, and
+//! images can be decoded directly at a smaller size with [`set_scaled_size`]. For formats
+//! like JPEG or SVG, this avoids decoding the full image when only a thumbnail is needed.
+//! Animated images are decoded frame by frame.
+//!
+//! Decoded images are returned as an [`Image`], with RGBA pixels.
+//!
+//! [`ImageReader`]: struct.ImageReader.html
+//! [`supported_formats`]: fn.supported_formats.html
+//! [`set_scaled_size`]: struct.ImageReader.html#method.set_scaled_size
+//! [`Image`]: struct.Image.html
+//!
+//! # Features
+//!
+//! This module is only available with the `gui` feature.
+//!
+//! # Limitations
+//!
+//! Image plugins are loaded by the [`Application`], that must be created before reading images.
+//!
+//! [`Application`]: ../app/struct.Application.html
+//!
+//! # Examples
+//!
+//! Decoding a thumbnail
+//!
+//! ```no_run
+//! use qt_binding::app::Application;
+//! use qt_binding::image::ImageReader;
+//!
+//! let _app = Application::new();
+//! let mut reader = ImageReader::from_path("photo.jpg");
+//! if let Some((width, height)) = reader.size() {
+//!     let scale = 256. / width.max(height) as f64;
+//!     reader.set_scaled_size((width as f64 * scale) as u32, (height as f64 * scale) as u32);
+//! }
+//! let thumbnail = reader.read().unwrap();
+//! println!("{}x{}", thumbnail.width(), thumbnail.height());
+//! ```
+
+use crate::app;
+use crate::variant::convert::{rs_string_fill, RsStringFillFunc};
+use std::error;
+use std::fmt;
+use std::os::raw::{c_char, c_int, c_void};
+use std::path::Path;
+use std::slice;
+
+/// Error reported when decoding an image
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Error {
+    message: String,
+}
+
+impl Error {
+    /// Error message
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl error::Error for Error {}
+
+/// An image with RGBA pixels
+///
+/// Pixels are stored line by line, with 4 bytes per pixel and without padding. Colors are not
+/// premultiplied by the alpha component.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Image {
+    width: u32,
+    height: u32,
+    data: Vec<u8>,
+}
+
+impl Image {
+    /// Creates an `Image` from RGBA pixels
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of the data does not match the size of the image.
+    pub fn new(width: u32, height: u32, data: Vec<u8>) -> Self {
+        let size = width as usize * height as usize * 4;
+        if data.len() != size {
+            panic!(
+                "Invalid image data: expected {} bytes, got {}",
+                size,
+                data.len()
+            );
+        }
+        Image {
+            width,
+            height,
+            data,
+        }
+    }
+
+    /// Width, in pixels
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    /// Height, in pixels
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// Pixel data
+    pub fn data(&self) -> &[u8] {
+        &self.data
+    }
+
+    /// Get the pixel data
+    pub fn into_data(self) -> Vec<u8> {
+        self.data
+    }
+
+    /// RGBA components of a pixel, `None` if out of bounds
+    pub fn pixel(&self, x: u32, y: u32) -> Option<[u8; 4]> {
+        if x >= self.width || y >= self.height {
+            return None;
+        }
+
+        let index = (y as usize * self.width as usize + x as usize) * 4;
+        let pixel = &self.data[index..index + 4];
+        Some([pixel[0], pixel[1], pixel[2], pixel[3]])
+    }
+}
+
+/// Image formats that can be read, like `png` or `svg`
+pub fn supported_formats() -> Vec<String> {
+    ffi_span!("image", "supported_formats");
+    let mut formats = Vec::new();
+    unsafe {
+        let data: *mut Vec<String> = &mut formats;
+        qt_binding_image_reader_supported_formats(data as *mut c_void, rs_string_list_append);
+    }
+    formats
+}
+
+/// Binding to `QImageReader`
+///
+/// See module level documentation for more information.
+pub struct ImageReader {
+    ptr: *mut c_void,
+}
+
+impl ImageReader {
+    /// Creates an `ImageReader` reading a file
+    ///
+    /// Paths starting with `:/` are read from Qt resources. The file is only opened when
+    /// needed.
+    pub fn from_path<P>(path: P) -> Self
+    where
+        P: AsRef<Path>,
+    {
+        ffi_span!("ImageReader", "from_path");
+        let path = path.as_ref().to_string_lossy();
+        track_created!(ImageReader);
+        ImageReader {
+            ptr: unsafe {
+                qt_binding_image_reader_create_from_path(
+                    path.as_ptr() as *const c_char,
+                    path.len() as u32,
+                )
+            },
+        }
+    }
+
+    /// Creates an `ImageReader` reading the content of an image file
+    pub fn from_bytes(data: &[u8]) -> Self {
+        ffi_span!("ImageReader", "from_bytes");
+        track_created!(ImageReader);
+        ImageReader {
+            ptr: unsafe {
+                qt_binding_image_reader_create_from_data(
+                    data.as_ptr() as *const c_char,
+                    data.len() as u64,
+                )
+            },
+        }
+    }
+
+    /// Format of the image, like `png`, empty if not supported
+    pub fn format(&self) -> String {
+        ffi_span!("ImageReader", "format");
+        let mut format = String::new();
+        unsafe {
+            let data: *mut String = &mut format;
+            qt_binding_image_reader_format(self.ptr, data as *mut c_void, rs_string_fill);
+        }
+        format
+    }
+
+    /// Size of the image, read from its header, as width and height
+    ///
+    /// Returns `None` if the size cannot be read without decoding the image.
+    pub fn size(&self) -> Option<(u32, u32)> {
+        ffi_span!("ImageReader", "size");
+        let mut width = 0;
+        let mut height = 0;
+        if unsafe { qt_binding_image_reader_size(self.ptr, &mut width, &mut height) } {
+            Some((width, height))
+        } else {
+            None
+        }
+    }
+
+    /// Decode images at a given size
+    ///
+    /// Formats that support it, like JPEG or SVG, are directly decoded at this size, and
+    /// other images are scaled after decoding.
+    pub fn set_scaled_size(&mut self, width: u32, height: u32) -> &mut Self {
+        ffi_span!("ImageReader", "set_scaled_size");
+        unsafe { qt_binding_image_reader_set_scaled_size(self.ptr, width, height) };
+        self
+    }
+
+    /// Number of frames of an animated image, `0` if unknown
+    pub fn image_count(&self) -> usize {
+        ffi_span!("ImageReader", "image_count");
+        unsafe { qt_binding_image_reader_image_count(self.ptr).max(0) as usize }
+    }
+
+    /// If an image can be read
+    ///
+    /// For animated images, returns `false` after reading the last frame.
+    pub fn can_read(&self) -> bool {
+        ffi_span!("ImageReader", "can_read");
+        unsafe { qt_binding_image_reader_can_read(self.ptr) }
+    }
+
+    /// Decode the image, or the next frame of an animated image
+    pub fn read(&mut self) -> Result<Image, Error> {
+        ffi_span!("ImageReader", "read");
+        let mut image = None;
+        let read = unsafe {
+            let data: *mut Option<Image> = &mut image;
+            qt_binding_image_reader_read(self.ptr, data as *mut c_void, rs_image_fill)
+        };
+        match image {
+            Some(image) if read => Ok(image),
+            _ => {
+                let mut message = String::new();
+                unsafe {
+                    let data: *mut String = &mut message;
+                    qt_binding_image_reader_error(self.ptr, data as *mut c_void, rs_string_fill);
+                }
+                Err(Error { message })
+            }
+        }
+    }
+}
+
+impl fmt::Debug for ImageReader {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ImageReader")
+            .field("format", &self.format())
+            .finish()
+    }
+}
+
+impl Drop for ImageReader {
+    fn drop(&mut self) {
+        ffi_span!("ImageReader", "drop");
+        if app::should_leak("ImageReader") {
+            return;
+        }
+        track_destroyed!(ImageReader);
+        unsafe { qt_binding_image_reader_delete(self.ptr) }
+    }
+}
+
+pub(crate) extern "C" fn rs_image_fill(
+    output: *mut c_void,
+    width: u32,
+    height: u32,
+    data: *const u8,
+    bytes_per_line: u32,
+) {
+    let output = unsafe { &mut *(output as *mut Option<Image>) };
+    let line_size = width as usize * 4;
+    let mut pixels = Vec::with_capacity(line_size * height as usize);
+    for line in 0..height as usize {
+        let line =
+            unsafe { slice::from_raw_parts(data.add(line * bytes_per_line as usize), line_size) };
+        pixels.extend_from_slice(line);
+    }
+    *output = Some(Image::new(width, height, pixels));
+}
+
+extern "C" fn rs_string_list_append(output: *mut c_void, input: *const c_char, input_size: u32) {
+    let output = unsafe { &mut *(output as *mut Vec<String>) };
+    unsafe {
+        let input = slice::from_raw_parts(input as *const u8, input_size as usize);
+        output.push(String::from_utf8_unchecked(Vec::from(input)));
+    }
+}
+
+pub(crate) type RsImageFillFunc = extern "C" fn(
+    output: *mut c_void,
+    width: u32,
+    height: u32,
+    data: *const u8,
+    bytes_per_line: u32,
+);
+
+extern "C" {
+    fn qt_binding_image_reader_supported_formats(output: *mut c_void, append: RsStringFillFunc);
+    fn qt_binding_image_reader_create_from_path(path: *const c_char, size: u32) -> *mut c_void;
+    fn qt_binding_image_reader_create_from_data(data: *const c_char, size: u64) -> *mut c_void;
+    fn qt_binding_image_reader_delete(reader: *mut c_void);
+    fn qt_binding_image_reader_format(
+        reader: *const c_void,
+        output: *mut c_void,
+        fill: RsStringFillFunc,
+    );
+    fn qt_binding_image_reader_size(
+        reader: *const c_void,
+        width: *mut u32,
+        height: *mut u32,
+    ) -> bool;
+    fn qt_binding_image_reader_set_scaled_size(reader: *mut c_void, width: u32, height: u32);
+    fn qt_binding_image_reader_image_count(reader: *const c_void) -> c_int;
+    fn qt_binding_image_reader_can_read(reader: *const c_void) -> bool;
+    fn qt_binding_image_reader_read(
+        reader: *mut c_void,
+        output: *mut c_void,
+        fill: RsImageFillFunc,
+    ) -> bool;
+    fn qt_binding_image_reader_error(
+        reader: *const c_void,
+        output: *mut c_void,
+        fill: RsStringFillFunc,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_image() {
+        let image = Image::new(2, 1, vec![255, 0, 0, 255, 0, 0, 255, 128]);
+        assert_eq!(image.pixel(0, 0), Some([255, 0, 0, 255]));
+        assert_eq!(image.pixel(1, 0), Some([0, 0, 255, 128]));
+        assert_eq!(image.pixel(2, 0), None);
+        assert_eq!(image.pixel(0, 1), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid image data: expected 8 bytes, got 4")]
+    fn test_image_invalid() {
+        Image::new(2, 1, vec![0; 4]);
+    }
+}
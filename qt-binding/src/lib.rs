@@ -5,19 +5,187 @@
 //! To make Qt and Rust interoperable, this crate offers several bindings Qt classes.
 //!
 //! Qt main application and event-loop can be access via the [`app`] module while `QVariant`
-//! bindings are available in the [`variant`] module.
+//! bindings are available in the [`variant`] module. Item models that can be used by QML views are
+//! available in the [`model`] module, and borrowed UTF-16 strings in the [`string`] module. Locale
+//! aware formatting and parsing is available in the [`locale`] module, and memory shared with other
+//! processes in the [`sharedmem`] module. File types are detected with the [`mime`] module, and
+//! files are saved safely with the [`file`] module. Properties shared by all Qt objects, like their
+//! object name, are available through the [`object`] module, and properties are animated with the
+//! [`animation`] module. UI flows are structured with the [`state`] module. Input validators
+//! implemented in Rust are available in the [`validator`] module, and keyboard shortcuts in the
+//! [`shortcut`] module. Screen readers are supported with the [`accessibility`] module, and the
+//! platform palette and style hints are available in the [`theme`] module. Fonts are loaded and
+//! text is measured with the [`font`] module, and images are decoded with the [`image`] module.
+//! Rich text is exported to HTML and PDF with the [`text`] module and printed with the [`print`]
+//! module, and PDF documents are rendered with the [`pdf`] module. Native windows are available in
+//! the [`window`] module. Native menus are available in the [`menu`] module, and desktop
+//! notifications in the [`notification`] module. Undo and redo are available in the [`undo`]
+//! module, and panics are reported to end users with the [`crash`] module. Qt and QML messages can
+//! be handled in Rust with the [`logging`] module. Databases can be accessed with Qt's drivers with
+//! the [`sql`] module, serial ports with the [`serialport`] module, WebSockets with the
+//! [`websocket`] module, and Bluetooth Low Energy devices with the [`bluetooth`] module. Audio,
+//! video and cameras are available in the [`multimedia`] module. Desktop integration on Linux is
+//! available with D-Bus, in the [`dbus`] module, and the position of the device in the
+//! [`positioning`] module.
 //!
 //! See module level documentation for more information.
 //!
 //! [`app`]: app/index.html
 //! [`variant`]: variant/index.html
+//! [`model`]: model/index.html
+//! [`string`]: string/index.html
+//! [`locale`]: locale/index.html
+//! [`sharedmem`]: sharedmem/index.html
+//! [`mime`]: mime/index.html
+//! [`file`]: file/index.html
+//! [`object`]: object/index.html
+//! [`animation`]: animation/index.html
+//! [`state`]: state/index.html
+//! [`validator`]: validator/index.html
+//! [`shortcut`]: shortcut/index.html
+//! [`accessibility`]: accessibility/index.html
+//! [`theme`]: theme/index.html
+//! [`font`]: font/index.html
+//! [`image`]: image/index.html
+//! [`window`]: window/index.html
+//! [`menu`]: menu/index.html
+//! [`notification`]: notification/index.html
+//! [`undo`]: undo/index.html
+//! [`crash`]: crash/index.html
+//! [`logging`]: logging/index.html
+//! [`sql`]: sql/index.html
+//! [`serialport`]: serialport/index.html
+//! [`websocket`]: websocket/index.html
+//! [`bluetooth`]: bluetooth/index.html
+//! [`multimedia`]: multimedia/index.html
+//! [`dbus`]: dbus/index.html
+//! [`pdf`]: pdf/index.html
+//! [`positioning`]: positioning/index.html
+//! [`print`]: print/index.html
+//! [`diagnostics`]: diagnostics/index.html
+//! [`qml`]: qml/index.html
+//! [`text`]: text/index.html
 //!
 //! # Features
 //!
 //! `qt-binding` comes with the following feature flags
 //!
-//! - `gui` enables the use of `QGuiApplication`
+//! - `gui` enables the use of `QGuiApplication`, and the [`accessibility`], [`font`],
+//!   [`image`], [`shortcut`], [`text`], [`theme`], [`validator`] and [`window`] modules
+//! - `widgets` enables the use of `QApplication`, and the [`crash`], [`menu`], [`notification`] and
+//!   [`undo`] modules
+//! - `qml` enables the [`qml`] module, with bindings to Qt QML
+//! - `quick-test` enables running QML unit tests with [`qml::run_tests`]
+//! - `serialport` enables the [`serialport`] module, with bindings to Qt Serial Port
+//! - `sql` enables the [`sql`] module, with bindings to Qt SQL
+//! - `websockets` enables the [`websocket`] module, with bindings to Qt WebSockets
+//! - `bluetooth` enables the [`bluetooth`] module, with bindings to Qt Bluetooth Low Energy
+//! - `multimedia` enables the [`multimedia`] module, with bindings to Qt Multimedia
+//! - `dbus` enables the [`dbus`] module, with bindings to Qt D-Bus
+//! - `pdf` enables the [`pdf`] module, with bindings to Qt PDF
+//! - `positioning` enables the [`positioning`] module, with bindings to Qt Positioning
+//! - `printsupport` enables the [`print`] module, with bindings to Qt Print Support, and the
+//!   `widgets` feature
 //! - `futures-executor` offers a Qt event-loop based executor to run futures.
+//! - `diagnostics` enables the [`diagnostics`] module, that counts binding objects to find leaks.
+//! - `mock` replaces Qt with a pure Rust backend for [`variant::Variant`] and [`object::Object`],
+//!   to unit test code using them without Qt, see the [`mock`] module. Modules that only bind
+//!   Qt classes, as well as `app` and `animation`, are not available with this feature.
+//! - `raw-window-handle` implements [`HasRawWindowHandle`] for [`window::Window`].
+//! - `log` forwards Qt and QML messages to the [`log`] crate, see the [`logging`] module, and
+//!   reports the warnings of `qt-binding` with it.
+//! - `tracing` wraps calls to Qt in [`tracing`] spans, named after the object and member being
+//!   called, and reports the warnings of `qt-binding` as `tracing` events.
+//! - `widestring` adds conversions between [`widestring`] strings and [`string::Utf16Str`].
+//!
+//! [`qml::run_tests`]: qml/fn.run_tests.html
+//! [`HasRawWindowHandle`]: https://docs.rs/raw-window-handle/0.3
+//! [`window::Window`]: window/struct.Window.html
+//! [`variant::Variant`]: variant/struct.Variant.html
+//! [`object::Object`]: object/trait.Object.html
+//! [`mock`]: mock/index.html
+//! [`log`]: https://docs.rs/log
+//! [`tracing`]: https://docs.rs/tracing
+//! [`widestring`]: https://docs.rs/widestring
+//! [`string::Utf16Str`]: string/struct.Utf16Str.html
+
+// `warning!` is only used by modules that are not available with the `mock` feature
+#[cfg_attr(feature = "mock", allow(unused_macros))]
+#[macro_use]
+mod trace;
 
+#[cfg(all(feature = "gui", not(feature = "mock")))]
+pub mod accessibility;
+#[cfg(not(feature = "mock"))]
+pub mod animation;
+#[cfg(not(feature = "mock"))]
 pub mod app;
+#[cfg(all(feature = "bluetooth", not(feature = "mock")))]
+pub mod bluetooth;
+#[cfg(all(
+    feature = "futures-executor",
+    any(feature = "bluetooth", feature = "positioning"),
+    not(feature = "mock")
+))]
+mod channel;
+#[cfg(all(feature = "widgets", not(feature = "mock")))]
+pub mod crash;
+#[cfg(all(feature = "dbus", not(feature = "mock")))]
+pub mod dbus;
+#[cfg(feature = "diagnostics")]
+pub mod diagnostics;
+#[cfg(not(feature = "mock"))]
+pub mod file;
+#[cfg(all(feature = "gui", not(feature = "mock")))]
+pub mod font;
+#[cfg(all(feature = "gui", not(feature = "mock")))]
+pub mod image;
+#[cfg(not(feature = "mock"))]
+pub mod locale;
+#[cfg(not(feature = "mock"))]
+pub mod logging;
+#[cfg(all(feature = "widgets", not(feature = "mock")))]
+pub mod menu;
+#[cfg(not(feature = "mock"))]
+pub mod mime;
+#[cfg(feature = "mock")]
+pub mod mock;
+#[cfg(not(feature = "mock"))]
+pub mod model;
+#[cfg(all(feature = "multimedia", not(feature = "mock")))]
+pub mod multimedia;
+#[cfg(all(feature = "widgets", not(feature = "mock")))]
+pub mod notification;
+pub mod object;
+#[cfg(all(feature = "pdf", not(feature = "mock")))]
+pub mod pdf;
+#[cfg(all(feature = "positioning", not(feature = "mock")))]
+pub mod positioning;
+#[cfg(all(feature = "printsupport", not(feature = "mock")))]
+pub mod print;
+#[cfg(all(feature = "qml", not(feature = "mock")))]
+pub mod qml;
+#[cfg(all(feature = "serialport", not(feature = "mock")))]
+pub mod serialport;
+#[cfg(not(feature = "mock"))]
+pub mod sharedmem;
+#[cfg(all(feature = "gui", not(feature = "mock")))]
+pub mod shortcut;
+#[cfg(all(feature = "sql", not(feature = "mock")))]
+pub mod sql;
+#[cfg(not(feature = "mock"))]
+pub mod state;
+pub mod string;
+#[cfg(all(feature = "gui", not(feature = "mock")))]
+pub mod text;
+#[cfg(all(feature = "gui", not(feature = "mock")))]
+pub mod theme;
+#[cfg(all(feature = "widgets", not(feature = "mock")))]
+pub mod undo;
+#[cfg(all(feature = "gui", not(feature = "mock")))]
+pub mod validator;
 pub mod variant;
+#[cfg(all(feature = "websockets", not(feature = "mock")))]
+pub mod websocket;
+#[cfg(all(feature = "gui", not(feature = "mock")))]
+pub mod window;
@@ -0,0 +1,121 @@
+#include <QtCore/QDate>
+#include <QtCore/QLocale>
+#include <QtCore/QTime>
+#include <cstdint>
+
+QT_USE_NAMESPACE
+
+using RsStringFillFunc = void (*)(void *output, const char *input, std::uint32_t inputSize);
+
+static void fillString(const QString &value, void *output, RsStringFillFunc fill)
+{
+    const auto byteArray = value.toUtf8();
+    fill(output, byteArray.constData(), static_cast<std::uint32_t>(byteArray.size()));
+}
+
+static QLocale::FormatType formatType(int format)
+{
+    switch (format) {
+    case 1:
+        return QLocale::ShortFormat;
+    case 2:
+        return QLocale::NarrowFormat;
+    default:
+        return QLocale::LongFormat;
+    }
+}
+
+extern "C" {
+
+QLocale *qt_binding_locale_create(const char *name, std::uint32_t size)
+{
+    return new QLocale(QString::fromUtf8(name, static_cast<int>(size)));
+}
+
+QLocale *qt_binding_locale_create_system()
+{
+    return new QLocale(QLocale::system());
+}
+
+QLocale *qt_binding_locale_clone(const QLocale *locale)
+{
+    return new QLocale(*locale);
+}
+
+bool qt_binding_locale_compare(const QLocale *first, const QLocale *second)
+{
+    return *first == *second;
+}
+
+void qt_binding_locale_delete(QLocale *locale)
+{
+    delete locale;
+}
+
+void qt_binding_locale_name(const QLocale *locale, void *output, RsStringFillFunc fill)
+{
+    fillString(locale->name(), output, fill);
+}
+
+void qt_binding_locale_format_i64(const QLocale *locale, std::int64_t value, void *output,
+                                  RsStringFillFunc fill)
+{
+    fillString(locale->toString(static_cast<qlonglong>(value)), output, fill);
+}
+
+void qt_binding_locale_format_f64(const QLocale *locale, double value, int precision,
+                                  void *output, RsStringFillFunc fill)
+{
+    fillString(locale->toString(value, 'f', precision), output, fill);
+}
+
+void qt_binding_locale_format_currency(const QLocale *locale, double value, const char *symbol,
+                                       std::uint32_t symbolSize, void *output,
+                                       RsStringFillFunc fill)
+{
+    const auto currencySymbol = QString::fromUtf8(symbol, static_cast<int>(symbolSize));
+    fillString(locale->toCurrencyString(value, currencySymbol), output, fill);
+}
+
+void qt_binding_locale_format_date(const QLocale *locale, int year, int month, int day,
+                                   int format, void *output, RsStringFillFunc fill)
+{
+    fillString(locale->toString(QDate(year, month, day), formatType(format)), output, fill);
+}
+
+void qt_binding_locale_format_time(const QLocale *locale, int hour, int minute, int second,
+                                   int format, void *output, RsStringFillFunc fill)
+{
+    fillString(locale->toString(QTime(hour, minute, second), formatType(format)), output, fill);
+}
+
+bool qt_binding_locale_parse_i64(const QLocale *locale, const char *value, std::uint32_t size,
+                                 std::int64_t *output)
+{
+    auto ok = false;
+    *output = locale->toLongLong(QString::fromUtf8(value, static_cast<int>(size)), &ok);
+    return ok;
+}
+
+bool qt_binding_locale_parse_f64(const QLocale *locale, const char *value, std::uint32_t size,
+                                 double *output)
+{
+    auto ok = false;
+    *output = locale->toDouble(QString::fromUtf8(value, static_cast<int>(size)), &ok);
+    return ok;
+}
+
+bool qt_binding_locale_parse_date(const QLocale *locale, const char *value, std::uint32_t size,
+                                  int format, int *year, int *month, int *day)
+{
+    const auto string = QString::fromUtf8(value, static_cast<int>(size));
+    const auto date = locale->toDate(string, formatType(format));
+    if (!date.isValid()) {
+        return false;
+    }
+
+    date.getDate(year, month, day);
+    return true;
+}
+
+} // extern "C"
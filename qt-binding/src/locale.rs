@@ -0,0 +1,437 @@
+//! Binding to `QLocale`
+//!
+//! [`Locale`] is a binding on Qt's `QLocale`. It formats and parses numbers, currencies, dates
+//! and times the same way QML does, so that values formatted by Rust code match what the UI
+//! shows.
+//!
+//! The locale used by the application is returned by [`Locale::system`], while
+//! [`Locale::new`] creates a locale from a name like `fr_FR`.
+//!
+//! [`Locale`]: struct.Locale.html
+//! [`Locale::system`]: struct.Locale.html#method.system
+//! [`Locale::new`]: struct.Locale.html#method.new
+//!
+//! # Limitations
+//!
+//! Since `Locale` is a binding over `QLocale`, it is neither `Send` nor `Sync`.
+//!
+//! # Examples
+//!
+//! ```
+//! use qt_binding::locale::{Date, FormatType, Locale};
+//!
+//! let locale = Locale::new("fr_FR");
+//!
+//! let text = locale.format_f64(1234.5, 2);
+//! assert!(text.ends_with("234,50"));
+//! assert_eq!(locale.parse_f64(&text), Some(1234.5));
+//!
+//! let date = Date::new(2019, 7, 14);
+//! let text = locale.format_date(date, FormatType::Short);
+//! assert_eq!(locale.parse_date(&text, FormatType::Short), Some(date));
+//! ```
+
+use crate::variant::convert::{rs_string_fill, RsStringFillFunc};
+use std::fmt;
+use std::os::raw::{c_char, c_int, c_void};
+
+/// Length of formatted dates and times
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum FormatType {
+    /// Long format, like `Sunday, July 14, 2019`
+    Long,
+    /// Short format, like `7/14/19`
+    Short,
+    /// Narrow format, that might be shorter than `Short`
+    Narrow,
+}
+
+impl FormatType {
+    fn as_c_int(self) -> c_int {
+        match self {
+            FormatType::Long => 0,
+            FormatType::Short => 1,
+            FormatType::Narrow => 2,
+        }
+    }
+}
+
+/// A calendar date
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Date {
+    /// Year
+    pub year: i32,
+    /// Month, from 1 to 12
+    pub month: u32,
+    /// Day of the month, from 1 to 31
+    pub day: u32,
+}
+
+impl Date {
+    /// Creates a new `Date`
+    pub fn new(year: i32, month: u32, day: u32) -> Self {
+        Date { year, month, day }
+    }
+}
+
+/// A time of the day
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Time {
+    /// Hour, from 0 to 23
+    pub hour: u32,
+    /// Minute, from 0 to 59
+    pub minute: u32,
+    /// Second, from 0 to 59
+    pub second: u32,
+}
+
+impl Time {
+    /// Creates a new `Time`
+    pub fn new(hour: u32, minute: u32, second: u32) -> Self {
+        Time {
+            hour,
+            minute,
+            second,
+        }
+    }
+}
+
+/// Binding to `QLocale`
+///
+/// See module level documentation for more information.
+pub struct Locale {
+    ptr: *mut c_void,
+}
+
+impl Locale {
+    /// Creates a `Locale` from its name
+    ///
+    /// The name has the format `language[_territory][.codeset][@modifier]`, like `fr_FR`. If
+    /// the name is not valid, the `C` locale is used.
+    pub fn new<S>(name: S) -> Self
+    where
+        S: AsRef<str>,
+    {
+        ffi_span!("Locale", "new");
+        let name = name.as_ref();
+        track_created!(Locale);
+        Locale {
+            ptr: unsafe {
+                qt_binding_locale_create(name.as_ptr() as *const c_char, name.len() as u32)
+            },
+        }
+    }
+
+    /// The system locale
+    pub fn system() -> Self {
+        ffi_span!("Locale", "system");
+        track_created!(Locale);
+        Locale {
+            ptr: unsafe { qt_binding_locale_create_system() },
+        }
+    }
+
+    /// The `C` locale
+    pub fn c() -> Self {
+        Locale::new("C")
+    }
+
+    /// Name of the locale, like `fr_FR`
+    pub fn name(&self) -> String {
+        ffi_span!("Locale", "name");
+        self.fill_string(|output, fill| unsafe { qt_binding_locale_name(self.ptr, output, fill) })
+    }
+
+    /// Format an integer
+    pub fn format_i64(&self, value: i64) -> String {
+        ffi_span!("Locale", "format_i64");
+        self.fill_string(|output, fill| unsafe {
+            qt_binding_locale_format_i64(self.ptr, value, output, fill)
+        })
+    }
+
+    /// Format a floating point number with a number of decimals
+    pub fn format_f64(&self, value: f64, precision: u32) -> String {
+        ffi_span!("Locale", "format_f64");
+        self.fill_string(|output, fill| unsafe {
+            qt_binding_locale_format_f64(self.ptr, value, precision as c_int, output, fill)
+        })
+    }
+
+    /// Format an amount of money
+    ///
+    /// Without a symbol, the currency symbol of the locale is used.
+    pub fn format_currency(&self, value: f64, symbol: Option<&str>) -> String {
+        ffi_span!("Locale", "format_currency");
+        let symbol = symbol.unwrap_or("");
+        self.fill_string(|output, fill| unsafe {
+            qt_binding_locale_format_currency(
+                self.ptr,
+                value,
+                symbol.as_ptr() as *const c_char,
+                symbol.len() as u32,
+                output,
+                fill,
+            )
+        })
+    }
+
+    /// Format a date
+    pub fn format_date(&self, date: Date, format: FormatType) -> String {
+        ffi_span!("Locale", "format_date");
+        self.fill_string(|output, fill| unsafe {
+            qt_binding_locale_format_date(
+                self.ptr,
+                date.year as c_int,
+                date.month as c_int,
+                date.day as c_int,
+                format.as_c_int(),
+                output,
+                fill,
+            )
+        })
+    }
+
+    /// Format a time
+    pub fn format_time(&self, time: Time, format: FormatType) -> String {
+        ffi_span!("Locale", "format_time");
+        self.fill_string(|output, fill| unsafe {
+            qt_binding_locale_format_time(
+                self.ptr,
+                time.hour as c_int,
+                time.minute as c_int,
+                time.second as c_int,
+                format.as_c_int(),
+                output,
+                fill,
+            )
+        })
+    }
+
+    /// Parse an integer
+    ///
+    /// Returns `None` if the string is not a valid integer for this locale.
+    pub fn parse_i64(&self, value: &str) -> Option<i64> {
+        ffi_span!("Locale", "parse_i64");
+        let mut result = 0;
+        let ok = unsafe {
+            qt_binding_locale_parse_i64(
+                self.ptr,
+                value.as_ptr() as *const c_char,
+                value.len() as u32,
+                &mut result,
+            )
+        };
+        if ok {
+            Some(result)
+        } else {
+            None
+        }
+    }
+
+    /// Parse a floating point number
+    ///
+    /// Returns `None` if the string is not a valid number for this locale.
+    pub fn parse_f64(&self, value: &str) -> Option<f64> {
+        ffi_span!("Locale", "parse_f64");
+        let mut result = 0.;
+        let ok = unsafe {
+            qt_binding_locale_parse_f64(
+                self.ptr,
+                value.as_ptr() as *const c_char,
+                value.len() as u32,
+                &mut result,
+            )
+        };
+        if ok {
+            Some(result)
+        } else {
+            None
+        }
+    }
+
+    /// Parse a date
+    ///
+    /// Returns `None` if the string is not a valid date in the given format for this locale.
+    pub fn parse_date(&self, value: &str, format: FormatType) -> Option<Date> {
+        ffi_span!("Locale", "parse_date");
+        let (mut year, mut month, mut day) = (0, 0, 0);
+        let ok = unsafe {
+            qt_binding_locale_parse_date(
+                self.ptr,
+                value.as_ptr() as *const c_char,
+                value.len() as u32,
+                format.as_c_int(),
+                &mut year,
+                &mut month,
+                &mut day,
+            )
+        };
+        if ok {
+            Some(Date::new(year, month as u32, day as u32))
+        } else {
+            None
+        }
+    }
+
+    fn fill_string<F>(&self, f: F) -> String
+    where
+        F: FnOnce(*mut c_void, RsStringFillFunc),
+    {
+        let mut value = String::new();
+        let data: *mut String = &mut value;
+        f(data as *mut c_void, rs_string_fill);
+        value
+    }
+}
+
+impl Default for Locale {
+    fn default() -> Self {
+        Locale::system()
+    }
+}
+
+impl Clone for Locale {
+    fn clone(&self) -> Self {
+        ffi_span!("Locale", "clone");
+        track_created!(Locale);
+        Locale {
+            ptr: unsafe { qt_binding_locale_clone(self.ptr) },
+        }
+    }
+}
+
+impl PartialEq for Locale {
+    fn eq(&self, other: &Locale) -> bool {
+        ffi_span!("Locale", "eq");
+        unsafe { qt_binding_locale_compare(self.ptr, other.ptr) }
+    }
+}
+
+impl Eq for Locale {}
+
+impl fmt::Debug for Locale {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "QLocale {{ name: {:?} }}", self.name())
+    }
+}
+
+impl Drop for Locale {
+    fn drop(&mut self) {
+        ffi_span!("Locale", "drop");
+        track_destroyed!(Locale);
+        unsafe { qt_binding_locale_delete(self.ptr) }
+    }
+}
+
+extern "C" {
+    fn qt_binding_locale_create(name: *const c_char, size: u32) -> *mut c_void;
+    fn qt_binding_locale_create_system() -> *mut c_void;
+    fn qt_binding_locale_clone(locale: *const c_void) -> *mut c_void;
+    fn qt_binding_locale_compare(first: *const c_void, second: *const c_void) -> bool;
+    fn qt_binding_locale_delete(locale: *mut c_void);
+
+    fn qt_binding_locale_name(locale: *const c_void, output: *mut c_void, fill: RsStringFillFunc);
+    fn qt_binding_locale_format_i64(
+        locale: *const c_void,
+        value: i64,
+        output: *mut c_void,
+        fill: RsStringFillFunc,
+    );
+    fn qt_binding_locale_format_f64(
+        locale: *const c_void,
+        value: f64,
+        precision: c_int,
+        output: *mut c_void,
+        fill: RsStringFillFunc,
+    );
+    fn qt_binding_locale_format_currency(
+        locale: *const c_void,
+        value: f64,
+        symbol: *const c_char,
+        symbol_size: u32,
+        output: *mut c_void,
+        fill: RsStringFillFunc,
+    );
+    fn qt_binding_locale_format_date(
+        locale: *const c_void,
+        year: c_int,
+        month: c_int,
+        day: c_int,
+        format: c_int,
+        output: *mut c_void,
+        fill: RsStringFillFunc,
+    );
+    fn qt_binding_locale_format_time(
+        locale: *const c_void,
+        hour: c_int,
+        minute: c_int,
+        second: c_int,
+        format: c_int,
+        output: *mut c_void,
+        fill: RsStringFillFunc,
+    );
+    fn qt_binding_locale_parse_i64(
+        locale: *const c_void,
+        value: *const c_char,
+        size: u32,
+        output: *mut i64,
+    ) -> bool;
+    fn qt_binding_locale_parse_f64(
+        locale: *const c_void,
+        value: *const c_char,
+        size: u32,
+        output: *mut f64,
+    ) -> bool;
+    fn qt_binding_locale_parse_date(
+        locale: *const c_void,
+        value: *const c_char,
+        size: u32,
+        format: c_int,
+        year: *mut c_int,
+        month: *mut c_int,
+        day: *mut c_int,
+    ) -> bool;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn numbers() {
+        let locale = Locale::c();
+        assert_eq!(locale.name(), "C");
+        assert_eq!(locale.format_i64(-1234), "-1234");
+        assert_eq!(locale.format_f64(1.5, 3), "1.500");
+        assert_eq!(locale.parse_i64("42"), Some(42));
+        assert_eq!(locale.parse_i64("hello"), None);
+
+        let locale = Locale::new("en_US");
+        assert_eq!(locale.name(), "en_US");
+        assert_eq!(locale.format_i64(1234567), "1,234,567");
+        assert_eq!(locale.parse_f64("1,234.5"), Some(1234.5));
+        assert_eq!(locale.format_currency(12.5, Some("€")), "€12.50");
+    }
+
+    #[test]
+    fn eq() {
+        assert_eq!(Locale::new("fr_FR"), Locale::new("fr_FR").clone());
+        assert_ne!(Locale::new("fr_FR"), Locale::new("en_US"));
+    }
+
+    #[test]
+    fn dates() {
+        let locale = Locale::new("en_US");
+        let date = Date::new(2019, 7, 14);
+
+        let text = locale.format_date(date, FormatType::Long);
+        assert!(text.contains("2019"));
+        assert!(text.contains("July"));
+        assert_eq!(locale.parse_date(&text, FormatType::Long), Some(date));
+        assert_eq!(locale.parse_date("hello", FormatType::Long), None);
+
+        let text = locale.format_time(Time::new(13, 5, 0), FormatType::Short);
+        assert!(text.contains("1:05"));
+    }
+}
@@ -0,0 +1,72 @@
+#include <QtCore/QLoggingCategory>
+#include <QtCore/QString>
+#include <QtCore/QtGlobal>
+#include <atomic>
+#include <cstdint>
+
+QT_USE_NAMESPACE
+
+using RsMessageFunc = void (*)(void *data, int level, const char *category, const char *file,
+                               int line, const char *message, std::uint32_t messageSize);
+
+namespace {
+
+struct Handler
+{
+    void *data;
+    RsMessageFunc func;
+};
+
+std::atomic<Handler *> handler{nullptr};
+
+int level(QtMsgType type)
+{
+    // Same values as Level::from_c_int in logging.rs
+    switch (type) {
+    case QtDebugMsg:
+        return 0;
+    case QtWarningMsg:
+        return 1;
+    case QtCriticalMsg:
+        return 2;
+    case QtFatalMsg:
+        return 3;
+    default:
+        return 4;
+    }
+}
+
+void messageHandler(QtMsgType type, const QMessageLogContext &context, const QString &message)
+{
+    const auto current = handler.load();
+    if (current == nullptr) {
+        return;
+    }
+
+    const auto byteArray = message.toUtf8();
+    current->func(current->data, level(type), context.category, context.file, context.line,
+                  byteArray.constData(), static_cast<std::uint32_t>(byteArray.size()));
+}
+
+} // namespace
+
+extern "C" {
+
+void qt_binding_logging_install(void *data, RsMessageFunc func)
+{
+    // Previous handlers are leaked, as they might still be used by other threads
+    handler.store(new Handler{data, func});
+    qInstallMessageHandler(messageHandler);
+}
+
+void qt_binding_logging_uninstall()
+{
+    qInstallMessageHandler(nullptr);
+}
+
+void qt_binding_logging_set_filter_rules(const char *rules, std::uint32_t size)
+{
+    QLoggingCategory::setFilterRules(QString::fromUtf8(rules, static_cast<int>(size)));
+}
+
+} // extern "C"
@@ -0,0 +1,228 @@
+//! Qt logging integration
+//!
+//! Qt and QML report messages through Qt's message handler, that prints them on the standard
+//! error by default. This includes QML `console.log`, `console.warn` and `console.error`
+//! calls, that are reported with the `qml` or `js` category.
+//!
+//! [`install`] replaces the message handler with a Rust closure, that receives each
+//! [`Message`] with its category and, when available, its source file and line. Categories
+//! can be filtered with [`set_filter_rules`].
+//!
+//! [`install`]: fn.install.html
+//! [`Message`]: struct.Message.html
+//! [`set_filter_rules`]: fn.set_filter_rules.html
+//!
+//! # Features
+//!
+//! With the `log` feature, [`install_log`] forwards messages to the [`log`] crate, with a
+//! target named after the category, like `qt::qml`.
+//!
+//! [`install_log`]: fn.install_log.html
+//! [`log`]: https://docs.rs/log
+//!
+//! # Examples
+//!
+//! Handling QML console messages in Rust and disabling Qt debug messages
+//!
+//! ```no_run
+//! use qt_binding::logging;
+//!
+//! logging::install(|message| {
+//!     if message.category == "qml" || message.category == "js" {
+//!         eprintln!("[QML {:?}] {}", message.level, message);
+//!     }
+//! });
+//! logging::set_filter_rules("*.debug=false\nqml.debug=true");
+//! ```
+
+use std::ffi::CStr;
+use std::fmt;
+use std::os::raw::{c_char, c_int, c_void};
+use std::slice::from_raw_parts;
+
+/// Severity of a message
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Level {
+    /// Debug message, from `qDebug` or `console.log`
+    Debug,
+    /// Information message, from `qInfo` or `console.info`
+    Info,
+    /// Warning, from `qWarning` or `console.warn`
+    Warning,
+    /// Critical error, from `qCritical` or `console.error`
+    Critical,
+    /// Fatal error, from `qFatal`, after which the program aborts
+    Fatal,
+}
+
+impl Level {
+    fn from_c_int(value: c_int) -> Self {
+        match value {
+            0 => Level::Debug,
+            1 => Level::Warning,
+            2 => Level::Critical,
+            3 => Level::Fatal,
+            _ => Level::Info,
+        }
+    }
+}
+
+/// A message reported by Qt
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Message {
+    /// Severity
+    pub level: Level,
+    /// Logging category, like `qml`, `js` or `qt.qpa.xcb`
+    pub category: String,
+    /// Text of the message
+    pub message: String,
+    /// Source file, if known
+    pub file: Option<String>,
+    /// Source line, if known
+    pub line: Option<u32>,
+}
+
+impl fmt::Display for Message {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(file) = &self.file {
+            write!(f, "{}", file)?;
+            if let Some(line) = self.line {
+                write!(f, ":{}", line)?;
+            }
+            write!(f, ": ")?;
+        }
+        write!(f, "{}", self.message)
+    }
+}
+
+type MessageHandler = Box<dyn Fn(&Message) + Send + Sync>;
+
+/// Install a message handler
+///
+/// The handler replaces Qt's default message handler, and is called for every message that
+/// is not filtered out, from any thread. Installing a new handler replaces the previous one.
+pub fn install<F>(handler: F)
+where
+    F: Fn(&Message) + Send + Sync + 'static,
+{
+    ffi_span!("logging", "install");
+    // The handler is leaked as messages might still be handled on other threads while a new
+    // handler is being installed
+    let handler: MessageHandler = Box::new(handler);
+    let data = Box::into_raw(Box::new(handler));
+    unsafe { qt_binding_logging_install(data as *mut c_void, rs_logging_message) }
+}
+
+/// Restore Qt's default message handler, that prints messages on the standard error
+pub fn uninstall() {
+    ffi_span!("logging", "uninstall");
+    unsafe { qt_binding_logging_uninstall() }
+}
+
+/// Forward messages to the `log` crate
+///
+/// Messages are logged with a target named after the category, prefixed with `qt::`.
+/// Warnings are logged as warnings, critical and fatal errors as errors.
+#[cfg(feature = "log")]
+pub fn install_log() {
+    install(|message| {
+        let level = match message.level {
+            Level::Debug => log::Level::Debug,
+            Level::Info => log::Level::Info,
+            Level::Warning => log::Level::Warn,
+            Level::Critical | Level::Fatal => log::Level::Error,
+        };
+        let target = format!("qt::{}", message.category);
+        log::logger().log(
+            &log::Record::builder()
+                .level(level)
+                .target(&target)
+                .file(message.file.as_deref())
+                .line(message.line)
+                .args(format_args!("{}", message.message))
+                .build(),
+        );
+    })
+}
+
+/// Set the rules that enable or disable logging categories
+///
+/// Rules use the syntax of `QLoggingCategory::setFilterRules`, one rule per line, like
+/// `qml.debug=false` or `qt.*=false`. Filtered messages are not sent to the handler.
+pub fn set_filter_rules<S>(rules: S)
+where
+    S: AsRef<str>,
+{
+    ffi_span!("logging", "set_filter_rules");
+    let rules = rules.as_ref();
+    unsafe {
+        qt_binding_logging_set_filter_rules(rules.as_ptr() as *const c_char, rules.len() as u32)
+    }
+}
+
+unsafe fn to_string(value: *const c_char) -> Option<String> {
+    if value.is_null() {
+        None
+    } else {
+        Some(CStr::from_ptr(value).to_string_lossy().into_owned())
+    }
+}
+
+extern "C" fn rs_logging_message(
+    data: *mut c_void,
+    level: c_int,
+    category: *const c_char,
+    file: *const c_char,
+    line: c_int,
+    message: *const c_char,
+    message_size: u32,
+) {
+    let handler = unsafe { &*(data as *const MessageHandler) };
+    let message = unsafe {
+        let bytes = from_raw_parts(message as *const u8, message_size as usize);
+        Message {
+            level: Level::from_c_int(level),
+            category: to_string(category).unwrap_or_default(),
+            message: String::from_utf8_lossy(bytes).into_owned(),
+            file: to_string(file),
+            line: if line > 0 { Some(line as u32) } else { None },
+        }
+    };
+    handler(&message);
+}
+
+type RsMessageFunc = extern "C" fn(
+    data: *mut c_void,
+    level: c_int,
+    category: *const c_char,
+    file: *const c_char,
+    line: c_int,
+    message: *const c_char,
+    message_size: u32,
+);
+
+extern "C" {
+    fn qt_binding_logging_install(data: *mut c_void, handler: RsMessageFunc);
+    fn qt_binding_logging_uninstall();
+    fn qt_binding_logging_set_filter_rules(rules: *const c_char, size: u32);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display_message() {
+        let mut message = Message {
+            level: Level::Debug,
+            category: "qml".to_string(),
+            message: "Hello".to_string(),
+            file: Some("qrc:/main.qml".to_string()),
+            line: Some(12),
+        };
+        assert_eq!(message.to_string(), "qrc:/main.qml:12: Hello");
+
+        message.file = None;
+        assert_eq!(message.to_string(), "Hello");
+    }
+}
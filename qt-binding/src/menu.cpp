@@ -0,0 +1,138 @@
+#include <QtGui/QCursor>
+#include <QtGui/QIcon>
+#include <QtGui/QKeySequence>
+#include <QtWidgets/QAction>
+#include <QtWidgets/QMenu>
+#include <QtWidgets/QMenuBar>
+#include <cstdint>
+
+QT_USE_NAMESPACE
+
+using RsStringFillFunc = void (*)(void *output, const char *input, std::uint32_t inputSize);
+using RsTriggeredFunc = void (*)(void *data, bool checked);
+
+static QString toString(const char *value, std::uint32_t size)
+{
+    return QString::fromUtf8(value, static_cast<int>(size));
+}
+
+extern "C" {
+
+QAction *qt_binding_action_create(const char *text, std::uint32_t size, void *data,
+                                  RsTriggeredFunc triggered)
+{
+    auto action = new QAction(toString(text, size), nullptr);
+    QObject::connect(action, &QAction::triggered,
+                     [data, triggered](bool checked) { triggered(data, checked); });
+    return action;
+}
+
+void qt_binding_action_delete(QAction *action)
+{
+    delete action;
+}
+
+void qt_binding_action_text(const QAction *action, void *output, RsStringFillFunc fill)
+{
+    const auto byteArray = action->text().toUtf8();
+    fill(output, byteArray.constData(), static_cast<std::uint32_t>(byteArray.size()));
+}
+
+void qt_binding_action_set_text(QAction *action, const char *text, std::uint32_t size)
+{
+    action->setText(toString(text, size));
+}
+
+void qt_binding_action_set_icon(QAction *action, const char *icon, std::uint32_t size,
+                                bool fromTheme)
+{
+    const auto name = toString(icon, size);
+    action->setIcon(fromTheme ? QIcon::fromTheme(name) : QIcon(name));
+}
+
+void qt_binding_action_set_shortcut(QAction *action, const char *shortcut, std::uint32_t size)
+{
+    action->setShortcut(QKeySequence::fromString(toString(shortcut, size)));
+}
+
+bool qt_binding_action_is_enabled(const QAction *action)
+{
+    return action->isEnabled();
+}
+
+void qt_binding_action_set_enabled(QAction *action, bool enabled)
+{
+    action->setEnabled(enabled);
+}
+
+bool qt_binding_action_is_checkable(const QAction *action)
+{
+    return action->isCheckable();
+}
+
+void qt_binding_action_set_checkable(QAction *action, bool checkable)
+{
+    action->setCheckable(checkable);
+}
+
+bool qt_binding_action_is_checked(const QAction *action)
+{
+    return action->isChecked();
+}
+
+void qt_binding_action_set_checked(QAction *action, bool checked)
+{
+    action->setChecked(checked);
+}
+
+void qt_binding_action_trigger(QAction *action)
+{
+    action->trigger();
+}
+
+QMenu *qt_binding_menu_create(const char *title, std::uint32_t size)
+{
+    return new QMenu(toString(title, size));
+}
+
+void qt_binding_menu_delete(QMenu *menu)
+{
+    delete menu;
+}
+
+void qt_binding_menu_add_action(QMenu *menu, QAction *action)
+{
+    menu->addAction(action);
+}
+
+void qt_binding_menu_add_menu(QMenu *menu, QMenu *submenu)
+{
+    menu->addMenu(submenu);
+}
+
+void qt_binding_menu_add_separator(QMenu *menu)
+{
+    menu->addSeparator();
+}
+
+void qt_binding_menu_popup(QMenu *menu)
+{
+    menu->popup(QCursor::pos());
+}
+
+QMenuBar *qt_binding_menu_bar_create()
+{
+    return new QMenuBar(nullptr);
+}
+
+void qt_binding_menu_bar_delete(QMenuBar *menuBar)
+{
+    delete menuBar;
+}
+
+void qt_binding_menu_bar_add_menu(QMenuBar *menuBar, QMenu *menu)
+{
+    menuBar->addMenu(menu);
+}
+
+} // extern "C"
@@ -0,0 +1,407 @@
+//! Menus and actions
+//!
+//! [`Action`] is a binding on Qt's `QAction`, an entry of a menu that calls a Rust closure when
+//! triggered. Actions can have an icon, a shortcut, and can be checkable.
+//!
+//! [`Menu`] is a binding on Qt's `QMenu`. It groups actions and sub-menus, and can be shown as
+//! a context menu with [`popup`].
+//!
+//! [`MenuBar`] is a binding on Qt's `QMenuBar`. On macOS, it is the global menu bar of the
+//! application. On other platforms, a menu bar needs to be displayed in a widgets window, and
+//! QML applications should use the `Qt.labs.platform` menus instead.
+//!
+//! [`Action`]: struct.Action.html
+//! [`Menu`]: struct.Menu.html
+//! [`popup`]: struct.Menu.html#method.popup
+//! [`MenuBar`]: struct.MenuBar.html
+//!
+//! # Features
+//!
+//! This module is only available with the `widgets` feature.
+//!
+//! # Limitations
+//!
+//! Since menus are bindings over Qt objects, they are neither `Send` nor `Sync`.
+//!
+//! Actions, menus and menu bars can be dropped in any order. A dropped action or menu is
+//! removed from the menus that contain it.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use qt_binding::app::Application;
+//! use qt_binding::menu::{Action, Menu, MenuBar};
+//!
+//! let mut app = Application::new();
+//!
+//! let mut quit = Action::new("Quit");
+//! quit.set_shortcut("Ctrl+Q").on_triggered(|_| Application::quit());
+//!
+//! let mut file = Menu::new("File");
+//! file.add_action(&quit);
+//!
+//! let mut menu_bar = MenuBar::new();
+//! menu_bar.add_menu(&file);
+//!
+//! app.exec();
+//! ```
+
+use crate::app;
+use crate::object::Object;
+use crate::variant::convert::{rs_string_fill, RsStringFillFunc};
+use std::os::raw::{c_char, c_void};
+
+type TriggeredHandler = Option<Box<dyn FnMut(bool)>>;
+
+/// Binding to `QAction`
+///
+/// See module level documentation for more information.
+pub struct Action {
+    ptr: *mut c_void,
+    handler: Box<TriggeredHandler>,
+}
+
+impl Action {
+    /// Creates an `Action` with a text
+    ///
+    /// A `&` in the text marks the next character as the mnemonic of the action.
+    pub fn new<S>(text: S) -> Self
+    where
+        S: AsRef<str>,
+    {
+        ffi_span!("Action", "new");
+        let text = text.as_ref();
+        let mut handler = Box::new(None);
+        let data: *mut TriggeredHandler = &mut *handler;
+        let ptr = unsafe {
+            qt_binding_action_create(
+                text.as_ptr() as *const c_char,
+                text.len() as u32,
+                data as *mut c_void,
+                rs_action_triggered,
+            )
+        };
+        track_created!(Action);
+        Action { ptr, handler }
+    }
+
+    /// Text of the action
+    pub fn text(&self) -> String {
+        ffi_span!("Action", "text");
+        let mut value = String::new();
+        unsafe {
+            let data: *mut String = &mut value;
+            qt_binding_action_text(self.ptr, data as *mut c_void, rs_string_fill);
+        }
+        value
+    }
+
+    /// Set the text of the action
+    pub fn set_text<S>(&mut self, text: S) -> &mut Self
+    where
+        S: AsRef<str>,
+    {
+        ffi_span!("Action", "set_text");
+        let text = text.as_ref();
+        unsafe {
+            qt_binding_action_set_text(self.ptr, text.as_ptr() as *const c_char, text.len() as u32)
+        };
+        self
+    }
+
+    /// Set the icon of the action from an image file
+    ///
+    /// Paths starting with `:/` are loaded from Qt resources.
+    pub fn set_icon<S>(&mut self, path: S) -> &mut Self
+    where
+        S: AsRef<str>,
+    {
+        ffi_span!("Action", "set_icon");
+        let path = path.as_ref();
+        unsafe {
+            qt_binding_action_set_icon(
+                self.ptr,
+                path.as_ptr() as *const c_char,
+                path.len() as u32,
+                false,
+            )
+        };
+        self
+    }
+
+    /// Set the icon of the action from the icon theme, like `document-open`
+    pub fn set_icon_from_theme<S>(&mut self, name: S) -> &mut Self
+    where
+        S: AsRef<str>,
+    {
+        ffi_span!("Action", "set_icon_from_theme");
+        let name = name.as_ref();
+        unsafe {
+            qt_binding_action_set_icon(
+                self.ptr,
+                name.as_ptr() as *const c_char,
+                name.len() as u32,
+                true,
+            )
+        };
+        self
+    }
+
+    /// Set the shortcut of the action, in Qt's portable format, like `Ctrl+Q`
+    pub fn set_shortcut<S>(&mut self, shortcut: S) -> &mut Self
+    where
+        S: AsRef<str>,
+    {
+        ffi_span!("Action", "set_shortcut");
+        let shortcut = shortcut.as_ref();
+        unsafe {
+            qt_binding_action_set_shortcut(
+                self.ptr,
+                shortcut.as_ptr() as *const c_char,
+                shortcut.len() as u32,
+            )
+        };
+        self
+    }
+
+    /// Check if the action is enabled
+    pub fn is_enabled(&self) -> bool {
+        ffi_span!("Action", "is_enabled");
+        unsafe { qt_binding_action_is_enabled(self.ptr) }
+    }
+
+    /// Enable or disable the action
+    pub fn set_enabled(&mut self, enabled: bool) -> &mut Self {
+        ffi_span!("Action", "set_enabled");
+        unsafe { qt_binding_action_set_enabled(self.ptr, enabled) };
+        self
+    }
+
+    /// Check if the action is checkable
+    pub fn is_checkable(&self) -> bool {
+        ffi_span!("Action", "is_checkable");
+        unsafe { qt_binding_action_is_checkable(self.ptr) }
+    }
+
+    /// Make the action checkable
+    pub fn set_checkable(&mut self, checkable: bool) -> &mut Self {
+        ffi_span!("Action", "set_checkable");
+        unsafe { qt_binding_action_set_checkable(self.ptr, checkable) };
+        self
+    }
+
+    /// Check if the action is checked
+    pub fn is_checked(&self) -> bool {
+        ffi_span!("Action", "is_checked");
+        unsafe { qt_binding_action_is_checked(self.ptr) }
+    }
+
+    /// Check or uncheck a checkable action
+    pub fn set_checked(&mut self, checked: bool) -> &mut Self {
+        ffi_span!("Action", "set_checked");
+        unsafe { qt_binding_action_set_checked(self.ptr, checked) };
+        self
+    }
+
+    /// Handle the action being triggered
+    ///
+    /// The handler is called with the checked state of the action. Setting a new handler
+    /// replaces the previous one.
+    pub fn on_triggered<F>(&mut self, handler: F) -> &mut Self
+    where
+        F: FnMut(bool) + 'static,
+    {
+        *self.handler = Some(Box::new(handler));
+        self
+    }
+
+    /// Trigger the action
+    pub fn trigger(&mut self) {
+        ffi_span!("Action", "trigger");
+        unsafe { qt_binding_action_trigger(self.ptr) }
+    }
+}
+
+impl Object for Action {
+    fn as_object_ptr(&self) -> *mut c_void {
+        self.ptr
+    }
+}
+
+impl Drop for Action {
+    fn drop(&mut self) {
+        ffi_span!("Action", "drop");
+        if app::should_leak("Action") {
+            return;
+        }
+        track_destroyed!(Action);
+        unsafe { qt_binding_action_delete(self.ptr) }
+    }
+}
+
+/// Binding to `QMenu`
+///
+/// See module level documentation for more information.
+pub struct Menu {
+    ptr: *mut c_void,
+}
+
+impl Menu {
+    /// Creates a `Menu` with a title
+    pub fn new<S>(title: S) -> Self
+    where
+        S: AsRef<str>,
+    {
+        ffi_span!("Menu", "new");
+        let title = title.as_ref();
+        track_created!(Menu);
+        Menu {
+            ptr: unsafe {
+                qt_binding_menu_create(title.as_ptr() as *const c_char, title.len() as u32)
+            },
+        }
+    }
+
+    /// Append an action
+    pub fn add_action(&mut self, action: &Action) -> &mut Self {
+        ffi_span!("Menu", "add_action");
+        unsafe { qt_binding_menu_add_action(self.ptr, action.ptr) };
+        self
+    }
+
+    /// Append a sub-menu
+    pub fn add_menu(&mut self, menu: &Menu) -> &mut Self {
+        ffi_span!("Menu", "add_menu");
+        unsafe { qt_binding_menu_add_menu(self.ptr, menu.ptr) };
+        self
+    }
+
+    /// Append a separator
+    pub fn add_separator(&mut self) -> &mut Self {
+        ffi_span!("Menu", "add_separator");
+        unsafe { qt_binding_menu_add_separator(self.ptr) };
+        self
+    }
+
+    /// Show the menu as a context menu, at the position of the mouse cursor
+    ///
+    /// This call does not block. Triggered actions call their handler.
+    pub fn popup(&mut self) {
+        ffi_span!("Menu", "popup");
+        unsafe { qt_binding_menu_popup(self.ptr) }
+    }
+}
+
+impl Object for Menu {
+    fn as_object_ptr(&self) -> *mut c_void {
+        self.ptr
+    }
+}
+
+impl Drop for Menu {
+    fn drop(&mut self) {
+        ffi_span!("Menu", "drop");
+        if app::should_leak("Menu") {
+            return;
+        }
+        track_destroyed!(Menu);
+        unsafe { qt_binding_menu_delete(self.ptr) }
+    }
+}
+
+/// Binding to `QMenuBar`
+///
+/// See module level documentation for more information.
+pub struct MenuBar {
+    ptr: *mut c_void,
+}
+
+impl MenuBar {
+    /// Creates a `MenuBar`
+    ///
+    /// On macOS, the menu bar is used as the global menu bar of the application.
+    pub fn new() -> Self {
+        ffi_span!("MenuBar", "new");
+        track_created!(MenuBar);
+        MenuBar {
+            ptr: unsafe { qt_binding_menu_bar_create() },
+        }
+    }
+
+    /// Append a menu
+    pub fn add_menu(&mut self, menu: &Menu) -> &mut Self {
+        ffi_span!("MenuBar", "add_menu");
+        unsafe { qt_binding_menu_bar_add_menu(self.ptr, menu.ptr) };
+        self
+    }
+}
+
+impl Default for MenuBar {
+    fn default() -> Self {
+        MenuBar::new()
+    }
+}
+
+impl Object for MenuBar {
+    fn as_object_ptr(&self) -> *mut c_void {
+        self.ptr
+    }
+}
+
+impl Drop for MenuBar {
+    fn drop(&mut self) {
+        ffi_span!("MenuBar", "drop");
+        if app::should_leak("MenuBar") {
+            return;
+        }
+        track_destroyed!(MenuBar);
+        unsafe { qt_binding_menu_bar_delete(self.ptr) }
+    }
+}
+
+extern "C" fn rs_action_triggered(data: *mut c_void, checked: bool) {
+    let handler = unsafe { &mut *(data as *mut TriggeredHandler) };
+    if let Some(handler) = handler {
+        handler(checked);
+    }
+}
+
+type RsTriggeredFunc = extern "C" fn(data: *mut c_void, checked: bool);
+
+extern "C" {
+    fn qt_binding_action_create(
+        text: *const c_char,
+        size: u32,
+        data: *mut c_void,
+        triggered: RsTriggeredFunc,
+    ) -> *mut c_void;
+    fn qt_binding_action_delete(action: *mut c_void);
+
+    fn qt_binding_action_text(action: *const c_void, output: *mut c_void, fill: RsStringFillFunc);
+    fn qt_binding_action_set_text(action: *mut c_void, text: *const c_char, size: u32);
+    fn qt_binding_action_set_icon(
+        action: *mut c_void,
+        icon: *const c_char,
+        size: u32,
+        from_theme: bool,
+    );
+    fn qt_binding_action_set_shortcut(action: *mut c_void, shortcut: *const c_char, size: u32);
+    fn qt_binding_action_is_enabled(action: *const c_void) -> bool;
+    fn qt_binding_action_set_enabled(action: *mut c_void, enabled: bool);
+    fn qt_binding_action_is_checkable(action: *const c_void) -> bool;
+    fn qt_binding_action_set_checkable(action: *mut c_void, checkable: bool);
+    fn qt_binding_action_is_checked(action: *const c_void) -> bool;
+    fn qt_binding_action_set_checked(action: *mut c_void, checked: bool);
+    fn qt_binding_action_trigger(action: *mut c_void);
+
+    fn qt_binding_menu_create(title: *const c_char, size: u32) -> *mut c_void;
+    fn qt_binding_menu_delete(menu: *mut c_void);
+    fn qt_binding_menu_add_action(menu: *mut c_void, action: *mut c_void);
+    fn qt_binding_menu_add_menu(menu: *mut c_void, submenu: *mut c_void);
+    fn qt_binding_menu_add_separator(menu: *mut c_void);
+    fn qt_binding_menu_popup(menu: *mut c_void);
+
+    fn qt_binding_menu_bar_create() -> *mut c_void;
+    fn qt_binding_menu_bar_delete(menu_bar: *mut c_void);
+    fn qt_binding_menu_bar_add_menu(menu_bar: *mut c_void, menu: *mut c_void);
+}
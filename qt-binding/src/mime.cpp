@@ -0,0 +1,141 @@
+#include <QtCore/QMimeDatabase>
+#include <cstdint>
+
+QT_USE_NAMESPACE
+
+using RsStringFillFunc = void (*)(void *output, const char *input, std::uint32_t inputSize);
+
+static QString toString(const char *value, std::uint32_t size)
+{
+    return QString::fromUtf8(value, static_cast<int>(size));
+}
+
+static void fillString(const QString &value, void *output, RsStringFillFunc fill)
+{
+    const auto byteArray = value.toUtf8();
+    fill(output, byteArray.constData(), static_cast<std::uint32_t>(byteArray.size()));
+}
+
+static void fillStringList(const QStringList &values, void *output, RsStringFillFunc append)
+{
+    for (const auto &value : values) {
+        fillString(value, output, append);
+    }
+}
+
+static QMimeType *validOrNull(const QMimeType &mimeType)
+{
+    return mimeType.isValid() ? new QMimeType(mimeType) : nullptr;
+}
+
+extern "C" {
+
+QMimeType *qt_binding_mime_type_for_name(const char *name, std::uint32_t size)
+{
+    return validOrNull(QMimeDatabase().mimeTypeForName(toString(name, size)));
+}
+
+QMimeType *qt_binding_mime_type_for_file(const char *path, std::uint32_t size, bool nameOnly)
+{
+    const auto mode = nameOnly ? QMimeDatabase::MatchExtension : QMimeDatabase::MatchDefault;
+    return new QMimeType(QMimeDatabase().mimeTypeForFile(toString(path, size), mode));
+}
+
+QMimeType *qt_binding_mime_type_for_data(const char *data, std::uint32_t size)
+{
+    return new QMimeType(QMimeDatabase().mimeTypeForData(QByteArray(data, static_cast<int>(size))));
+}
+
+QMimeType *qt_binding_mime_type_for_file_name_and_data(const char *name, std::uint32_t nameSize,
+                                                       const char *data, std::uint32_t dataSize)
+{
+    return new QMimeType(QMimeDatabase().mimeTypeForFileNameAndData(
+        toString(name, nameSize), QByteArray(data, static_cast<int>(dataSize))));
+}
+
+void qt_binding_mime_suffix_for_file_name(const char *name, std::uint32_t size, void *output,
+                                          RsStringFillFunc fill)
+{
+    fillString(QMimeDatabase().suffixForFileName(toString(name, size)), output, fill);
+}
+
+QMimeType *qt_binding_mime_type_clone(const QMimeType *mimeType)
+{
+    return new QMimeType(*mimeType);
+}
+
+void qt_binding_mime_type_delete(QMimeType *mimeType)
+{
+    delete mimeType;
+}
+
+bool qt_binding_mime_type_compare(const QMimeType *first, const QMimeType *second)
+{
+    return *first == *second;
+}
+
+bool qt_binding_mime_type_is_default(const QMimeType *mimeType)
+{
+    return mimeType->isDefault();
+}
+
+void qt_binding_mime_type_name(const QMimeType *mimeType, void *output, RsStringFillFunc fill)
+{
+    fillString(mimeType->name(), output, fill);
+}
+
+void qt_binding_mime_type_comment(const QMimeType *mimeType, void *output,
+                                  RsStringFillFunc fill)
+{
+    fillString(mimeType->comment(), output, fill);
+}
+
+void qt_binding_mime_type_icon_name(const QMimeType *mimeType, void *output,
+                                    RsStringFillFunc fill)
+{
+    fillString(mimeType->iconName(), output, fill);
+}
+
+void qt_binding_mime_type_generic_icon_name(const QMimeType *mimeType, void *output,
+                                            RsStringFillFunc fill)
+{
+    fillString(mimeType->genericIconName(), output, fill);
+}
+
+void qt_binding_mime_type_preferred_suffix(const QMimeType *mimeType, void *output,
+                                           RsStringFillFunc fill)
+{
+    fillString(mimeType->preferredSuffix(), output, fill);
+}
+
+void qt_binding_mime_type_glob_patterns(const QMimeType *mimeType, void *output,
+                                        RsStringFillFunc append)
+{
+    fillStringList(mimeType->globPatterns(), output, append);
+}
+
+void qt_binding_mime_type_suffixes(const QMimeType *mimeType, void *output,
+                                   RsStringFillFunc append)
+{
+    fillStringList(mimeType->suffixes(), output, append);
+}
+
+void qt_binding_mime_type_aliases(const QMimeType *mimeType, void *output,
+                                  RsStringFillFunc append)
+{
+    fillStringList(mimeType->aliases(), output, append);
+}
+
+void qt_binding_mime_type_parents(const QMimeType *mimeType, void *output,
+                                  RsStringFillFunc append)
+{
+    fillStringList(mimeType->parentMimeTypes(), output, append);
+}
+
+bool qt_binding_mime_type_inherits(const QMimeType *mimeType, const char *name,
+                                   std::uint32_t size)
+{
+    return mimeType->inherits(toString(name, size));
+}
+
+} // extern "C"
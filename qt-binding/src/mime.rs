@@ -0,0 +1,439 @@
+//! Binding to `QMimeDatabase`
+//!
+//! [`MimeDatabase`] is a binding on Qt's `QMimeDatabase`. It detects the MIME type of files,
+//! from their name, their content or both, using the shared MIME-info database. Since it is the
+//! database used by Qt, types detected by Rust code match the ones detected by the UI.
+//!
+//! MIME types are described by [`MimeType`], a binding on `QMimeType`, that gives access to
+//! their description, icon name and glob patterns.
+//!
+//! [`MimeDatabase`]: struct.MimeDatabase.html
+//! [`MimeType`]: struct.MimeType.html
+//!
+//! # Limitations
+//!
+//! Since `MimeType` is a binding over `QMimeType`, it is neither `Send` nor `Sync`.
+//!
+//! # Examples
+//!
+//! ```
+//! use qt_binding::mime::MimeDatabase;
+//!
+//! let database = MimeDatabase::new();
+//!
+//! let mime_type = database.mime_type_for_file_name("notes.txt");
+//! assert_eq!(mime_type.name(), "text/plain");
+//! assert!(mime_type.glob_patterns().contains(&"*.txt".to_string()));
+//!
+//! let mime_type = database.mime_type_for_data(b"%PDF-1.4");
+//! assert_eq!(mime_type.name(), "application/pdf");
+//! ```
+
+use crate::variant::convert::{rs_string_fill, RsStringFillFunc};
+use std::fmt;
+use std::os::raw::{c_char, c_void};
+use std::path::Path;
+use std::slice;
+
+/// Binding to `QMimeDatabase`
+///
+/// See module level documentation for more information.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct MimeDatabase;
+
+impl MimeDatabase {
+    /// Creates a `MimeDatabase`
+    ///
+    /// The database is loaded once, and shared by every `MimeDatabase`.
+    pub fn new() -> Self {
+        MimeDatabase
+    }
+
+    /// MIME type for a name, like `text/plain`
+    ///
+    /// Aliases are resolved. Returns `None` if no MIME type has this name.
+    pub fn mime_type_for_name<S>(&self, name: S) -> Option<MimeType>
+    where
+        S: AsRef<str>,
+    {
+        ffi_span!("MimeDatabase", "mime_type_for_name");
+        let name = name.as_ref();
+        let ptr = unsafe {
+            qt_binding_mime_type_for_name(name.as_ptr() as *const c_char, name.len() as u32)
+        };
+        if ptr.is_null() {
+            None
+        } else {
+            track_created!(MimeType);
+            Some(MimeType { ptr })
+        }
+    }
+
+    /// MIME type of a file, detected from its name only
+    ///
+    /// The file is not read, and does not need to exist. The default MIME type,
+    /// `application/octet-stream`, is returned if the name does not match any glob pattern.
+    pub fn mime_type_for_file_name<P>(&self, path: P) -> MimeType
+    where
+        P: AsRef<Path>,
+    {
+        ffi_span!("MimeDatabase", "mime_type_for_file_name");
+        MimeDatabase::mime_type_for_file_impl(path.as_ref(), true)
+    }
+
+    /// MIME type of a file, detected from its name and content
+    ///
+    /// The content is read if the name is ambiguous or does not match any glob pattern.
+    /// Directories are reported as `inode/directory`.
+    pub fn mime_type_for_file<P>(&self, path: P) -> MimeType
+    where
+        P: AsRef<Path>,
+    {
+        ffi_span!("MimeDatabase", "mime_type_for_file");
+        MimeDatabase::mime_type_for_file_impl(path.as_ref(), false)
+    }
+
+    /// MIME type of some data, detected from its content
+    pub fn mime_type_for_data(&self, data: &[u8]) -> MimeType {
+        ffi_span!("MimeDatabase", "mime_type_for_data");
+        track_created!(MimeType);
+        MimeType {
+            ptr: unsafe {
+                qt_binding_mime_type_for_data(data.as_ptr() as *const c_char, data.len() as u32)
+            },
+        }
+    }
+
+    /// MIME type of some data, detected from its content and the name of the file it comes from
+    ///
+    /// This is useful to detect the type of a file that is downloaded or read from an archive.
+    pub fn mime_type_for_file_name_and_data<S>(&self, name: S, data: &[u8]) -> MimeType
+    where
+        S: AsRef<str>,
+    {
+        ffi_span!("MimeDatabase", "mime_type_for_file_name_and_data");
+        let name = name.as_ref();
+        track_created!(MimeType);
+        MimeType {
+            ptr: unsafe {
+                qt_binding_mime_type_for_file_name_and_data(
+                    name.as_ptr() as *const c_char,
+                    name.len() as u32,
+                    data.as_ptr() as *const c_char,
+                    data.len() as u32,
+                )
+            },
+        }
+    }
+
+    /// Suffix of a file name, as defined by its MIME type
+    ///
+    /// Unlike [`Path::extension`], multi-part suffixes like `tar.gz` are recognized. Returns an
+    /// empty string if the name does not match any glob pattern.
+    ///
+    /// [`Path::extension`]: https://doc.rust-lang.org/std/path/struct.Path.html#method.extension
+    pub fn suffix_for_file_name<S>(&self, name: S) -> String
+    where
+        S: AsRef<str>,
+    {
+        ffi_span!("MimeDatabase", "suffix_for_file_name");
+        let name = name.as_ref();
+        let mut suffix = String::new();
+        unsafe {
+            let data: *mut String = &mut suffix;
+            qt_binding_mime_suffix_for_file_name(
+                name.as_ptr() as *const c_char,
+                name.len() as u32,
+                data as *mut c_void,
+                rs_string_fill,
+            );
+        }
+        suffix
+    }
+
+    fn mime_type_for_file_impl(path: &Path, name_only: bool) -> MimeType {
+        let path = path.to_string_lossy();
+        track_created!(MimeType);
+        MimeType {
+            ptr: unsafe {
+                qt_binding_mime_type_for_file(
+                    path.as_ptr() as *const c_char,
+                    path.len() as u32,
+                    name_only,
+                )
+            },
+        }
+    }
+}
+
+/// Binding to `QMimeType`
+///
+/// See module level documentation for more information.
+pub struct MimeType {
+    ptr: *mut c_void,
+}
+
+impl MimeType {
+    /// Name, like `text/plain`
+    pub fn name(&self) -> String {
+        self.string(qt_binding_mime_type_name)
+    }
+
+    /// Description, translated to the language of the user
+    pub fn comment(&self) -> String {
+        self.string(qt_binding_mime_type_comment)
+    }
+
+    /// Name of the icon, following the freedesktop.org icon naming specification
+    pub fn icon_name(&self) -> String {
+        self.string(qt_binding_mime_type_icon_name)
+    }
+
+    /// Name of the generic icon, like `text-x-generic`
+    pub fn generic_icon_name(&self) -> String {
+        self.string(qt_binding_mime_type_generic_icon_name)
+    }
+
+    /// Preferred suffix, without the leading `.`
+    pub fn preferred_suffix(&self) -> String {
+        self.string(qt_binding_mime_type_preferred_suffix)
+    }
+
+    /// Glob patterns, like `*.txt`
+    pub fn glob_patterns(&self) -> Vec<String> {
+        self.strings(qt_binding_mime_type_glob_patterns)
+    }
+
+    /// Suffixes, without the leading `.`
+    pub fn suffixes(&self) -> Vec<String> {
+        self.strings(qt_binding_mime_type_suffixes)
+    }
+
+    /// Aliases of the MIME type
+    pub fn aliases(&self) -> Vec<String> {
+        self.strings(qt_binding_mime_type_aliases)
+    }
+
+    /// Names of the direct parents of the MIME type
+    pub fn parents(&self) -> Vec<String> {
+        self.strings(qt_binding_mime_type_parents)
+    }
+
+    /// Returns `true` if the MIME type is, or inherits from, the MIME type called `name`
+    ///
+    /// For example, `text/x-csrc` inherits from `text/plain`.
+    pub fn inherits<S>(&self, name: S) -> bool
+    where
+        S: AsRef<str>,
+    {
+        ffi_span!("MimeType", "inherits");
+        let name = name.as_ref();
+        unsafe {
+            qt_binding_mime_type_inherits(
+                self.ptr,
+                name.as_ptr() as *const c_char,
+                name.len() as u32,
+            )
+        }
+    }
+
+    /// Returns `true` if this is the default MIME type, `application/octet-stream`
+    pub fn is_default(&self) -> bool {
+        ffi_span!("MimeType", "is_default");
+        unsafe { qt_binding_mime_type_is_default(self.ptr) }
+    }
+
+    fn string(
+        &self,
+        f: unsafe extern "C" fn(*const c_void, *mut c_void, RsStringFillFunc),
+    ) -> String {
+        let mut value = String::new();
+        unsafe {
+            let data: *mut String = &mut value;
+            f(self.ptr, data as *mut c_void, rs_string_fill);
+        }
+        value
+    }
+
+    fn strings(
+        &self,
+        f: unsafe extern "C" fn(*const c_void, *mut c_void, RsStringFillFunc),
+    ) -> Vec<String> {
+        let mut values = Vec::new();
+        unsafe {
+            let data: *mut Vec<String> = &mut values;
+            f(self.ptr, data as *mut c_void, rs_string_list_append);
+        }
+        values
+    }
+}
+
+impl Clone for MimeType {
+    fn clone(&self) -> Self {
+        ffi_span!("MimeType", "clone");
+        track_created!(MimeType);
+        MimeType {
+            ptr: unsafe { qt_binding_mime_type_clone(self.ptr) },
+        }
+    }
+}
+
+impl PartialEq for MimeType {
+    fn eq(&self, other: &MimeType) -> bool {
+        ffi_span!("MimeType", "eq");
+        unsafe { qt_binding_mime_type_compare(self.ptr, other.ptr) }
+    }
+}
+
+impl Eq for MimeType {}
+
+impl fmt::Debug for MimeType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("MimeType").field(&self.name()).finish()
+    }
+}
+
+impl Drop for MimeType {
+    fn drop(&mut self) {
+        ffi_span!("MimeType", "drop");
+        track_destroyed!(MimeType);
+        unsafe { qt_binding_mime_type_delete(self.ptr) }
+    }
+}
+
+extern "C" fn rs_string_list_append(output: *mut c_void, input: *const c_char, input_size: u32) {
+    let output = unsafe { &mut *(output as *mut Vec<String>) };
+    unsafe {
+        let input = slice::from_raw_parts(input as *const u8, input_size as usize);
+        output.push(String::from_utf8_unchecked(Vec::from(input)));
+    }
+}
+
+extern "C" {
+    fn qt_binding_mime_type_for_name(name: *const c_char, size: u32) -> *mut c_void;
+    fn qt_binding_mime_type_for_file(
+        path: *const c_char,
+        size: u32,
+        name_only: bool,
+    ) -> *mut c_void;
+    fn qt_binding_mime_type_for_data(data: *const c_char, size: u32) -> *mut c_void;
+    fn qt_binding_mime_type_for_file_name_and_data(
+        name: *const c_char,
+        name_size: u32,
+        data: *const c_char,
+        data_size: u32,
+    ) -> *mut c_void;
+    fn qt_binding_mime_suffix_for_file_name(
+        name: *const c_char,
+        size: u32,
+        output: *mut c_void,
+        fill: RsStringFillFunc,
+    );
+
+    fn qt_binding_mime_type_clone(mime_type: *const c_void) -> *mut c_void;
+    fn qt_binding_mime_type_delete(mime_type: *mut c_void);
+    fn qt_binding_mime_type_compare(first: *const c_void, second: *const c_void) -> bool;
+    fn qt_binding_mime_type_is_default(mime_type: *const c_void) -> bool;
+    fn qt_binding_mime_type_name(
+        mime_type: *const c_void,
+        output: *mut c_void,
+        fill: RsStringFillFunc,
+    );
+    fn qt_binding_mime_type_comment(
+        mime_type: *const c_void,
+        output: *mut c_void,
+        fill: RsStringFillFunc,
+    );
+    fn qt_binding_mime_type_icon_name(
+        mime_type: *const c_void,
+        output: *mut c_void,
+        fill: RsStringFillFunc,
+    );
+    fn qt_binding_mime_type_generic_icon_name(
+        mime_type: *const c_void,
+        output: *mut c_void,
+        fill: RsStringFillFunc,
+    );
+    fn qt_binding_mime_type_preferred_suffix(
+        mime_type: *const c_void,
+        output: *mut c_void,
+        fill: RsStringFillFunc,
+    );
+    fn qt_binding_mime_type_glob_patterns(
+        mime_type: *const c_void,
+        output: *mut c_void,
+        append: RsStringFillFunc,
+    );
+    fn qt_binding_mime_type_suffixes(
+        mime_type: *const c_void,
+        output: *mut c_void,
+        append: RsStringFillFunc,
+    );
+    fn qt_binding_mime_type_aliases(
+        mime_type: *const c_void,
+        output: *mut c_void,
+        append: RsStringFillFunc,
+    );
+    fn qt_binding_mime_type_parents(
+        mime_type: *const c_void,
+        output: *mut c_void,
+        append: RsStringFillFunc,
+    );
+    fn qt_binding_mime_type_inherits(
+        mime_type: *const c_void,
+        name: *const c_char,
+        size: u32,
+    ) -> bool;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mime_type_for_name() {
+        let database = MimeDatabase::new();
+
+        let mime_type = database.mime_type_for_name("text/x-csrc").unwrap();
+        assert_eq!(mime_type.name(), "text/x-csrc");
+        assert!(mime_type.inherits("text/plain"));
+        assert!(mime_type.suffixes().contains(&"c".to_string()));
+        assert!(!mime_type.is_default());
+
+        assert!(database
+            .mime_type_for_name("application/x-does-not-exist")
+            .is_none());
+    }
+
+    #[test]
+    fn mime_type_for_file_name() {
+        let database = MimeDatabase::new();
+
+        let mime_type = database.mime_type_for_file_name("archive.tar.gz");
+        assert_eq!(mime_type.name(), "application/x-compressed-tar");
+        assert_eq!(database.suffix_for_file_name("archive.tar.gz"), "tar.gz");
+
+        let mime_type = database.mime_type_for_file_name("unknown.does-not-exist");
+        assert!(mime_type.is_default());
+        assert_eq!(
+            mime_type,
+            database
+                .mime_type_for_name("application/octet-stream")
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn mime_type_for_data() {
+        let database = MimeDatabase::new();
+
+        let png = b"\x89PNG\r\n\x1a\n";
+        assert_eq!(database.mime_type_for_data(png).name(), "image/png");
+        assert_eq!(
+            database
+                .mime_type_for_file_name_and_data("image.dat", png)
+                .name(),
+            "image/png"
+        );
+    }
+}
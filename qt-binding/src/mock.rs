@@ -0,0 +1,564 @@
+//! Pure Rust backend for unit tests
+//!
+//! With the `mock` feature, `qt-binding` does not build nor link against Qt. [`Variant`] is
+//! backed by an in-memory value instead of a `QVariant`, and [`MockObject`] is a Qt object
+//! that does nothing besides holding properties common to all objects. Code using these types
+//! can be unit tested with a plain `cargo test`, or with Miri, without a Qt installation.
+//!
+//! Enable this feature in `dev-dependencies` or behind a feature of the crate being tested,
+//! and keep tests that need Qt in integration tests, that run with a Qt installation.
+//!
+//! [`Variant`]: ../variant/struct.Variant.html
+//! [`MockObject`]: struct.MockObject.html
+//!
+//! # Features
+//!
+//! This module is only available with the `mock` feature.
+//!
+//! # Limitations
+//!
+//! Only [`Variant`], the [`Object`] trait and [`ObjectHandle`] are implemented by the mock
+//! backend. Modules that only bind Qt classes, like [`model`] or [`qml`], are not available, and
+//! [`Utf16Str`] cannot be converted from or to a `Variant`. The [`app`] and [`animation`]
+//! modules, as well as [`Object::animate`], need a running Qt and are not available either.
+//! Handles to mock objects do not track their destruction, and must not outlive them.
+//!
+//! Conversions between values follow `QVariant` for common cases: numbers, booleans and
+//! strings convert between each other, but numbers are formatted with Rust's formatting when
+//! converted to strings.
+//!
+//! [`Object`]: ../object/trait.Object.html
+//! [`ObjectHandle`]: ../object/struct.ObjectHandle.html
+//! [`model`]: ../model/index.html
+//! [`qml`]: ../qml/index.html
+//! [`Utf16Str`]: ../string/struct.Utf16Str.html
+//! [`app`]: ../app/index.html
+//! [`animation`]: ../animation/index.html
+//! [`Object::animate`]: ../object/trait.Object.html#method.animate
+//!
+//! # Examples
+//!
+//! ```
+//! use qt_binding::mock::MockObject;
+//! use qt_binding::object::Object;
+//! use qt_binding::variant::Variant;
+//! use std::convert::TryFrom;
+//!
+//! let variant = Variant::from(123);
+//! assert_eq!(String::try_from(&variant).unwrap(), "123");
+//!
+//! let mut object = MockObject::new();
+//! object.set_object_name("settings");
+//! assert_eq!(object.object_name(), "settings");
+//! ```
+
+use crate::object::Object;
+use crate::variant::convert::{RsPointsFillFunc, RsStringFillFunc};
+use crate::variant::Variant;
+use std::collections::HashMap;
+use std::fmt;
+use std::os::raw::{c_char, c_void};
+use std::slice;
+use std::str;
+
+/// A Qt object that does nothing
+///
+/// `MockObject` implements [`Object`], and can be stored in a [`Variant`], in tests.
+///
+/// See module level documentation for more information.
+///
+/// [`Object`]: ../object/trait.Object.html
+/// [`Variant`]: ../variant/struct.Variant.html
+pub struct MockObject {
+    data: Box<ObjectData>,
+}
+
+impl MockObject {
+    /// Creates a `MockObject`
+    pub fn new() -> Self {
+        MockObject {
+            data: Box::new(ObjectData::default()),
+        }
+    }
+}
+
+impl Default for MockObject {
+    fn default() -> Self {
+        MockObject::new()
+    }
+}
+
+impl fmt::Debug for MockObject {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("MockObject")
+            .field("object_name", &self.data.name)
+            .finish()
+    }
+}
+
+impl Object for MockObject {
+    fn as_object_ptr(&self) -> *mut c_void {
+        &*self.data as *const ObjectData as *mut c_void
+    }
+}
+
+impl From<&'_ MockObject> for Variant {
+    fn from(object: &MockObject) -> Self {
+        Variant::from_object(object)
+    }
+}
+
+#[derive(Default)]
+struct ObjectData {
+    name: String,
+    properties: HashMap<String, Value>,
+}
+
+/// Value stored in place of a `QVariant`
+#[derive(Clone, Debug, PartialEq)]
+enum Value {
+    Invalid,
+    Bool(bool),
+    I32(i32),
+    U32(u32),
+    I64(i64),
+    U64(u64),
+    F32(f32),
+    F64(f64),
+    String(String),
+    List(Vec<Value>),
+    Point(f64, f64),
+    Object(*mut c_void),
+}
+
+impl Value {
+    fn type_name(&self) -> &'static [u8] {
+        match self {
+            Value::Invalid => b"Unknown\0",
+            Value::Bool(_) => b"bool\0",
+            Value::I32(_) => b"int\0",
+            Value::U32(_) => b"uint\0",
+            Value::I64(_) => b"qlonglong\0",
+            Value::U64(_) => b"qulonglong\0",
+            Value::F32(_) => b"float\0",
+            Value::F64(_) => b"double\0",
+            Value::String(_) => b"QString\0",
+            Value::List(_) => b"QVariantList\0",
+            Value::Point(_, _) => b"QPointF\0",
+            Value::Object(_) => b"QObject*\0",
+        }
+    }
+
+    fn to_f64(&self) -> Option<f64> {
+        match self {
+            Value::Bool(value) => Some(if *value { 1. } else { 0. }),
+            Value::I32(value) => Some(f64::from(*value)),
+            Value::U32(value) => Some(f64::from(*value)),
+            Value::I64(value) => Some(*value as f64),
+            Value::U64(value) => Some(*value as f64),
+            Value::F32(value) => Some(f64::from(*value)),
+            Value::F64(value) => Some(*value),
+            Value::String(value) => value.trim().parse().ok(),
+            _ => None,
+        }
+    }
+
+    fn to_i64(&self) -> Option<i64> {
+        match self {
+            Value::I32(value) => Some(i64::from(*value)),
+            Value::U32(value) => Some(i64::from(*value)),
+            Value::I64(value) => Some(*value),
+            Value::U64(value) => Some(*value as i64),
+            Value::String(value) => value.trim().parse().ok(),
+            _ => self.to_f64().map(|value| value.round() as i64),
+        }
+    }
+
+    fn to_bool(&self) -> Option<bool> {
+        match self {
+            Value::Bool(value) => Some(*value),
+            Value::String(value) => Some(!(value.is_empty() || value == "0" || value == "false")),
+            _ => self.to_f64().map(|value| value != 0.),
+        }
+    }
+
+    fn to_text(&self) -> Option<String> {
+        match self {
+            Value::Bool(value) => Some(value.to_string()),
+            Value::I32(value) => Some(value.to_string()),
+            Value::U32(value) => Some(value.to_string()),
+            Value::I64(value) => Some(value.to_string()),
+            Value::U64(value) => Some(value.to_string()),
+            Value::F32(value) => Some(value.to_string()),
+            Value::F64(value) => Some(value.to_string()),
+            Value::String(value) => Some(value.clone()),
+            _ => None,
+        }
+    }
+}
+
+fn into_ptr(value: Value) -> *mut c_void {
+    Box::into_raw(Box::new(value)) as *mut c_void
+}
+
+fn into_handle(object: *mut c_void) -> *mut c_void {
+    Box::into_raw(Box::new(object)) as *mut c_void
+}
+
+unsafe fn value<'a>(variant: *const c_void) -> &'a Value {
+    &*(variant as *const Value)
+}
+
+unsafe fn to_str<'a>(value: *const c_char, size: u32) -> &'a str {
+    str::from_utf8_unchecked(slice::from_raw_parts(value as *const u8, size as usize))
+}
+
+unsafe fn fill<T>(
+    variant: *const c_void,
+    output: *mut T,
+    convert: fn(&Value) -> Option<T>,
+) -> bool {
+    match convert(value(variant)) {
+        Some(converted) => {
+            *output = converted;
+            true
+        }
+        None => false,
+    }
+}
+
+// The functions below replace the functions implemented in C++, and are resolved by the linker
+// when the `mock` feature is enabled.
+
+#[no_mangle]
+extern "C" fn qt_binding_variant_create_invalid() -> *mut c_void {
+    into_ptr(Value::Invalid)
+}
+
+#[no_mangle]
+unsafe extern "C" fn qt_binding_variant_clone(variant: *const c_void) -> *mut c_void {
+    into_ptr(value(variant).clone())
+}
+
+#[no_mangle]
+unsafe extern "C" fn qt_binding_variant_compare(
+    first: *const c_void,
+    second: *const c_void,
+) -> bool {
+    value(first) == value(second)
+}
+
+#[no_mangle]
+unsafe extern "C" fn qt_binding_variant_delete(variant: *mut c_void) {
+    drop(Box::from_raw(variant as *mut Value));
+}
+
+#[no_mangle]
+unsafe extern "C" fn qt_binding_variant_get_type_name(variant: *const c_void) -> *const c_char {
+    value(variant).type_name().as_ptr() as *const c_char
+}
+
+#[no_mangle]
+extern "C" fn qt_binding_variant_create_bool(value: bool) -> *mut c_void {
+    into_ptr(Value::Bool(value))
+}
+
+#[no_mangle]
+extern "C" fn qt_binding_variant_create_i32(value: i32) -> *mut c_void {
+    into_ptr(Value::I32(value))
+}
+
+#[no_mangle]
+extern "C" fn qt_binding_variant_create_u32(value: u32) -> *mut c_void {
+    into_ptr(Value::U32(value))
+}
+
+#[no_mangle]
+extern "C" fn qt_binding_variant_create_i64(value: i64) -> *mut c_void {
+    into_ptr(Value::I64(value))
+}
+
+#[no_mangle]
+extern "C" fn qt_binding_variant_create_u64(value: u64) -> *mut c_void {
+    into_ptr(Value::U64(value))
+}
+
+#[no_mangle]
+extern "C" fn qt_binding_variant_create_f32(value: f32) -> *mut c_void {
+    into_ptr(Value::F32(value))
+}
+
+#[no_mangle]
+extern "C" fn qt_binding_variant_create_f64(value: f64) -> *mut c_void {
+    into_ptr(Value::F64(value))
+}
+
+#[no_mangle]
+unsafe extern "C" fn qt_binding_variant_create_string(
+    value: *const c_char,
+    size: u32,
+) -> *mut c_void {
+    into_ptr(Value::String(to_str(value, size).to_string()))
+}
+
+extern "C" fn list_append(output: *mut c_void, variant: *const c_void) {
+    let output = unsafe { &mut *(output as *mut Vec<Value>) };
+    output.push(unsafe { value(variant) }.clone());
+}
+
+#[no_mangle]
+unsafe extern "C" fn qt_binding_variant_create_list(
+    input: *mut c_void,
+    fill: extern "C" fn(*mut c_void, *mut c_void, extern "C" fn(*mut c_void, *const c_void)),
+) -> *mut c_void {
+    let mut list = Vec::new();
+    let output: *mut Vec<Value> = &mut list;
+    fill(input, output as *mut c_void, list_append);
+    into_ptr(Value::List(list))
+}
+
+#[no_mangle]
+unsafe extern "C" fn qt_binding_variant_create_points(
+    points: *const f64,
+    count: u32,
+) -> *mut c_void {
+    let points = slice::from_raw_parts(points, 2 * count as usize);
+    let list = points
+        .chunks(2)
+        .map(|point| Value::Point(point[0], point[1]))
+        .collect();
+    into_ptr(Value::List(list))
+}
+
+#[no_mangle]
+unsafe extern "C" fn qt_binding_variant_fill_bool(
+    variant: *const c_void,
+    output: *mut bool,
+) -> bool {
+    fill(variant, output, Value::to_bool)
+}
+
+#[no_mangle]
+unsafe extern "C" fn qt_binding_variant_fill_i32(variant: *const c_void, output: *mut i32) -> bool {
+    fill(variant, output, |value| {
+        value.to_i64().map(|value| value as i32)
+    })
+}
+
+#[no_mangle]
+unsafe extern "C" fn qt_binding_variant_fill_u32(variant: *const c_void, output: *mut u32) -> bool {
+    fill(variant, output, |value| {
+        value.to_i64().map(|value| value as u32)
+    })
+}
+
+#[no_mangle]
+unsafe extern "C" fn qt_binding_variant_fill_i64(variant: *const c_void, output: *mut i64) -> bool {
+    fill(variant, output, Value::to_i64)
+}
+
+#[no_mangle]
+unsafe extern "C" fn qt_binding_variant_fill_u64(variant: *const c_void, output: *mut u64) -> bool {
+    fill(variant, output, |value| match value {
+        Value::U64(value) => Some(*value),
+        value => value.to_i64().map(|value| value as u64),
+    })
+}
+
+#[no_mangle]
+unsafe extern "C" fn qt_binding_variant_fill_f32(variant: *const c_void, output: *mut f32) -> bool {
+    fill(variant, output, |value| match value {
+        Value::F32(value) => Some(*value),
+        value => value.to_f64().map(|value| value as f32),
+    })
+}
+
+#[no_mangle]
+unsafe extern "C" fn qt_binding_variant_fill_f64(variant: *const c_void, output: *mut f64) -> bool {
+    fill(variant, output, Value::to_f64)
+}
+
+#[no_mangle]
+unsafe extern "C" fn qt_binding_variant_fill_string(
+    variant: *const c_void,
+    output: *mut c_void,
+    fill: RsStringFillFunc,
+) -> bool {
+    match value(variant).to_text() {
+        Some(string) => {
+            fill(
+                output,
+                string.as_ptr() as *const c_char,
+                string.len() as u32,
+            );
+            true
+        }
+        None => false,
+    }
+}
+
+#[no_mangle]
+unsafe extern "C" fn qt_binding_variant_fill_list(
+    variant: *const c_void,
+    output: *mut c_void,
+    fill: extern "C" fn(*mut c_void, *mut c_void),
+) -> bool {
+    match value(variant) {
+        Value::List(values) => {
+            for value in values {
+                fill(output, into_ptr(value.clone()));
+            }
+            true
+        }
+        _ => false,
+    }
+}
+
+#[no_mangle]
+unsafe extern "C" fn qt_binding_variant_fill_points(
+    variant: *const c_void,
+    output: *mut c_void,
+    fill: RsPointsFillFunc,
+) -> bool {
+    let values = match value(variant) {
+        Value::List(values) => values,
+        _ => return false,
+    };
+    let mut points = Vec::with_capacity(2 * values.len());
+    for value in values {
+        match value {
+            Value::Point(x, y) => {
+                points.push(*x);
+                points.push(*y);
+            }
+            _ => return false,
+        }
+    }
+    fill(output, points.as_ptr(), values.len() as u32);
+    true
+}
+
+#[no_mangle]
+unsafe extern "C" fn qt_binding_object_name(
+    object: *const c_void,
+    output: *mut c_void,
+    fill: RsStringFillFunc,
+) {
+    let name = &(*(object as *const ObjectData)).name;
+    fill(output, name.as_ptr() as *const c_char, name.len() as u32);
+}
+
+#[no_mangle]
+unsafe extern "C" fn qt_binding_object_set_name(
+    object: *mut c_void,
+    name: *const c_char,
+    size: u32,
+) {
+    (*(object as *mut ObjectData)).name = to_str(name, size).to_string();
+}
+
+#[no_mangle]
+unsafe extern "C" fn qt_binding_object_class_name(
+    _object: *const c_void,
+    output: *mut c_void,
+    fill: RsStringFillFunc,
+) {
+    let name = "MockObject";
+    fill(output, name.as_ptr() as *const c_char, name.len() as u32);
+}
+
+#[no_mangle]
+unsafe extern "C" fn qt_binding_object_property(
+    object: *const c_void,
+    name: *const c_char,
+    size: u32,
+) -> *mut c_void {
+    let properties = &(*(object as *const ObjectData)).properties;
+    let value = properties.get(to_str(name, size)).cloned();
+    into_ptr(value.unwrap_or(Value::Invalid))
+}
+
+#[no_mangle]
+unsafe extern "C" fn qt_binding_object_set_property(
+    object: *mut c_void,
+    name: *const c_char,
+    size: u32,
+    variant: *const c_void,
+) -> bool {
+    let properties = &mut (*(object as *mut ObjectData)).properties;
+    properties.insert(to_str(name, size).to_string(), value(variant).clone());
+    true
+}
+
+#[no_mangle]
+unsafe extern "C" fn qt_binding_object_handle_clone(handle: *const c_void) -> *mut c_void {
+    into_handle(*(handle as *const *mut c_void))
+}
+
+#[no_mangle]
+unsafe extern "C" fn qt_binding_object_handle_delete(handle: *mut c_void) {
+    drop(Box::from_raw(handle as *mut *mut c_void));
+}
+
+#[no_mangle]
+unsafe extern "C" fn qt_binding_object_handle_get(handle: *const c_void) -> *mut c_void {
+    *(handle as *const *mut c_void)
+}
+
+#[no_mangle]
+extern "C" fn qt_binding_variant_create_object(object: *mut c_void) -> *mut c_void {
+    into_ptr(Value::Object(object))
+}
+
+#[no_mangle]
+unsafe extern "C" fn qt_binding_variant_to_object(variant: *const c_void) -> *mut c_void {
+    match value(variant) {
+        Value::Object(object) if !object.is_null() => into_handle(*object),
+        _ => std::ptr::null_mut(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::convert::TryFrom;
+
+    #[test]
+    fn test_conversions() {
+        assert_eq!(i32::try_from(Variant::from("42")), Ok(42));
+        assert_eq!(
+            String::try_from(Variant::from(true)),
+            Ok("true".to_string())
+        );
+        assert_eq!(bool::try_from(Variant::from(0)), Ok(false));
+        assert!(i32::try_from(Variant::default()).is_err());
+        assert!(Vec::<Variant>::try_from(Variant::from(1)).is_err());
+    }
+
+    #[test]
+    fn test_object() {
+        let mut object = MockObject::new();
+        object.set_object_name("object");
+        assert_eq!(object.object_name(), "object");
+        assert_eq!(Variant::from(&object), Variant::from(&object));
+        assert_ne!(Variant::from(&object), Variant::from(&MockObject::new()));
+    }
+
+    #[test]
+    fn test_object_handle() {
+        let mut object = MockObject::new();
+        object.set_object_name("object");
+
+        let mut handle = Variant::from(&object).try_to_object().unwrap();
+        assert!(!handle.is_null());
+        assert_eq!(handle.object_name(), "object");
+        assert_eq!(handle.class_name(), "MockObject");
+        assert_eq!(Variant::from(&handle), Variant::from(&object));
+
+        assert_eq!(handle.property("count"), Variant::default());
+        assert!(handle.set_property("count", &Variant::from(3)));
+        assert_eq!(handle.clone().property("count"), Variant::from(3));
+
+        assert!(Variant::from(3).try_to_object().is_err());
+    }
+}
@@ -0,0 +1,61 @@
+//! Item models
+//!
+//! This module contains item models that can be used as models by QML views, like `ListView`
+//! or `ComboBox`.
+//!
+//! [`StringListModel`] is a binding on Qt's `QStringListModel`, a simple list model built from
+//! a list of strings. Items can be replaced all at once, which resets the model, or inserted,
+//! removed and changed one by one, which lets views animate the changes. When a whole new list
+//! is computed, [`set_items_diffed`] compares it with the current items and only notifies views
+//! about the rows that differ. Items can also be identified by a key with
+//! [`set_items_diffed_by`], so that items whose text changed are updated in place.
+//!
+//! [`FuzzyFilterModel`] filters and ranks a list of strings with a fuzzy search query, for
+//! command palettes and search fields. Matching is done in Rust, and only the matching items
+//! are exposed to views.
+//!
+//! [`PointListModel`] holds a list of `(x, y)` points, for charts and plotting items. Points
+//! are replaced or appended in bulk, with a single call to Qt.
+//!
+//! [`FetchModel`] is populated on demand from a Rust iterator, when views are scrolled to the
+//! end, for large data sources like log files or database cursors.
+//!
+//! A model is exposed to QML by converting a reference to it into a [`Variant`]. Delegates
+//! access items with the `display` role.
+//!
+//! [`StringListModel`]: struct.StringListModel.html
+//! [`FuzzyFilterModel`]: struct.FuzzyFilterModel.html
+//! [`PointListModel`]: struct.PointListModel.html
+//! [`FetchModel`]: struct.FetchModel.html
+//! [`set_items_diffed`]: struct.StringListModel.html#method.set_items_diffed
+//! [`set_items_diffed_by`]: struct.StringListModel.html#method.set_items_diffed_by
+//! [`Variant`]: ../variant/struct.Variant.html
+//!
+//! # Limitations
+//!
+//! Since models are bindings over Qt objects, they are neither `Send` nor `Sync`.
+//!
+//! A model must outlive the views that use it.
+//!
+//! # Examples
+//!
+//! ```
+//! use qt_binding::model::StringListModel;
+//!
+//! let mut model = StringListModel::from(vec!["first".to_string(), "third".to_string()]);
+//! model.insert(1, "second");
+//! model.push("fourth");
+//!
+//! assert_eq!(model.to_vec(), vec!["first", "second", "third", "fourth"]);
+//! ```
+
+mod diff;
+mod fetch;
+mod fuzzy_filter;
+mod point_list;
+mod string_list;
+
+pub use self::fetch::FetchModel;
+pub use self::fuzzy_filter::FuzzyFilterModel;
+pub use self::point_list::PointListModel;
+pub use self::string_list::StringListModel;
@@ -0,0 +1,258 @@
+use std::collections::{HashMap, VecDeque};
+use std::hash::Hash;
+
+/// An edit applied on a list
+///
+/// Rows are expressed relative to the list as it is after all the previous edits have been
+/// applied.
+#[derive(Debug, Eq, PartialEq)]
+pub(crate) enum Edit<'a, T> {
+    Insert(usize, &'a T),
+    Remove(usize),
+    Set(usize, &'a T),
+}
+
+/// Compute the edits that transform `old` into `new`
+///
+/// Items are their own keys, see [`diff_by_keys`].
+///
+/// [`diff_by_keys`]: fn.diff_by_keys.html
+pub(crate) fn diff<'a, T>(old: &[T], new: &'a [T]) -> Vec<Edit<'a, T>>
+where
+    T: Eq + Hash,
+{
+    diff_by_keys(old, new, old, new)
+}
+
+/// Compute the edits that transform `old` into `new`, identifying items by key
+///
+/// `old_keys` and `new_keys` are the keys of the items in `old` and `new`. Items with the same
+/// key are matched, in order when keys are repeated, and the longest sequence of matched items
+/// that are still in the same order is kept. Kept items are never touched, unless their value
+/// changed, which results in a `Set`. Other items are removed or inserted. A removal
+/// immediately followed by an insertion at the same row is merged into a single `Set`.
+///
+/// This runs in `O((N + M) log(N + M))`, so that large lists can be diffed on the GUI thread.
+pub(crate) fn diff_by_keys<'a, T, K>(
+    old: &[T],
+    new: &'a [T],
+    old_keys: &[K],
+    new_keys: &[K],
+) -> Vec<Edit<'a, T>>
+where
+    T: Eq,
+    K: Eq + Hash,
+{
+    let mut edits = Vec::new();
+
+    let prefix = old_keys
+        .iter()
+        .zip(new_keys.iter())
+        .take_while(|(old, new)| old == new)
+        .count();
+    let suffix = old_keys[prefix..]
+        .iter()
+        .rev()
+        .zip(new_keys[prefix..].iter().rev())
+        .take_while(|(old, new)| old == new)
+        .count();
+
+    for row in 0..prefix {
+        if old[row] != new[row] {
+            edits.push(Edit::Set(row, &new[row]));
+        }
+    }
+
+    let old_end = old.len() - suffix;
+    let new_end = new.len() - suffix;
+    let (kept_old, kept_new) = kept(&old_keys[prefix..old_end], &new_keys[prefix..new_end]);
+
+    let mut row = prefix;
+    let (mut i, mut j) = (0, 0);
+    while i < kept_old.len() || j < kept_new.len() {
+        if i < kept_old.len() && !kept_old[i] {
+            edits.push(Edit::Remove(row));
+            i += 1;
+        } else if j < kept_new.len() && !kept_new[j] {
+            let item = &new[prefix + j];
+            match edits.last() {
+                Some(Edit::Remove(removed)) if *removed == row => {
+                    edits.pop();
+                    edits.push(Edit::Set(row, item));
+                }
+                _ => edits.push(Edit::Insert(row, item)),
+            }
+            row += 1;
+            j += 1;
+        } else {
+            if old[prefix + i] != new[prefix + j] {
+                edits.push(Edit::Set(row, &new[prefix + j]));
+            }
+            row += 1;
+            i += 1;
+            j += 1;
+        }
+    }
+
+    for offset in 0..suffix {
+        if old[old_end + offset] != new[new_end + offset] {
+            edits.push(Edit::Set(row + offset, &new[new_end + offset]));
+        }
+    }
+    edits
+}
+
+/// Which old and new items are kept
+///
+/// Items are matched by key, and the longest increasing subsequence of the old rows of the
+/// matched new items is kept.
+fn kept<K>(old_keys: &[K], new_keys: &[K]) -> (Vec<bool>, Vec<bool>)
+where
+    K: Eq + Hash,
+{
+    let mut rows = HashMap::<&K, VecDeque<usize>>::new();
+    for (i, key) in old_keys.iter().enumerate() {
+        rows.entry(key).or_default().push_back(i);
+    }
+    let sources = new_keys
+        .iter()
+        .map(|key| rows.get_mut(key).and_then(VecDeque::pop_front))
+        .collect::<Vec<_>>();
+
+    // Patience sorting: tails[k] is the new row ending the smallest increasing subsequence of
+    // length k + 1, and previous links each new row to the one before it in its subsequence
+    let mut tails: Vec<usize> = Vec::new();
+    let mut previous = vec![None; sources.len()];
+    for (j, source) in sources.iter().enumerate() {
+        if let Some(source) = *source {
+            let k = tails.partition_point(|&tail| sources[tail] < Some(source));
+            if k > 0 {
+                previous[j] = Some(tails[k - 1]);
+            }
+            if k == tails.len() {
+                tails.push(j);
+            } else {
+                tails[k] = j;
+            }
+        }
+    }
+
+    let mut kept_old = vec![false; old_keys.len()];
+    let mut kept_new = vec![false; new_keys.len()];
+    let mut current = tails.last().cloned();
+    while let Some(j) = current {
+        kept_new[j] = true;
+        if let Some(i) = sources[j] {
+            kept_old[i] = true;
+        }
+        current = previous[j];
+    }
+    (kept_old, kept_new)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn apply<T>(mut list: Vec<T>, edits: Vec<Edit<T>>) -> Vec<T>
+    where
+        T: Clone,
+    {
+        for edit in edits {
+            match edit {
+                Edit::Insert(row, item) => list.insert(row, item.clone()),
+                Edit::Remove(row) => {
+                    list.remove(row);
+                }
+                Edit::Set(row, item) => list[row] = item.clone(),
+            }
+        }
+        list
+    }
+
+    #[test]
+    fn unchanged() {
+        let list = vec![1, 2, 3];
+        assert_eq!(diff(&list, &list), vec![]);
+    }
+
+    #[test]
+    fn insert_and_remove() {
+        let old = vec![1, 2, 3, 4];
+        let new = vec![0, 1, 3, 4, 5];
+
+        let edits = diff(&old, &new);
+        assert_eq!(
+            edits,
+            vec![Edit::Insert(0, &0), Edit::Remove(2), Edit::Insert(4, &5)]
+        );
+        assert_eq!(apply(old, edits), new);
+    }
+
+    #[test]
+    fn set() {
+        let old = vec![1, 2, 3];
+        let new = vec![1, 4, 3];
+
+        let edits = diff(&old, &new);
+        assert_eq!(edits, vec![Edit::Set(1, &4)]);
+        assert_eq!(apply(old, edits), new);
+    }
+
+    #[test]
+    fn reorder() {
+        let old = vec![1, 2, 3, 4, 5, 6];
+        let new = vec![6, 2, 1, 5, 3, 7];
+
+        let edits = diff(&old, &new);
+        assert_eq!(apply(old, edits), new);
+    }
+
+    #[test]
+    fn from_and_to_empty() {
+        let empty = Vec::<i32>::new();
+        let list = vec![1, 2];
+
+        assert_eq!(apply(empty.clone(), diff(&empty, &list)), list);
+        assert_eq!(apply(list.clone(), diff(&list, &empty)), empty);
+    }
+
+    #[test]
+    fn repeated_items() {
+        let old = vec![1, 2, 1, 2];
+        let new = vec![2, 1, 2, 1, 1];
+
+        let edits = diff(&old, &new);
+        assert_eq!(apply(old, edits), new);
+    }
+
+    #[test]
+    fn keyed() {
+        let old = vec![(1, "a"), (2, "b"), (3, "c")];
+        let new = vec![(3, "c"), (1, "a"), (2, "B")];
+        let old_keys = old.iter().map(|(key, _)| *key).collect::<Vec<_>>();
+        let new_keys = new.iter().map(|(key, _)| *key).collect::<Vec<_>>();
+
+        let edits = diff_by_keys(&old, &new, &old_keys, &new_keys);
+        assert_eq!(
+            edits,
+            vec![
+                Edit::Insert(0, &(3, "c")),
+                Edit::Set(2, &(2, "B")),
+                Edit::Remove(3)
+            ]
+        );
+        assert_eq!(apply(old, edits), new);
+    }
+
+    #[test]
+    fn large() {
+        let old = (0..10_000).collect::<Vec<_>>();
+        let mut new = old.clone();
+        new[2_000..8_000].reverse();
+
+        let edits = diff(&old, &new);
+        assert_eq!(edits.len(), 2 * 5_999);
+        assert_eq!(apply(old, edits), new);
+    }
+}
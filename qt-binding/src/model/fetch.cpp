@@ -0,0 +1,132 @@
+#include <QtCore/QAbstractListModel>
+#include <QtCore/QVariant>
+#include <QtCore/QVector>
+
+QT_USE_NAMESPACE
+
+using RsFetchFunc = bool (*)(void *data);
+
+namespace qt_binding {
+
+class FetchModel : public QAbstractListModel
+{
+public:
+    explicit FetchModel(void *data, RsFetchFunc fetch)
+        : m_data(data)
+        , m_fetch(fetch)
+    {
+    }
+
+    int rowCount(const QModelIndex &parent = QModelIndex()) const override
+    {
+        return parent.isValid() ? 0 : m_items.count();
+    }
+
+    QVariant data(const QModelIndex &index, int role) const override
+    {
+        if (!index.isValid() || index.row() >= m_items.count() || role != Qt::DisplayRole) {
+            return QVariant();
+        }
+        return m_items.at(index.row());
+    }
+
+    bool canFetchMore(const QModelIndex &parent = QModelIndex()) const override
+    {
+        return !parent.isValid() && m_hasMore;
+    }
+
+    void fetchMore(const QModelIndex &parent = QModelIndex()) override
+    {
+        if (!canFetchMore(parent)) {
+            return;
+        }
+        // Rust appends the fetched items before returning if there are more
+        m_hasMore = m_fetch(m_data);
+    }
+
+    const QVariant &at(int row) const
+    {
+        return m_items.at(row);
+    }
+
+    void append(const QVariant *const *items, int count)
+    {
+        if (count <= 0) {
+            return;
+        }
+
+        const auto first = m_items.count();
+        beginInsertRows(QModelIndex(), first, first + count - 1);
+        m_items.reserve(first + count);
+        for (int i = 0; i < count; ++i) {
+            m_items.append(*items[i]);
+        }
+        endInsertRows();
+    }
+
+    void reset()
+    {
+        beginResetModel();
+        m_items.clear();
+        m_hasMore = true;
+        endResetModel();
+    }
+
+private:
+    void *m_data;
+    RsFetchFunc m_fetch;
+    QVector<QVariant> m_items;
+    bool m_hasMore = true;
+};
+
+} // namespace qt_binding
+
+extern "C" {
+
+qt_binding::FetchModel *qt_binding_fetch_model_create(void *data, RsFetchFunc fetch)
+{
+    return new qt_binding::FetchModel(data, fetch);
+}
+
+void qt_binding_fetch_model_delete(qt_binding::FetchModel *model)
+{
+    delete model;
+}
+
+QVariant *qt_binding_fetch_model_to_variant(qt_binding::FetchModel *model)
+{
+    return new QVariant(QVariant::fromValue<QObject *>(model));
+}
+
+int qt_binding_fetch_model_row_count(const qt_binding::FetchModel *model)
+{
+    return model->rowCount();
+}
+
+QVariant *qt_binding_fetch_model_get(const qt_binding::FetchModel *model, int row)
+{
+    return new QVariant(model->at(row));
+}
+
+bool qt_binding_fetch_model_can_fetch_more(const qt_binding::FetchModel *model)
+{
+    return model->canFetchMore();
+}
+
+void qt_binding_fetch_model_fetch_more(qt_binding::FetchModel *model)
+{
+    model->fetchMore();
+}
+
+void qt_binding_fetch_model_append(qt_binding::FetchModel *model, const QVariant *const *items,
+                                   int count)
+{
+    model->append(items, count);
+}
+
+void qt_binding_fetch_model_reset(qt_binding::FetchModel *model)
+{
+    model->reset();
+}
+
+} // extern "C"
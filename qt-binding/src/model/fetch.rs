@@ -0,0 +1,278 @@
+use crate::app;
+use crate::object::Object;
+use crate::variant::Variant;
+use std::fmt;
+use std::iter::Peekable;
+use std::os::raw::{c_int, c_void};
+
+/// Number of items fetched at once by default
+const DEFAULT_BATCH_SIZE: usize = 100;
+
+type Source = Peekable<Box<dyn Iterator<Item = Variant>>>;
+
+struct Inner {
+    ptr: *mut c_void,
+    source: Source,
+    batch_size: usize,
+}
+
+impl Inner {
+    /// Pull a batch of items from the source, and append them to the Qt model
+    ///
+    /// Returns `true` if the source has more items.
+    fn fetch(&mut self) -> bool {
+        let items = self
+            .source
+            .by_ref()
+            .take(self.batch_size)
+            .collect::<Vec<_>>();
+        let ptrs = items.iter().map(Variant::as_ptr).collect::<Vec<_>>();
+        let ptr = self.ptr;
+        unsafe { qt_binding_fetch_model_append(ptr, ptrs.as_ptr(), ptrs.len() as c_int) };
+        self.source.peek().is_some()
+    }
+}
+
+/// A list model populated on demand from an iterator
+///
+/// `FetchModel` pulls items from a Rust iterator when views need them, through Qt's
+/// `canFetchMore` and `fetchMore`. QML views, like `ListView`, fetch more items when they are
+/// scrolled to the end, so large data sources, like log files or database cursors, are never
+/// materialized at once.
+///
+/// Items are fetched by batches, of [`set_batch_size`] items. They are converted to
+/// [`Variant`], and exposed with the `display` role.
+///
+/// [`set_batch_size`]: #method.set_batch_size
+/// [`Variant`]: ../variant/struct.Variant.html
+///
+/// # Limitations
+///
+/// The iterator is pulled in the main thread, and should not block. Items from asynchronous
+/// sources, like streams, are better appended to a [`StringListModel`] as they arrive.
+///
+/// [`StringListModel`]: struct.StringListModel.html
+///
+/// # Examples
+///
+/// ```
+/// use qt_binding::model::FetchModel;
+///
+/// let mut model = FetchModel::new((1..=250).map(|line| format!("Line {}", line)));
+/// model.set_batch_size(100);
+/// assert!(model.is_empty());
+///
+/// model.fetch_more();
+/// assert_eq!(model.len(), 100);
+///
+/// while model.can_fetch_more() {
+///     model.fetch_more();
+/// }
+/// assert_eq!(model.len(), 250);
+/// ```
+pub struct FetchModel {
+    inner: Box<Inner>,
+}
+
+impl FetchModel {
+    /// Creates a `FetchModel` pulling items from an iterator
+    ///
+    /// No item is fetched until a view, or [`fetch_more`], requests it.
+    ///
+    /// [`fetch_more`]: #method.fetch_more
+    pub fn new<I, T>(source: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        I::IntoIter: 'static,
+        T: Into<Variant> + 'static,
+    {
+        ffi_span!("FetchModel", "new");
+        let mut inner = Box::new(Inner {
+            ptr: std::ptr::null_mut(),
+            source: into_source(source),
+            batch_size: DEFAULT_BATCH_SIZE,
+        });
+        let data: *mut Inner = &mut *inner;
+        inner.ptr =
+            unsafe { qt_binding_fetch_model_create(data as *mut c_void, rs_fetch_model_fetch) };
+        track_created!(FetchModel);
+        FetchModel { inner }
+    }
+
+    /// Replace the iterator
+    ///
+    /// Fetched items are discarded, and views are notified with a model reset.
+    pub fn set_source<I, T>(&mut self, source: I)
+    where
+        I: IntoIterator<Item = T>,
+        I::IntoIter: 'static,
+        T: Into<Variant> + 'static,
+    {
+        ffi_span!("FetchModel", "set_source");
+        self.inner.source = into_source(source);
+        unsafe { qt_binding_fetch_model_reset(self.inner.ptr) }
+    }
+
+    /// Set the number of items fetched at once
+    ///
+    /// Defaults to 100 items.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `batch_size` is 0.
+    pub fn set_batch_size(&mut self, batch_size: usize) -> &mut Self {
+        assert!(batch_size > 0, "Batch size cannot be 0");
+        self.inner.batch_size = batch_size;
+        self
+    }
+
+    /// Number of fetched items
+    pub fn len(&self) -> usize {
+        ffi_span!("FetchModel", "len");
+        unsafe { qt_binding_fetch_model_row_count(self.inner.ptr) as usize }
+    }
+
+    /// Returns `true` if no items were fetched
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Fetched item at a row
+    ///
+    /// Returns `None` if the row is out of bounds.
+    pub fn get(&self, row: usize) -> Option<Variant> {
+        ffi_span!("FetchModel", "get");
+        if row >= self.len() {
+            return None;
+        }
+        Some(Variant::from_ptr(unsafe {
+            qt_binding_fetch_model_get(self.inner.ptr, row as c_int)
+        }))
+    }
+
+    /// Returns `true` if the iterator might have more items
+    pub fn can_fetch_more(&self) -> bool {
+        ffi_span!("FetchModel", "can_fetch_more");
+        unsafe { qt_binding_fetch_model_can_fetch_more(self.inner.ptr) }
+    }
+
+    /// Fetch a batch of items
+    ///
+    /// Views are notified that rows were inserted. Does nothing if the iterator is exhausted.
+    pub fn fetch_more(&mut self) {
+        ffi_span!("FetchModel", "fetch_more");
+        let ptr = self.inner.ptr;
+        unsafe { qt_binding_fetch_model_fetch_more(ptr) }
+    }
+}
+
+impl From<&'_ FetchModel> for Variant {
+    fn from(model: &FetchModel) -> Self {
+        ffi_span!("Variant", "from_fetch_model");
+        Variant::from_ptr(unsafe { qt_binding_fetch_model_to_variant(model.inner.ptr) })
+    }
+}
+
+impl fmt::Debug for FetchModel {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("FetchModel")
+            .field("len", &self.len())
+            .field("can_fetch_more", &self.can_fetch_more())
+            .finish()
+    }
+}
+
+impl Object for FetchModel {
+    fn as_object_ptr(&self) -> *mut c_void {
+        self.inner.ptr
+    }
+}
+
+impl Drop for FetchModel {
+    fn drop(&mut self) {
+        ffi_span!("FetchModel", "drop");
+        if app::should_leak("FetchModel") {
+            return;
+        }
+        track_destroyed!(FetchModel);
+        unsafe { qt_binding_fetch_model_delete(self.inner.ptr) }
+    }
+}
+
+fn into_source<I, T>(source: I) -> Source
+where
+    I: IntoIterator<Item = T>,
+    I::IntoIter: 'static,
+    T: Into<Variant> + 'static,
+{
+    let source: Box<dyn Iterator<Item = Variant>> = Box::new(source.into_iter().map(Into::into));
+    source.peekable()
+}
+
+extern "C" fn rs_fetch_model_fetch(data: *mut c_void) -> bool {
+    let inner = unsafe { &mut *(data as *mut Inner) };
+    inner.fetch()
+}
+
+type RsFetchFunc = extern "C" fn(data: *mut c_void) -> bool;
+
+extern "C" {
+    fn qt_binding_fetch_model_create(data: *mut c_void, fetch: RsFetchFunc) -> *mut c_void;
+    fn qt_binding_fetch_model_delete(model: *mut c_void);
+    fn qt_binding_fetch_model_to_variant(model: *mut c_void) -> *mut c_void;
+
+    fn qt_binding_fetch_model_row_count(model: *const c_void) -> c_int;
+    fn qt_binding_fetch_model_get(model: *const c_void, row: c_int) -> *mut c_void;
+    fn qt_binding_fetch_model_can_fetch_more(model: *const c_void) -> bool;
+    fn qt_binding_fetch_model_fetch_more(model: *mut c_void);
+    fn qt_binding_fetch_model_append(model: *mut c_void, items: *const *const c_void, count: c_int);
+    fn qt_binding_fetch_model_reset(model: *mut c_void);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::convert::TryFrom;
+
+    #[test]
+    fn fetch() {
+        let mut model = FetchModel::new(0..5);
+        model.set_batch_size(2);
+        assert!(model.is_empty());
+        assert!(model.can_fetch_more());
+
+        model.fetch_more();
+        assert_eq!(model.len(), 2);
+        assert_eq!(model.get(1), Some(Variant::from(1)));
+        assert_eq!(model.get(2), None);
+
+        model.fetch_more();
+        model.fetch_more();
+        assert_eq!(model.len(), 5);
+        assert!(!model.can_fetch_more());
+
+        model.fetch_more();
+        assert_eq!(model.len(), 5);
+    }
+
+    #[test]
+    fn set_source() {
+        let mut model = FetchModel::new(vec!["first", "second"]);
+        model.fetch_more();
+        assert_eq!(model.len(), 2);
+
+        model.set_source(vec!["third"]);
+        assert!(model.is_empty());
+        assert!(model.can_fetch_more());
+
+        model.fetch_more();
+        let item = model.get(0).unwrap();
+        assert_eq!(String::try_from(item), Ok("third".to_string()));
+    }
+
+    #[test]
+    #[should_panic(expected = "Batch size cannot be 0")]
+    fn zero_batch_size() {
+        FetchModel::new(0..1).set_batch_size(0);
+    }
+}
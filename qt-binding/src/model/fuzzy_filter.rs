@@ -0,0 +1,393 @@
+use super::diff;
+use super::StringListModel;
+use crate::variant::Variant;
+use std::num::NonZeroUsize;
+use std::thread;
+
+/// Number of items above which items are scored in parallel
+const PARALLEL_THRESHOLD: usize = 4096;
+
+/// Item model filtered and ranked by a fuzzy search query
+///
+/// `FuzzyFilterModel` holds a list of items and a query. Items are matched against the query
+/// in Rust, and only the matching items are exposed to views, best matches first. The query
+/// and the items can be updated at any time, and views are only notified of the rows that
+/// changed.
+///
+/// An item matches if all the characters of the query appear in it in order, ignoring case.
+/// Consecutive characters and characters at the start of words rank higher. When the query is
+/// empty, all items are exposed in their original order.
+///
+/// Items are scored on the calling thread, or in parallel on scoped threads when there are
+/// more than a few thousand items. Scores are kept between updates: appending or removing an
+/// item only updates its row, and refining the query only scores the items that currently
+/// match again. Views are notified of the rows that changed, with the same diffing as
+/// [`StringListModel::set_items_diffed`].
+///
+/// [`StringListModel::set_items_diffed`]: struct.StringListModel.html#method.set_items_diffed
+///
+/// Delegates access items with the `display` role.
+///
+/// # Examples
+///
+/// ```
+/// use qt_binding::model::FuzzyFilterModel;
+///
+/// let mut model = FuzzyFilterModel::from(vec!["Open File", "Save File", "Close Window"]);
+/// model.set_query("ofi");
+///
+/// assert_eq!(model.filtered(), vec!["Open File"]);
+/// ```
+pub struct FuzzyFilterModel {
+    model: StringListModel,
+    items: Vec<String>,
+    scores: Vec<Option<i64>>,
+    ranked: Vec<(i64, usize)>,
+    query: String,
+}
+
+impl FuzzyFilterModel {
+    /// Creates an empty `FuzzyFilterModel`
+    pub fn new() -> Self {
+        FuzzyFilterModel {
+            model: StringListModel::new(),
+            items: Vec::new(),
+            scores: Vec::new(),
+            ranked: Vec::new(),
+            query: String::new(),
+        }
+    }
+
+    /// Current query
+    pub fn query(&self) -> &str {
+        &self.query
+    }
+
+    /// Set the query
+    ///
+    /// When the new query extends the current one, like when the user types, only the items
+    /// that currently match are scored again.
+    pub fn set_query<S>(&mut self, query: S)
+    where
+        S: Into<String>,
+    {
+        ffi_span!("FuzzyFilterModel", "set_query");
+        let query = query.into();
+        let indices = if query.starts_with(&self.query) {
+            // Items that do not match a query do not match the queries extending it
+            let mut indices = self
+                .ranked
+                .iter()
+                .map(|(_, index)| *index)
+                .collect::<Vec<_>>();
+            indices.sort_unstable();
+            indices
+        } else {
+            (0..self.items.len()).collect()
+        };
+        self.query = query;
+
+        let items = indices
+            .iter()
+            .map(|index| self.items[*index].as_str())
+            .collect::<Vec<_>>();
+        let scores = score_all(&self.query, &items);
+        self.scores = vec![None; self.items.len()];
+        for (index, score) in indices.into_iter().zip(scores) {
+            self.scores[index] = score;
+        }
+
+        let ranked = self.rank();
+        self.update_rows(ranked);
+    }
+
+    /// All the items, including the ones that do not match the query
+    pub fn items(&self) -> &[String] {
+        &self.items
+    }
+
+    /// Replace all the items
+    pub fn set_items<I>(&mut self, items: I)
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        ffi_span!("FuzzyFilterModel", "set_items");
+        self.items = items.into_iter().map(Into::into).collect();
+        let items = self.items.iter().map(String::as_str).collect::<Vec<_>>();
+        self.scores = score_all(&self.query, &items);
+
+        // Rows can't be matched by index with the previous items, and are matched by value
+        self.ranked = self.rank();
+        let items = &self.items;
+        let filtered = self.ranked.iter().map(|(_, index)| &items[*index]);
+        self.model.set_items_diffed(filtered);
+    }
+
+    /// Append an item
+    ///
+    /// Only the new item is scored, and it is inserted in the filtered items if it matches.
+    pub fn push<S>(&mut self, item: S)
+    where
+        S: Into<String>,
+    {
+        ffi_span!("FuzzyFilterModel", "push");
+        let item = item.into();
+        let index = self.items.len();
+        let score = score(&self.query, &item);
+        self.scores.push(score);
+
+        if let Some(score) = score {
+            // New items come last among items with the same score
+            let row = self.ranked.partition_point(|(other, _)| *other >= score);
+            self.ranked.insert(row, (score, index));
+            self.model.insert(row, &item);
+        }
+        self.items.push(item);
+    }
+
+    /// Remove an item
+    ///
+    /// The index refers to [`items`], not to the filtered items.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index >= items().len()`.
+    ///
+    /// [`items`]: #method.items
+    pub fn remove(&mut self, index: usize) -> String {
+        ffi_span!("FuzzyFilterModel", "remove");
+        let item = self.items.remove(index);
+        self.scores.remove(index);
+
+        if let Some(row) = self.ranked.iter().position(|(_, other)| *other == index) {
+            self.ranked.remove(row);
+            self.model.remove(row);
+        }
+        for (_, other) in &mut self.ranked {
+            if *other > index {
+                *other -= 1;
+            }
+        }
+        item
+    }
+
+    /// Items matching the query, best matches first
+    pub fn filtered(&self) -> Vec<String> {
+        self.model.to_vec()
+    }
+
+    /// Underlying model exposed to views
+    pub fn model(&self) -> &StringListModel {
+        &self.model
+    }
+
+    /// Matching items, as `(score, index)`, best matches first
+    fn rank(&self) -> Vec<(i64, usize)> {
+        let mut ranked = self
+            .scores
+            .iter()
+            .enumerate()
+            .filter_map(|(index, score)| score.map(|score| (score, index)))
+            .collect::<Vec<_>>();
+        ranked.sort_by(|(first_score, first), (second_score, second)| {
+            second_score.cmp(first_score).then(first.cmp(second))
+        });
+        ranked
+    }
+
+    /// Update the rows of the model, identifying them by the index of their item
+    fn update_rows(&mut self, ranked: Vec<(i64, usize)>) {
+        let row = |(_, index): &(i64, usize)| self.items[*index].clone();
+        let old = self.ranked.iter().map(row).collect::<Vec<_>>();
+        let new = ranked.iter().map(row).collect::<Vec<_>>();
+        let old_keys = self
+            .ranked
+            .iter()
+            .map(|(_, index)| *index)
+            .collect::<Vec<_>>();
+        let new_keys = ranked.iter().map(|(_, index)| *index).collect::<Vec<_>>();
+
+        self.model
+            .apply(diff::diff_by_keys(&old, &new, &old_keys, &new_keys));
+        self.ranked = ranked;
+    }
+}
+
+impl Default for FuzzyFilterModel {
+    fn default() -> Self {
+        FuzzyFilterModel::new()
+    }
+}
+
+impl<S> From<Vec<S>> for FuzzyFilterModel
+where
+    S: Into<String>,
+{
+    fn from(items: Vec<S>) -> Self {
+        let mut model = FuzzyFilterModel::new();
+        model.set_items(items);
+        model
+    }
+}
+
+impl From<&'_ FuzzyFilterModel> for Variant {
+    fn from(model: &FuzzyFilterModel) -> Self {
+        Variant::from(&model.model)
+    }
+}
+
+/// Score candidates against a fuzzy query
+///
+/// Large lists are split in chunks, that are scored in parallel.
+fn score_all(query: &str, candidates: &[&str]) -> Vec<Option<i64>> {
+    let threads = thread::available_parallelism().map_or(1, NonZeroUsize::get);
+    if threads == 1 || candidates.len() < PARALLEL_THRESHOLD {
+        return candidates
+            .iter()
+            .map(|candidate| score(query, candidate))
+            .collect();
+    }
+
+    let chunk_size = candidates.len().div_ceil(threads);
+    thread::scope(|scope| {
+        let chunks = candidates
+            .chunks(chunk_size)
+            .map(|chunk| {
+                scope.spawn(move || {
+                    chunk
+                        .iter()
+                        .map(|candidate| score(query, candidate))
+                        .collect::<Vec<_>>()
+                })
+            })
+            .collect::<Vec<_>>();
+        chunks
+            .into_iter()
+            .flat_map(|chunk| chunk.join().unwrap())
+            .collect()
+    })
+}
+
+/// Score a candidate against a fuzzy query
+///
+/// Returns `None` if the candidate does not match.
+fn score(query: &str, candidate: &str) -> Option<i64> {
+    let mut score = 0;
+    let mut previous: Option<char> = None;
+    let mut previous_matched = false;
+    let mut query = query.chars().flat_map(char::to_lowercase).peekable();
+
+    for c in candidate.chars() {
+        let expected = match query.peek() {
+            Some(expected) => *expected,
+            None => break,
+        };
+
+        let word_start = match previous {
+            None => true,
+            Some(previous) => {
+                !previous.is_alphanumeric() || (previous.is_lowercase() && c.is_uppercase())
+            }
+        };
+
+        if c.to_lowercase().eq(std::iter::once(expected)) {
+            query.next();
+            score += 1;
+            if previous_matched {
+                score += 4;
+            }
+            if word_start {
+                score += 8;
+            }
+            previous_matched = true;
+        } else {
+            score -= 1;
+            previous_matched = false;
+        }
+        previous = Some(c);
+    }
+
+    if query.peek().is_some() {
+        None
+    } else {
+        Some(score)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn score_matches() {
+        assert!(score("", "anything").is_some());
+        assert!(score("ofi", "Open File").is_some());
+        assert!(score("OFI", "open file").is_some());
+        assert_eq!(score("fo", "Open File"), None);
+        assert_eq!(score("x", "Open File"), None);
+    }
+
+    #[test]
+    fn score_ranks() {
+        let word_starts = score("sf", "Save File").unwrap();
+        let middle = score("sf", "Misfit").unwrap();
+        assert!(word_starts > middle);
+
+        let consecutive = score("ope", "Open").unwrap();
+        let scattered = score("ope", "Oakpine").unwrap();
+        assert!(consecutive > scattered);
+
+        let camel_case = score("ff", "fooFile").unwrap();
+        let no_camel_case = score("ff", "foofile").unwrap();
+        assert!(camel_case > no_camel_case);
+    }
+
+    #[test]
+    fn filter() {
+        let mut model = FuzzyFilterModel::from(vec!["Open File", "Save File", "Close Window"]);
+        assert_eq!(model.filtered().len(), 3);
+
+        model.set_query("file");
+        assert_eq!(model.filtered(), vec!["Open File", "Save File"]);
+
+        model.push("File Properties");
+        assert_eq!(model.filtered()[0], "File Properties");
+
+        model.set_query("");
+        assert_eq!(model.filtered(), model.items().to_vec());
+    }
+
+    #[test]
+    fn update_incrementally() {
+        let mut model = FuzzyFilterModel::from(vec!["Open File", "Save File", "Close Window"]);
+
+        model.set_query("f");
+        model.set_query("fi");
+        assert_eq!(model.filtered(), vec!["Open File", "Save File"]);
+
+        model.push("Find");
+        model.push("Quit");
+        assert_eq!(model.filtered(), vec!["Find", "Open File", "Save File"]);
+
+        assert_eq!(model.remove(0), "Open File");
+        assert_eq!(model.filtered(), vec!["Find", "Save File"]);
+
+        model.set_query("q");
+        assert_eq!(model.filtered(), vec!["Quit"]);
+    }
+
+    #[test]
+    fn score_in_parallel() {
+        let items = (0..2 * PARALLEL_THRESHOLD)
+            .map(|index| format!("item {}", index))
+            .collect::<Vec<_>>();
+        let candidates = items.iter().map(String::as_str).collect::<Vec<_>>();
+
+        let expected = candidates
+            .iter()
+            .map(|candidate| score("i12", candidate))
+            .collect::<Vec<_>>();
+        assert_eq!(score_all("i12", &candidates), expected);
+    }
+}
@@ -0,0 +1,166 @@
+#include "../variant/points.h"
+#include <QtCore/QAbstractTableModel>
+#include <QtCore/QPointF>
+#include <QtCore/QVariant>
+#include <QtCore/QVector>
+#include <cstdint>
+
+QT_USE_NAMESPACE
+
+namespace qt_binding {
+
+class PointListModel : public QAbstractTableModel
+{
+public:
+    enum Roles { XRole = Qt::UserRole + 1, YRole };
+
+    int rowCount(const QModelIndex &parent = QModelIndex()) const override
+    {
+        return parent.isValid() ? 0 : m_points.count();
+    }
+
+    int columnCount(const QModelIndex &parent = QModelIndex()) const override
+    {
+        return parent.isValid() ? 0 : 2;
+    }
+
+    QVariant data(const QModelIndex &index, int role) const override
+    {
+        if (!index.isValid() || index.row() >= m_points.count()) {
+            return QVariant();
+        }
+
+        const auto &point = m_points.at(index.row());
+        switch (role) {
+        case Qt::DisplayRole:
+        case Qt::EditRole:
+            return index.column() == 0 ? point.x() : point.y();
+        case XRole:
+            return point.x();
+        case YRole:
+            return point.y();
+        default:
+            return QVariant();
+        }
+    }
+
+    QVariant headerData(int section, Qt::Orientation orientation, int role) const override
+    {
+        if (orientation != Qt::Horizontal || role != Qt::DisplayRole) {
+            return QAbstractTableModel::headerData(section, orientation, role);
+        }
+        return section == 0 ? QStringLiteral("x") : QStringLiteral("y");
+    }
+
+    QHash<int, QByteArray> roleNames() const override
+    {
+        auto roles = QAbstractTableModel::roleNames();
+        roles.insert(XRole, "x");
+        roles.insert(YRole, "y");
+        return roles;
+    }
+
+    const QVector<QPointF> &points() const
+    {
+        return m_points;
+    }
+
+    void setPoints(const double *points, std::uint32_t count)
+    {
+        beginResetModel();
+        m_points.clear();
+        append(points, count);
+        endResetModel();
+    }
+
+    void appendPoints(const double *points, std::uint32_t count)
+    {
+        if (count == 0) {
+            return;
+        }
+
+        const auto first = m_points.count();
+        beginInsertRows(QModelIndex(), first, first + static_cast<int>(count) - 1);
+        append(points, count);
+        endInsertRows();
+    }
+
+    void removeFirst(int count)
+    {
+        if (count <= 0) {
+            return;
+        }
+
+        beginRemoveRows(QModelIndex(), 0, count - 1);
+        m_points.remove(0, count);
+        endRemoveRows();
+    }
+
+private:
+    void append(const double *points, std::uint32_t count)
+    {
+        m_points.reserve(m_points.count() + static_cast<int>(count));
+        for (std::uint32_t i = 0; i < count; ++i) {
+            m_points.append(QPointF(points[2 * i], points[2 * i + 1]));
+        }
+    }
+
+    QVector<QPointF> m_points;
+};
+
+} // namespace qt_binding
+
+extern "C" {
+
+qt_binding::PointListModel *qt_binding_point_list_model_create()
+{
+    return new qt_binding::PointListModel();
+}
+
+void qt_binding_point_list_model_delete(qt_binding::PointListModel *model)
+{
+    delete model;
+}
+
+QVariant *qt_binding_point_list_model_to_variant(qt_binding::PointListModel *model)
+{
+    return new QVariant(QVariant::fromValue<QObject *>(model));
+}
+
+int qt_binding_point_list_model_row_count(const qt_binding::PointListModel *model)
+{
+    return model->rowCount();
+}
+
+void qt_binding_point_list_model_get(const qt_binding::PointListModel *model, int row,
+                                     double *x, double *y)
+{
+    const auto &point = model->points().at(row);
+    *x = point.x();
+    *y = point.y();
+}
+
+void qt_binding_point_list_model_fill(const qt_binding::PointListModel *model, void *output,
+                                      RsPointsFillFunc fill)
+{
+    qt_binding::fillPoints(model->points(), output, fill);
+}
+
+void qt_binding_point_list_model_set_points(qt_binding::PointListModel *model,
+                                            const double *points, std::uint32_t count)
+{
+    model->setPoints(points, count);
+}
+
+void qt_binding_point_list_model_append_points(qt_binding::PointListModel *model,
+                                               const double *points, std::uint32_t count)
+{
+    model->appendPoints(points, count);
+}
+
+void qt_binding_point_list_model_remove_first(qt_binding::PointListModel *model, int count)
+{
+    model->removeFirst(count);
+}
+
+} // extern "C"
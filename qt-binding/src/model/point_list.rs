@@ -0,0 +1,229 @@
+use crate::app;
+use crate::object::Object;
+use crate::variant::convert::{flatten_points, rs_points_fill, RsPointsFillFunc};
+use crate::variant::Variant;
+use std::fmt;
+use std::os::raw::{c_int, c_void};
+
+/// A table model of points
+///
+/// `PointListModel` holds a list of `(x, y)` points, exposed as a table with an `x` column and
+/// a `y` column, and with `x` and `y` roles. It can feed QtCharts series through a
+/// `VXYModelMapper`, or any custom plotting item.
+///
+/// Points are passed to Qt in a single call, so that large series can be replaced or extended
+/// efficiently. [`append_points`] only notifies views of the inserted rows, and
+/// [`remove_first`] of the removed rows, to implement scrolling series.
+///
+/// [`append_points`]: #method.append_points
+/// [`remove_first`]: #method.remove_first
+///
+/// # Examples
+///
+/// ```
+/// use qt_binding::model::PointListModel;
+///
+/// let mut model = PointListModel::from(vec![(0., 1.), (1., 4.)]);
+/// model.append_points(&[(2., 9.), (3., 16.)]);
+/// model.remove_first(1);
+///
+/// assert_eq!(model.to_vec(), vec![(1., 4.), (2., 9.), (3., 16.)]);
+/// ```
+pub struct PointListModel {
+    ptr: *mut c_void,
+}
+
+impl PointListModel {
+    /// Creates an empty `PointListModel`
+    pub fn new() -> Self {
+        ffi_span!("PointListModel", "new");
+        track_created!(PointListModel);
+        PointListModel {
+            ptr: unsafe { qt_binding_point_list_model_create() },
+        }
+    }
+
+    /// Number of points
+    pub fn len(&self) -> usize {
+        ffi_span!("PointListModel", "len");
+        unsafe { qt_binding_point_list_model_row_count(self.ptr) as usize }
+    }
+
+    /// Returns `true` if the model contains no points
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Point at a row
+    ///
+    /// Returns `None` if the row is out of bounds.
+    pub fn get(&self, row: usize) -> Option<(f64, f64)> {
+        ffi_span!("PointListModel", "get");
+        if row >= self.len() {
+            return None;
+        }
+
+        let mut x = 0.;
+        let mut y = 0.;
+        unsafe { qt_binding_point_list_model_get(self.ptr, row as c_int, &mut x, &mut y) };
+        Some((x, y))
+    }
+
+    /// All points
+    pub fn to_vec(&self) -> Vec<(f64, f64)> {
+        ffi_span!("PointListModel", "to_vec");
+        let mut points = Vec::with_capacity(self.len());
+        unsafe {
+            let data: *mut Vec<(f64, f64)> = &mut points;
+            qt_binding_point_list_model_fill(self.ptr, data as *mut c_void, rs_points_fill);
+        }
+        points
+    }
+
+    /// Replace all points
+    ///
+    /// Views are notified with a model reset.
+    pub fn set_points(&mut self, points: &[(f64, f64)]) {
+        ffi_span!("PointListModel", "set_points");
+        let points = flatten_points(points);
+        unsafe {
+            qt_binding_point_list_model_set_points(
+                self.ptr,
+                points.as_ptr(),
+                (points.len() / 2) as u32,
+            )
+        }
+    }
+
+    /// Append points
+    ///
+    /// Views are notified that rows were inserted.
+    pub fn append_points(&mut self, points: &[(f64, f64)]) {
+        ffi_span!("PointListModel", "append_points");
+        let points = flatten_points(points);
+        unsafe {
+            qt_binding_point_list_model_append_points(
+                self.ptr,
+                points.as_ptr(),
+                (points.len() / 2) as u32,
+            )
+        }
+    }
+
+    /// Remove the first points
+    ///
+    /// Views are notified that rows were removed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `count > len`.
+    pub fn remove_first(&mut self, count: usize) {
+        ffi_span!("PointListModel", "remove_first");
+        assert!(count <= self.len(), "Cannot remove {} points", count);
+        unsafe { qt_binding_point_list_model_remove_first(self.ptr, count as c_int) }
+    }
+
+    /// Remove all points
+    ///
+    /// Views are notified with a model reset.
+    pub fn clear(&mut self) {
+        self.set_points(&[])
+    }
+}
+
+impl Default for PointListModel {
+    fn default() -> Self {
+        PointListModel::new()
+    }
+}
+
+impl From<Vec<(f64, f64)>> for PointListModel {
+    fn from(points: Vec<(f64, f64)>) -> Self {
+        let mut model = PointListModel::new();
+        model.set_points(&points);
+        model
+    }
+}
+
+impl From<&'_ PointListModel> for Variant {
+    fn from(model: &PointListModel) -> Self {
+        ffi_span!("Variant", "from_point_list_model");
+        Variant::from_ptr(unsafe { qt_binding_point_list_model_to_variant(model.ptr) })
+    }
+}
+
+impl fmt::Debug for PointListModel {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.to_vec()).finish()
+    }
+}
+
+impl Object for PointListModel {
+    fn as_object_ptr(&self) -> *mut c_void {
+        self.ptr
+    }
+}
+
+impl Drop for PointListModel {
+    fn drop(&mut self) {
+        ffi_span!("PointListModel", "drop");
+        if app::should_leak("PointListModel") {
+            return;
+        }
+        track_destroyed!(PointListModel);
+        unsafe { qt_binding_point_list_model_delete(self.ptr) }
+    }
+}
+
+extern "C" {
+    fn qt_binding_point_list_model_create() -> *mut c_void;
+    fn qt_binding_point_list_model_delete(model: *mut c_void);
+    fn qt_binding_point_list_model_to_variant(model: *mut c_void) -> *mut c_void;
+
+    fn qt_binding_point_list_model_row_count(model: *const c_void) -> c_int;
+    fn qt_binding_point_list_model_get(model: *const c_void, row: c_int, x: *mut f64, y: *mut f64);
+    fn qt_binding_point_list_model_fill(
+        model: *const c_void,
+        output: *mut c_void,
+        fill: RsPointsFillFunc,
+    );
+    fn qt_binding_point_list_model_set_points(model: *mut c_void, points: *const f64, count: u32);
+    fn qt_binding_point_list_model_append_points(
+        model: *mut c_void,
+        points: *const f64,
+        count: u32,
+    );
+    fn qt_binding_point_list_model_remove_first(model: *mut c_void, count: c_int);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn update() {
+        let mut model = PointListModel::new();
+        assert!(model.is_empty());
+        assert_eq!(model.to_vec(), Vec::new());
+
+        model.set_points(&[(0., 0.), (1., 1.)]);
+        model.append_points(&[(2., 4.), (3., 9.)]);
+        model.append_points(&[]);
+        assert_eq!(model.len(), 4);
+        assert_eq!(model.get(2), Some((2., 4.)));
+        assert_eq!(model.get(4), None);
+
+        model.remove_first(2);
+        assert_eq!(model.to_vec(), vec![(2., 4.), (3., 9.)]);
+
+        model.clear();
+        assert!(model.is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot remove 3 points")]
+    fn remove_first_out_of_bounds() {
+        let mut model = PointListModel::from(vec![(0., 0.), (1., 1.)]);
+        model.remove_first(3);
+    }
+}
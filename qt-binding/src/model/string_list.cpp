@@ -0,0 +1,69 @@
+#include <QtCore/QStringListModel>
+#include <QtCore/QVariant>
+#include <cstdint>
+
+QT_USE_NAMESPACE
+
+using RsStringFillFunc = void (*)(void *output, const char *input, std::uint32_t inputSize);
+
+extern "C" {
+
+QStringListModel *qt_binding_string_list_model_create()
+{
+    return new QStringListModel();
+}
+
+void qt_binding_string_list_model_delete(QStringListModel *model)
+{
+    delete model;
+}
+
+QVariant *qt_binding_string_list_model_to_variant(QStringListModel *model)
+{
+    return new QVariant(QVariant::fromValue<QObject *>(model));
+}
+
+int qt_binding_string_list_model_row_count(const QStringListModel *model)
+{
+    return model->rowCount();
+}
+
+void qt_binding_string_list_model_get(const QStringListModel *model, int row, void *output,
+                                      RsStringFillFunc fill)
+{
+    const auto value = model->data(model->index(row), Qt::DisplayRole).toString();
+    const auto byteArray = value.toUtf8();
+    fill(output, byteArray.constData(), static_cast<std::uint32_t>(byteArray.size()));
+}
+
+void qt_binding_string_list_model_set_items(QStringListModel *model, const char *const *items,
+                                            const std::uint32_t *sizes, std::uint32_t count)
+{
+    auto list = QStringList();
+    list.reserve(static_cast<int>(count));
+    for (std::uint32_t i = 0; i < count; ++i) {
+        list.append(QString::fromUtf8(items[i], static_cast<int>(sizes[i])));
+    }
+    model->setStringList(list);
+}
+
+void qt_binding_string_list_model_insert(QStringListModel *model, int row, const char *item,
+                                         std::uint32_t size)
+{
+    if (model->insertRows(row, 1)) {
+        model->setData(model->index(row), QString::fromUtf8(item, static_cast<int>(size)));
+    }
+}
+
+void qt_binding_string_list_model_set(QStringListModel *model, int row, const char *item,
+                                      std::uint32_t size)
+{
+    model->setData(model->index(row), QString::fromUtf8(item, static_cast<int>(size)));
+}
+
+void qt_binding_string_list_model_remove(QStringListModel *model, int row, int count)
+{
+    model->removeRows(row, count);
+}
+
+} // extern "C"
@@ -0,0 +1,393 @@
+use super::diff::{self, Edit};
+use crate::app;
+use crate::object::Object;
+use crate::variant::convert::{rs_string_fill, RsStringFillFunc};
+use crate::variant::Variant;
+use std::fmt;
+use std::hash::Hash;
+use std::os::raw::{c_char, c_int, c_void};
+
+/// Binding to `QStringListModel`
+///
+/// See module level documentation for more information.
+pub struct StringListModel {
+    ptr: *mut c_void,
+}
+
+impl StringListModel {
+    /// Creates an empty `StringListModel`
+    pub fn new() -> Self {
+        ffi_span!("StringListModel", "new");
+        track_created!(StringListModel);
+        StringListModel {
+            ptr: unsafe { qt_binding_string_list_model_create() },
+        }
+    }
+
+    /// Number of items
+    pub fn len(&self) -> usize {
+        ffi_span!("StringListModel", "len");
+        unsafe { qt_binding_string_list_model_row_count(self.ptr) as usize }
+    }
+
+    /// Returns `true` if the model contains no items
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Item at a row
+    ///
+    /// Returns `None` if the row is out of bounds.
+    pub fn get(&self, row: usize) -> Option<String> {
+        ffi_span!("StringListModel", "get");
+        if row >= self.len() {
+            return None;
+        }
+
+        let mut value = String::new();
+        unsafe {
+            let data: *mut String = &mut value;
+            qt_binding_string_list_model_get(
+                self.ptr,
+                row as c_int,
+                data as *mut c_void,
+                rs_string_fill,
+            );
+        }
+        Some(value)
+    }
+
+    /// All items
+    pub fn to_vec(&self) -> Vec<String> {
+        (0..self.len()).filter_map(|row| self.get(row)).collect()
+    }
+
+    /// Replace all items
+    ///
+    /// Views are notified with a model reset.
+    pub fn set_items<I>(&mut self, items: I)
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        ffi_span!("StringListModel", "set_items");
+        let items = items
+            .into_iter()
+            .map(|item| item.as_ref().to_string())
+            .collect::<Vec<_>>();
+        let data = items
+            .iter()
+            .map(|item| item.as_ptr() as *const c_char)
+            .collect::<Vec<_>>();
+        let sizes = items
+            .iter()
+            .map(|item| item.len() as u32)
+            .collect::<Vec<_>>();
+
+        unsafe {
+            qt_binding_string_list_model_set_items(
+                self.ptr,
+                data.as_ptr(),
+                sizes.as_ptr(),
+                items.len() as u32,
+            )
+        }
+    }
+
+    /// Replace all items with minimal updates
+    ///
+    /// Unlike [`set_items`], the model is not reset. The current and new items are compared,
+    /// and views are only notified of the rows that were inserted, removed or changed. Items
+    /// are identified by their value, so rows that are kept are left untouched, and views keep
+    /// their scroll position.
+    ///
+    /// Items are matched with a hash map, so large lists are diffed in `O(N log N)`.
+    ///
+    /// [`set_items`]: #method.set_items
+    pub fn set_items_diffed<I>(&mut self, items: I)
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        ffi_span!("StringListModel", "set_items_diffed");
+        let old = self.to_vec();
+        let new = items
+            .into_iter()
+            .map(|item| item.as_ref().to_string())
+            .collect::<Vec<_>>();
+
+        self.apply(diff::diff(&old, &new));
+    }
+
+    /// Replace all items with minimal updates, identifying items by key
+    ///
+    /// Like [`set_items_diffed`], but items are identified by the key returned by `key`
+    /// instead of by their value. An item whose key is kept but whose text changed is updated
+    /// in place, and views are notified that the row changed, instead of a removal and an
+    /// insertion.
+    ///
+    /// [`set_items_diffed`]: #method.set_items_diffed
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use qt_binding::model::StringListModel;
+    ///
+    /// let mut model = StringListModel::from(vec!["1: draft", "2: todo"]);
+    /// model.set_items_diffed_by(vec!["2: done", "1: draft"], |item| {
+    ///     item.split(':').next().unwrap().to_string()
+    /// });
+    /// assert_eq!(model.to_vec(), vec!["2: done", "1: draft"]);
+    /// ```
+    pub fn set_items_diffed_by<I, F, K>(&mut self, items: I, mut key: F)
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+        F: FnMut(&str) -> K,
+        K: Eq + Hash,
+    {
+        ffi_span!("StringListModel", "set_items_diffed_by");
+        let old = self.to_vec();
+        let new = items
+            .into_iter()
+            .map(|item| item.as_ref().to_string())
+            .collect::<Vec<_>>();
+        let old_keys = old.iter().map(|item| key(item)).collect::<Vec<_>>();
+        let new_keys = new.iter().map(|item| key(item)).collect::<Vec<_>>();
+
+        self.apply(diff::diff_by_keys(&old, &new, &old_keys, &new_keys));
+    }
+
+    pub(super) fn apply(&mut self, edits: Vec<Edit<String>>) {
+        for edit in edits {
+            match edit {
+                Edit::Insert(row, item) => self.insert(row, item),
+                Edit::Remove(row) => {
+                    self.remove(row);
+                }
+                Edit::Set(row, item) => self.set(row, item),
+            }
+        }
+    }
+
+    /// Insert an item at a row
+    ///
+    /// Views are notified that a row was inserted.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `row > len`.
+    pub fn insert<S>(&mut self, row: usize, item: S)
+    where
+        S: AsRef<str>,
+    {
+        ffi_span!("StringListModel", "insert");
+        assert!(row <= self.len(), "Row {} is out of bounds", row);
+
+        let item = item.as_ref();
+        unsafe {
+            qt_binding_string_list_model_insert(
+                self.ptr,
+                row as c_int,
+                item.as_ptr() as *const c_char,
+                item.len() as u32,
+            )
+        }
+    }
+
+    /// Append an item
+    ///
+    /// Views are notified that a row was inserted.
+    pub fn push<S>(&mut self, item: S)
+    where
+        S: AsRef<str>,
+    {
+        let len = self.len();
+        self.insert(len, item)
+    }
+
+    /// Replace the item at a row
+    ///
+    /// Views are notified that the row changed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `row >= len`.
+    pub fn set<S>(&mut self, row: usize, item: S)
+    where
+        S: AsRef<str>,
+    {
+        ffi_span!("StringListModel", "set");
+        assert!(row < self.len(), "Row {} is out of bounds", row);
+
+        let item = item.as_ref();
+        unsafe {
+            qt_binding_string_list_model_set(
+                self.ptr,
+                row as c_int,
+                item.as_ptr() as *const c_char,
+                item.len() as u32,
+            )
+        }
+    }
+
+    /// Remove the item at a row
+    ///
+    /// Views are notified that a row was removed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `row >= len`.
+    pub fn remove(&mut self, row: usize) -> String {
+        ffi_span!("StringListModel", "remove");
+        let item = self
+            .get(row)
+            .unwrap_or_else(|| panic!("Row {} is out of bounds", row));
+
+        unsafe { qt_binding_string_list_model_remove(self.ptr, row as c_int, 1) };
+        item
+    }
+
+    /// Remove all items
+    ///
+    /// Views are notified with a model reset.
+    pub fn clear(&mut self) {
+        self.set_items(Vec::<String>::new())
+    }
+}
+
+impl Default for StringListModel {
+    fn default() -> Self {
+        StringListModel::new()
+    }
+}
+
+impl<S> From<Vec<S>> for StringListModel
+where
+    S: AsRef<str>,
+{
+    fn from(items: Vec<S>) -> Self {
+        let mut model = StringListModel::new();
+        model.set_items(items);
+        model
+    }
+}
+
+impl From<&'_ StringListModel> for Variant {
+    fn from(model: &StringListModel) -> Self {
+        ffi_span!("Variant", "from_string_list_model");
+        Variant::from_ptr(unsafe { qt_binding_string_list_model_to_variant(model.ptr) })
+    }
+}
+
+impl fmt::Debug for StringListModel {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.to_vec()).finish()
+    }
+}
+
+impl Object for StringListModel {
+    fn as_object_ptr(&self) -> *mut c_void {
+        self.ptr
+    }
+}
+
+impl Drop for StringListModel {
+    fn drop(&mut self) {
+        ffi_span!("StringListModel", "drop");
+        if app::should_leak("StringListModel") {
+            return;
+        }
+        track_destroyed!(StringListModel);
+        unsafe { qt_binding_string_list_model_delete(self.ptr) }
+    }
+}
+
+extern "C" {
+    fn qt_binding_string_list_model_create() -> *mut c_void;
+    fn qt_binding_string_list_model_delete(model: *mut c_void);
+    fn qt_binding_string_list_model_to_variant(model: *mut c_void) -> *mut c_void;
+
+    fn qt_binding_string_list_model_row_count(model: *const c_void) -> c_int;
+    fn qt_binding_string_list_model_get(
+        model: *const c_void,
+        row: c_int,
+        output: *mut c_void,
+        fill: RsStringFillFunc,
+    );
+    fn qt_binding_string_list_model_set_items(
+        model: *mut c_void,
+        items: *const *const c_char,
+        sizes: *const u32,
+        count: u32,
+    );
+    fn qt_binding_string_list_model_insert(
+        model: *mut c_void,
+        row: c_int,
+        item: *const c_char,
+        size: u32,
+    );
+    fn qt_binding_string_list_model_set(
+        model: *mut c_void,
+        row: c_int,
+        item: *const c_char,
+        size: u32,
+    );
+    fn qt_binding_string_list_model_remove(model: *mut c_void, row: c_int, count: c_int);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_vec() {
+        let model = StringListModel::from(vec!["hello", "world 世界"]);
+
+        assert_eq!(model.len(), 2);
+        assert_eq!(model.get(0), Some("hello".to_string()));
+        assert_eq!(model.get(1), Some("world 世界".to_string()));
+        assert_eq!(model.get(2), None);
+    }
+
+    #[test]
+    fn update() {
+        let mut model = StringListModel::new();
+        assert!(model.is_empty());
+
+        model.push("b");
+        model.insert(0, "a");
+        model.push("d");
+        model.set(2, "c");
+        assert_eq!(model.to_vec(), vec!["a", "b", "c"]);
+
+        assert_eq!(model.remove(1), "b");
+        assert_eq!(model.to_vec(), vec!["a", "c"]);
+
+        model.clear();
+        assert!(model.is_empty());
+    }
+
+    #[test]
+    fn set_items_diffed() {
+        let mut model = StringListModel::from(vec!["a", "b", "c", "d"]);
+
+        model.set_items_diffed(vec!["z", "a", "c", "e", "d"]);
+        assert_eq!(model.to_vec(), vec!["z", "a", "c", "e", "d"]);
+    }
+
+    #[test]
+    fn set_items_diffed_by() {
+        let mut model = StringListModel::from(vec!["1: a", "2: b", "3: c"]);
+
+        model.set_items_diffed_by(vec!["3: c", "1: A", "4: d"], |item| item[..1].to_string());
+        assert_eq!(model.to_vec(), vec!["3: c", "1: A", "4: d"]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Row 3 is out of bounds")]
+    fn insert_out_of_bounds() {
+        let mut model = StringListModel::from(vec!["a", "b"]);
+        model.insert(3, "c");
+    }
+}
@@ -0,0 +1,204 @@
+#include <QtMultimedia/QAbstractVideoSurface>
+#include <QtMultimedia/QCamera>
+#include <QtMultimedia/QMediaPlayer>
+#include <QtMultimedia/QVideoFrame>
+#include <cstdint>
+
+QT_USE_NAMESPACE
+
+using RsStateFunc = void (*)(void *data, int state);
+using RsMessageFunc = void (*)(void *data, const char *message, std::uint32_t size);
+using RsFrameFunc = void (*)(void *data, const uchar *bits, std::uint32_t size, int width,
+                             int height, int bytesPerLine, int format);
+
+static void sendMessage(void *data, RsMessageFunc func, const QString &message)
+{
+    const auto byteArray = message.toUtf8();
+    func(data, byteArray.constData(), static_cast<std::uint32_t>(byteArray.size()));
+}
+
+static int toFormat(QVideoFrame::PixelFormat format)
+{
+    switch (format) {
+    case QVideoFrame::Format_RGB32:
+        return 0;
+    case QVideoFrame::Format_ARGB32:
+        return 1;
+    case QVideoFrame::Format_RGB24:
+        return 2;
+    case QVideoFrame::Format_YUV420P:
+        return 3;
+    case QVideoFrame::Format_NV12:
+        return 4;
+    case QVideoFrame::Format_YUYV:
+        return 5;
+    case QVideoFrame::Format_UYVY:
+        return 6;
+    case QVideoFrame::Format_Jpeg:
+        return 7;
+    default:
+        return -1;
+    }
+}
+
+namespace qt_binding {
+
+class VideoSurface : public QAbstractVideoSurface
+{
+public:
+    VideoSurface(void *data, RsFrameFunc frame)
+        : m_data(data)
+        , m_frame(frame)
+    {
+    }
+
+    QList<QVideoFrame::PixelFormat>
+    supportedPixelFormats(QAbstractVideoBuffer::HandleType type) const override
+    {
+        if (type != QAbstractVideoBuffer::NoHandle) {
+            return {};
+        }
+        return {QVideoFrame::Format_RGB32, QVideoFrame::Format_ARGB32, QVideoFrame::Format_RGB24,
+                QVideoFrame::Format_YUV420P, QVideoFrame::Format_NV12, QVideoFrame::Format_YUYV,
+                QVideoFrame::Format_UYVY, QVideoFrame::Format_Jpeg};
+    }
+
+    bool present(const QVideoFrame &frame) override
+    {
+        auto mappedFrame = QVideoFrame(frame);
+        if (!mappedFrame.map(QAbstractVideoBuffer::ReadOnly)) {
+            return false;
+        }
+        m_frame(m_data, mappedFrame.bits(), static_cast<std::uint32_t>(mappedFrame.mappedBytes()),
+                mappedFrame.width(), mappedFrame.height(), mappedFrame.bytesPerLine(),
+                toFormat(mappedFrame.pixelFormat()));
+        mappedFrame.unmap();
+        return true;
+    }
+
+private:
+    void *m_data;
+    RsFrameFunc m_frame;
+};
+
+class Camera
+{
+public:
+    Camera(void *data, RsFrameFunc frame, RsMessageFunc error)
+        : m_surface(data, frame)
+    {
+        m_camera.setViewfinder(&m_surface);
+        QObject::connect(&m_camera,
+                         static_cast<void (QCamera::*)(QCamera::Error)>(&QCamera::error),
+                         [this, data, error](QCamera::Error) {
+                             sendMessage(data, error, m_camera.errorString());
+                         });
+    }
+
+    void start()
+    {
+        m_camera.start();
+    }
+
+    void stop()
+    {
+        m_camera.stop();
+    }
+
+private:
+    // The surface is declared first, so that it outlives the camera
+    VideoSurface m_surface;
+    QCamera m_camera;
+};
+
+} // namespace qt_binding
+
+extern "C" {
+
+QMediaPlayer *qt_binding_media_player_create(void *data, RsStateFunc stateChanged,
+                                             RsMessageFunc error)
+{
+    auto player = new QMediaPlayer();
+    QObject::connect(player, &QMediaPlayer::stateChanged,
+                     [data, stateChanged](QMediaPlayer::State state) {
+                         stateChanged(data, static_cast<int>(state));
+                     });
+    QObject::connect(
+        player, static_cast<void (QMediaPlayer::*)(QMediaPlayer::Error)>(&QMediaPlayer::error),
+        [player, data, error](QMediaPlayer::Error) {
+            sendMessage(data, error, player->errorString());
+        });
+    return player;
+}
+
+void qt_binding_media_player_delete(QMediaPlayer *player)
+{
+    delete player;
+}
+
+void qt_binding_media_player_set_source(QMediaPlayer *player, const char *url, std::uint32_t size)
+{
+    player->setMedia(QUrl(QString::fromUtf8(url, static_cast<int>(size))));
+}
+
+void qt_binding_media_player_play(QMediaPlayer *player)
+{
+    player->play();
+}
+
+void qt_binding_media_player_pause(QMediaPlayer *player)
+{
+    player->pause();
+}
+
+void qt_binding_media_player_stop(QMediaPlayer *player)
+{
+    player->stop();
+}
+
+int qt_binding_media_player_state(const QMediaPlayer *player)
+{
+    return static_cast<int>(player->state());
+}
+
+void qt_binding_media_player_set_position(QMediaPlayer *player, std::int64_t position)
+{
+    player->setPosition(position);
+}
+
+std::int64_t qt_binding_media_player_position(const QMediaPlayer *player)
+{
+    return player->position();
+}
+
+std::int64_t qt_binding_media_player_duration(const QMediaPlayer *player)
+{
+    return player->duration();
+}
+
+void qt_binding_media_player_set_volume(QMediaPlayer *player, int volume)
+{
+    player->setVolume(volume);
+}
+
+qt_binding::Camera *qt_binding_camera_create(void *data, RsFrameFunc frame, RsMessageFunc error)
+{
+    return new qt_binding::Camera(data, frame, error);
+}
+
+void qt_binding_camera_delete(qt_binding::Camera *camera)
+{
+    delete camera;
+}
+
+void qt_binding_camera_start(qt_binding::Camera *camera)
+{
+    camera->start();
+}
+
+void qt_binding_camera_stop(qt_binding::Camera *camera)
+{
+    camera->stop();
+}
+
+} // extern "C"
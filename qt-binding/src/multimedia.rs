@@ -0,0 +1,526 @@
+//! Bindings to Qt Multimedia
+//!
+//! [`MediaPlayer`] is a binding to `QMediaPlayer`, that plays audio and video from a url.
+//! [`Camera`] is a binding to `QCamera`, that captures frames from a camera and passes them to
+//! Rust as byte buffers, described by a [`Frame`].
+//!
+//! Playback and capture are asynchronous and integrated with Qt's event-loop. State changes and
+//! captured frames are passed to handlers.
+//!
+//! [`MediaPlayer`]: struct.MediaPlayer.html
+//! [`Camera`]: struct.Camera.html
+//! [`Frame`]: struct.Frame.html
+//!
+//! # Features
+//!
+//! This module is only available with the `multimedia` feature.
+//!
+//! # Examples
+//!
+//! Playing a sound
+//!
+//! ```no_run
+//! use qt_binding::app::Application;
+//! use qt_binding::multimedia::MediaPlayer;
+//!
+//! let mut app = Application::new();
+//!
+//! let mut player = MediaPlayer::new();
+//! player.on_state_changed(|state| println!("Player is {:?}", state));
+//! player.set_source("qrc:/sounds/notification.ogg");
+//! player.play();
+//!
+//! app.exec();
+//! ```
+//!
+//! Capturing camera frames
+//!
+//! ```no_run
+//! use qt_binding::app::Application;
+//! use qt_binding::multimedia::Camera;
+//!
+//! let mut app = Application::new();
+//!
+//! let mut camera = Camera::new();
+//! camera.on_frame(|frame| {
+//!     println!("{}x{} {:?} frame", frame.width(), frame.height(), frame.format());
+//! });
+//! camera.start();
+//!
+//! app.exec();
+//! ```
+
+use crate::app;
+use crate::variant::convert::rs_string_fill;
+use std::error;
+use std::fmt;
+use std::os::raw::{c_char, c_int, c_void};
+use std::slice;
+use std::time::Duration;
+
+type StateHandler = Option<Box<dyn FnMut(State)>>;
+type ErrorHandler = Option<Box<dyn FnMut(Error)>>;
+type FrameHandler = Option<Box<dyn FnMut(&Frame)>>;
+
+/// Error reported by Qt Multimedia
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Error {
+    message: String,
+}
+
+impl Error {
+    /// Error message
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl error::Error for Error {}
+
+/// State of a media player
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum State {
+    /// Playback is stopped
+    Stopped,
+    /// Media is playing
+    Playing,
+    /// Playback is paused
+    Paused,
+}
+
+impl State {
+    fn from_c_int(state: c_int) -> Self {
+        match state {
+            1 => State::Playing,
+            2 => State::Paused,
+            _ => State::Stopped,
+        }
+    }
+}
+
+#[derive(Default)]
+struct PlayerHandlers {
+    state_changed: StateHandler,
+    error: ErrorHandler,
+}
+
+/// Binding to `QMediaPlayer`
+///
+/// See module level documentation for more information.
+pub struct MediaPlayer {
+    ptr: *mut c_void,
+    handlers: Box<PlayerHandlers>,
+}
+
+impl MediaPlayer {
+    /// Creates a `MediaPlayer` without source
+    pub fn new() -> Self {
+        ffi_span!("MediaPlayer", "new");
+        let mut handlers = Box::new(PlayerHandlers::default());
+        let data: *mut PlayerHandlers = &mut *handlers;
+        let ptr = unsafe {
+            qt_binding_media_player_create(
+                data as *mut c_void,
+                rs_media_player_state_changed,
+                rs_media_player_error,
+            )
+        };
+        track_created!(MediaPlayer);
+        MediaPlayer { ptr, handlers }
+    }
+
+    /// Set the url of the media to play
+    ///
+    /// Local files are played with `file://` urls, and resources with `qrc:` urls.
+    pub fn set_source<S>(&mut self, url: S) -> &mut Self
+    where
+        S: AsRef<str>,
+    {
+        ffi_span!("MediaPlayer", "set_source");
+        let url = url.as_ref();
+        unsafe {
+            qt_binding_media_player_set_source(
+                self.ptr,
+                url.as_ptr() as *const c_char,
+                url.len() as u32,
+            )
+        }
+        self
+    }
+
+    /// Start or resume playback
+    pub fn play(&mut self) {
+        ffi_span!("MediaPlayer", "play");
+        unsafe { qt_binding_media_player_play(self.ptr) }
+    }
+
+    /// Pause playback
+    pub fn pause(&mut self) {
+        ffi_span!("MediaPlayer", "pause");
+        unsafe { qt_binding_media_player_pause(self.ptr) }
+    }
+
+    /// Stop playback
+    ///
+    /// The position is reset to the beginning of the media.
+    pub fn stop(&mut self) {
+        ffi_span!("MediaPlayer", "stop");
+        unsafe { qt_binding_media_player_stop(self.ptr) }
+    }
+
+    /// Current state
+    pub fn state(&self) -> State {
+        ffi_span!("MediaPlayer", "state");
+        State::from_c_int(unsafe { qt_binding_media_player_state(self.ptr) })
+    }
+
+    /// Move the playback position
+    pub fn seek(&mut self, position: Duration) {
+        ffi_span!("MediaPlayer", "seek");
+        unsafe { qt_binding_media_player_set_position(self.ptr, position.as_millis() as i64) }
+    }
+
+    /// Playback position
+    pub fn position(&self) -> Duration {
+        ffi_span!("MediaPlayer", "position");
+        let position = unsafe { qt_binding_media_player_position(self.ptr) };
+        Duration::from_millis(position.max(0) as u64)
+    }
+
+    /// Duration of the media
+    ///
+    /// Returns `None` if the duration is not known yet.
+    pub fn duration(&self) -> Option<Duration> {
+        ffi_span!("MediaPlayer", "duration");
+        let duration = unsafe { qt_binding_media_player_duration(self.ptr) };
+        if duration > 0 {
+            Some(Duration::from_millis(duration as u64))
+        } else {
+            None
+        }
+    }
+
+    /// Set the volume, between 0 and 100
+    ///
+    /// # Panics
+    ///
+    /// Panics if `volume > 100`.
+    pub fn set_volume(&mut self, volume: u8) -> &mut Self {
+        ffi_span!("MediaPlayer", "set_volume");
+        assert!(volume <= 100, "Volume {} is out of bounds", volume);
+        unsafe { qt_binding_media_player_set_volume(self.ptr, c_int::from(volume)) }
+        self
+    }
+
+    /// Handle state changes
+    ///
+    /// Setting a new handler replaces the previous one.
+    pub fn on_state_changed<F>(&mut self, handler: F) -> &mut Self
+    where
+        F: FnMut(State) + 'static,
+    {
+        self.handlers.state_changed = Some(Box::new(handler));
+        self
+    }
+
+    /// Handle errors
+    ///
+    /// Setting a new handler replaces the previous one.
+    pub fn on_error<F>(&mut self, handler: F) -> &mut Self
+    where
+        F: FnMut(Error) + 'static,
+    {
+        self.handlers.error = Some(Box::new(handler));
+        self
+    }
+}
+
+impl Default for MediaPlayer {
+    fn default() -> Self {
+        MediaPlayer::new()
+    }
+}
+
+impl fmt::Debug for MediaPlayer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("MediaPlayer")
+            .field("state", &self.state())
+            .field("position", &self.position())
+            .finish()
+    }
+}
+
+impl Drop for MediaPlayer {
+    fn drop(&mut self) {
+        ffi_span!("MediaPlayer", "drop");
+        if app::should_leak("MediaPlayer") {
+            return;
+        }
+        track_destroyed!(MediaPlayer);
+        unsafe { qt_binding_media_player_delete(self.ptr) }
+    }
+}
+
+/// Pixel format of a camera frame
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PixelFormat {
+    /// 32 bits RGB, as `0xffRRGGBB`
+    Rgb32,
+    /// 32 bits ARGB, as `0xAARRGGBB`
+    Argb32,
+    /// 24 bits RGB
+    Rgb24,
+    /// Planar YUV 4:2:0
+    Yuv420P,
+    /// Semi-planar YUV 4:2:0, with interleaved UV plane
+    Nv12,
+    /// Packed YUV 4:2:2, as Y0 U Y1 V
+    Yuyv,
+    /// Packed YUV 4:2:2, as U Y0 V Y1
+    Uyvy,
+    /// Compressed JPEG
+    Jpeg,
+    /// Other pixel format
+    Other,
+}
+
+impl PixelFormat {
+    fn from_c_int(format: c_int) -> Self {
+        match format {
+            0 => PixelFormat::Rgb32,
+            1 => PixelFormat::Argb32,
+            2 => PixelFormat::Rgb24,
+            3 => PixelFormat::Yuv420P,
+            4 => PixelFormat::Nv12,
+            5 => PixelFormat::Yuyv,
+            6 => PixelFormat::Uyvy,
+            7 => PixelFormat::Jpeg,
+            _ => PixelFormat::Other,
+        }
+    }
+}
+
+/// A frame captured by a camera
+///
+/// The frame borrows the buffer of the camera, and is only valid in the handler set with
+/// [`Camera::on_frame`].
+///
+/// [`Camera::on_frame`]: struct.Camera.html#method.on_frame
+#[derive(Debug)]
+pub struct Frame<'a> {
+    data: &'a [u8],
+    width: u32,
+    height: u32,
+    bytes_per_line: u32,
+    format: PixelFormat,
+}
+
+impl<'a> Frame<'a> {
+    /// Pixel data
+    pub fn data(&self) -> &'a [u8] {
+        self.data
+    }
+
+    /// Width, in pixels
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    /// Height, in pixels
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// Number of bytes of a line of pixels, including padding
+    ///
+    /// For planar formats, this is the number of bytes of a line of the first plane.
+    pub fn bytes_per_line(&self) -> u32 {
+        self.bytes_per_line
+    }
+
+    /// Pixel format
+    pub fn format(&self) -> PixelFormat {
+        self.format
+    }
+}
+
+#[derive(Default)]
+struct CameraHandlers {
+    frame: FrameHandler,
+    error: ErrorHandler,
+}
+
+/// Binding to `QCamera`
+///
+/// See module level documentation for more information.
+pub struct Camera {
+    ptr: *mut c_void,
+    handlers: Box<CameraHandlers>,
+}
+
+impl Camera {
+    /// Creates a `Camera` using the default camera
+    pub fn new() -> Self {
+        ffi_span!("Camera", "new");
+        let mut handlers = Box::new(CameraHandlers::default());
+        let data: *mut CameraHandlers = &mut *handlers;
+        let ptr = unsafe {
+            qt_binding_camera_create(data as *mut c_void, rs_camera_frame, rs_camera_error)
+        };
+        track_created!(Camera);
+        Camera { ptr, handlers }
+    }
+
+    /// Start capturing frames
+    pub fn start(&mut self) {
+        ffi_span!("Camera", "start");
+        unsafe { qt_binding_camera_start(self.ptr) }
+    }
+
+    /// Stop capturing frames
+    pub fn stop(&mut self) {
+        ffi_span!("Camera", "stop");
+        unsafe { qt_binding_camera_stop(self.ptr) }
+    }
+
+    /// Handle captured frames
+    ///
+    /// The handler is called from the event-loop for each captured frame. Setting a new handler
+    /// replaces the previous one.
+    pub fn on_frame<F>(&mut self, handler: F) -> &mut Self
+    where
+        F: FnMut(&Frame) + 'static,
+    {
+        self.handlers.frame = Some(Box::new(handler));
+        self
+    }
+
+    /// Handle errors
+    ///
+    /// Setting a new handler replaces the previous one.
+    pub fn on_error<F>(&mut self, handler: F) -> &mut Self
+    where
+        F: FnMut(Error) + 'static,
+    {
+        self.handlers.error = Some(Box::new(handler));
+        self
+    }
+}
+
+impl Default for Camera {
+    fn default() -> Self {
+        Camera::new()
+    }
+}
+
+impl fmt::Debug for Camera {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Camera").finish()
+    }
+}
+
+impl Drop for Camera {
+    fn drop(&mut self) {
+        ffi_span!("Camera", "drop");
+        if app::should_leak("Camera") {
+            return;
+        }
+        track_destroyed!(Camera);
+        unsafe { qt_binding_camera_delete(self.ptr) }
+    }
+}
+
+fn to_error(message: *const c_char, size: u32) -> Error {
+    let mut value = String::new();
+    let output: *mut String = &mut value;
+    rs_string_fill(output as *mut c_void, message, size);
+    Error { message: value }
+}
+
+extern "C" fn rs_media_player_state_changed(data: *mut c_void, state: c_int) {
+    let handlers = unsafe { &mut *(data as *mut PlayerHandlers) };
+    if let Some(handler) = &mut handlers.state_changed {
+        handler(State::from_c_int(state));
+    }
+}
+
+extern "C" fn rs_media_player_error(data: *mut c_void, message: *const c_char, size: u32) {
+    let handlers = unsafe { &mut *(data as *mut PlayerHandlers) };
+    if let Some(handler) = &mut handlers.error {
+        handler(to_error(message, size));
+    }
+}
+
+extern "C" fn rs_camera_frame(
+    data: *mut c_void,
+    bits: *const u8,
+    size: u32,
+    width: c_int,
+    height: c_int,
+    bytes_per_line: c_int,
+    format: c_int,
+) {
+    let handlers = unsafe { &mut *(data as *mut CameraHandlers) };
+    if let Some(handler) = &mut handlers.frame {
+        let frame = Frame {
+            data: unsafe { slice::from_raw_parts(bits, size as usize) },
+            width: width as u32,
+            height: height as u32,
+            bytes_per_line: bytes_per_line as u32,
+            format: PixelFormat::from_c_int(format),
+        };
+        handler(&frame);
+    }
+}
+
+extern "C" fn rs_camera_error(data: *mut c_void, message: *const c_char, size: u32) {
+    let handlers = unsafe { &mut *(data as *mut CameraHandlers) };
+    if let Some(handler) = &mut handlers.error {
+        handler(to_error(message, size));
+    }
+}
+
+type RsStateFunc = extern "C" fn(data: *mut c_void, state: c_int);
+type RsMessageFunc = extern "C" fn(data: *mut c_void, message: *const c_char, size: u32);
+type RsFrameFunc = extern "C" fn(
+    data: *mut c_void,
+    bits: *const u8,
+    size: u32,
+    width: c_int,
+    height: c_int,
+    bytes_per_line: c_int,
+    format: c_int,
+);
+
+extern "C" {
+    fn qt_binding_media_player_create(
+        data: *mut c_void,
+        state_changed: RsStateFunc,
+        error: RsMessageFunc,
+    ) -> *mut c_void;
+    fn qt_binding_media_player_delete(player: *mut c_void);
+    fn qt_binding_media_player_set_source(player: *mut c_void, url: *const c_char, size: u32);
+    fn qt_binding_media_player_play(player: *mut c_void);
+    fn qt_binding_media_player_pause(player: *mut c_void);
+    fn qt_binding_media_player_stop(player: *mut c_void);
+    fn qt_binding_media_player_state(player: *const c_void) -> c_int;
+    fn qt_binding_media_player_set_position(player: *mut c_void, position: i64);
+    fn qt_binding_media_player_position(player: *const c_void) -> i64;
+    fn qt_binding_media_player_duration(player: *const c_void) -> i64;
+    fn qt_binding_media_player_set_volume(player: *mut c_void, volume: c_int);
+
+    fn qt_binding_camera_create(
+        data: *mut c_void,
+        frame: RsFrameFunc,
+        error: RsMessageFunc,
+    ) -> *mut c_void;
+    fn qt_binding_camera_delete(camera: *mut c_void);
+    fn qt_binding_camera_start(camera: *mut c_void);
+    fn qt_binding_camera_stop(camera: *mut c_void);
+}
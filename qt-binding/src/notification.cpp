@@ -0,0 +1,44 @@
+#include <QtGui/QIcon>
+#include <QtWidgets/QSystemTrayIcon>
+#include <cstdint>
+
+QT_USE_NAMESPACE
+
+using RsActivatedFunc = void (*)(void *data);
+
+static QString toString(const char *value, std::uint32_t size)
+{
+    return QString::fromUtf8(value, static_cast<int>(size));
+}
+
+extern "C" {
+
+bool qt_binding_notifier_is_supported()
+{
+    return QSystemTrayIcon::isSystemTrayAvailable() && QSystemTrayIcon::supportsMessages();
+}
+
+QSystemTrayIcon *qt_binding_notifier_create(const char *icon, std::uint32_t size, void *data,
+                                            RsActivatedFunc activated)
+{
+    auto trayIcon = new QSystemTrayIcon(QIcon(toString(icon, size)));
+    QObject::connect(trayIcon, &QSystemTrayIcon::messageClicked,
+                     [data, activated]() { activated(data); });
+    trayIcon->show();
+    return trayIcon;
+}
+
+void qt_binding_notifier_delete(QSystemTrayIcon *trayIcon)
+{
+    delete trayIcon;
+}
+
+void qt_binding_notifier_show(QSystemTrayIcon *trayIcon, const char *title,
+                              std::uint32_t titleSize, const char *body, std::uint32_t bodySize,
+                              int icon, int timeout)
+{
+    trayIcon->showMessage(toString(title, titleSize), toString(body, bodySize),
+                          static_cast<QSystemTrayIcon::MessageIcon>(icon), timeout);
+}
+
+} // extern "C"
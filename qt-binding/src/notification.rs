@@ -0,0 +1,227 @@
+//! Desktop notifications
+//!
+//! [`Notifier`] shows desktop [`Notification`]s with Qt's `QSystemTrayIcon`, that uses the
+//! native notification mechanism of each platform: notification center on macOS, balloon
+//! messages or toasts on Windows, and freedesktop notifications on Linux.
+//!
+//! [`Notifier`]: struct.Notifier.html
+//! [`Notification`]: struct.Notification.html
+//!
+//! # Features
+//!
+//! This module is only available with the `widgets` feature.
+//!
+//! # Limitations
+//!
+//! The `Notifier` displays an icon in the system tray while it is alive, as notifications are
+//! attached to a tray icon on some platforms. Notifications might not be supported at all,
+//! see [`Notifier::is_supported`].
+//!
+//! [`Notifier::is_supported`]: struct.Notifier.html#method.is_supported
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use qt_binding::app::Application;
+//! use qt_binding::notification::{Notification, Notifier};
+//! use std::time::Duration;
+//!
+//! let mut app = Application::new();
+//! let mut notifier = Notifier::new(":/icon.png");
+//! notifier.on_activated(|| println!("Notification clicked"));
+//! notifier.show(
+//!     Notification::new("Download finished")
+//!         .body("report.pdf was saved in Downloads")
+//!         .timeout(Duration::from_secs(5)),
+//! );
+//! app.exec();
+//! ```
+
+use crate::app;
+use std::os::raw::{c_char, c_int, c_void};
+use std::time::Duration;
+
+/// Icon displayed in a notification
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum NotificationIcon {
+    /// No icon
+    NoIcon,
+    /// Information icon
+    Information,
+    /// Warning icon
+    Warning,
+    /// Critical error icon
+    Critical,
+}
+
+impl NotificationIcon {
+    fn as_c_int(self) -> c_int {
+        match self {
+            NotificationIcon::NoIcon => 0,
+            NotificationIcon::Information => 1,
+            NotificationIcon::Warning => 2,
+            NotificationIcon::Critical => 3,
+        }
+    }
+}
+
+/// A desktop notification
+///
+/// See module level documentation for more information.
+#[derive(Clone, Debug)]
+pub struct Notification {
+    title: String,
+    body: String,
+    icon: NotificationIcon,
+    timeout: Duration,
+}
+
+impl Notification {
+    /// Creates a `Notification` with a title
+    ///
+    /// By default, a notification has no body, an information icon, and is displayed for 10
+    /// seconds.
+    pub fn new<S>(title: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Notification {
+            title: title.into(),
+            body: String::new(),
+            icon: NotificationIcon::Information,
+            timeout: Duration::from_secs(10),
+        }
+    }
+
+    /// Body of the notification
+    pub fn body<S>(&mut self, body: S) -> &mut Self
+    where
+        S: Into<String>,
+    {
+        self.body = body.into();
+        self
+    }
+
+    /// Icon of the notification
+    pub fn icon(&mut self, icon: NotificationIcon) -> &mut Self {
+        self.icon = icon;
+        self
+    }
+
+    /// How long the notification is displayed
+    ///
+    /// This is only a hint, that some platforms ignore.
+    pub fn timeout(&mut self, timeout: Duration) -> &mut Self {
+        self.timeout = timeout;
+        self
+    }
+}
+
+type ActivatedHandler = Option<Box<dyn FnMut()>>;
+
+/// Shows desktop notifications
+///
+/// See module level documentation for more information.
+pub struct Notifier {
+    ptr: *mut c_void,
+    handler: Box<ActivatedHandler>,
+}
+
+impl Notifier {
+    /// Check if the platform can display notifications
+    pub fn is_supported() -> bool {
+        ffi_span!("Notifier", "is_supported");
+        unsafe { qt_binding_notifier_is_supported() }
+    }
+
+    /// Creates a `Notifier` with the path of the icon displayed in the system tray
+    ///
+    /// Paths starting with `:/` are loaded from Qt resources.
+    pub fn new<S>(icon: S) -> Self
+    where
+        S: AsRef<str>,
+    {
+        ffi_span!("Notifier", "new");
+        let icon = icon.as_ref();
+        let mut handler = Box::new(None);
+        let data: *mut ActivatedHandler = &mut *handler;
+        let ptr = unsafe {
+            qt_binding_notifier_create(
+                icon.as_ptr() as *const c_char,
+                icon.len() as u32,
+                data as *mut c_void,
+                rs_notifier_activated,
+            )
+        };
+        track_created!(Notifier);
+        Notifier { ptr, handler }
+    }
+
+    /// Show a notification
+    pub fn show(&mut self, notification: &Notification) {
+        ffi_span!("Notifier", "show");
+        let timeout = notification.timeout.as_millis().min(c_int::MAX as u128);
+        unsafe {
+            qt_binding_notifier_show(
+                self.ptr,
+                notification.title.as_ptr() as *const c_char,
+                notification.title.len() as u32,
+                notification.body.as_ptr() as *const c_char,
+                notification.body.len() as u32,
+                notification.icon.as_c_int(),
+                timeout as c_int,
+            )
+        }
+    }
+
+    /// Handle a notification being clicked
+    ///
+    /// Setting a new handler replaces the previous one. Some platforms never report clicks.
+    pub fn on_activated<F>(&mut self, handler: F) -> &mut Self
+    where
+        F: FnMut() + 'static,
+    {
+        *self.handler = Some(Box::new(handler));
+        self
+    }
+}
+
+impl Drop for Notifier {
+    fn drop(&mut self) {
+        ffi_span!("Notifier", "drop");
+        if app::should_leak("Notifier") {
+            return;
+        }
+        track_destroyed!(Notifier);
+        unsafe { qt_binding_notifier_delete(self.ptr) }
+    }
+}
+
+extern "C" fn rs_notifier_activated(data: *mut c_void) {
+    let handler = unsafe { &mut *(data as *mut ActivatedHandler) };
+    if let Some(handler) = handler {
+        handler();
+    }
+}
+
+type RsActivatedFunc = extern "C" fn(data: *mut c_void);
+
+extern "C" {
+    fn qt_binding_notifier_is_supported() -> bool;
+    fn qt_binding_notifier_create(
+        icon: *const c_char,
+        size: u32,
+        data: *mut c_void,
+        activated: RsActivatedFunc,
+    ) -> *mut c_void;
+    fn qt_binding_notifier_delete(notifier: *mut c_void);
+    fn qt_binding_notifier_show(
+        notifier: *mut c_void,
+        title: *const c_char,
+        title_size: u32,
+        body: *const c_char,
+        body_size: u32,
+        icon: c_int,
+        timeout: c_int,
+    );
+}
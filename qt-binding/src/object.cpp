@@ -0,0 +1,92 @@
+#include <QtCore/QObject>
+#include <QtCore/QPointer>
+#include <QtCore/QVariant>
+#include <cstdint>
+
+QT_USE_NAMESPACE
+
+using RsStringFillFunc = void (*)(void *output, const char *input, std::uint32_t inputSize);
+
+static void fillString(const QString &value, void *output, RsStringFillFunc fill)
+{
+    const auto byteArray = value.toUtf8();
+    fill(output, byteArray.constData(), static_cast<std::uint32_t>(byteArray.size()));
+}
+
+extern "C" {
+
+// Objects might be null when accessed through a handle to a destroyed object
+
+void qt_binding_object_name(const QObject *object, void *output, RsStringFillFunc fill)
+{
+    if (object != nullptr) {
+        fillString(object->objectName(), output, fill);
+    }
+}
+
+void qt_binding_object_set_name(QObject *object, const char *name, std::uint32_t size)
+{
+    if (object != nullptr) {
+        object->setObjectName(QString::fromUtf8(name, static_cast<int>(size)));
+    }
+}
+
+void qt_binding_object_class_name(const QObject *object, void *output, RsStringFillFunc fill)
+{
+    if (object != nullptr) {
+        fillString(QString::fromLatin1(object->metaObject()->className()), output, fill);
+    }
+}
+
+QVariant *qt_binding_object_property(const QObject *object, const char *name, std::uint32_t size)
+{
+    if (object == nullptr) {
+        return new QVariant();
+    }
+    const auto propertyName = QByteArray(name, static_cast<int>(size));
+    return new QVariant(object->property(propertyName.constData()));
+}
+
+bool qt_binding_object_set_property(QObject *object, const char *name, std::uint32_t size,
+                                    const QVariant *value)
+{
+    if (object == nullptr) {
+        return false;
+    }
+    const auto propertyName = QByteArray(name, static_cast<int>(size));
+    const auto index = object->metaObject()->indexOfProperty(propertyName.constData());
+    object->setProperty(propertyName.constData(), *value);
+    // Dynamic properties are always set
+    return index < 0 || object->property(propertyName.constData()) == *value;
+}
+
+QPointer<QObject> *qt_binding_object_handle_clone(const QPointer<QObject> *handle)
+{
+    return new QPointer<QObject>(*handle);
+}
+
+void qt_binding_object_handle_delete(QPointer<QObject> *handle)
+{
+    delete handle;
+}
+
+QObject *qt_binding_object_handle_get(const QPointer<QObject> *handle)
+{
+    return handle->data();
+}
+
+QVariant *qt_binding_variant_create_object(QObject *object)
+{
+    return new QVariant(QVariant::fromValue<QObject *>(object));
+}
+
+QPointer<QObject> *qt_binding_variant_to_object(const QVariant *variant)
+{
+    if (!variant->canConvert<QObject *>()) {
+        return nullptr;
+    }
+    auto object = variant->value<QObject *>();
+    return object != nullptr ? new QPointer<QObject>(object) : nullptr;
+}
+
+} // extern "C"
@@ -0,0 +1,295 @@
+//! Common `QObject` properties
+//!
+//! Bindings over Qt objects implement the [`Object`] trait, that gives access to properties
+//! shared by all `QObject`s, like the object name. UI test tooling locates elements by their
+//! object name, and accessibility bindings in the [`accessibility`] module take objects
+//! implementing [`Object`].
+//!
+//! [`Object`]: trait.Object.html
+//! [`accessibility`]: ../accessibility/index.html
+//!
+//! Objects can be stored in a [`Variant`] with [`Variant::from_object`], to be passed to QML,
+//! and objects stored in a `Variant`, like objects passed from QML, are retrieved as an
+//! [`ObjectHandle`] with [`Variant::try_to_object`].
+//!
+//! [`Variant`]: ../variant/struct.Variant.html
+//! [`Variant::from_object`]: ../variant/struct.Variant.html#method.from_object
+//! [`Variant::try_to_object`]: ../variant/struct.Variant.html#method.try_to_object
+//! [`ObjectHandle`]: struct.ObjectHandle.html
+//!
+//! # Examples
+//!
+//! ```
+//! # #[cfg(not(feature = "mock"))]
+//! # fn main() {
+//! use qt_binding::model::StringListModel;
+//! use qt_binding::object::Object;
+//!
+//! let mut model = StringListModel::new();
+//! model.set_object_name("recentFiles");
+//! assert_eq!(model.object_name(), "recentFiles");
+//! # }
+//! # #[cfg(feature = "mock")]
+//! # fn main() {}
+//! ```
+
+#[cfg(not(feature = "mock"))]
+use crate::animation::Animation;
+#[cfg(not(feature = "mock"))]
+use crate::app;
+use crate::variant::convert::{rs_string_fill, RsStringFillFunc};
+use crate::variant::{TryFromError, Variant};
+use std::fmt;
+use std::os::raw::{c_char, c_void};
+
+/// A binding over a `QObject`
+pub trait Object {
+    /// Pointer to the underlying `QObject`
+    #[doc(hidden)]
+    fn as_object_ptr(&self) -> *mut c_void;
+
+    /// Name of the object, empty by default
+    fn object_name(&self) -> String {
+        ffi_span!("Object", "object_name");
+        let mut name = String::new();
+        unsafe {
+            let data: *mut String = &mut name;
+            qt_binding_object_name(self.as_object_ptr(), data as *mut c_void, rs_string_fill);
+        }
+        name
+    }
+
+    /// Set the name of the object
+    ///
+    /// The name is the `objectName` property, as seen by QML and by tools that find objects
+    /// by name.
+    fn set_object_name<S>(&mut self, name: S)
+    where
+        S: AsRef<str>,
+        Self: Sized,
+    {
+        ffi_span!("Object", "set_object_name");
+        let name = name.as_ref();
+        unsafe {
+            qt_binding_object_set_name(
+                self.as_object_ptr(),
+                name.as_ptr() as *const c_char,
+                name.len() as u32,
+            )
+        }
+    }
+
+    /// Animate a property of the object
+    ///
+    /// The animation is configured by `configure`, then started. It stops when it is dropped.
+    /// See [`Animation`] for more information.
+    ///
+    /// [`Animation`]: ../animation/struct.Animation.html
+    #[cfg(not(feature = "mock"))]
+    fn animate<S, F>(&self, property: S, configure: F) -> Animation
+    where
+        S: AsRef<str>,
+        F: FnOnce(&mut Animation) -> &mut Animation,
+        Self: Sized,
+    {
+        let mut animation = Animation::new(self, property);
+        configure(&mut animation).start();
+        animation
+    }
+}
+
+/// A handle to a `QObject` owned by someone else
+///
+/// `ObjectHandle`s are retrieved from a [`Variant`] with [`Variant::try_to_object`]. The
+/// handle does not own the object, and tracks its destruction: once the object is destroyed,
+/// [`is_null`] returns `true`, and the handle behaves like an object with no name, no class
+/// and no properties.
+///
+/// Properties are introspected by name, with [`property`] and [`set_property`].
+///
+/// [`Variant`]: ../variant/struct.Variant.html
+/// [`Variant::try_to_object`]: ../variant/struct.Variant.html#method.try_to_object
+/// [`is_null`]: #method.is_null
+/// [`property`]: #method.property
+/// [`set_property`]: #method.set_property
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(not(feature = "mock"))]
+/// # fn main() {
+/// use qt_binding::model::StringListModel;
+/// use qt_binding::object::Object;
+/// use qt_binding::variant::Variant;
+///
+/// let mut model = StringListModel::new();
+/// model.set_object_name("recentFiles");
+///
+/// let variant = Variant::from_object(&model);
+/// let handle = variant.try_to_object().unwrap();
+/// assert_eq!(handle.object_name(), "recentFiles");
+/// assert_eq!(handle.class_name(), "QStringListModel");
+/// # }
+/// # #[cfg(feature = "mock")]
+/// # fn main() {}
+/// ```
+pub struct ObjectHandle {
+    ptr: *mut c_void,
+}
+
+impl ObjectHandle {
+    /// If the object was destroyed
+    pub fn is_null(&self) -> bool {
+        self.as_object_ptr().is_null()
+    }
+
+    /// Name of the class of the object, like `QStringListModel`
+    ///
+    /// Empty if the object was destroyed.
+    pub fn class_name(&self) -> String {
+        ffi_span!("ObjectHandle", "class_name");
+        let mut name = String::new();
+        unsafe {
+            let data: *mut String = &mut name;
+            qt_binding_object_class_name(self.as_object_ptr(), data as *mut c_void, rs_string_fill);
+        }
+        name
+    }
+
+    /// Value of a property, or an invalid `Variant` if the property does not exist
+    pub fn property<S>(&self, name: S) -> Variant
+    where
+        S: AsRef<str>,
+    {
+        ffi_span!("ObjectHandle", "property");
+        let name = name.as_ref();
+        Variant::from_ptr(unsafe {
+            qt_binding_object_property(
+                self.as_object_ptr(),
+                name.as_ptr() as *const c_char,
+                name.len() as u32,
+            )
+        })
+    }
+
+    /// Set the value of a property
+    ///
+    /// Returns `false` if the property is read-only, or if the value can't be converted to the
+    /// type of the property. Setting a property that is not declared by the class of the
+    /// object adds a dynamic property.
+    pub fn set_property<S>(&mut self, name: S, value: &Variant) -> bool
+    where
+        S: AsRef<str>,
+    {
+        ffi_span!("ObjectHandle", "set_property");
+        let name = name.as_ref();
+        unsafe {
+            qt_binding_object_set_property(
+                self.as_object_ptr(),
+                name.as_ptr() as *const c_char,
+                name.len() as u32,
+                value.as_ptr(),
+            )
+        }
+    }
+}
+
+impl Object for ObjectHandle {
+    fn as_object_ptr(&self) -> *mut c_void {
+        ffi_span!("ObjectHandle", "as_object_ptr");
+        unsafe { qt_binding_object_handle_get(self.ptr) }
+    }
+}
+
+impl Clone for ObjectHandle {
+    fn clone(&self) -> Self {
+        ffi_span!("ObjectHandle", "clone");
+        track_created!(ObjectHandle);
+        ObjectHandle {
+            ptr: unsafe { qt_binding_object_handle_clone(self.ptr) },
+        }
+    }
+}
+
+impl fmt::Debug for ObjectHandle {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ObjectHandle")
+            .field("class_name", &self.class_name())
+            .field("object_name", &self.object_name())
+            .finish()
+    }
+}
+
+impl Drop for ObjectHandle {
+    fn drop(&mut self) {
+        ffi_span!("ObjectHandle", "drop");
+        #[cfg(not(feature = "mock"))]
+        if app::should_leak("ObjectHandle") {
+            return;
+        }
+        track_destroyed!(ObjectHandle);
+        unsafe { qt_binding_object_handle_delete(self.ptr) }
+    }
+}
+
+impl From<&'_ ObjectHandle> for Variant {
+    fn from(handle: &ObjectHandle) -> Self {
+        Variant::from_object(handle)
+    }
+}
+
+impl Variant {
+    /// Creates a `Variant` containing an object
+    ///
+    /// The `Variant` holds a `QObject *`, that QML uses as the object. It does not own the
+    /// object, that should outlive the `Variant`.
+    pub fn from_object<O>(object: &O) -> Self
+    where
+        O: Object,
+    {
+        ffi_span!("Variant", "from_object");
+        Variant::from_ptr(unsafe { qt_binding_variant_create_object(object.as_object_ptr()) })
+    }
+
+    /// Retrieve the object contained in a `Variant`
+    ///
+    /// Fails if the `Variant` does not contain a `QObject *`, or contains a null pointer.
+    pub fn try_to_object(&self) -> Result<ObjectHandle, TryFromError> {
+        ffi_span!("Variant", "try_to_object");
+        let ptr = unsafe { qt_binding_variant_to_object(self.as_ptr()) };
+        if ptr.is_null() {
+            Err(TryFromError)
+        } else {
+            track_created!(ObjectHandle);
+            Ok(ObjectHandle { ptr })
+        }
+    }
+}
+
+extern "C" {
+    fn qt_binding_object_name(object: *const c_void, output: *mut c_void, fill: RsStringFillFunc);
+    fn qt_binding_object_set_name(object: *mut c_void, name: *const c_char, size: u32);
+    fn qt_binding_object_class_name(
+        object: *const c_void,
+        output: *mut c_void,
+        fill: RsStringFillFunc,
+    );
+    fn qt_binding_object_property(
+        object: *const c_void,
+        name: *const c_char,
+        size: u32,
+    ) -> *mut c_void;
+    fn qt_binding_object_set_property(
+        object: *mut c_void,
+        name: *const c_char,
+        size: u32,
+        value: *const c_void,
+    ) -> bool;
+
+    fn qt_binding_object_handle_clone(handle: *const c_void) -> *mut c_void;
+    fn qt_binding_object_handle_delete(handle: *mut c_void);
+    fn qt_binding_object_handle_get(handle: *const c_void) -> *mut c_void;
+
+    fn qt_binding_variant_create_object(object: *mut c_void) -> *mut c_void;
+    fn qt_binding_variant_to_object(variant: *const c_void) -> *mut c_void;
+}
@@ -0,0 +1,131 @@
+#include <QtCore/QBuffer>
+#include <QtCore/QByteArray>
+#include <QtGui/QImage>
+#include <QtPdf/QPdfDocument>
+#include <cstdint>
+#include <memory>
+
+QT_USE_NAMESPACE
+
+using RsStringFillFunc = void (*)(void *output, const char *input, std::uint32_t inputSize);
+using RsImageFillFunc = void (*)(void *output, std::uint32_t width, std::uint32_t height,
+                                 const unsigned char *data, std::uint32_t bytesPerLine);
+
+static void fillString(const QString &value, void *output, RsStringFillFunc fill)
+{
+    auto utf8 = value.toUtf8();
+    fill(output, utf8.constData(), static_cast<std::uint32_t>(utf8.size()));
+}
+
+namespace qt_binding {
+
+class PdfDocument
+{
+public:
+    explicit PdfDocument(const QString &password)
+    {
+        m_document.setPassword(password);
+    }
+
+    int load(const QString &fileName)
+    {
+        return static_cast<int>(m_document.load(fileName));
+    }
+
+    int load(QByteArray data)
+    {
+        m_buffer.reset(new QBuffer());
+        m_buffer->setData(std::move(data));
+        m_buffer->open(QIODevice::ReadOnly);
+        m_document.load(m_buffer.get());
+        return static_cast<int>(m_document.error());
+    }
+
+    const QPdfDocument &document() const
+    {
+        return m_document;
+    }
+
+    QPdfDocument &document()
+    {
+        return m_document;
+    }
+
+private:
+    // The buffer must outlive the document
+    std::unique_ptr<QBuffer> m_buffer;
+    QPdfDocument m_document;
+};
+
+} // namespace qt_binding
+
+extern "C" {
+
+qt_binding::PdfDocument *qt_binding_pdf_document_create_from_path(const char *path,
+                                                                  std::uint32_t size,
+                                                                  const char *password,
+                                                                  std::uint32_t passwordSize,
+                                                                  int *error)
+{
+    auto document =
+        new qt_binding::PdfDocument(QString::fromUtf8(password, static_cast<int>(passwordSize)));
+    *error = document->load(QString::fromUtf8(path, static_cast<int>(size)));
+    return document;
+}
+
+qt_binding::PdfDocument *qt_binding_pdf_document_create_from_data(const char *data,
+                                                                  std::uint64_t size,
+                                                                  const char *password,
+                                                                  std::uint32_t passwordSize,
+                                                                  int *error)
+{
+    auto document =
+        new qt_binding::PdfDocument(QString::fromUtf8(password, static_cast<int>(passwordSize)));
+    // Sizes above INT_MAX are rejected by the caller
+    *error = document->load(QByteArray(data, static_cast<int>(size)));
+    return document;
+}
+
+void qt_binding_pdf_document_delete(qt_binding::PdfDocument *document)
+{
+    delete document;
+}
+
+int qt_binding_pdf_document_page_count(const qt_binding::PdfDocument *document)
+{
+    return document->document().pageCount();
+}
+
+void qt_binding_pdf_document_page_size(const qt_binding::PdfDocument *document, int page,
+                                       double *width, double *height)
+{
+    const auto size = document->document().pageSize(page);
+    *width = size.width();
+    *height = size.height();
+}
+
+void qt_binding_pdf_document_title(const qt_binding::PdfDocument *document, void *output,
+                                   RsStringFillFunc fill)
+{
+    const auto title = document->document().metaData(QPdfDocument::Title).toString();
+    fillString(title, output, fill);
+}
+
+void qt_binding_pdf_document_render(const qt_binding::PdfDocument *document, int page,
+                                    std::uint32_t width, std::uint32_t height, void *output,
+                                    RsImageFillFunc fill)
+{
+    // QPdfDocument::render is not const, as it might load the page
+    auto &pdf = const_cast<qt_binding::PdfDocument *>(document)->document();
+    const auto image = pdf.render(page, QSize(static_cast<int>(width), static_cast<int>(height)));
+    if (image.isNull()) {
+        return;
+    }
+
+    const auto converted = image.convertToFormat(QImage::Format_RGBA8888);
+    fill(output, static_cast<std::uint32_t>(converted.width()),
+         static_cast<std::uint32_t>(converted.height()), converted.constBits(),
+         static_cast<std::uint32_t>(converted.bytesPerLine()));
+}
+
+} // extern "C"
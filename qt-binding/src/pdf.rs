@@ -0,0 +1,310 @@
+//! PDF documents
+//!
+//! [`PdfDocument`] is a binding on Qt PDF's `QPdfDocument`. It opens PDF files, from a path or
+//! from bytes, and renders their pages to an [`Image`] with [`render_page`], for example to
+//! display them in a document viewer, without bundling another PDF library.
+//!
+//! [`PdfDocument`]: struct.PdfDocument.html
+//! [`Image`]: ../image/struct.Image.html
+//! [`render_page`]: struct.PdfDocument.html#method.render_page
+//!
+//! # Features
+//!
+//! This module is only available with the `pdf` feature.
+//!
+//! # Limitations
+//!
+//! Qt PDF is distributed with Qt WebEngine, starting from Qt 5.15, and might not be installed
+//! with Qt.
+//!
+//! # Examples
+//!
+//! Rendering the first page at 150 DPI
+//!
+//! ```no_run
+//! use qt_binding::app::Application;
+//! use qt_binding::pdf::PdfDocument;
+//!
+//! let _app = Application::new();
+//! let document = PdfDocument::open("manual.pdf").unwrap();
+//! if let Some((width, height)) = document.page_size(0) {
+//!     let scale = 150. / 72.;
+//!     let image = document.render_page(0, (width * scale) as u32, (height * scale) as u32);
+//!     println!("{} pages, first page {:?}", document.page_count(), image.map(|i| i.width()));
+//! }
+//! ```
+
+use crate::app;
+use crate::image::{rs_image_fill, Image, RsImageFillFunc};
+use crate::variant::convert::{rs_string_fill, RsStringFillFunc};
+use std::error;
+use std::fmt;
+use std::os::raw::{c_char, c_int, c_void};
+use std::path::Path;
+use std::ptr;
+
+/// Error reported when opening a PDF document
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Error {
+    /// The file does not exist
+    FileNotFound,
+    /// The file is not a valid PDF document
+    InvalidFormat,
+    /// The document is encrypted, and the password is missing or incorrect
+    IncorrectPassword,
+    /// The document is encrypted with an unsupported scheme
+    UnsupportedSecurityScheme,
+    /// The content is larger than the 2 GiB that Qt can load from memory
+    TooLarge,
+    /// Another error
+    Unknown,
+}
+
+impl Error {
+    fn from_value(value: c_int) -> Option<Self> {
+        // See QPdfDocument::DocumentError
+        match value {
+            0 => None,
+            2 => Some(Error::FileNotFound),
+            3 => Some(Error::InvalidFormat),
+            4 => Some(Error::IncorrectPassword),
+            5 => Some(Error::UnsupportedSecurityScheme),
+            _ => Some(Error::Unknown),
+        }
+    }
+
+    fn check_size(size: usize) -> Result<(), Self> {
+        // QByteArray is indexed with an int
+        if size > c_int::MAX as usize {
+            Err(Error::TooLarge)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let message = match self {
+            Error::FileNotFound => "File not found",
+            Error::InvalidFormat => "Invalid PDF document",
+            Error::IncorrectPassword => "Incorrect password",
+            Error::UnsupportedSecurityScheme => "Unsupported security scheme",
+            Error::TooLarge => "Document too large",
+            Error::Unknown => "Unknown error",
+        };
+        write!(f, "{}", message)
+    }
+}
+
+impl error::Error for Error {}
+
+/// Binding to `QPdfDocument`
+///
+/// See module level documentation for more information.
+pub struct PdfDocument {
+    ptr: *mut c_void,
+}
+
+impl PdfDocument {
+    /// Open a PDF file
+    ///
+    /// Paths starting with `:/` are read from Qt resources.
+    pub fn open<P>(path: P) -> Result<Self, Error>
+    where
+        P: AsRef<Path>,
+    {
+        PdfDocument::open_with_password(path, "")
+    }
+
+    /// Open an encrypted PDF file
+    pub fn open_with_password<P, S>(path: P, password: S) -> Result<Self, Error>
+    where
+        P: AsRef<Path>,
+        S: AsRef<str>,
+    {
+        ffi_span!("PdfDocument", "open");
+        let path = path.as_ref().to_string_lossy();
+        let password = password.as_ref();
+        let mut error = 0;
+        let ptr = unsafe {
+            qt_binding_pdf_document_create_from_path(
+                path.as_ptr() as *const c_char,
+                path.len() as u32,
+                password.as_ptr() as *const c_char,
+                password.len() as u32,
+                &mut error,
+            )
+        };
+        PdfDocument::checked(ptr, error)
+    }
+
+    /// Open a PDF document from its content
+    ///
+    /// The content is copied. Content larger than 2 GiB is rejected with [`Error::TooLarge`].
+    ///
+    /// [`Error::TooLarge`]: enum.Error.html#variant.TooLarge
+    pub fn from_bytes(data: &[u8]) -> Result<Self, Error> {
+        ffi_span!("PdfDocument", "from_bytes");
+        Error::check_size(data.len())?;
+        let mut error = 0;
+        let ptr = unsafe {
+            qt_binding_pdf_document_create_from_data(
+                data.as_ptr() as *const c_char,
+                data.len() as u64,
+                ptr::null(),
+                0,
+                &mut error,
+            )
+        };
+        PdfDocument::checked(ptr, error)
+    }
+
+    /// Number of pages
+    pub fn page_count(&self) -> usize {
+        ffi_span!("PdfDocument", "page_count");
+        unsafe { qt_binding_pdf_document_page_count(self.ptr).max(0) as usize }
+    }
+
+    /// Size of a page, in points, as width and height, `None` if out of bounds
+    ///
+    /// There are 72 points per inch.
+    pub fn page_size(&self, page: usize) -> Option<(f64, f64)> {
+        ffi_span!("PdfDocument", "page_size");
+        if page >= self.page_count() {
+            return None;
+        }
+
+        let mut width = 0.;
+        let mut height = 0.;
+        unsafe {
+            qt_binding_pdf_document_page_size(self.ptr, page as c_int, &mut width, &mut height)
+        };
+        Some((width, height))
+    }
+
+    /// Title of the document, from its metadata
+    pub fn title(&self) -> String {
+        ffi_span!("PdfDocument", "title");
+        let mut title = String::new();
+        unsafe {
+            let data: *mut String = &mut title;
+            qt_binding_pdf_document_title(self.ptr, data as *mut c_void, rs_string_fill);
+        }
+        title
+    }
+
+    /// Render a page to an image of a given size, `None` if out of bounds
+    ///
+    /// The page is scaled to the size of the image.
+    pub fn render_page(&self, page: usize, width: u32, height: u32) -> Option<Image> {
+        ffi_span!("PdfDocument", "render_page");
+        if page >= self.page_count() {
+            return None;
+        }
+
+        let mut image = None;
+        unsafe {
+            let data: *mut Option<Image> = &mut image;
+            qt_binding_pdf_document_render(
+                self.ptr,
+                page as c_int,
+                width,
+                height,
+                data as *mut c_void,
+                rs_image_fill,
+            )
+        };
+        image
+    }
+
+    fn checked(ptr: *mut c_void, error: c_int) -> Result<Self, Error> {
+        track_created!(PdfDocument);
+        let document = PdfDocument { ptr };
+        match Error::from_value(error) {
+            Some(error) => Err(error),
+            None => Ok(document),
+        }
+    }
+}
+
+impl fmt::Debug for PdfDocument {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("PdfDocument")
+            .field("title", &self.title())
+            .field("page_count", &self.page_count())
+            .finish()
+    }
+}
+
+impl Drop for PdfDocument {
+    fn drop(&mut self) {
+        ffi_span!("PdfDocument", "drop");
+        if app::should_leak("PdfDocument") {
+            return;
+        }
+        track_destroyed!(PdfDocument);
+        unsafe { qt_binding_pdf_document_delete(self.ptr) }
+    }
+}
+
+extern "C" {
+    fn qt_binding_pdf_document_create_from_path(
+        path: *const c_char,
+        size: u32,
+        password: *const c_char,
+        password_size: u32,
+        error: *mut c_int,
+    ) -> *mut c_void;
+    fn qt_binding_pdf_document_create_from_data(
+        data: *const c_char,
+        size: u64,
+        password: *const c_char,
+        password_size: u32,
+        error: *mut c_int,
+    ) -> *mut c_void;
+    fn qt_binding_pdf_document_delete(document: *mut c_void);
+    fn qt_binding_pdf_document_page_count(document: *const c_void) -> c_int;
+    fn qt_binding_pdf_document_page_size(
+        document: *const c_void,
+        page: c_int,
+        width: *mut f64,
+        height: *mut f64,
+    );
+    fn qt_binding_pdf_document_title(
+        document: *const c_void,
+        output: *mut c_void,
+        fill: RsStringFillFunc,
+    );
+    fn qt_binding_pdf_document_render(
+        document: *const c_void,
+        page: c_int,
+        width: u32,
+        height: u32,
+        output: *mut c_void,
+        fill: RsImageFillFunc,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_error_from_value() {
+        assert_eq!(Error::from_value(0), None);
+        assert_eq!(Error::from_value(2), Some(Error::FileNotFound));
+        assert_eq!(Error::from_value(4), Some(Error::IncorrectPassword));
+        assert_eq!(Error::from_value(1), Some(Error::Unknown));
+    }
+
+    #[test]
+    fn test_check_size() {
+        assert_eq!(Error::check_size(0), Ok(()));
+        assert_eq!(Error::check_size(c_int::MAX as usize), Ok(()));
+        assert_eq!(
+            Error::check_size(c_int::MAX as usize + 1),
+            Err(Error::TooLarge)
+        );
+    }
+}
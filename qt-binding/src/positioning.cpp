@@ -0,0 +1,131 @@
+#include <QtPositioning/QGeoPositionInfoSource>
+#include <cmath>
+#include <cstdint>
+
+QT_USE_NAMESPACE
+
+using RsPositionFunc = void (*)(void *data, double latitude, double longitude, double altitude,
+                                double horizontalAccuracy, double verticalAccuracy, double speed,
+                                double direction, std::int64_t timestamp);
+using RsMessageFunc = void (*)(void *data, const char *message, std::uint32_t size);
+using RsStringFillFunc = void (*)(void *output, const char *input, std::uint32_t inputSize);
+
+static double attribute(const QGeoPositionInfo &info, QGeoPositionInfo::Attribute attribute)
+{
+    return info.hasAttribute(attribute) ? info.attribute(attribute) : NAN;
+}
+
+static void sendPosition(void *data, RsPositionFunc func, const QGeoPositionInfo &info)
+{
+    const auto coordinate = info.coordinate();
+    func(data, coordinate.latitude(), coordinate.longitude(), coordinate.altitude(),
+         attribute(info, QGeoPositionInfo::HorizontalAccuracy),
+         attribute(info, QGeoPositionInfo::VerticalAccuracy),
+         attribute(info, QGeoPositionInfo::GroundSpeed),
+         attribute(info, QGeoPositionInfo::Direction), info.timestamp().toMSecsSinceEpoch());
+}
+
+static void sendMessage(void *data, RsMessageFunc func, const QString &message)
+{
+    const auto byteArray = message.toUtf8();
+    func(data, byteArray.constData(), static_cast<std::uint32_t>(byteArray.size()));
+}
+
+static QString errorMessage(QGeoPositionInfoSource::Error error)
+{
+    switch (error) {
+    case QGeoPositionInfoSource::AccessError:
+        return QStringLiteral("Access to the position source is denied");
+    case QGeoPositionInfoSource::ClosedError:
+        return QStringLiteral("The position source was closed");
+    case QGeoPositionInfoSource::NoError:
+        return QString();
+    default:
+        return QStringLiteral("Unknown position source error");
+    }
+}
+
+extern "C" {
+
+QGeoPositionInfoSource *qt_binding_position_source_create(const char *name, std::uint32_t size,
+                                                          void *data,
+                                                          RsPositionFunc positionUpdated,
+                                                          RsMessageFunc error)
+{
+    auto source = size > 0 ? QGeoPositionInfoSource::createSource(
+                                 QString::fromUtf8(name, static_cast<int>(size)), nullptr)
+                           : QGeoPositionInfoSource::createDefaultSource(nullptr);
+    if (!source) {
+        return nullptr;
+    }
+
+    QObject::connect(source, &QGeoPositionInfoSource::positionUpdated,
+                     [data, positionUpdated](const QGeoPositionInfo &info) {
+                         sendPosition(data, positionUpdated, info);
+                     });
+    QObject::connect(source,
+                     static_cast<void (QGeoPositionInfoSource::*)(QGeoPositionInfoSource::Error)>(
+                         &QGeoPositionInfoSource::error),
+                     [data, error](QGeoPositionInfoSource::Error value) {
+                         sendMessage(data, error, errorMessage(value));
+                     });
+    QObject::connect(source, &QGeoPositionInfoSource::updateTimeout, [data, error]() {
+        sendMessage(data, error, QStringLiteral("Position update timed out"));
+    });
+    return source;
+}
+
+void qt_binding_position_source_delete(QGeoPositionInfoSource *source)
+{
+    delete source;
+}
+
+void qt_binding_position_source_name(const QGeoPositionInfoSource *source, void *name,
+                                     RsStringFillFunc fill)
+{
+    const auto byteArray = source->sourceName().toUtf8();
+    fill(name, byteArray.constData(), static_cast<std::uint32_t>(byteArray.size()));
+}
+
+void qt_binding_position_source_set_update_interval(QGeoPositionInfoSource *source, int interval)
+{
+    source->setUpdateInterval(interval);
+}
+
+int qt_binding_position_source_update_interval(const QGeoPositionInfoSource *source)
+{
+    return source->updateInterval();
+}
+
+int qt_binding_position_source_minimum_update_interval(const QGeoPositionInfoSource *source)
+{
+    return source->minimumUpdateInterval();
+}
+
+void qt_binding_position_source_start(QGeoPositionInfoSource *source)
+{
+    source->startUpdates();
+}
+
+void qt_binding_position_source_stop(QGeoPositionInfoSource *source)
+{
+    source->stopUpdates();
+}
+
+void qt_binding_position_source_request_update(QGeoPositionInfoSource *source, int timeout)
+{
+    source->requestUpdate(timeout);
+}
+
+bool qt_binding_position_source_last_known_position(const QGeoPositionInfoSource *source,
+                                                    void *data, RsPositionFunc position)
+{
+    const auto info = source->lastKnownPosition();
+    if (!info.isValid()) {
+        return false;
+    }
+    sendPosition(data, position, info);
+    return true;
+}
+
+} // extern "C"
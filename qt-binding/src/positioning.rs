@@ -0,0 +1,509 @@
+//! Bindings to Qt Positioning
+//!
+//! [`PositionSource`] is a binding to `QGeoPositionInfoSource`, that provides the position of
+//! the device, from satellites, network or any other positioning plugin available on the
+//! platform. Positions are described by [`Position`].
+//!
+//! Updates are asynchronous and integrated with Qt's event-loop. They are passed to a handler,
+//! registered with [`on_position_updated`]. With the `futures-executor` feature, updates can
+//! also be received as a `Stream`, with [`PositionSource::positions`], and processed by futures
+//! spawned with [`Application::spawn`].
+//!
+//! [`PositionSource`]: struct.PositionSource.html
+//! [`Position`]: struct.Position.html
+//! [`on_position_updated`]: struct.PositionSource.html#method.on_position_updated
+//! [`PositionSource::positions`]: struct.PositionSource.html#method.positions
+//! [`Application::spawn`]: ../app/struct.Application.html#method.spawn
+//!
+//! # Features
+//!
+//! This module is only available with the `positioning` feature.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use qt_binding::app::Application;
+//! use qt_binding::positioning::PositionSource;
+//! use std::time::Duration;
+//!
+//! let mut app = Application::new();
+//!
+//! let mut source = PositionSource::new().unwrap();
+//! source.set_update_interval(Duration::from_secs(5));
+//! source.on_position_updated(|position| {
+//!     println!("At {}, {}", position.latitude(), position.longitude());
+//! });
+//! source.start();
+//!
+//! app.exec();
+//! ```
+
+use crate::app;
+use crate::variant::convert::{rs_string_fill, RsStringFillFunc};
+use std::error;
+use std::fmt;
+use std::os::raw::{c_char, c_int, c_void};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+#[cfg(feature = "futures-executor")]
+use crate::channel;
+#[cfg(feature = "futures-executor")]
+use futures_core::stream::Stream;
+#[cfg(feature = "futures-executor")]
+use std::pin::Pin;
+#[cfg(feature = "futures-executor")]
+use std::task::{Context, Poll};
+
+type PositionHandler = Option<Box<dyn FnMut(&Position)>>;
+type ErrorHandler = Option<Box<dyn FnMut(Error)>>;
+
+/// Error reported by Qt Positioning
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Error {
+    message: String,
+}
+
+impl Error {
+    /// Error message
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl error::Error for Error {}
+
+/// Position of the device
+///
+/// Binding to `QGeoPositionInfo`. Coordinates are in degrees, using the WGS84 datum. Optional
+/// values are `None` when they are not provided by the position source.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Position {
+    latitude: f64,
+    longitude: f64,
+    altitude: Option<f64>,
+    horizontal_accuracy: Option<f64>,
+    vertical_accuracy: Option<f64>,
+    speed: Option<f64>,
+    direction: Option<f64>,
+    timestamp: SystemTime,
+}
+
+impl Position {
+    /// Latitude, in degrees
+    pub fn latitude(&self) -> f64 {
+        self.latitude
+    }
+
+    /// Longitude, in degrees
+    pub fn longitude(&self) -> f64 {
+        self.longitude
+    }
+
+    /// Altitude above sea level, in meters
+    pub fn altitude(&self) -> Option<f64> {
+        self.altitude
+    }
+
+    /// Accuracy of the latitude and longitude, in meters
+    pub fn horizontal_accuracy(&self) -> Option<f64> {
+        self.horizontal_accuracy
+    }
+
+    /// Accuracy of the altitude, in meters
+    pub fn vertical_accuracy(&self) -> Option<f64> {
+        self.vertical_accuracy
+    }
+
+    /// Ground speed, in meters per second
+    pub fn speed(&self) -> Option<f64> {
+        self.speed
+    }
+
+    /// Bearing to true north, in degrees
+    pub fn direction(&self) -> Option<f64> {
+        self.direction
+    }
+
+    /// Time at which the position was measured
+    pub fn timestamp(&self) -> SystemTime {
+        self.timestamp
+    }
+}
+
+#[derive(Default)]
+struct Handlers {
+    position_updated: PositionHandler,
+    error: ErrorHandler,
+    #[cfg(feature = "futures-executor")]
+    positions: Vec<channel::Sender<Position>>,
+}
+
+/// Binding to `QGeoPositionInfoSource`
+///
+/// See module level documentation for more information.
+pub struct PositionSource {
+    ptr: *mut c_void,
+    handlers: Box<Handlers>,
+}
+
+impl PositionSource {
+    /// Creates the default `PositionSource` of the platform
+    ///
+    /// Returns an error if no position source is available.
+    pub fn new() -> Result<Self, Error> {
+        PositionSource::create("")
+    }
+
+    /// Creates a `PositionSource` from a positioning plugin, like `geoclue2` or `serialnmea`
+    ///
+    /// Returns an error if the plugin is not available.
+    pub fn with_name<S>(name: S) -> Result<Self, Error>
+    where
+        S: AsRef<str>,
+    {
+        PositionSource::create(name.as_ref())
+    }
+
+    fn create(name: &str) -> Result<Self, Error> {
+        ffi_span!("PositionSource", "new");
+        let mut handlers = Box::new(Handlers::default());
+        let data: *mut Handlers = &mut *handlers;
+        let ptr = unsafe {
+            qt_binding_position_source_create(
+                name.as_ptr() as *const c_char,
+                name.len() as u32,
+                data as *mut c_void,
+                rs_position_source_position_updated,
+                rs_position_source_error,
+            )
+        };
+        if ptr.is_null() {
+            Err(Error {
+                message: if name.is_empty() {
+                    "No position source is available".to_string()
+                } else {
+                    format!("Position source {} is not available", name)
+                },
+            })
+        } else {
+            track_created!(PositionSource);
+            Ok(PositionSource { ptr, handlers })
+        }
+    }
+
+    /// Name of the positioning plugin
+    pub fn name(&self) -> String {
+        ffi_span!("PositionSource", "name");
+        let mut name = String::new();
+        unsafe {
+            let output: *mut String = &mut name;
+            qt_binding_position_source_name(self.ptr, output as *mut c_void, rs_string_fill);
+        }
+        name
+    }
+
+    /// Set the interval between updates
+    ///
+    /// Intervals smaller than the [`minimum_update_interval`] are clamped. A zero interval lets
+    /// the source choose the update interval.
+    ///
+    /// [`minimum_update_interval`]: #method.minimum_update_interval
+    pub fn set_update_interval(&mut self, interval: Duration) -> &mut Self {
+        ffi_span!("PositionSource", "set_update_interval");
+        unsafe {
+            qt_binding_position_source_set_update_interval(self.ptr, to_msecs(interval));
+        }
+        self
+    }
+
+    /// Interval between updates
+    pub fn update_interval(&self) -> Duration {
+        ffi_span!("PositionSource", "update_interval");
+        let interval = unsafe { qt_binding_position_source_update_interval(self.ptr) };
+        Duration::from_millis(interval.max(0) as u64)
+    }
+
+    /// Minimum interval between updates supported by the source
+    pub fn minimum_update_interval(&self) -> Duration {
+        ffi_span!("PositionSource", "minimum_update_interval");
+        let interval = unsafe { qt_binding_position_source_minimum_update_interval(self.ptr) };
+        Duration::from_millis(interval.max(0) as u64)
+    }
+
+    /// Start receiving regular updates
+    pub fn start(&mut self) {
+        ffi_span!("PositionSource", "start");
+        unsafe { qt_binding_position_source_start(self.ptr) }
+    }
+
+    /// Stop receiving regular updates
+    pub fn stop(&mut self) {
+        ffi_span!("PositionSource", "stop");
+        unsafe { qt_binding_position_source_stop(self.ptr) }
+    }
+
+    /// Request a single update
+    ///
+    /// An error is reported if no position is available before the timeout. A zero timeout lets
+    /// the source choose the timeout.
+    pub fn request_update(&mut self, timeout: Duration) {
+        ffi_span!("PositionSource", "request_update");
+        unsafe { qt_binding_position_source_request_update(self.ptr, to_msecs(timeout)) }
+    }
+
+    /// Last known position
+    ///
+    /// Returns `None` if no position was ever received.
+    pub fn last_known_position(&self) -> Option<Position> {
+        ffi_span!("PositionSource", "last_known_position");
+        let mut position = None;
+        let data: *mut Option<Position> = &mut position;
+        unsafe {
+            qt_binding_position_source_last_known_position(
+                self.ptr,
+                data as *mut c_void,
+                rs_position_source_last_known_position,
+            );
+        }
+        position
+    }
+
+    /// Set the handler called when the position is updated
+    pub fn on_position_updated<F>(&mut self, handler: F) -> &mut Self
+    where
+        F: FnMut(&Position) + 'static,
+    {
+        self.handlers.position_updated = Some(Box::new(handler));
+        self
+    }
+
+    /// Set the handler called when an error occurs
+    ///
+    /// Errors are also reported when a requested update timed out.
+    pub fn on_error<F>(&mut self, handler: F) -> &mut Self
+    where
+        F: FnMut(Error) + 'static,
+    {
+        self.handlers.error = Some(Box::new(handler));
+        self
+    }
+
+    /// Receive position updates as a `Stream`
+    ///
+    /// The stream ends when the `PositionSource` is dropped. Updates still need to be started
+    /// with [`start`] or [`request_update`].
+    ///
+    /// [`start`]: #method.start
+    /// [`request_update`]: #method.request_update
+    ///
+    /// # Features
+    ///
+    /// This method is only available with the `futures-executor` feature.
+    #[cfg(feature = "futures-executor")]
+    pub fn positions(&mut self) -> Positions {
+        let (sender, receiver) = channel::channel();
+        self.handlers.positions.push(sender);
+        Positions { receiver }
+    }
+}
+
+impl fmt::Debug for PositionSource {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("PositionSource")
+            .field("name", &self.name())
+            .field("update_interval", &self.update_interval())
+            .finish()
+    }
+}
+
+impl Drop for PositionSource {
+    fn drop(&mut self) {
+        ffi_span!("PositionSource", "drop");
+        if !app::should_leak("PositionSource") {
+            track_destroyed!(PositionSource);
+            unsafe { qt_binding_position_source_delete(self.ptr) }
+        }
+        #[cfg(feature = "futures-executor")]
+        {
+            for sender in &self.handlers.positions {
+                sender.close();
+            }
+        }
+    }
+}
+
+/// Stream of position updates
+///
+/// See [`PositionSource::positions`] for more information.
+///
+/// [`PositionSource::positions`]: struct.PositionSource.html#method.positions
+#[cfg(feature = "futures-executor")]
+pub struct Positions {
+    receiver: channel::Receiver<Position>,
+}
+
+#[cfg(feature = "futures-executor")]
+impl Stream for Positions {
+    type Item = Position;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        Pin::new(&mut self.receiver).poll_next(cx)
+    }
+}
+
+fn to_msecs(duration: Duration) -> c_int {
+    duration.as_millis().min(c_int::MAX as u128) as c_int
+}
+
+fn to_option(value: f64) -> Option<f64> {
+    if value.is_nan() {
+        None
+    } else {
+        Some(value)
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn to_position(
+    latitude: f64,
+    longitude: f64,
+    altitude: f64,
+    horizontal_accuracy: f64,
+    vertical_accuracy: f64,
+    speed: f64,
+    direction: f64,
+    timestamp: i64,
+) -> Position {
+    Position {
+        latitude,
+        longitude,
+        altitude: to_option(altitude),
+        horizontal_accuracy: to_option(horizontal_accuracy),
+        vertical_accuracy: to_option(vertical_accuracy),
+        speed: to_option(speed),
+        direction: to_option(direction),
+        timestamp: UNIX_EPOCH + Duration::from_millis(timestamp.max(0) as u64),
+    }
+}
+
+fn to_string(input: *const c_char, size: u32) -> String {
+    let mut value = String::new();
+    let output: *mut String = &mut value;
+    rs_string_fill(output as *mut c_void, input, size);
+    value
+}
+
+#[allow(clippy::too_many_arguments)]
+extern "C" fn rs_position_source_position_updated(
+    data: *mut c_void,
+    latitude: f64,
+    longitude: f64,
+    altitude: f64,
+    horizontal_accuracy: f64,
+    vertical_accuracy: f64,
+    speed: f64,
+    direction: f64,
+    timestamp: i64,
+) {
+    let handlers = unsafe { &mut *(data as *mut Handlers) };
+    let position = to_position(
+        latitude,
+        longitude,
+        altitude,
+        horizontal_accuracy,
+        vertical_accuracy,
+        speed,
+        direction,
+        timestamp,
+    );
+    if let Some(handler) = &mut handlers.position_updated {
+        handler(&position);
+    }
+    #[cfg(feature = "futures-executor")]
+    {
+        handlers.positions.retain(|sender| !sender.is_dropped());
+        for sender in &handlers.positions {
+            sender.send(position);
+        }
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+extern "C" fn rs_position_source_last_known_position(
+    data: *mut c_void,
+    latitude: f64,
+    longitude: f64,
+    altitude: f64,
+    horizontal_accuracy: f64,
+    vertical_accuracy: f64,
+    speed: f64,
+    direction: f64,
+    timestamp: i64,
+) {
+    let position = unsafe { &mut *(data as *mut Option<Position>) };
+    *position = Some(to_position(
+        latitude,
+        longitude,
+        altitude,
+        horizontal_accuracy,
+        vertical_accuracy,
+        speed,
+        direction,
+        timestamp,
+    ));
+}
+
+extern "C" fn rs_position_source_error(data: *mut c_void, message: *const c_char, size: u32) {
+    let handlers = unsafe { &mut *(data as *mut Handlers) };
+    if let Some(handler) = &mut handlers.error {
+        handler(Error {
+            message: to_string(message, size),
+        });
+    }
+}
+
+type RsPositionFunc = extern "C" fn(
+    data: *mut c_void,
+    latitude: f64,
+    longitude: f64,
+    altitude: f64,
+    horizontal_accuracy: f64,
+    vertical_accuracy: f64,
+    speed: f64,
+    direction: f64,
+    timestamp: i64,
+);
+type RsMessageFunc = extern "C" fn(data: *mut c_void, message: *const c_char, size: u32);
+
+extern "C" {
+    fn qt_binding_position_source_create(
+        name: *const c_char,
+        size: u32,
+        data: *mut c_void,
+        position_updated: RsPositionFunc,
+        error: RsMessageFunc,
+    ) -> *mut c_void;
+    fn qt_binding_position_source_delete(source: *mut c_void);
+    fn qt_binding_position_source_name(
+        source: *const c_void,
+        name: *mut c_void,
+        fill: RsStringFillFunc,
+    );
+    fn qt_binding_position_source_set_update_interval(source: *mut c_void, interval: c_int);
+    fn qt_binding_position_source_update_interval(source: *const c_void) -> c_int;
+    fn qt_binding_position_source_minimum_update_interval(source: *const c_void) -> c_int;
+    fn qt_binding_position_source_start(source: *mut c_void);
+    fn qt_binding_position_source_stop(source: *mut c_void);
+    fn qt_binding_position_source_request_update(source: *mut c_void, timeout: c_int);
+    fn qt_binding_position_source_last_known_position(
+        source: *const c_void,
+        data: *mut c_void,
+        position: RsPositionFunc,
+    ) -> bool;
+}
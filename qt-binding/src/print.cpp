@@ -0,0 +1,98 @@
+#include <QtGui/QPageLayout>
+#include <QtGui/QPageSize>
+#include <QtGui/QTextDocument>
+#include <QtPrintSupport/QPrintDialog>
+#include <QtPrintSupport/QPrinter>
+#include <QtPrintSupport/QPrinterInfo>
+#include <cstdint>
+
+QT_USE_NAMESPACE
+
+using RsStringFillFunc = void (*)(void *output, const char *input, std::uint32_t inputSize);
+using RsPrinterInfoAppendFunc = void (*)(void *output, const char *name, std::uint32_t nameSize,
+                                         const char *description,
+                                         std::uint32_t descriptionSize, bool isDefault);
+
+static void fillString(const QString &value, void *output, RsStringFillFunc fill)
+{
+    auto utf8 = value.toUtf8();
+    fill(output, utf8.constData(), static_cast<std::uint32_t>(utf8.size()));
+}
+
+extern "C" {
+
+void qt_binding_print_printers(void *output, RsPrinterInfoAppendFunc append)
+{
+    for (const auto &info : QPrinterInfo::availablePrinters()) {
+        const auto name = info.printerName().toUtf8();
+        const auto description = info.description().toUtf8();
+        append(output, name.constData(), static_cast<std::uint32_t>(name.size()),
+               description.constData(), static_cast<std::uint32_t>(description.size()),
+               info.isDefault());
+    }
+}
+
+QPrinter *qt_binding_printer_create()
+{
+    return new QPrinter(QPrinter::HighResolution);
+}
+
+void qt_binding_printer_delete(QPrinter *printer)
+{
+    delete printer;
+}
+
+void qt_binding_printer_set_printer_name(QPrinter *printer, const char *name, std::uint32_t size)
+{
+    printer->setPrinterName(QString::fromUtf8(name, static_cast<int>(size)));
+}
+
+void qt_binding_printer_printer_name(const QPrinter *printer, void *output, RsStringFillFunc fill)
+{
+    fillString(printer->printerName(), output, fill);
+}
+
+void qt_binding_printer_set_document_name(QPrinter *printer, const char *name, std::uint32_t size)
+{
+    printer->setDocName(QString::fromUtf8(name, static_cast<int>(size)));
+}
+
+void qt_binding_printer_set_page_size(QPrinter *printer, double width, double height)
+{
+    printer->setPageSize(QPageSize(QSizeF(width, height), QPageSize::Millimeter));
+}
+
+void qt_binding_printer_set_landscape(QPrinter *printer, bool landscape)
+{
+    printer->setPageOrientation(landscape ? QPageLayout::Landscape : QPageLayout::Portrait);
+}
+
+void qt_binding_printer_set_margins(QPrinter *printer, double left, double top, double right,
+                                    double bottom)
+{
+    printer->setPageMargins(QMarginsF(left, top, right, bottom), QPageLayout::Millimeter);
+}
+
+void qt_binding_printer_set_copies(QPrinter *printer, std::uint32_t copies)
+{
+    printer->setCopyCount(static_cast<int>(copies));
+}
+
+void qt_binding_printer_set_output_file(QPrinter *printer, const char *path, std::uint32_t size)
+{
+    printer->setOutputFormat(QPrinter::PdfFormat);
+    printer->setOutputFileName(QString::fromUtf8(path, static_cast<int>(size)));
+}
+
+bool qt_binding_printer_exec_dialog(QPrinter *printer)
+{
+    QPrintDialog dialog(printer);
+    return dialog.exec() == QDialog::Accepted;
+}
+
+void qt_binding_printer_print_document(QPrinter *printer, const QTextDocument *document)
+{
+    document->print(printer);
+}
+
+} // extern "C"
@@ -0,0 +1,317 @@
+//! Printing
+//!
+//! [`Printer`] is a binding on Qt's `QPrinter`. It selects a printer, configures the page
+//! layout and the number of copies, shows the native print dialog with [`exec_dialog`], and
+//! prints a [`TextDocument`] with [`print_document`]. Available printers are listed with
+//! [`printers`].
+//!
+//! Printers can also print to a PDF file, with [`set_output_file`], for example to preview
+//! printed documents.
+//!
+//! [`Printer`]: struct.Printer.html
+//! [`exec_dialog`]: struct.Printer.html#method.exec_dialog
+//! [`TextDocument`]: ../text/struct.TextDocument.html
+//! [`print_document`]: struct.Printer.html#method.print_document
+//! [`printers`]: fn.printers.html
+//! [`set_output_file`]: struct.Printer.html#method.set_output_file
+//!
+//! # Features
+//!
+//! This module is only available with the `printsupport` feature.
+//!
+//! # Limitations
+//!
+//! The print dialog is a Qt Widgets dialog, and requires an [`Application`] created with the
+//! `widgets` feature. Pages are only painted from a [`TextDocument`].
+//!
+//! [`Application`]: ../app/struct.Application.html
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use qt_binding::app::Application;
+//! use qt_binding::print::{Orientation, Printer};
+//! use qt_binding::text::{PageSize, TextDocument};
+//!
+//! let _app = Application::new();
+//! let document = TextDocument::from_html("<h1>Invoice</h1><p>Total: 42 €</p>");
+//!
+//! let mut printer = Printer::new();
+//! printer
+//!     .set_document_name("Invoice")
+//!     .set_page_size(PageSize::A4)
+//!     .set_orientation(Orientation::Portrait);
+//! if printer.exec_dialog() {
+//!     printer.print_document(&document);
+//! }
+//! ```
+
+use crate::app;
+use crate::text::{PageSize, TextDocument};
+use crate::variant::convert::{rs_string_fill, RsStringFillFunc};
+use std::fmt;
+use std::os::raw::{c_char, c_void};
+use std::path::Path;
+use std::slice;
+
+/// A printer available on the system
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PrinterInfo {
+    name: String,
+    description: String,
+    is_default: bool,
+}
+
+impl PrinterInfo {
+    /// Name of the printer, used by [`Printer::with_printer`]
+    ///
+    /// [`Printer::with_printer`]: struct.Printer.html#method.with_printer
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Human readable description of the printer
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+
+    /// If the printer is the default printer of the system
+    pub fn is_default(&self) -> bool {
+        self.is_default
+    }
+}
+
+/// Printers available on the system
+pub fn printers() -> Vec<PrinterInfo> {
+    ffi_span!("print", "printers");
+    let mut printers = Vec::new();
+    unsafe {
+        let data: *mut Vec<PrinterInfo> = &mut printers;
+        qt_binding_print_printers(data as *mut c_void, rs_printer_info_append);
+    }
+    printers
+}
+
+/// Orientation of the pages
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Orientation {
+    /// Pages are taller than wide
+    Portrait,
+    /// Pages are wider than tall
+    Landscape,
+}
+
+/// Binding to `QPrinter`
+///
+/// See module level documentation for more information.
+pub struct Printer {
+    ptr: *mut c_void,
+}
+
+impl Printer {
+    /// Creates a `Printer` printing to the default printer of the system
+    pub fn new() -> Self {
+        ffi_span!("Printer", "new");
+        track_created!(Printer);
+        Printer {
+            ptr: unsafe { qt_binding_printer_create() },
+        }
+    }
+
+    /// Creates a `Printer` printing to a printer, by name
+    ///
+    /// See [`printers`].
+    ///
+    /// [`printers`]: fn.printers.html
+    pub fn with_printer<S>(name: S) -> Self
+    where
+        S: AsRef<str>,
+    {
+        ffi_span!("Printer", "with_printer");
+        let name = name.as_ref();
+        let printer = Printer::new();
+        unsafe {
+            qt_binding_printer_set_printer_name(
+                printer.ptr,
+                name.as_ptr() as *const c_char,
+                name.len() as u32,
+            )
+        };
+        printer
+    }
+
+    /// Name of the selected printer, empty when printing to a file
+    pub fn printer_name(&self) -> String {
+        ffi_span!("Printer", "printer_name");
+        let mut name = String::new();
+        unsafe {
+            let data: *mut String = &mut name;
+            qt_binding_printer_printer_name(self.ptr, data as *mut c_void, rs_string_fill);
+        }
+        name
+    }
+
+    /// Name of the document, displayed in the print queue
+    pub fn set_document_name<S>(&mut self, name: S) -> &mut Self
+    where
+        S: AsRef<str>,
+    {
+        ffi_span!("Printer", "set_document_name");
+        let name = name.as_ref();
+        unsafe {
+            qt_binding_printer_set_document_name(
+                self.ptr,
+                name.as_ptr() as *const c_char,
+                name.len() as u32,
+            )
+        };
+        self
+    }
+
+    /// Size of the pages
+    pub fn set_page_size(&mut self, page_size: PageSize) -> &mut Self {
+        ffi_span!("Printer", "set_page_size");
+        let (width, height) = page_size.millimeters();
+        unsafe { qt_binding_printer_set_page_size(self.ptr, width, height) };
+        self
+    }
+
+    /// Orientation of the pages
+    pub fn set_orientation(&mut self, orientation: Orientation) -> &mut Self {
+        ffi_span!("Printer", "set_orientation");
+        let landscape = orientation == Orientation::Landscape;
+        unsafe { qt_binding_printer_set_landscape(self.ptr, landscape) };
+        self
+    }
+
+    /// Margins of the pages, in millimeters
+    pub fn set_margins(&mut self, left: f64, top: f64, right: f64, bottom: f64) -> &mut Self {
+        ffi_span!("Printer", "set_margins");
+        unsafe { qt_binding_printer_set_margins(self.ptr, left, top, right, bottom) };
+        self
+    }
+
+    /// Number of copies
+    pub fn set_copies(&mut self, copies: u32) -> &mut Self {
+        ffi_span!("Printer", "set_copies");
+        unsafe { qt_binding_printer_set_copies(self.ptr, copies) };
+        self
+    }
+
+    /// Print to a PDF file instead of a printer
+    pub fn set_output_file<P>(&mut self, path: P) -> &mut Self
+    where
+        P: AsRef<Path>,
+    {
+        ffi_span!("Printer", "set_output_file");
+        let path = path.as_ref().to_string_lossy();
+        unsafe {
+            qt_binding_printer_set_output_file(
+                self.ptr,
+                path.as_ptr() as *const c_char,
+                path.len() as u32,
+            )
+        };
+        self
+    }
+
+    /// Show the native print dialog, and block until it is closed
+    ///
+    /// The dialog is initialized with the configuration of the printer, and the printer is
+    /// updated with the choices of the user. Returns `false` if printing was canceled.
+    pub fn exec_dialog(&mut self) -> bool {
+        ffi_span!("Printer", "exec_dialog");
+        unsafe { qt_binding_printer_exec_dialog(self.ptr) }
+    }
+
+    /// Print a document
+    ///
+    /// The document is laid out on the pages of the printer.
+    pub fn print_document(&mut self, document: &TextDocument) {
+        ffi_span!("Printer", "print_document");
+        unsafe { qt_binding_printer_print_document(self.ptr, document.as_ptr()) }
+    }
+}
+
+impl Default for Printer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for Printer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Printer")
+            .field("printer_name", &self.printer_name())
+            .finish()
+    }
+}
+
+impl Drop for Printer {
+    fn drop(&mut self) {
+        ffi_span!("Printer", "drop");
+        if app::should_leak("Printer") {
+            return;
+        }
+        track_destroyed!(Printer);
+        unsafe { qt_binding_printer_delete(self.ptr) }
+    }
+}
+
+extern "C" fn rs_printer_info_append(
+    output: *mut c_void,
+    name: *const c_char,
+    name_size: u32,
+    description: *const c_char,
+    description_size: u32,
+    is_default: bool,
+) {
+    let output = unsafe { &mut *(output as *mut Vec<PrinterInfo>) };
+    output.push(PrinterInfo {
+        name: to_string(name, name_size),
+        description: to_string(description, description_size),
+        is_default,
+    });
+}
+
+fn to_string(input: *const c_char, input_size: u32) -> String {
+    unsafe {
+        let input = slice::from_raw_parts(input as *const u8, input_size as usize);
+        String::from_utf8_unchecked(Vec::from(input))
+    }
+}
+
+type RsPrinterInfoAppendFunc = extern "C" fn(
+    output: *mut c_void,
+    name: *const c_char,
+    name_size: u32,
+    description: *const c_char,
+    description_size: u32,
+    is_default: bool,
+);
+
+extern "C" {
+    fn qt_binding_print_printers(output: *mut c_void, append: RsPrinterInfoAppendFunc);
+    fn qt_binding_printer_create() -> *mut c_void;
+    fn qt_binding_printer_delete(printer: *mut c_void);
+    fn qt_binding_printer_set_printer_name(printer: *mut c_void, name: *const c_char, size: u32);
+    fn qt_binding_printer_printer_name(
+        printer: *const c_void,
+        output: *mut c_void,
+        fill: RsStringFillFunc,
+    );
+    fn qt_binding_printer_set_document_name(printer: *mut c_void, name: *const c_char, size: u32);
+    fn qt_binding_printer_set_page_size(printer: *mut c_void, width: f64, height: f64);
+    fn qt_binding_printer_set_landscape(printer: *mut c_void, landscape: bool);
+    fn qt_binding_printer_set_margins(
+        printer: *mut c_void,
+        left: f64,
+        top: f64,
+        right: f64,
+        bottom: f64,
+    );
+    fn qt_binding_printer_set_copies(printer: *mut c_void, copies: u32);
+    fn qt_binding_printer_set_output_file(printer: *mut c_void, path: *const c_char, size: u32);
+    fn qt_binding_printer_exec_dialog(printer: *mut c_void) -> bool;
+    fn qt_binding_printer_print_document(printer: *mut c_void, document: *const c_void);
+}
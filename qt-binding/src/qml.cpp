@@ -0,0 +1,23 @@
+#include <QtQml/QQmlDebuggingEnabler>
+#include <cstdint>
+
+QT_USE_NAMESPACE
+
+extern "C" {
+
+void qt_binding_qml_debugger_enable()
+{
+    QQmlDebuggingEnabler enabler(false);
+    Q_UNUSED(enabler)
+}
+
+bool qt_binding_qml_debugger_start_tcp_server(int port, bool wait, const char *host,
+                                              std::uint32_t hostSize)
+{
+    const auto mode = wait ? QQmlDebuggingEnabler::WaitForClient
+                           : QQmlDebuggingEnabler::DoNotWaitForClient;
+    const auto hostName = QString::fromUtf8(host, static_cast<int>(hostSize));
+    return QQmlDebuggingEnabler::startTcpDebugServer(port, mode, hostName);
+}
+
+} // extern "C"
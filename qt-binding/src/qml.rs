@@ -0,0 +1,199 @@
+//! Bindings to Qt QML
+//!
+//! This module contains bindings to the QML engine and its tooling.
+//!
+//! [`Engine`] is a binding on Qt's `QQmlEngine`. It loads QML files, exposes values to QML as
+//! context properties, and reports QML warnings and errors as [`Diagnostic`]s to a Rust
+//! handler, so that applications can log them or fail tests on QML errors. [`Component`] loads
+//! QML files asynchronously, and reports their status.
+//!
+//! [`Debugger`] enables the QML debugging and profiling services, so that Qt Creator's QML
+//! debugger and profiler can attach to the application.
+//!
+//! [`run_tests`] runs QML unit tests written with `TestCase`, with the Qt Quick Test harness.
+//!
+//! [`DevTools`] collects QML warnings, Rust panics and Qt warnings in debug builds, and exposes
+//! them to a bundled QML overlay.
+//!
+//! [`Controls`] configures the style and theme of Qt Quick Controls 2.
+//!
+//! [`Config`] exposes configuration values, like feature flags or endpoints, to QML as a
+//! read-only map.
+//!
+//! [`Engine`]: struct.Engine.html
+//! [`Diagnostic`]: struct.Diagnostic.html
+//! [`Component`]: struct.Component.html
+//! [`Debugger`]: struct.Debugger.html
+//! [`run_tests`]: fn.run_tests.html
+//! [`DevTools`]: struct.DevTools.html
+//! [`Controls`]: struct.Controls.html
+//! [`Config`]: struct.Config.html
+//!
+//! # Features
+//!
+//! This module is only available with the `qml` feature. [`run_tests`] requires the
+//! `quick-test` feature.
+//!
+//! # Examples
+//!
+//! Loading a QML file and logging QML warnings
+//!
+//! ```no_run
+//! use qt_binding::app::Application;
+//! use qt_binding::qml::Engine;
+//!
+//! let mut app = Application::new();
+//! let mut engine = Engine::new();
+//! engine.on_warnings(|diagnostics| {
+//!     for diagnostic in diagnostics {
+//!         eprintln!("QML warning: {}", diagnostic);
+//!     }
+//! });
+//! engine.load("main.qml");
+//! app.exec();
+//! ```
+//!
+//! Enabling the QML debugger in debug builds, and letting Qt Creator attach on port 3768
+//!
+//! ```no_run
+//! use qt_binding::app::Application;
+//! use qt_binding::qml::Debugger;
+//!
+//! let mut app = Application::new();
+//! Debugger::new().port(3768).start();
+//! // Create QML engines here
+//! app.exec();
+//! ```
+
+mod component;
+mod config;
+mod controls;
+mod dev_tools;
+mod engine;
+#[cfg(feature = "quick-test")]
+mod test;
+
+pub use self::component::{CompilationMode, Component, Status};
+pub use self::config::Config;
+pub use self::controls::{Controls, Style, Theme};
+pub use self::dev_tools::DevTools;
+pub use self::engine::{Diagnostic, Engine};
+#[cfg(feature = "quick-test")]
+pub use self::test::run_tests;
+
+use std::os::raw::{c_char, c_int};
+
+/// How the QML debug server starts
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum StartMode {
+    /// QML engines run without waiting for a debugger
+    DoNotWaitForClient,
+    /// QML engines wait for a debugger to attach before running
+    WaitForClient,
+}
+
+/// QML debugging and profiling services
+///
+/// The QML debugging services must be enabled before any QML engine is created. They are
+/// enabled by default for debug builds only, as they allow any client to connect to the
+/// application and run code.
+///
+/// Without a [`port`], the debug server is only started when the program is run with the
+/// `-qmljsdebugger=port:<port>` argument, which is what Qt Creator does.
+///
+/// [`port`]: #method.port
+pub struct Debugger {
+    enabled: bool,
+    port: Option<u16>,
+    host: Option<String>,
+    mode: StartMode,
+}
+
+impl Debugger {
+    /// Creates a new `Debugger`
+    ///
+    /// The `Debugger` is enabled for debug builds, and disabled for release builds.
+    pub fn new() -> Self {
+        Debugger {
+            enabled: cfg!(debug_assertions),
+            port: None,
+            host: None,
+            mode: StartMode::DoNotWaitForClient,
+        }
+    }
+
+    /// Enable or disable the debugging services
+    ///
+    /// This overrides the default that only enables debugging for debug builds.
+    pub fn enabled(&mut self, enabled: bool) -> &mut Self {
+        self.enabled = enabled;
+        self
+    }
+
+    /// Port the debug server listens to
+    pub fn port(&mut self, port: u16) -> &mut Self {
+        self.port = Some(port);
+        self
+    }
+
+    /// Host name the debug server listens to
+    ///
+    /// By default, the debug server listens to all interfaces.
+    pub fn host<S>(&mut self, host: S) -> &mut Self
+    where
+        S: Into<String>,
+    {
+        self.host = Some(host.into());
+        self
+    }
+
+    /// How the debug server starts
+    pub fn mode(&mut self, mode: StartMode) -> &mut Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Enable the debugging services
+    ///
+    /// Returns `true` if the debugging services are enabled and, when a port was set, if the
+    /// debug server could be started.
+    pub fn start(&self) -> bool {
+        if !self.enabled {
+            return false;
+        }
+
+        ffi_span!("Debugger", "start");
+        unsafe { qt_binding_qml_debugger_enable() };
+
+        if let Some(port) = self.port {
+            let host = self.host.as_deref().unwrap_or("");
+            let wait = self.mode == StartMode::WaitForClient;
+            unsafe {
+                qt_binding_qml_debugger_start_tcp_server(
+                    port as c_int,
+                    wait,
+                    host.as_ptr() as *const c_char,
+                    host.len() as u32,
+                )
+            }
+        } else {
+            true
+        }
+    }
+}
+
+impl Default for Debugger {
+    fn default() -> Self {
+        Debugger::new()
+    }
+}
+
+extern "C" {
+    fn qt_binding_qml_debugger_enable();
+    fn qt_binding_qml_debugger_start_tcp_server(
+        port: c_int,
+        wait: bool,
+        host: *const c_char,
+        host_size: u32,
+    ) -> bool;
+}
@@ -0,0 +1,38 @@
+import QtQuick 2.0
+
+Rectangle {
+    id: overlay
+    property bool dismissed: false
+
+    anchors.left: parent.left
+    anchors.right: parent.right
+    anchors.bottom: parent.bottom
+    height: Math.min(list.contentHeight + 8, parent.height / 3)
+    visible: list.count > 0 && !dismissed
+    color: "#e0202020"
+    z: 1000
+
+    ListView {
+        id: list
+        anchors.fill: parent
+        anchors.margins: 4
+        clip: true
+        model: devTools
+        delegate: Text {
+            width: list.width
+            wrapMode: Text.Wrap
+            font.family: "monospace"
+            color: model.kind === "panic" ? "#ff6060" : model.kind === "qml" ? "#ffb040" : "#e0e0e0"
+            text: model.kind + ": " + model.message
+        }
+        onCountChanged: {
+            overlay.dismissed = false
+            positionViewAtEnd()
+        }
+    }
+
+    MouseArea {
+        anchors.fill: parent
+        onDoubleClicked: overlay.dismissed = true
+    }
+}
@@ -0,0 +1,75 @@
+#include <QtCore/QFileInfo>
+#include <QtCore/QUrl>
+#include <QtCore/QVariant>
+#include <QtQml/QQmlComponent>
+#include <QtQml/QQmlEngine>
+#include <QtQml/QQmlError>
+#include <cstdint>
+
+QT_USE_NAMESPACE
+
+using RsComponentStatusFunc = void (*)(void *data, int status);
+using RsEngineWarningsFunc = void (*)(void *data, const QList<QQmlError> *errors, int count);
+
+static QUrl toUrl(const char *path, std::uint32_t size)
+{
+    const auto filePath = QString::fromUtf8(path, static_cast<int>(size));
+    return filePath.startsWith(QLatin1String("qrc:"))
+            ? QUrl(filePath)
+            : QUrl::fromLocalFile(QFileInfo(filePath).absoluteFilePath());
+}
+
+extern "C" {
+
+QQmlComponent *qt_binding_qml_component_create(QQmlEngine *engine, const char *path,
+                                               std::uint32_t size, bool asynchronous,
+                                               void *handler, RsComponentStatusFunc statusChanged)
+{
+    const auto mode = asynchronous ? QQmlComponent::Asynchronous
+                                   : QQmlComponent::PreferSynchronous;
+    auto component = new QQmlComponent(engine);
+    QObject::connect(component, &QQmlComponent::statusChanged,
+                     [handler, statusChanged](QQmlComponent::Status status) {
+                         statusChanged(handler, static_cast<int>(status));
+                     });
+    component->loadUrl(toUrl(path, size), mode);
+    return component;
+}
+
+void qt_binding_qml_component_delete(QQmlComponent *component)
+{
+    delete component;
+}
+
+int qt_binding_qml_component_status(const QQmlComponent *component)
+{
+    return static_cast<int>(component->status());
+}
+
+double qt_binding_qml_component_progress(const QQmlComponent *component)
+{
+    return component->progress();
+}
+
+void qt_binding_qml_component_errors(const QQmlComponent *component, void *output,
+                                     RsEngineWarningsFunc errors)
+{
+    const auto list = component->errors();
+    errors(output, &list, list.size());
+}
+
+QVariant *qt_binding_qml_component_create_object(QQmlComponent *component)
+{
+    if (!component->isReady()) {
+        return new QVariant();
+    }
+
+    auto object = component->create();
+    if (object == nullptr) {
+        return new QVariant();
+    }
+    object->setParent(qmlEngine(object));
+    return new QVariant(QVariant::fromValue<QObject *>(object));
+}
+
+} // extern "C"
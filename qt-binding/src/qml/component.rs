@@ -0,0 +1,238 @@
+use super::engine::{diagnostics, RsEngineWarningsFunc};
+use super::{Diagnostic, Engine};
+use crate::app;
+use crate::object::{Object, ObjectHandle};
+use crate::variant::Variant;
+use std::fmt;
+use std::os::raw::{c_char, c_int, c_void};
+use std::path::Path;
+
+type StatusHandler = Option<Box<dyn FnMut(Status)>>;
+
+/// Status of a [`Component`]
+///
+/// [`Component`]: struct.Component.html
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Status {
+    /// No QML source is set
+    Null,
+    /// The component is ready to create objects
+    Ready,
+    /// The component is being loaded, or compiled
+    Loading,
+    /// An error occurred, see [`Component::errors`]
+    ///
+    /// [`Component::errors`]: struct.Component.html#method.errors
+    Error,
+}
+
+impl Status {
+    fn from_value(value: c_int) -> Self {
+        match value {
+            1 => Status::Ready,
+            2 => Status::Loading,
+            3 => Status::Error,
+            _ => Status::Null,
+        }
+    }
+}
+
+/// How a [`Component`] is loaded
+///
+/// [`Component`]: struct.Component.html
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CompilationMode {
+    /// Load and compile in the calling thread, unless the QML file is remote
+    PreferSynchronous,
+    /// Load and compile in a background thread when possible
+    Asynchronous,
+}
+
+/// Binding to `QQmlComponent`
+///
+/// A `Component` loads a QML file, and creates objects from it with [`create`]. Unlike
+/// [`Engine::load`], a component can be loaded asynchronously, so that the event loop keeps
+/// running while heavy QML files are compiled, for example to animate a splash screen. Status
+/// changes are reported with [`on_status_changed`].
+///
+/// A `Component` should be dropped before the [`Engine`] it was created with. Objects created
+/// by the component are owned by the engine.
+///
+/// [`create`]: #method.create
+/// [`Engine::load`]: struct.Engine.html#method.load
+/// [`on_status_changed`]: #method.on_status_changed
+/// [`Engine`]: struct.Engine.html
+///
+/// # Examples
+///
+/// ```no_run
+/// use qt_binding::app::Application;
+/// use qt_binding::qml::{CompilationMode, Component, Engine, Status};
+///
+/// let mut app = Application::new();
+/// let mut engine = Engine::new();
+/// engine.load("qrc:/qml/Splash.qml");
+///
+/// let mut component = Component::new(&engine, "qrc:/qml/main.qml", CompilationMode::Asynchronous);
+/// component.on_status_changed(|status| match status {
+///     Status::Ready => println!("Main window is ready"),
+///     Status::Error => Application::exit(1),
+///     _ => {}
+/// });
+///
+/// app.exec();
+/// ```
+pub struct Component {
+    ptr: *mut c_void,
+    handler: Box<StatusHandler>,
+}
+
+impl Component {
+    /// Creates a `Component`, and starts loading a QML file
+    ///
+    /// Paths starting with `qrc:` are loaded from Qt resources.
+    pub fn new<P>(engine: &Engine, path: P, mode: CompilationMode) -> Self
+    where
+        P: AsRef<Path>,
+    {
+        ffi_span!("Component", "new");
+        let path = path.as_ref().to_string_lossy();
+        let asynchronous = mode == CompilationMode::Asynchronous;
+        let mut handler = Box::new(None);
+        let data: *mut StatusHandler = &mut *handler;
+        let ptr = unsafe {
+            qt_binding_qml_component_create(
+                engine.as_object_ptr(),
+                path.as_ptr() as *const c_char,
+                path.len() as u32,
+                asynchronous,
+                data as *mut c_void,
+                rs_component_status_changed,
+            )
+        };
+        track_created!(QmlComponent);
+        Component { ptr, handler }
+    }
+
+    /// Current status
+    pub fn status(&self) -> Status {
+        ffi_span!("Component", "status");
+        Status::from_value(unsafe { qt_binding_qml_component_status(self.ptr) })
+    }
+
+    /// Loading progress, from 0 to 1
+    pub fn progress(&self) -> f64 {
+        ffi_span!("Component", "progress");
+        unsafe { qt_binding_qml_component_progress(self.ptr) }
+    }
+
+    /// Errors that occurred while loading, or creating objects, with their location
+    pub fn errors(&self) -> Vec<Diagnostic> {
+        ffi_span!("Component", "errors");
+        let mut errors = Vec::new();
+        unsafe {
+            let data: *mut Vec<Diagnostic> = &mut errors;
+            qt_binding_qml_component_errors(self.ptr, data as *mut c_void, rs_component_errors);
+        }
+        errors
+    }
+
+    /// Handle status changes
+    ///
+    /// Setting a new handler replaces the previous one. Components that are loaded
+    /// synchronously might already be ready when the handler is set: check [`status`] first.
+    ///
+    /// [`status`]: #method.status
+    pub fn on_status_changed<F>(&mut self, handler: F) -> &mut Self
+    where
+        F: FnMut(Status) + 'static,
+    {
+        *self.handler = Some(Box::new(handler));
+        self
+    }
+
+    /// Create an object from the component
+    ///
+    /// The object is owned by the engine. Returns `None` if the component is not ready, or if
+    /// the object could not be created: see [`errors`].
+    ///
+    /// [`errors`]: #method.errors
+    pub fn create(&mut self) -> Option<ObjectHandle> {
+        ffi_span!("Component", "create");
+        let variant =
+            Variant::from_ptr(unsafe { qt_binding_qml_component_create_object(self.ptr) });
+        variant.try_to_object().ok()
+    }
+}
+
+impl fmt::Debug for Component {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Component")
+            .field("status", &self.status())
+            .finish()
+    }
+}
+
+impl Object for Component {
+    fn as_object_ptr(&self) -> *mut c_void {
+        self.ptr
+    }
+}
+
+impl Drop for Component {
+    fn drop(&mut self) {
+        ffi_span!("Component", "drop");
+        if app::should_leak("Component") {
+            return;
+        }
+        track_destroyed!(QmlComponent);
+        unsafe { qt_binding_qml_component_delete(self.ptr) }
+    }
+}
+
+type RsComponentStatusFunc = extern "C" fn(data: *mut c_void, status: c_int);
+
+extern "C" fn rs_component_status_changed(data: *mut c_void, status: c_int) {
+    let handler = unsafe { &mut *(data as *mut StatusHandler) };
+    if let Some(handler) = handler {
+        handler(Status::from_value(status));
+    }
+}
+
+extern "C" fn rs_component_errors(data: *mut c_void, errors: *const c_void, count: c_int) {
+    let output = unsafe { &mut *(data as *mut Vec<Diagnostic>) };
+    *output = diagnostics(errors, count);
+}
+
+extern "C" {
+    fn qt_binding_qml_component_create(
+        engine: *mut c_void,
+        path: *const c_char,
+        size: u32,
+        asynchronous: bool,
+        handler: *mut c_void,
+        status_changed: RsComponentStatusFunc,
+    ) -> *mut c_void;
+    fn qt_binding_qml_component_delete(component: *mut c_void);
+    fn qt_binding_qml_component_status(component: *const c_void) -> c_int;
+    fn qt_binding_qml_component_progress(component: *const c_void) -> f64;
+    fn qt_binding_qml_component_errors(
+        component: *const c_void,
+        output: *mut c_void,
+        errors: RsEngineWarningsFunc,
+    );
+    fn qt_binding_qml_component_create_object(component: *mut c_void) -> *mut c_void;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_status_from_value() {
+        assert_eq!(Status::from_value(0), Status::Null);
+        assert_eq!(Status::from_value(1), Status::Ready);
+        assert_eq!(Status::from_value(2), Status::Loading);
+        assert_eq!(Status::from_value(3), Status::Error);
+    }
+}
@@ -0,0 +1,67 @@
+#include <QtCore/QVariant>
+#include <QtQml/QQmlPropertyMap>
+#include <cstdint>
+
+QT_USE_NAMESPACE
+
+namespace qt_binding {
+
+class Config : public QQmlPropertyMap
+{
+protected:
+    QVariant updateValue(const QString &key, const QVariant &input) override
+    {
+        // Values are read-only from QML, keep the current value
+        Q_UNUSED(input)
+        return value(key);
+    }
+};
+
+} // namespace qt_binding
+
+static QString toKey(const char *key, std::uint32_t size)
+{
+    return QString::fromUtf8(key, static_cast<int>(size));
+}
+
+extern "C" {
+
+qt_binding::Config *qt_binding_qml_config_create()
+{
+    return new qt_binding::Config();
+}
+
+void qt_binding_qml_config_delete(qt_binding::Config *config)
+{
+    delete config;
+}
+
+QVariant *qt_binding_qml_config_to_variant(qt_binding::Config *config)
+{
+    return new QVariant(QVariant::fromValue<QObject *>(config));
+}
+
+void qt_binding_qml_config_insert(qt_binding::Config *config, const char *key, std::uint32_t size,
+                                  const QVariant *value)
+{
+    config->insert(toKey(key, size), *value);
+}
+
+QVariant *qt_binding_qml_config_value(const qt_binding::Config *config, const char *key,
+                                      std::uint32_t size)
+{
+    return new QVariant(config->value(toKey(key, size)));
+}
+
+bool qt_binding_qml_config_contains(const qt_binding::Config *config, const char *key,
+                                    std::uint32_t size)
+{
+    return config->contains(toKey(key, size));
+}
+
+int qt_binding_qml_config_count(const qt_binding::Config *config)
+{
+    return config->count();
+}
+
+} // extern "C"
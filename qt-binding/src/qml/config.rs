@@ -0,0 +1,238 @@
+use crate::app;
+use crate::object::Object;
+use crate::variant::Variant;
+use std::collections::HashMap;
+use std::env;
+use std::fmt;
+use std::iter::FromIterator;
+use std::os::raw::{c_char, c_int, c_void};
+
+/// Read-only configuration values exposed to QML
+///
+/// `Config` is a map of values, like feature flags or endpoints, backed by a `QQmlPropertyMap`.
+/// Exposed to QML, each key is a property of the map, that QML can read and bind to, but not
+/// modify. Values are set from Rust, from a `HashMap`, from environment variables with
+/// [`from_env`], or from a configuration file parsed by the application.
+///
+/// [`from_env`]: #method.from_env
+///
+/// # Limitations
+///
+/// Since `Config` is a binding over a Qt object, it is neither `Send` nor `Sync`. It must
+/// outlive the QML engines it is exposed to.
+///
+/// # Examples
+///
+/// ```no_run
+/// use qt_binding::app::Application;
+/// use qt_binding::qml::{Config, Engine};
+/// use qt_binding::variant::Variant;
+///
+/// let mut app = Application::new();
+/// let mut config = Config::from_env("APP_");
+/// config
+///     .insert("endpoint", &Variant::from("https://example.com/api"))
+///     .insert("betaFeatures", &Variant::from(false));
+///
+/// let mut engine = Engine::new();
+/// engine.set_context_property("config", &Variant::from(&config));
+/// engine.load("main.qml");
+/// app.exec();
+/// ```
+///
+/// In QML
+///
+/// ```qml
+/// Button {
+///     visible: config.betaFeatures
+/// }
+/// ```
+pub struct Config {
+    ptr: *mut c_void,
+}
+
+impl Config {
+    /// Creates an empty `Config`
+    pub fn new() -> Self {
+        ffi_span!("Config", "new");
+        track_created!(QmlConfig);
+        Config {
+            ptr: unsafe { qt_binding_qml_config_create() },
+        }
+    }
+
+    /// Creates a `Config` from the environment variables starting with a prefix
+    ///
+    /// The prefix is removed, and the rest of the name is converted to lower case to get the
+    /// key: with the `APP_` prefix, `APP_API_URL` is available as `api_url`. Values are strings.
+    pub fn from_env<S>(prefix: S) -> Self
+    where
+        S: AsRef<str>,
+    {
+        let prefix = prefix.as_ref();
+        env::vars()
+            .filter_map(|(name, value)| env_key(prefix, &name).map(|key| (key, value.into())))
+            .collect()
+    }
+
+    /// Set a value
+    ///
+    /// QML bindings depending on the value are updated.
+    pub fn insert<S>(&mut self, key: S, value: &Variant) -> &mut Self
+    where
+        S: AsRef<str>,
+    {
+        ffi_span!("Config", "insert");
+        let key = key.as_ref();
+        unsafe {
+            qt_binding_qml_config_insert(
+                self.ptr,
+                key.as_ptr() as *const c_char,
+                key.len() as u32,
+                value.as_ptr(),
+            )
+        }
+        self
+    }
+
+    /// Value of a key, or an invalid `Variant` if the key is not set
+    pub fn get<S>(&self, key: S) -> Variant
+    where
+        S: AsRef<str>,
+    {
+        ffi_span!("Config", "get");
+        let key = key.as_ref();
+        Variant::from_ptr(unsafe {
+            qt_binding_qml_config_value(self.ptr, key.as_ptr() as *const c_char, key.len() as u32)
+        })
+    }
+
+    /// If a key is set
+    pub fn contains<S>(&self, key: S) -> bool
+    where
+        S: AsRef<str>,
+    {
+        ffi_span!("Config", "contains");
+        let key = key.as_ref();
+        unsafe {
+            qt_binding_qml_config_contains(
+                self.ptr,
+                key.as_ptr() as *const c_char,
+                key.len() as u32,
+            )
+        }
+    }
+
+    /// Number of keys
+    pub fn len(&self) -> usize {
+        ffi_span!("Config", "len");
+        unsafe { qt_binding_qml_config_count(self.ptr) as usize }
+    }
+
+    /// If no key is set
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config::new()
+    }
+}
+
+impl<K> FromIterator<(K, Variant)> for Config
+where
+    K: AsRef<str>,
+{
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = (K, Variant)>,
+    {
+        let mut config = Config::new();
+        for (key, value) in iter {
+            config.insert(key, &value);
+        }
+        config
+    }
+}
+
+impl From<HashMap<String, Variant>> for Config {
+    fn from(values: HashMap<String, Variant>) -> Self {
+        values.into_iter().collect()
+    }
+}
+
+impl From<&'_ Config> for Variant {
+    fn from(config: &Config) -> Self {
+        ffi_span!("Variant", "from_config");
+        Variant::from_ptr(unsafe { qt_binding_qml_config_to_variant(config.ptr) })
+    }
+}
+
+impl fmt::Debug for Config {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Config").field("len", &self.len()).finish()
+    }
+}
+
+impl Object for Config {
+    fn as_object_ptr(&self) -> *mut c_void {
+        self.ptr
+    }
+}
+
+impl Drop for Config {
+    fn drop(&mut self) {
+        ffi_span!("Config", "drop");
+        if app::should_leak("Config") {
+            return;
+        }
+        track_destroyed!(QmlConfig);
+        unsafe { qt_binding_qml_config_delete(self.ptr) }
+    }
+}
+
+fn env_key(prefix: &str, name: &str) -> Option<String> {
+    let key = name.strip_prefix(prefix)?;
+    if key.is_empty() {
+        None
+    } else {
+        Some(key.to_lowercase())
+    }
+}
+
+extern "C" {
+    fn qt_binding_qml_config_create() -> *mut c_void;
+    fn qt_binding_qml_config_delete(config: *mut c_void);
+    fn qt_binding_qml_config_to_variant(config: *mut c_void) -> *mut c_void;
+    fn qt_binding_qml_config_insert(
+        config: *mut c_void,
+        key: *const c_char,
+        key_size: u32,
+        value: *const c_void,
+    );
+    fn qt_binding_qml_config_value(
+        config: *const c_void,
+        key: *const c_char,
+        key_size: u32,
+    ) -> *mut c_void;
+    fn qt_binding_qml_config_contains(
+        config: *const c_void,
+        key: *const c_char,
+        key_size: u32,
+    ) -> bool;
+    fn qt_binding_qml_config_count(config: *const c_void) -> c_int;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_env_key() {
+        assert_eq!(env_key("APP_", "APP_API_URL"), Some("api_url".to_string()));
+        assert_eq!(env_key("APP_", "APP_"), None);
+        assert_eq!(env_key("APP_", "HOME"), None);
+    }
+}
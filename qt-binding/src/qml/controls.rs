@@ -0,0 +1,217 @@
+use std::env;
+
+/// A Qt Quick Controls 2 style
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Style {
+    /// The default style
+    Default,
+    /// A desktop style, close to native desktop applications
+    Fusion,
+    /// A style based on image assets
+    Imagine,
+    /// Google's Material Design
+    Material,
+    /// Microsoft's Universal Design
+    Universal,
+    /// A custom style, by name or by path
+    Custom(String),
+}
+
+impl Style {
+    fn name(&self) -> &str {
+        match self {
+            Style::Default => "Default",
+            Style::Fusion => "Fusion",
+            Style::Imagine => "Imagine",
+            Style::Material => "Material",
+            Style::Universal => "Universal",
+            Style::Custom(name) => name,
+        }
+    }
+}
+
+/// Theme of the Material and Universal styles
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Theme {
+    /// Light theme
+    Light,
+    /// Dark theme
+    Dark,
+    /// Light or dark, following the system theme
+    System,
+}
+
+impl Theme {
+    fn name(self) -> &'static str {
+        match self {
+            Theme::Light => "Light",
+            Theme::Dark => "Dark",
+            Theme::System => "System",
+        }
+    }
+}
+
+/// Configuration of Qt Quick Controls 2
+///
+/// Qt Quick Controls 2 is configured with environment variables, like
+/// `QT_QUICK_CONTROLS_STYLE`, that are read when controls are first imported. `Controls`
+/// sets them with a typed API. Options that are not set keep the value of the environment,
+/// or of `qtquickcontrols2.conf`.
+///
+/// [`apply`] should be called before loading QML files importing `QtQuick.Controls`.
+///
+/// [`apply`]: #method.apply
+///
+/// # Examples
+///
+/// ```no_run
+/// use qt_binding::app::Application;
+/// use qt_binding::qml::{Controls, Engine, Style, Theme};
+///
+/// let mut app = Application::new();
+/// Controls::new()
+///     .style(Style::Material)
+///     .material_theme(Theme::Dark)
+///     .material_accent("Teal")
+///     .apply();
+///
+/// let mut engine = Engine::new();
+/// engine.load("main.qml");
+/// app.exec();
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Controls {
+    style: Option<Style>,
+    fallback_style: Option<Style>,
+    material_theme: Option<Theme>,
+    material_accent: Option<String>,
+    material_primary: Option<String>,
+    universal_theme: Option<Theme>,
+    universal_accent: Option<String>,
+}
+
+impl Controls {
+    /// Creates a `Controls` configuration, with no option set
+    pub fn new() -> Self {
+        Controls::default()
+    }
+
+    /// Style of the controls
+    pub fn style(&mut self, style: Style) -> &mut Self {
+        self.style = Some(style);
+        self
+    }
+
+    /// Style used for controls that are not implemented by a custom style
+    pub fn fallback_style(&mut self, style: Style) -> &mut Self {
+        self.fallback_style = Some(style);
+        self
+    }
+
+    /// Theme of the Material style
+    pub fn material_theme(&mut self, theme: Theme) -> &mut Self {
+        self.material_theme = Some(theme);
+        self
+    }
+
+    /// Accent color of the Material style, as a color name like `Teal`, or as `#rrggbb`
+    pub fn material_accent<S>(&mut self, color: S) -> &mut Self
+    where
+        S: Into<String>,
+    {
+        self.material_accent = Some(color.into());
+        self
+    }
+
+    /// Primary color of the Material style, as a color name like `Indigo`, or as `#rrggbb`
+    pub fn material_primary<S>(&mut self, color: S) -> &mut Self
+    where
+        S: Into<String>,
+    {
+        self.material_primary = Some(color.into());
+        self
+    }
+
+    /// Theme of the Universal style
+    pub fn universal_theme(&mut self, theme: Theme) -> &mut Self {
+        self.universal_theme = Some(theme);
+        self
+    }
+
+    /// Accent color of the Universal style, as a color name like `Cobalt`, or as `#rrggbb`
+    pub fn universal_accent<S>(&mut self, color: S) -> &mut Self
+    where
+        S: Into<String>,
+    {
+        self.universal_accent = Some(color.into());
+        self
+    }
+
+    /// Apply the configuration, by setting environment variables
+    pub fn apply(&self) {
+        for (key, value) in self.variables() {
+            env::set_var(key, value);
+        }
+    }
+
+    fn variables(&self) -> Vec<(&'static str, &str)> {
+        let options = vec![
+            (
+                "QT_QUICK_CONTROLS_STYLE",
+                self.style.as_ref().map(Style::name),
+            ),
+            (
+                "QT_QUICK_CONTROLS_FALLBACK_STYLE",
+                self.fallback_style.as_ref().map(Style::name),
+            ),
+            (
+                "QT_QUICK_CONTROLS_MATERIAL_THEME",
+                self.material_theme.map(Theme::name),
+            ),
+            (
+                "QT_QUICK_CONTROLS_MATERIAL_ACCENT",
+                self.material_accent.as_deref(),
+            ),
+            (
+                "QT_QUICK_CONTROLS_MATERIAL_PRIMARY",
+                self.material_primary.as_deref(),
+            ),
+            (
+                "QT_QUICK_CONTROLS_UNIVERSAL_THEME",
+                self.universal_theme.map(Theme::name),
+            ),
+            (
+                "QT_QUICK_CONTROLS_UNIVERSAL_ACCENT",
+                self.universal_accent.as_deref(),
+            ),
+        ];
+        options
+            .into_iter()
+            .filter_map(|(key, value)| value.map(|value| (key, value)))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_variables() {
+        assert!(Controls::new().variables().is_empty());
+
+        let mut controls = Controls::new();
+        controls
+            .style(Style::Custom(":/styles/Brand".to_string()))
+            .fallback_style(Style::Material)
+            .material_theme(Theme::Dark);
+        assert_eq!(
+            controls.variables(),
+            vec![
+                ("QT_QUICK_CONTROLS_STYLE", ":/styles/Brand"),
+                ("QT_QUICK_CONTROLS_FALLBACK_STYLE", "Material"),
+                ("QT_QUICK_CONTROLS_MATERIAL_THEME", "Dark"),
+            ]
+        );
+    }
+}
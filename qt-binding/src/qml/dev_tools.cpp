@@ -0,0 +1,120 @@
+#include <QtCore/QAbstractListModel>
+#include <QtCore/QMetaObject>
+#include <QtCore/QVariant>
+#include <cstdint>
+
+QT_USE_NAMESPACE
+
+namespace qt_binding {
+
+class DevToolsModel : public QAbstractListModel
+{
+public:
+    enum Roles { KindRole = Qt::UserRole + 1, MessageRole };
+
+    explicit DevToolsModel(int limit)
+        : m_limit(limit)
+    {
+    }
+
+    int rowCount(const QModelIndex &parent = QModelIndex()) const override
+    {
+        return parent.isValid() ? 0 : m_entries.count();
+    }
+
+    QVariant data(const QModelIndex &index, int role) const override
+    {
+        if (!index.isValid() || index.row() >= m_entries.count()) {
+            return QVariant();
+        }
+
+        const auto &entry = m_entries.at(index.row());
+        switch (role) {
+        case Qt::DisplayRole:
+            return QStringLiteral("%1: %2").arg(entry.kind, entry.message);
+        case KindRole:
+            return entry.kind;
+        case MessageRole:
+            return entry.message;
+        default:
+            return QVariant();
+        }
+    }
+
+    QHash<int, QByteArray> roleNames() const override
+    {
+        auto roles = QAbstractListModel::roleNames();
+        roles.insert(KindRole, "kind");
+        roles.insert(MessageRole, "message");
+        return roles;
+    }
+
+    void append(const QString &kind, const QString &message)
+    {
+        if (m_entries.count() >= m_limit) {
+            beginRemoveRows(QModelIndex(), 0, 0);
+            m_entries.removeFirst();
+            endRemoveRows();
+        }
+
+        const auto row = m_entries.count();
+        beginInsertRows(QModelIndex(), row, row);
+        m_entries.append({kind, message});
+        endInsertRows();
+    }
+
+    void clear()
+    {
+        beginResetModel();
+        m_entries.clear();
+        endResetModel();
+    }
+
+private:
+    struct Entry
+    {
+        QString kind;
+        QString message;
+    };
+
+    int m_limit;
+    QList<Entry> m_entries;
+};
+
+} // namespace qt_binding
+
+extern "C" {
+
+qt_binding::DevToolsModel *qt_binding_dev_tools_create(int limit)
+{
+    return new qt_binding::DevToolsModel(limit);
+}
+
+QVariant *qt_binding_dev_tools_to_variant(qt_binding::DevToolsModel *model)
+{
+    return new QVariant(QVariant::fromValue<QObject *>(model));
+}
+
+int qt_binding_dev_tools_row_count(const qt_binding::DevToolsModel *model)
+{
+    return model->rowCount();
+}
+
+void qt_binding_dev_tools_append(qt_binding::DevToolsModel *model, const char *kind,
+                                 std::uint32_t kindSize, const char *message,
+                                 std::uint32_t messageSize)
+{
+    // Entries might be appended from any thread, and are added from the thread of the model
+    const auto kindString = QString::fromUtf8(kind, static_cast<int>(kindSize));
+    const auto messageString = QString::fromUtf8(message, static_cast<int>(messageSize));
+    QMetaObject::invokeMethod(
+        model, [model, kindString, messageString]() { model->append(kindString, messageString); },
+        Qt::QueuedConnection);
+}
+
+void qt_binding_dev_tools_clear(qt_binding::DevToolsModel *model)
+{
+    model->clear();
+}
+
+} // extern "C"
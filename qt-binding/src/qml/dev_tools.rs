@@ -0,0 +1,191 @@
+use super::Engine;
+use crate::logging::{self, Level};
+use crate::object::Object;
+use crate::variant::Variant;
+use std::os::raw::{c_char, c_int, c_void};
+use std::panic;
+use std::ptr;
+use std::sync::atomic::{AtomicPtr, Ordering};
+use std::sync::Once;
+
+/// Maximum number of entries kept by the model, older entries are discarded
+const LIMIT: c_int = 500;
+
+static INSTALL: Once = Once::new();
+static MODEL: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+
+/// Error overlay for development builds
+///
+/// `DevTools` collects QML warnings, Rust panic messages, and warnings reported through Qt's
+/// message handler in a list model, with the `kind` and `message` roles. The kind is `qml`,
+/// `panic` or `log`.
+///
+/// [`attach`] exposes the model to QML as the `devTools` context property, and the source of
+/// a bundled overlay component, that displays the entries on top of the window, as the
+/// `devToolsOverlay` context property. The overlay requires Qt Quick, and is dismissed with a
+/// double click.
+///
+/// `DevTools` is meant for debug builds, and is usually only installed when
+/// `cfg!(debug_assertions)` is set.
+///
+/// [`attach`]: #method.attach
+///
+/// # Limitations
+///
+/// [`install`] replaces the message handler installed with [`logging::install`], and the
+/// warning handler of attached engines. Messages and warnings are still printed on the
+/// standard error.
+///
+/// [`install`]: #method.install
+/// [`logging::install`]: ../logging/fn.install.html
+///
+/// # Examples
+///
+/// ```no_run
+/// use qt_binding::app::Application;
+/// use qt_binding::qml::{DevTools, Engine};
+///
+/// let mut app = Application::new();
+/// let mut engine = Engine::new();
+/// if cfg!(debug_assertions) {
+///     DevTools::install().attach(&mut engine);
+/// }
+/// engine.load("main.qml");
+/// app.exec();
+/// ```
+///
+/// With, in the root item of `main.qml`
+///
+/// ```qml
+/// Component.onCompleted: {
+///     if (typeof devToolsOverlay !== "undefined") {
+///         Qt.createQmlObject(devToolsOverlay, contentItem)
+///     }
+/// }
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct DevTools {
+    ptr: *mut c_void,
+}
+
+impl DevTools {
+    /// Source of the bundled overlay component
+    pub const OVERLAY_QML: &'static str = include_str!("DevToolsOverlay.qml");
+
+    /// Install the panic hook and the message handler, and get the `DevTools`
+    ///
+    /// The previous panic hook is still called after recording panics. Installing `DevTools`
+    /// more than once returns the same `DevTools`.
+    pub fn install() -> Self {
+        ffi_span!("DevTools", "install");
+        INSTALL.call_once(|| {
+            let ptr = unsafe { qt_binding_dev_tools_create(LIMIT) };
+            MODEL.store(ptr, Ordering::SeqCst);
+
+            // Raw pointers are neither Send nor Sync, entries are appended through queued calls
+            let model = ptr as usize;
+            let previous = panic::take_hook();
+            panic::set_hook(Box::new(move |info| {
+                append(model as *mut c_void, "panic", &info.to_string());
+                previous(info);
+            }));
+            logging::install(move |message| {
+                eprintln!("{}", message);
+                if message.level >= Level::Warning {
+                    let text = format!("[{}] {}", message.category, message);
+                    append(model as *mut c_void, "log", &text);
+                }
+            });
+        });
+        DevTools {
+            ptr: MODEL.load(Ordering::SeqCst),
+        }
+    }
+
+    /// Expose the entries and the overlay to QML files loaded by an engine
+    ///
+    /// Must be called before loading QML files.
+    pub fn attach(&self, engine: &mut Engine) -> &Self {
+        ffi_span!("DevTools", "attach");
+        let model = self.ptr as usize;
+        engine.output_warnings_to_stderr(false);
+        engine.on_warnings(move |diagnostics| {
+            for diagnostic in diagnostics {
+                eprintln!("{}", diagnostic);
+                append(model as *mut c_void, "qml", &diagnostic.to_string());
+            }
+        });
+        engine.set_context_property("devTools", &Variant::from(self));
+        engine.set_context_property("devToolsOverlay", &Variant::from(Self::OVERLAY_QML));
+        self
+    }
+
+    /// Record an entry
+    ///
+    /// Can be called from any thread. Entries are added to the model asynchronously, from the
+    /// event loop.
+    pub fn record<S, T>(&self, kind: S, message: T)
+    where
+        S: AsRef<str>,
+        T: AsRef<str>,
+    {
+        ffi_span!("DevTools", "record");
+        append(self.ptr, kind.as_ref(), message.as_ref())
+    }
+
+    /// Number of entries
+    pub fn len(&self) -> usize {
+        ffi_span!("DevTools", "len");
+        unsafe { qt_binding_dev_tools_row_count(self.ptr) as usize }
+    }
+
+    /// If there are no entries
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Remove all entries
+    pub fn clear(&self) {
+        ffi_span!("DevTools", "clear");
+        unsafe { qt_binding_dev_tools_clear(self.ptr) }
+    }
+}
+
+impl From<&'_ DevTools> for Variant {
+    fn from(dev_tools: &DevTools) -> Self {
+        ffi_span!("Variant", "from_dev_tools");
+        Variant::from_ptr(unsafe { qt_binding_dev_tools_to_variant(dev_tools.ptr) })
+    }
+}
+
+impl Object for DevTools {
+    fn as_object_ptr(&self) -> *mut c_void {
+        self.ptr
+    }
+}
+
+fn append(model: *mut c_void, kind: &str, message: &str) {
+    unsafe {
+        qt_binding_dev_tools_append(
+            model,
+            kind.as_ptr() as *const c_char,
+            kind.len() as u32,
+            message.as_ptr() as *const c_char,
+            message.len() as u32,
+        )
+    }
+}
+
+extern "C" {
+    fn qt_binding_dev_tools_create(limit: c_int) -> *mut c_void;
+    fn qt_binding_dev_tools_to_variant(model: *mut c_void) -> *mut c_void;
+    fn qt_binding_dev_tools_row_count(model: *const c_void) -> c_int;
+    fn qt_binding_dev_tools_append(
+        model: *mut c_void,
+        kind: *const c_char,
+        kind_size: u32,
+        message: *const c_char,
+        message_size: u32,
+    );
+    fn qt_binding_dev_tools_clear(model: *mut c_void);
+}
@@ -0,0 +1,180 @@
+#include <QtCore/QCoreApplication>
+#include <QtCore/QDebug>
+#include <QtCore/QFileInfo>
+#include <QtCore/QFileSelector>
+#include <QtCore/QTimer>
+#include <QtCore/QUrl>
+#include <QtCore/QVariant>
+#include <QtQml/QQmlComponent>
+#include <QtQml/QQmlContext>
+#include <QtQml/QQmlEngine>
+#include <QtQml/QQmlError>
+#include <QtQml/QQmlFileSelector>
+#include <QtQml/QQmlIncubationController>
+#include <cstdint>
+
+QT_USE_NAMESPACE
+
+using RsStringFillFunc = void (*)(void *output, const char *input, std::uint32_t inputSize);
+using RsEngineWarningsFunc = void (*)(void *data, const QList<QQmlError> *errors, int count);
+
+namespace qt_binding {
+
+class IncubationController : public QObject, public QQmlIncubationController
+{
+public:
+    explicit IncubationController(QObject *parent)
+        : QObject(parent)
+    {
+        QObject::connect(&m_timer, &QTimer::timeout, this, [this]() { incubateFor(m_budget); });
+    }
+
+    void setBudget(int budget, int interval)
+    {
+        m_budget = budget;
+        m_timer.setInterval(interval);
+    }
+
+protected:
+    void incubatingObjectCountChanged(int count) override
+    {
+        if (count > 0) {
+            m_timer.start();
+        } else {
+            m_timer.stop();
+        }
+    }
+
+private:
+    QTimer m_timer;
+    int m_budget = 0;
+};
+
+} // namespace qt_binding
+
+static void fillString(const QString &value, void *output, RsStringFillFunc fill)
+{
+    const auto byteArray = value.toUtf8();
+    fill(output, byteArray.constData(), static_cast<std::uint32_t>(byteArray.size()));
+}
+
+static QQmlFileSelector *fileSelector(QQmlEngine *engine)
+{
+    auto selector = QQmlFileSelector::get(engine);
+    return selector != nullptr ? selector : new QQmlFileSelector(engine, engine);
+}
+
+static void reportErrors(QQmlEngine *engine, const QList<QQmlError> &errors)
+{
+    if (engine->outputWarningsToStandardError()) {
+        for (const auto &error : errors) {
+            qWarning().noquote() << error.toString();
+        }
+    }
+    emit engine->warnings(errors);
+}
+
+extern "C" {
+
+QQmlEngine *qt_binding_qml_engine_create(void *handler, RsEngineWarningsFunc warnings)
+{
+    auto engine = new QQmlEngine();
+    QObject::connect(engine, &QQmlEngine::quit, QCoreApplication::instance(),
+                     &QCoreApplication::quit);
+    QObject::connect(engine, &QQmlEngine::warnings,
+                     [handler, warnings](const QList<QQmlError> &errors) {
+                         warnings(handler, &errors, errors.size());
+                     });
+    return engine;
+}
+
+void qt_binding_qml_engine_delete(QQmlEngine *engine)
+{
+    delete engine;
+}
+
+bool qt_binding_qml_engine_load(QQmlEngine *engine, const char *path,
+                                std::uint32_t size)
+{
+    const auto filePath = QString::fromUtf8(path, static_cast<int>(size));
+    const auto url = filePath.startsWith(QLatin1String("qrc:"))
+            ? QUrl(filePath)
+            : QUrl::fromLocalFile(QFileInfo(filePath).absoluteFilePath());
+
+    QQmlComponent component(engine, url);
+    auto object = component.isError() ? nullptr : component.create();
+    if (object == nullptr) {
+        reportErrors(engine, component.errors());
+        return false;
+    }
+
+    object->setParent(engine);
+    return true;
+}
+
+void qt_binding_qml_engine_set_context_property(QQmlEngine *engine, const char *name,
+                                                std::uint32_t size, const QVariant *value)
+{
+    const auto propertyName = QString::fromUtf8(name, static_cast<int>(size));
+    engine->rootContext()->setContextProperty(propertyName, *value);
+}
+
+void qt_binding_qml_engine_set_extra_selectors(QQmlEngine *engine, const char *const *selectors,
+                                               const std::uint32_t *sizes, std::uint32_t count)
+{
+    auto list = QStringList();
+    list.reserve(static_cast<int>(count));
+    for (std::uint32_t i = 0; i < count; ++i) {
+        list.append(QString::fromUtf8(selectors[i], static_cast<int>(sizes[i])));
+    }
+    fileSelector(engine)->setExtraSelectors(list);
+}
+
+void qt_binding_qml_engine_file_selectors(QQmlEngine *engine, void *output, RsStringFillFunc append)
+{
+    for (const auto &selector : fileSelector(engine)->selector()->allSelectors()) {
+        fillString(selector, output, append);
+    }
+}
+
+void qt_binding_qml_engine_set_incubation_budget(QQmlEngine *engine, int budget, int interval)
+{
+    auto controller = dynamic_cast<qt_binding::IncubationController *>(
+            engine->incubationController());
+    if (controller == nullptr) {
+        // Owned by the engine, that does not take ownership of its incubation controller
+        controller = new qt_binding::IncubationController(engine);
+        engine->setIncubationController(controller);
+    }
+    controller->setBudget(budget, interval);
+}
+
+void qt_binding_qml_engine_set_output_warnings_to_stderr(QQmlEngine *engine,
+                                                         bool enabled)
+{
+    engine->setOutputWarningsToStandardError(enabled);
+}
+
+void qt_binding_qml_error_url(const QList<QQmlError> *errors, int index, void *output,
+                              RsStringFillFunc fill)
+{
+    fillString(errors->at(index).url().toString(), output, fill);
+}
+
+void qt_binding_qml_error_description(const QList<QQmlError> *errors, int index, void *output,
+                                      RsStringFillFunc fill)
+{
+    fillString(errors->at(index).description(), output, fill);
+}
+
+int qt_binding_qml_error_line(const QList<QQmlError> *errors, int index)
+{
+    return errors->at(index).line();
+}
+
+int qt_binding_qml_error_column(const QList<QQmlError> *errors, int index)
+{
+    return errors->at(index).column();
+}
+
+} // extern "C"
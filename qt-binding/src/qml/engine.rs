@@ -0,0 +1,410 @@
+use crate::app;
+use crate::object::Object;
+use crate::variant::convert::{rs_string_fill, RsStringFillFunc};
+use crate::variant::Variant;
+#[cfg(feature = "gui")]
+use crate::window::Window;
+use std::fmt;
+use std::os::raw::{c_char, c_int, c_void};
+use std::path::Path;
+use std::slice;
+use std::time::Duration;
+
+type WarningHandler = Option<Box<dyn FnMut(&[Diagnostic])>>;
+
+/// A warning or an error reported by the QML engine
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Diagnostic {
+    /// Url of the QML file that caused the warning, empty if unknown
+    pub url: String,
+    /// Line of the warning, if known
+    pub line: Option<u32>,
+    /// Column of the warning, if known
+    pub column: Option<u32>,
+    /// Description of the warning
+    pub message: String,
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let url = if self.url.is_empty() {
+            "<Unknown File>"
+        } else {
+            &self.url
+        };
+        write!(f, "{}", url)?;
+        if let Some(line) = self.line {
+            write!(f, ":{}", line)?;
+            if let Some(column) = self.column {
+                write!(f, ":{}", column)?;
+            }
+        }
+        write!(f, ": {}", self.message)
+    }
+}
+
+/// Binding to `QQmlEngine`
+///
+/// Root objects created by [`load`] are owned by the engine, and destroyed with it. Calling
+/// `Qt.quit()` from QML quits the [`Application`].
+///
+/// See module level documentation for more information.
+///
+/// [`load`]: #method.load
+/// [`Application`]: ../app/struct.Application.html
+pub struct Engine {
+    ptr: *mut c_void,
+    handler: Box<WarningHandler>,
+}
+
+impl Engine {
+    /// Creates a new `Engine`
+    ///
+    /// An [`Application`] must exist before creating an `Engine`, and the `Engine` should be
+    /// dropped before the `Application`.
+    ///
+    /// [`Application`]: ../app/struct.Application.html
+    pub fn new() -> Self {
+        ffi_span!("Engine", "new");
+        let mut handler = Box::new(None);
+        let data: *mut WarningHandler = &mut *handler;
+        let ptr = unsafe { qt_binding_qml_engine_create(data as *mut c_void, rs_engine_warnings) };
+        track_created!(QmlEngine);
+        Engine { ptr, handler }
+    }
+
+    /// Load a QML file and create its root object
+    ///
+    /// Paths starting with `qrc:` are loaded from Qt resources. Returns `true` if the root
+    /// object was created. Errors that prevent loading are reported like other warnings.
+    pub fn load<P>(&mut self, path: P) -> bool
+    where
+        P: AsRef<Path>,
+    {
+        ffi_span!("Engine", "load");
+        let path = path.as_ref().to_string_lossy();
+        unsafe {
+            qt_binding_qml_engine_load(self.ptr, path.as_ptr() as *const c_char, path.len() as u32)
+        }
+    }
+
+    /// Expose a value to QML as a context property
+    ///
+    /// Context properties must be set before loading the QML files that use them.
+    pub fn set_context_property<S>(&mut self, name: S, value: &Variant)
+    where
+        S: AsRef<str>,
+    {
+        ffi_span!("Engine", "set_context_property");
+        let name = name.as_ref();
+        unsafe {
+            qt_binding_qml_engine_set_context_property(
+                self.ptr,
+                name.as_ptr() as *const c_char,
+                name.len() as u32,
+                value.as_ptr(),
+            )
+        }
+    }
+
+    /// Handle warnings and errors reported by the engine
+    ///
+    /// The handler is called with every batch of diagnostics emitted by the engine, including
+    /// errors raised while loading QML files and JavaScript runtime errors. Setting a new
+    /// handler replaces the previous one.
+    ///
+    /// Diagnostics are still printed on the standard error, unless disabled with
+    /// [`output_warnings_to_stderr`].
+    ///
+    /// [`output_warnings_to_stderr`]: #method.output_warnings_to_stderr
+    pub fn on_warnings<F>(&mut self, handler: F) -> &mut Self
+    where
+        F: FnMut(&[Diagnostic]) + 'static,
+    {
+        *self.handler = Some(Box::new(handler));
+        self
+    }
+
+    /// Set extra file selectors, like `tablet` or `dark`
+    ///
+    /// File selectors load variants of QML files, and of other files loaded by the engine,
+    /// from sub-directories prefixed with `+`. With the `tablet` selector, loading
+    /// `qml/main.qml` loads `qml/+tablet/main.qml` if it exists. Platform selectors, like
+    /// `linux`, `android` or `windows`, and locale selectors are always enabled, and have a
+    /// lower priority than extra selectors. Selectors should be set before loading QML files.
+    ///
+    /// Directories embedded with `qt_binding_build::Builder::res_dir` keep their `+selector`
+    /// sub-directories, so selectors also apply to resources.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use qt_binding::app::Application;
+    /// use qt_binding::qml::Engine;
+    ///
+    /// let mut app = Application::new();
+    /// let mut engine = Engine::new();
+    /// engine.set_extra_selectors(&["tablet"]);
+    /// // Loads qrc:/qml/+tablet/main.qml if it exists
+    /// engine.load("qrc:/qml/main.qml");
+    /// app.exec();
+    /// ```
+    pub fn set_extra_selectors<I>(&mut self, selectors: I) -> &mut Self
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        ffi_span!("Engine", "set_extra_selectors");
+        let selectors = selectors
+            .into_iter()
+            .map(|selector| selector.as_ref().to_string())
+            .collect::<Vec<_>>();
+        let data = selectors
+            .iter()
+            .map(|selector| selector.as_ptr() as *const c_char)
+            .collect::<Vec<_>>();
+        let sizes = selectors
+            .iter()
+            .map(|selector| selector.len() as u32)
+            .collect::<Vec<_>>();
+
+        unsafe {
+            qt_binding_qml_engine_set_extra_selectors(
+                self.ptr,
+                data.as_ptr(),
+                sizes.as_ptr(),
+                selectors.len() as u32,
+            )
+        };
+        self
+    }
+
+    /// All the file selectors used by the engine, by decreasing priority
+    ///
+    /// Extra selectors set with [`set_extra_selectors`] come first, followed by locale and
+    /// platform selectors.
+    ///
+    /// [`set_extra_selectors`]: #method.set_extra_selectors
+    pub fn file_selectors(&self) -> Vec<String> {
+        ffi_span!("Engine", "file_selectors");
+        let mut selectors = Vec::new();
+        unsafe {
+            let data: *mut Vec<String> = &mut selectors;
+            qt_binding_qml_engine_file_selectors(
+                self.ptr,
+                data as *mut c_void,
+                rs_string_list_append,
+            );
+        }
+        selectors
+    }
+
+    /// Incubate asynchronous objects for a time budget, at a regular interval
+    ///
+    /// Objects created asynchronously, like the items of a `Loader` with `asynchronous: true`,
+    /// are incubated by the engine in small steps. Without an incubation controller, or before
+    /// a window is shown, they are not incubated smoothly. This installs a controller that
+    /// incubates objects for `budget` every `interval`, while objects are incubating.
+    ///
+    /// A budget of a few milliseconds every frame, like 5 ms every 16 ms, keeps animations
+    /// smooth while loading heavy scenes. Must be called before loading QML files. Calling it
+    /// again updates the budget and the interval.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use qt_binding::app::Application;
+    /// use qt_binding::qml::Engine;
+    /// use std::time::Duration;
+    ///
+    /// let mut app = Application::new();
+    /// let mut engine = Engine::new();
+    /// engine.set_incubation_budget(Duration::from_millis(5), Duration::from_millis(16));
+    /// engine.load("main.qml");
+    /// app.exec();
+    /// ```
+    pub fn set_incubation_budget(&mut self, budget: Duration, interval: Duration) -> &mut Self {
+        ffi_span!("Engine", "set_incubation_budget");
+        unsafe {
+            qt_binding_qml_engine_set_incubation_budget(
+                self.ptr,
+                to_msecs(budget),
+                to_msecs(interval),
+            )
+        };
+        self
+    }
+
+    /// Windows created by the loaded QML files, like an `ApplicationWindow`
+    ///
+    /// # Features
+    ///
+    /// This method requires the `gui` feature.
+    #[cfg(feature = "gui")]
+    pub fn windows(&self) -> Vec<Window<'_>> {
+        ffi_span!("Engine", "windows");
+        Window::children(self.ptr)
+    }
+
+    /// Print warnings and errors on the standard error
+    ///
+    /// Enabled by default.
+    pub fn output_warnings_to_stderr(&mut self, enabled: bool) -> &mut Self {
+        ffi_span!("Engine", "output_warnings_to_stderr");
+        unsafe { qt_binding_qml_engine_set_output_warnings_to_stderr(self.ptr, enabled) };
+        self
+    }
+}
+
+impl Default for Engine {
+    fn default() -> Self {
+        Engine::new()
+    }
+}
+
+impl Object for Engine {
+    fn as_object_ptr(&self) -> *mut c_void {
+        self.ptr
+    }
+}
+
+impl Drop for Engine {
+    fn drop(&mut self) {
+        ffi_span!("Engine", "drop");
+        if app::should_leak("Engine") {
+            return;
+        }
+        track_destroyed!(QmlEngine);
+        unsafe { qt_binding_qml_engine_delete(self.ptr) }
+    }
+}
+
+fn to_msecs(duration: Duration) -> c_int {
+    duration.as_millis().min(c_int::MAX as u128) as c_int
+}
+
+extern "C" fn rs_string_list_append(output: *mut c_void, input: *const c_char, input_size: u32) {
+    let output = unsafe { &mut *(output as *mut Vec<String>) };
+    unsafe {
+        let input = slice::from_raw_parts(input as *const u8, input_size as usize);
+        output.push(String::from_utf8_unchecked(Vec::from(input)));
+    }
+}
+
+pub(super) type RsEngineWarningsFunc =
+    extern "C" fn(data: *mut c_void, errors: *const c_void, count: c_int);
+
+extern "C" fn rs_engine_warnings(data: *mut c_void, errors: *const c_void, count: c_int) {
+    let handler = unsafe { &mut *(data as *mut WarningHandler) };
+    if let Some(handler) = handler {
+        handler(&diagnostics(errors, count));
+    }
+}
+
+/// Convert a list of `QQmlError`
+pub(super) fn diagnostics(errors: *const c_void, count: c_int) -> Vec<Diagnostic> {
+    (0..count).map(|index| diagnostic(errors, index)).collect()
+}
+
+fn diagnostic(errors: *const c_void, index: c_int) -> Diagnostic {
+    let mut url = String::new();
+    let mut message = String::new();
+    let (line, column) = unsafe {
+        let data: *mut String = &mut url;
+        qt_binding_qml_error_url(errors, index, data as *mut c_void, rs_string_fill);
+        let data: *mut String = &mut message;
+        qt_binding_qml_error_description(errors, index, data as *mut c_void, rs_string_fill);
+        (
+            qt_binding_qml_error_line(errors, index),
+            qt_binding_qml_error_column(errors, index),
+        )
+    };
+
+    let position = |value: c_int| if value > 0 { Some(value as u32) } else { None };
+    Diagnostic {
+        url,
+        line: position(line),
+        column: position(column),
+        message,
+    }
+}
+
+extern "C" {
+    fn qt_binding_qml_engine_create(
+        handler: *mut c_void,
+        warnings: RsEngineWarningsFunc,
+    ) -> *mut c_void;
+    fn qt_binding_qml_engine_delete(engine: *mut c_void);
+
+    fn qt_binding_qml_engine_load(engine: *mut c_void, path: *const c_char, size: u32) -> bool;
+    fn qt_binding_qml_engine_set_context_property(
+        engine: *mut c_void,
+        name: *const c_char,
+        size: u32,
+        value: *const c_void,
+    );
+    fn qt_binding_qml_engine_set_extra_selectors(
+        engine: *mut c_void,
+        selectors: *const *const c_char,
+        sizes: *const u32,
+        count: u32,
+    );
+    fn qt_binding_qml_engine_file_selectors(
+        engine: *mut c_void,
+        output: *mut c_void,
+        append: RsStringFillFunc,
+    );
+    fn qt_binding_qml_engine_set_incubation_budget(
+        engine: *mut c_void,
+        budget: c_int,
+        interval: c_int,
+    );
+    fn qt_binding_qml_engine_set_output_warnings_to_stderr(engine: *mut c_void, enabled: bool);
+
+    fn qt_binding_qml_error_url(
+        errors: *const c_void,
+        index: c_int,
+        output: *mut c_void,
+        fill: RsStringFillFunc,
+    );
+    fn qt_binding_qml_error_description(
+        errors: *const c_void,
+        index: c_int,
+        output: *mut c_void,
+        fill: RsStringFillFunc,
+    );
+    fn qt_binding_qml_error_line(errors: *const c_void, index: c_int) -> c_int;
+    fn qt_binding_qml_error_column(errors: *const c_void, index: c_int) -> c_int;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display_diagnostic() {
+        let mut diagnostic = Diagnostic {
+            url: "qrc:/main.qml".to_string(),
+            line: Some(12),
+            column: Some(5),
+            message: "ReferenceError: foo is not defined".to_string(),
+        };
+        assert_eq!(
+            diagnostic.to_string(),
+            "qrc:/main.qml:12:5: ReferenceError: foo is not defined"
+        );
+
+        diagnostic.column = None;
+        assert_eq!(
+            diagnostic.to_string(),
+            "qrc:/main.qml:12: ReferenceError: foo is not defined"
+        );
+
+        diagnostic.url = String::new();
+        diagnostic.line = None;
+        assert_eq!(
+            diagnostic.to_string(),
+            "<Unknown File>: ReferenceError: foo is not defined"
+        );
+    }
+}
@@ -0,0 +1,12 @@
+#include <QtQuickTest/quicktest.h>
+
+QT_USE_NAMESPACE
+
+extern "C" {
+
+int qt_binding_qml_run_tests(int argc, char **argv, const char *name, const char *dir)
+{
+    return quick_test_main(argc, argv, name, dir);
+}
+
+} // extern "C"
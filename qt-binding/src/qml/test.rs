@@ -0,0 +1,79 @@
+use std::env;
+use std::ffi::CString;
+use std::os::raw::{c_char, c_int};
+use std::path::Path;
+
+/// Run QML unit tests
+///
+/// This runs the Qt Quick Test harness, like `qmltestrunner`, on the QML files named
+/// `tst_*.qml` in `dir`. QML tests are written with the `TestCase` type from the `QtTest`
+/// QML module. Returns the number of failed tests, so `0` on success.
+///
+/// Arguments passed to the program are forwarded to the harness, so that tests can be
+/// filtered, like `cargo test --test qml -- -functions`.
+///
+/// The harness creates its own [`Application`] and must be called from the main thread, so
+/// QML tests should be run from a test target without the default test harness.
+///
+/// [`Application`]: ../app/struct.Application.html
+///
+/// # Features
+///
+/// This function is only available with the `quick-test` feature.
+///
+/// # Examples
+///
+/// With a test target declared with `harness = false` in `Cargo.toml`
+///
+/// ```toml
+/// [[test]]
+/// name = "qml"
+/// harness = false
+/// ```
+///
+/// `tests/qml.rs` runs the QML tests of `tests/qml`
+///
+/// ```no_run
+/// use qt_binding::qml;
+/// use std::process::exit;
+///
+/// fn main() {
+///     let dir = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/qml");
+///     exit(qml::run_tests("qml", dir));
+/// }
+/// ```
+pub fn run_tests<P>(name: &str, dir: P) -> i32
+where
+    P: AsRef<Path>,
+{
+    ffi_span!("QuickTest", "run_tests");
+    let dir = dir.as_ref();
+    let argv_strings = env::args()
+        .map(|arg| CString::new(arg).unwrap())
+        .collect::<Vec<_>>();
+    let mut argv = argv_strings
+        .iter()
+        .map(|arg| arg.as_ptr() as *mut c_char)
+        .collect::<Vec<_>>();
+
+    let name = CString::new(name).expect("Test name must not contain NUL characters");
+    let dir = CString::new(dir.to_string_lossy().as_bytes())
+        .expect("Test directory must not contain NUL characters");
+    unsafe {
+        qt_binding_qml_run_tests(
+            argv.len() as c_int,
+            argv.as_mut_ptr(),
+            name.as_ptr(),
+            dir.as_ptr(),
+        ) as i32
+    }
+}
+
+extern "C" {
+    fn qt_binding_qml_run_tests(
+        argc: c_int,
+        argv: *mut *mut c_char,
+        name: *const c_char,
+        dir: *const c_char,
+    ) -> c_int;
+}
@@ -0,0 +1,156 @@
+#include <QtSerialPort/QSerialPort>
+#include <QtSerialPort/QSerialPortInfo>
+#include <cstdint>
+
+QT_USE_NAMESPACE
+
+using RsStringFillFunc = void (*)(void *output, const char *input, std::uint32_t inputSize);
+using RsBytesFunc = void (*)(void *data, const char *input, std::uint32_t inputSize);
+
+static void fillString(const QString &value, void *output, RsStringFillFunc fill)
+{
+    const auto byteArray = value.toUtf8();
+    fill(output, byteArray.constData(), static_cast<std::uint32_t>(byteArray.size()));
+}
+
+extern "C" {
+
+QList<QSerialPortInfo> *qt_binding_serial_port_info_list_create()
+{
+    return new QList<QSerialPortInfo>(QSerialPortInfo::availablePorts());
+}
+
+void qt_binding_serial_port_info_list_delete(QList<QSerialPortInfo> *list)
+{
+    delete list;
+}
+
+int qt_binding_serial_port_info_list_count(const QList<QSerialPortInfo> *list)
+{
+    return list->count();
+}
+
+void qt_binding_serial_port_info_list_string(const QList<QSerialPortInfo> *list, int index,
+                                             int field, void *output, RsStringFillFunc fill)
+{
+    const auto &info = list->at(index);
+    switch (field) {
+    case 0:
+        fillString(info.portName(), output, fill);
+        break;
+    case 1:
+        fillString(info.description(), output, fill);
+        break;
+    case 2:
+        fillString(info.manufacturer(), output, fill);
+        break;
+    case 3:
+        fillString(info.serialNumber(), output, fill);
+        break;
+    default:
+        break;
+    }
+}
+
+int qt_binding_serial_port_info_list_vendor_id(const QList<QSerialPortInfo> *list, int index)
+{
+    const auto &info = list->at(index);
+    return info.hasVendorIdentifier() ? info.vendorIdentifier() : -1;
+}
+
+int qt_binding_serial_port_info_list_product_id(const QList<QSerialPortInfo> *list, int index)
+{
+    const auto &info = list->at(index);
+    return info.hasProductIdentifier() ? info.productIdentifier() : -1;
+}
+
+QSerialPort *qt_binding_serial_port_create(const char *name, std::uint32_t size, void *data,
+                                           RsStringFillFunc error)
+{
+    auto port = new QSerialPort(QString::fromUtf8(name, static_cast<int>(size)));
+    QObject::connect(port, &QSerialPort::errorOccurred,
+                     [port, data, error](QSerialPort::SerialPortError serialPortError) {
+                         if (serialPortError != QSerialPort::NoError) {
+                             fillString(port->errorString(), data, error);
+                         }
+                     });
+    return port;
+}
+
+void qt_binding_serial_port_delete(QSerialPort *port)
+{
+    delete port;
+}
+
+void qt_binding_serial_port_set_baud_rate(QSerialPort *port, int baudRate)
+{
+    port->setBaudRate(baudRate);
+}
+
+void qt_binding_serial_port_set_data_bits(QSerialPort *port, int dataBits)
+{
+    port->setDataBits(static_cast<QSerialPort::DataBits>(dataBits));
+}
+
+void qt_binding_serial_port_set_parity(QSerialPort *port, int parity)
+{
+    port->setParity(static_cast<QSerialPort::Parity>(parity));
+}
+
+void qt_binding_serial_port_set_stop_bits(QSerialPort *port, int stopBits)
+{
+    port->setStopBits(static_cast<QSerialPort::StopBits>(stopBits));
+}
+
+void qt_binding_serial_port_set_flow_control(QSerialPort *port, int flowControl)
+{
+    port->setFlowControl(static_cast<QSerialPort::FlowControl>(flowControl));
+}
+
+bool qt_binding_serial_port_open(QSerialPort *port)
+{
+    return port->open(QIODevice::ReadWrite);
+}
+
+void qt_binding_serial_port_close(QSerialPort *port)
+{
+    port->close();
+}
+
+bool qt_binding_serial_port_is_open(const QSerialPort *port)
+{
+    return port->isOpen();
+}
+
+std::int64_t qt_binding_serial_port_write(QSerialPort *port, const char *data, std::uint32_t size)
+{
+    return port->write(data, static_cast<qint64>(size));
+}
+
+std::int64_t qt_binding_serial_port_bytes_available(const QSerialPort *port)
+{
+    return port->bytesAvailable();
+}
+
+void qt_binding_serial_port_read_all(QSerialPort *port, void *output, RsBytesFunc fill)
+{
+    const auto byteArray = port->readAll();
+    fill(output, byteArray.constData(), static_cast<std::uint32_t>(byteArray.size()));
+}
+
+void qt_binding_serial_port_connect_ready_read(QSerialPort *port, void *data,
+                                               RsBytesFunc readyRead)
+{
+    QObject::connect(port, &QSerialPort::readyRead, [port, data, readyRead]() {
+        const auto byteArray = port->readAll();
+        readyRead(data, byteArray.constData(), static_cast<std::uint32_t>(byteArray.size()));
+    });
+}
+
+void qt_binding_serial_port_error_string(const QSerialPort *port, void *output,
+                                         RsStringFillFunc fill)
+{
+    fillString(port->errorString(), output, fill);
+}
+
+} // extern "C"
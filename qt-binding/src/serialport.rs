@@ -0,0 +1,526 @@
+//! Bindings to Qt Serial Port
+//!
+//! [`SerialPort`] is a binding to `QSerialPort`. Reading and writing is asynchronous and
+//! integrated with Qt's event-loop: writes are buffered and sent while the event-loop runs, and
+//! received data is passed to the handler set with [`on_ready_read`]. Available ports are
+//! listed with [`PortInfo::available`].
+//!
+//! [`SerialPort`]: struct.SerialPort.html
+//! [`on_ready_read`]: struct.SerialPort.html#method.on_ready_read
+//! [`PortInfo::available`]: struct.PortInfo.html#method.available
+//!
+//! # Features
+//!
+//! This module is only available with the `serialport` feature.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use qt_binding::app::Application;
+//! use qt_binding::serialport::{Parity, PortInfo, SerialPort};
+//!
+//! let mut app = Application::new();
+//!
+//! let info = PortInfo::available().into_iter().next().unwrap();
+//! let mut port = SerialPort::new(info.name());
+//! port.set_baud_rate(115_200).set_parity(Parity::None);
+//! port.on_ready_read(|data| println!("Received {:?}", data));
+//! port.on_error(|error| eprintln!("Serial port error: {}", error));
+//! port.open().unwrap();
+//! port.write(b"hello\n").unwrap();
+//!
+//! app.exec();
+//! ```
+
+use crate::app;
+use crate::variant::convert::{rs_string_fill, RsStringFillFunc};
+use std::error;
+use std::fmt;
+use std::os::raw::{c_char, c_int, c_void};
+use std::slice;
+
+type ReadyReadHandler = Option<Box<dyn FnMut(&[u8])>>;
+type ErrorHandler = Option<Box<dyn FnMut(Error)>>;
+
+/// Error reported by a serial port
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Error {
+    message: String,
+}
+
+impl Error {
+    /// Error message
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl error::Error for Error {}
+
+/// Number of data bits in a frame
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DataBits {
+    /// 5 bits
+    Five,
+    /// 6 bits
+    Six,
+    /// 7 bits
+    Seven,
+    /// 8 bits
+    Eight,
+}
+
+impl DataBits {
+    fn as_c_int(self) -> c_int {
+        match self {
+            DataBits::Five => 5,
+            DataBits::Six => 6,
+            DataBits::Seven => 7,
+            DataBits::Eight => 8,
+        }
+    }
+}
+
+/// Parity checking mode
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Parity {
+    /// No parity bit
+    None,
+    /// Even parity
+    Even,
+    /// Odd parity
+    Odd,
+    /// Parity bit always 0
+    Space,
+    /// Parity bit always 1
+    Mark,
+}
+
+impl Parity {
+    fn as_c_int(self) -> c_int {
+        match self {
+            Parity::None => 0,
+            Parity::Even => 2,
+            Parity::Odd => 3,
+            Parity::Space => 4,
+            Parity::Mark => 5,
+        }
+    }
+}
+
+/// Number of stop bits in a frame
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum StopBits {
+    /// 1 stop bit
+    One,
+    /// 1.5 stop bits
+    OneAndHalf,
+    /// 2 stop bits
+    Two,
+}
+
+impl StopBits {
+    fn as_c_int(self) -> c_int {
+        match self {
+            StopBits::One => 1,
+            StopBits::OneAndHalf => 3,
+            StopBits::Two => 2,
+        }
+    }
+}
+
+/// Flow control mode
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum FlowControl {
+    /// No flow control
+    None,
+    /// Hardware flow control, with RTS/CTS
+    Hardware,
+    /// Software flow control, with XON/XOFF
+    Software,
+}
+
+impl FlowControl {
+    fn as_c_int(self) -> c_int {
+        match self {
+            FlowControl::None => 0,
+            FlowControl::Hardware => 1,
+            FlowControl::Software => 2,
+        }
+    }
+}
+
+/// Information about an available serial port
+///
+/// Binding to `QSerialPortInfo`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PortInfo {
+    name: String,
+    description: String,
+    manufacturer: String,
+    serial_number: String,
+    vendor_id: Option<u16>,
+    product_id: Option<u16>,
+}
+
+impl PortInfo {
+    /// Serial ports available on the system
+    pub fn available() -> Vec<PortInfo> {
+        ffi_span!("PortInfo", "available");
+        unsafe {
+            let list = qt_binding_serial_port_info_list_create();
+            let count = qt_binding_serial_port_info_list_count(list);
+            let infos = (0..count)
+                .map(|index| PortInfo {
+                    name: info_string(list, index, InfoField::Name),
+                    description: info_string(list, index, InfoField::Description),
+                    manufacturer: info_string(list, index, InfoField::Manufacturer),
+                    serial_number: info_string(list, index, InfoField::SerialNumber),
+                    vendor_id: info_id(qt_binding_serial_port_info_list_vendor_id(list, index)),
+                    product_id: info_id(qt_binding_serial_port_info_list_product_id(list, index)),
+                })
+                .collect();
+            qt_binding_serial_port_info_list_delete(list);
+            infos
+        }
+    }
+
+    /// Name of the port, like `ttyUSB0` or `COM1`
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Description of the port
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+
+    /// Manufacturer of the device
+    pub fn manufacturer(&self) -> &str {
+        &self.manufacturer
+    }
+
+    /// Serial number of the device
+    pub fn serial_number(&self) -> &str {
+        &self.serial_number
+    }
+
+    /// USB vendor identifier of the device, if any
+    pub fn vendor_id(&self) -> Option<u16> {
+        self.vendor_id
+    }
+
+    /// USB product identifier of the device, if any
+    pub fn product_id(&self) -> Option<u16> {
+        self.product_id
+    }
+}
+
+#[derive(Clone, Copy)]
+enum InfoField {
+    Name = 0,
+    Description = 1,
+    Manufacturer = 2,
+    SerialNumber = 3,
+}
+
+unsafe fn info_string(list: *const c_void, index: c_int, field: InfoField) -> String {
+    let mut value = String::new();
+    let data: *mut String = &mut value;
+    qt_binding_serial_port_info_list_string(
+        list,
+        index,
+        field as c_int,
+        data as *mut c_void,
+        rs_string_fill,
+    );
+    value
+}
+
+fn info_id(id: c_int) -> Option<u16> {
+    if id < 0 {
+        None
+    } else {
+        Some(id as u16)
+    }
+}
+
+/// Binding to `QSerialPort`
+///
+/// See module level documentation for more information.
+pub struct SerialPort {
+    ptr: *mut c_void,
+    ready_read: Box<ReadyReadHandler>,
+    ready_read_connected: bool,
+    error: Box<ErrorHandler>,
+}
+
+impl SerialPort {
+    /// Creates a `SerialPort` for a port name
+    ///
+    /// The name is either a name returned by [`PortInfo::name`], or the full path to the
+    /// device.
+    ///
+    /// [`PortInfo::name`]: struct.PortInfo.html#method.name
+    pub fn new<S>(name: S) -> Self
+    where
+        S: AsRef<str>,
+    {
+        ffi_span!("SerialPort", "new");
+        let name = name.as_ref();
+        let mut error = Box::new(None);
+        let data: *mut ErrorHandler = &mut *error;
+        let ptr = unsafe {
+            qt_binding_serial_port_create(
+                name.as_ptr() as *const c_char,
+                name.len() as u32,
+                data as *mut c_void,
+                rs_serial_port_error,
+            )
+        };
+        track_created!(SerialPort);
+        SerialPort {
+            ptr,
+            ready_read: Box::new(None),
+            ready_read_connected: false,
+            error,
+        }
+    }
+
+    /// Set the baud rate
+    pub fn set_baud_rate(&mut self, baud_rate: u32) -> &mut Self {
+        ffi_span!("SerialPort", "set_baud_rate");
+        unsafe { qt_binding_serial_port_set_baud_rate(self.ptr, baud_rate as c_int) }
+        self
+    }
+
+    /// Set the number of data bits
+    pub fn set_data_bits(&mut self, data_bits: DataBits) -> &mut Self {
+        ffi_span!("SerialPort", "set_data_bits");
+        unsafe { qt_binding_serial_port_set_data_bits(self.ptr, data_bits.as_c_int()) }
+        self
+    }
+
+    /// Set the parity checking mode
+    pub fn set_parity(&mut self, parity: Parity) -> &mut Self {
+        ffi_span!("SerialPort", "set_parity");
+        unsafe { qt_binding_serial_port_set_parity(self.ptr, parity.as_c_int()) }
+        self
+    }
+
+    /// Set the number of stop bits
+    pub fn set_stop_bits(&mut self, stop_bits: StopBits) -> &mut Self {
+        ffi_span!("SerialPort", "set_stop_bits");
+        unsafe { qt_binding_serial_port_set_stop_bits(self.ptr, stop_bits.as_c_int()) }
+        self
+    }
+
+    /// Set the flow control mode
+    pub fn set_flow_control(&mut self, flow_control: FlowControl) -> &mut Self {
+        ffi_span!("SerialPort", "set_flow_control");
+        unsafe { qt_binding_serial_port_set_flow_control(self.ptr, flow_control.as_c_int()) }
+        self
+    }
+
+    /// Open the port for reading and writing
+    pub fn open(&mut self) -> Result<(), Error> {
+        ffi_span!("SerialPort", "open");
+        if unsafe { qt_binding_serial_port_open(self.ptr) } {
+            Ok(())
+        } else {
+            Err(self.last_error())
+        }
+    }
+
+    /// Close the port
+    pub fn close(&mut self) {
+        ffi_span!("SerialPort", "close");
+        unsafe { qt_binding_serial_port_close(self.ptr) }
+    }
+
+    /// Returns `true` if the port is open
+    pub fn is_open(&self) -> bool {
+        ffi_span!("SerialPort", "is_open");
+        unsafe { qt_binding_serial_port_is_open(self.ptr) }
+    }
+
+    /// Write data
+    ///
+    /// Data is buffered, and written while the event-loop runs. Returns the number of bytes
+    /// that were buffered.
+    pub fn write(&mut self, data: &[u8]) -> Result<usize, Error> {
+        ffi_span!("SerialPort", "write");
+        let written = unsafe {
+            qt_binding_serial_port_write(
+                self.ptr,
+                data.as_ptr() as *const c_char,
+                data.len() as u32,
+            )
+        };
+        if written < 0 {
+            Err(self.last_error())
+        } else {
+            Ok(written as usize)
+        }
+    }
+
+    /// Number of bytes waiting to be read
+    pub fn bytes_available(&self) -> usize {
+        ffi_span!("SerialPort", "bytes_available");
+        unsafe { qt_binding_serial_port_bytes_available(self.ptr) as usize }
+    }
+
+    /// Read all data waiting to be read
+    pub fn read_all(&mut self) -> Vec<u8> {
+        ffi_span!("SerialPort", "read_all");
+        let mut value = Vec::new();
+        unsafe {
+            let data: *mut Vec<u8> = &mut value;
+            qt_binding_serial_port_read_all(self.ptr, data as *mut c_void, rs_bytes_fill);
+        }
+        value
+    }
+
+    /// Handle received data
+    ///
+    /// The handler is called from the event-loop with the data received. Once a handler is set,
+    /// data is passed to the handler, and is no longer available with [`read_all`]. Setting a
+    /// new handler replaces the previous one.
+    ///
+    /// [`read_all`]: #method.read_all
+    pub fn on_ready_read<F>(&mut self, handler: F) -> &mut Self
+    where
+        F: FnMut(&[u8]) + 'static,
+    {
+        ffi_span!("SerialPort", "on_ready_read");
+        *self.ready_read = Some(Box::new(handler));
+        if !self.ready_read_connected {
+            let data: *mut ReadyReadHandler = &mut *self.ready_read;
+            unsafe {
+                qt_binding_serial_port_connect_ready_read(
+                    self.ptr,
+                    data as *mut c_void,
+                    rs_serial_port_ready_read,
+                )
+            }
+            self.ready_read_connected = true;
+        }
+        self
+    }
+
+    /// Handle errors
+    ///
+    /// The handler is called from the event-loop when an error occurs, like when the device is
+    /// unplugged. Setting a new handler replaces the previous one.
+    pub fn on_error<F>(&mut self, handler: F) -> &mut Self
+    where
+        F: FnMut(Error) + 'static,
+    {
+        *self.error = Some(Box::new(handler));
+        self
+    }
+
+    fn last_error(&self) -> Error {
+        let mut message = String::new();
+        unsafe {
+            let data: *mut String = &mut message;
+            qt_binding_serial_port_error_string(self.ptr, data as *mut c_void, rs_string_fill);
+        }
+        Error { message }
+    }
+}
+
+impl fmt::Debug for SerialPort {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("SerialPort")
+            .field("is_open", &self.is_open())
+            .finish()
+    }
+}
+
+impl Drop for SerialPort {
+    fn drop(&mut self) {
+        ffi_span!("SerialPort", "drop");
+        if app::should_leak("SerialPort") {
+            return;
+        }
+        track_destroyed!(SerialPort);
+        unsafe { qt_binding_serial_port_delete(self.ptr) }
+    }
+}
+
+extern "C" fn rs_bytes_fill(output: *mut c_void, input: *const c_char, input_size: u32) {
+    let output = unsafe { &mut *(output as *mut Vec<u8>) };
+    let input = unsafe { slice::from_raw_parts(input as *const u8, input_size as usize) };
+    output.extend_from_slice(input);
+}
+
+extern "C" fn rs_serial_port_ready_read(data: *mut c_void, input: *const c_char, input_size: u32) {
+    let handler = unsafe { &mut *(data as *mut ReadyReadHandler) };
+    if let Some(handler) = handler {
+        let input = unsafe { slice::from_raw_parts(input as *const u8, input_size as usize) };
+        handler(input);
+    }
+}
+
+extern "C" fn rs_serial_port_error(data: *mut c_void, message: *const c_char, message_size: u32) {
+    let handler = unsafe { &mut *(data as *mut ErrorHandler) };
+    if let Some(handler) = handler {
+        let mut value = String::new();
+        let output: *mut String = &mut value;
+        rs_string_fill(output as *mut c_void, message, message_size);
+        handler(Error { message: value });
+    }
+}
+
+type RsBytesFunc = extern "C" fn(data: *mut c_void, input: *const c_char, input_size: u32);
+
+extern "C" {
+    fn qt_binding_serial_port_info_list_create() -> *mut c_void;
+    fn qt_binding_serial_port_info_list_delete(list: *mut c_void);
+    fn qt_binding_serial_port_info_list_count(list: *const c_void) -> c_int;
+    fn qt_binding_serial_port_info_list_string(
+        list: *const c_void,
+        index: c_int,
+        field: c_int,
+        output: *mut c_void,
+        fill: RsStringFillFunc,
+    );
+    fn qt_binding_serial_port_info_list_vendor_id(list: *const c_void, index: c_int) -> c_int;
+    fn qt_binding_serial_port_info_list_product_id(list: *const c_void, index: c_int) -> c_int;
+
+    fn qt_binding_serial_port_create(
+        name: *const c_char,
+        size: u32,
+        data: *mut c_void,
+        error: RsStringFillFunc,
+    ) -> *mut c_void;
+    fn qt_binding_serial_port_delete(port: *mut c_void);
+    fn qt_binding_serial_port_set_baud_rate(port: *mut c_void, baud_rate: c_int);
+    fn qt_binding_serial_port_set_data_bits(port: *mut c_void, data_bits: c_int);
+    fn qt_binding_serial_port_set_parity(port: *mut c_void, parity: c_int);
+    fn qt_binding_serial_port_set_stop_bits(port: *mut c_void, stop_bits: c_int);
+    fn qt_binding_serial_port_set_flow_control(port: *mut c_void, flow_control: c_int);
+    fn qt_binding_serial_port_open(port: *mut c_void) -> bool;
+    fn qt_binding_serial_port_close(port: *mut c_void);
+    fn qt_binding_serial_port_is_open(port: *const c_void) -> bool;
+    fn qt_binding_serial_port_write(port: *mut c_void, data: *const c_char, size: u32) -> i64;
+    fn qt_binding_serial_port_bytes_available(port: *const c_void) -> i64;
+    fn qt_binding_serial_port_read_all(port: *mut c_void, output: *mut c_void, fill: RsBytesFunc);
+    fn qt_binding_serial_port_connect_ready_read(
+        port: *mut c_void,
+        data: *mut c_void,
+        ready_read: RsBytesFunc,
+    );
+    fn qt_binding_serial_port_error_string(
+        port: *const c_void,
+        output: *mut c_void,
+        fill: RsStringFillFunc,
+    );
+}
@@ -0,0 +1,81 @@
+#include <QtCore/QSharedMemory>
+#include <cstdint>
+
+QT_USE_NAMESPACE
+
+using RsStringFillFunc = void (*)(void *output, const char *input, std::uint32_t inputSize);
+
+static QSharedMemory::AccessMode toAccessMode(bool readOnly)
+{
+    return readOnly ? QSharedMemory::ReadOnly : QSharedMemory::ReadWrite;
+}
+
+extern "C" {
+
+QSharedMemory *qt_binding_shared_memory_create(const char *key, std::uint32_t size)
+{
+    return new QSharedMemory(QString::fromUtf8(key, static_cast<int>(size)));
+}
+
+void qt_binding_shared_memory_delete(QSharedMemory *memory)
+{
+    delete memory;
+}
+
+void qt_binding_shared_memory_key(const QSharedMemory *memory, void *output,
+                                  RsStringFillFunc fill)
+{
+    const auto byteArray = memory->key().toUtf8();
+    fill(output, byteArray.constData(), static_cast<std::uint32_t>(byteArray.size()));
+}
+
+bool qt_binding_shared_memory_create_segment(QSharedMemory *memory, std::uint64_t size,
+                                             bool readOnly)
+{
+    return memory->create(static_cast<int>(size), toAccessMode(readOnly));
+}
+
+bool qt_binding_shared_memory_attach(QSharedMemory *memory, bool readOnly)
+{
+    return memory->attach(toAccessMode(readOnly));
+}
+
+bool qt_binding_shared_memory_detach(QSharedMemory *memory)
+{
+    return memory->detach();
+}
+
+bool qt_binding_shared_memory_is_attached(const QSharedMemory *memory)
+{
+    return memory->isAttached();
+}
+
+std::uint64_t qt_binding_shared_memory_size(const QSharedMemory *memory)
+{
+    return static_cast<std::uint64_t>(memory->size());
+}
+
+void *qt_binding_shared_memory_data(QSharedMemory *memory)
+{
+    return memory->data();
+}
+
+bool qt_binding_shared_memory_lock(QSharedMemory *memory)
+{
+    return memory->lock();
+}
+
+bool qt_binding_shared_memory_unlock(QSharedMemory *memory)
+{
+    return memory->unlock();
+}
+
+int qt_binding_shared_memory_error(const QSharedMemory *memory, void *output,
+                                   RsStringFillFunc fill)
+{
+    const auto byteArray = memory->errorString().toUtf8();
+    fill(output, byteArray.constData(), static_cast<std::uint32_t>(byteArray.size()));
+    return static_cast<int>(memory->error());
+}
+
+} // extern "C"
@@ -0,0 +1,409 @@
+//! Binding to `QSharedMemory`
+//!
+//! [`SharedMemory`] is a binding on Qt's `QSharedMemory`. It gives access to a segment of memory
+//! shared between processes, identified by a key. Since Qt's semantics are used, Rust processes
+//! can exchange data with Qt applications on every platform supported by Qt.
+//!
+//! A segment is created with [`create`], and other processes access it with [`attach`]. Access
+//! to the segment is synchronized between processes with [`lock`], that returns a [`Lock`]
+//! guard giving access to the bytes of the segment.
+//!
+//! [`SharedMemory`]: struct.SharedMemory.html
+//! [`create`]: struct.SharedMemory.html#method.create
+//! [`attach`]: struct.SharedMemory.html#method.attach
+//! [`lock`]: struct.SharedMemory.html#method.lock
+//! [`Lock`]: struct.Lock.html
+//!
+//! # Limitations
+//!
+//! Since `SharedMemory` is a binding over `QSharedMemory`, it is neither `Send` nor `Sync`.
+//!
+//! On Unix, the segment is destroyed when the last `SharedMemory` attached to it is dropped, or
+//! detached. If the process that created the segment crashes, it may outlive the process.
+//!
+//! # Examples
+//!
+//! ```
+//! use qt_binding::sharedmem::{AccessMode, SharedMemory};
+//!
+//! let mut writer = SharedMemory::new("qt-binding-example");
+//! writer.create(4, AccessMode::ReadWrite).unwrap();
+//! writer.lock().unwrap().as_mut_slice()[..4].copy_from_slice(b"ping");
+//!
+//! let mut reader = SharedMemory::new("qt-binding-example");
+//! reader.attach(AccessMode::ReadOnly).unwrap();
+//! assert_eq!(&reader.lock().unwrap().as_slice()[..4], b"ping");
+//! ```
+
+use crate::variant::convert::{rs_string_fill, RsStringFillFunc};
+use std::error;
+use std::fmt;
+use std::os::raw::{c_char, c_int, c_void};
+use std::slice;
+
+/// Kind of error reported by `QSharedMemory`
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ErrorKind {
+    /// The segment could not be accessed because of permissions
+    Permission,
+    /// The requested size is invalid
+    InvalidSize,
+    /// The key is invalid
+    Key,
+    /// A segment already exists with this key
+    AlreadyExists,
+    /// No segment exists with this key
+    NotFound,
+    /// The segment could not be locked
+    Lock,
+    /// The system ran out of memory or resources
+    OutOfResources,
+    /// Any other error
+    Unknown,
+}
+
+impl ErrorKind {
+    fn from_c_int(error: c_int) -> Self {
+        match error {
+            1 => ErrorKind::Permission,
+            2 => ErrorKind::InvalidSize,
+            3 => ErrorKind::Key,
+            4 => ErrorKind::AlreadyExists,
+            5 => ErrorKind::NotFound,
+            6 => ErrorKind::Lock,
+            7 => ErrorKind::OutOfResources,
+            _ => ErrorKind::Unknown,
+        }
+    }
+}
+
+/// Error reported by `QSharedMemory`
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Error {
+    kind: ErrorKind,
+    message: String,
+}
+
+impl Error {
+    /// Kind of error
+    pub fn kind(&self) -> ErrorKind {
+        self.kind
+    }
+
+    /// Error message
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl error::Error for Error {}
+
+/// Access to a shared memory segment
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum AccessMode {
+    /// The segment can only be read
+    ReadOnly,
+    /// The segment can be read and written
+    ReadWrite,
+}
+
+impl AccessMode {
+    fn is_read_only(self) -> bool {
+        self == AccessMode::ReadOnly
+    }
+}
+
+/// Binding to `QSharedMemory`
+///
+/// See module level documentation for more information.
+pub struct SharedMemory {
+    ptr: *mut c_void,
+    access_mode: AccessMode,
+}
+
+impl SharedMemory {
+    /// Creates a `SharedMemory` for a key
+    ///
+    /// The `SharedMemory` is not attached to any segment. Call [`create`] or [`attach`] to
+    /// access the segment.
+    ///
+    /// [`create`]: #method.create
+    /// [`attach`]: #method.attach
+    pub fn new<S>(key: S) -> Self
+    where
+        S: AsRef<str>,
+    {
+        ffi_span!("SharedMemory", "new");
+        let key = key.as_ref();
+        track_created!(SharedMemory);
+        SharedMemory {
+            ptr: unsafe {
+                qt_binding_shared_memory_create(key.as_ptr() as *const c_char, key.len() as u32)
+            },
+            access_mode: AccessMode::ReadWrite,
+        }
+    }
+
+    /// Key identifying the segment
+    pub fn key(&self) -> String {
+        ffi_span!("SharedMemory", "key");
+        let mut key = String::new();
+        unsafe {
+            let data: *mut String = &mut key;
+            qt_binding_shared_memory_key(self.ptr, data as *mut c_void, rs_string_fill);
+        }
+        key
+    }
+
+    /// Create a segment of `size` bytes and attach to it
+    ///
+    /// The segment is initialized to zero. An error of kind [`AlreadyExists`] is returned if
+    /// a segment already exists for the key. It can then be accessed with [`attach`].
+    ///
+    /// [`AlreadyExists`]: enum.ErrorKind.html#variant.AlreadyExists
+    /// [`attach`]: #method.attach
+    pub fn create(&mut self, size: usize, access_mode: AccessMode) -> Result<(), Error> {
+        ffi_span!("SharedMemory", "create");
+        let result = unsafe {
+            qt_binding_shared_memory_create_segment(
+                self.ptr,
+                size as u64,
+                access_mode.is_read_only(),
+            )
+        };
+        self.access_mode = access_mode;
+        self.check(result)
+    }
+
+    /// Attach to an existing segment
+    ///
+    /// An error of kind [`NotFound`] is returned if no segment exists for the key.
+    ///
+    /// [`NotFound`]: enum.ErrorKind.html#variant.NotFound
+    pub fn attach(&mut self, access_mode: AccessMode) -> Result<(), Error> {
+        ffi_span!("SharedMemory", "attach");
+        let result =
+            unsafe { qt_binding_shared_memory_attach(self.ptr, access_mode.is_read_only()) };
+        self.access_mode = access_mode;
+        self.check(result)
+    }
+
+    /// Detach from the segment
+    ///
+    /// On Unix, the segment is destroyed if no other process is attached to it.
+    pub fn detach(&mut self) -> Result<(), Error> {
+        ffi_span!("SharedMemory", "detach");
+        let result = unsafe { qt_binding_shared_memory_detach(self.ptr) };
+        self.check(result)
+    }
+
+    /// Returns `true` if attached to a segment
+    pub fn is_attached(&self) -> bool {
+        ffi_span!("SharedMemory", "is_attached");
+        unsafe { qt_binding_shared_memory_is_attached(self.ptr) }
+    }
+
+    /// Size of the segment in bytes
+    ///
+    /// Returns 0 if not attached to a segment. The size might be larger than the size used to
+    /// create the segment, as it might be rounded by the platform.
+    pub fn size(&self) -> usize {
+        ffi_span!("SharedMemory", "size");
+        unsafe { qt_binding_shared_memory_size(self.ptr) as usize }
+    }
+
+    /// Lock the segment
+    ///
+    /// This call blocks until no other process holds the lock. The segment is unlocked when
+    /// the returned [`Lock`] is dropped.
+    ///
+    /// [`Lock`]: struct.Lock.html
+    pub fn lock(&mut self) -> Result<Lock<'_>, Error> {
+        ffi_span!("SharedMemory", "lock");
+        if !self.is_attached() {
+            return Err(Error {
+                kind: ErrorKind::NotFound,
+                message: "Not attached to a shared memory segment".to_string(),
+            });
+        }
+
+        let result = unsafe { qt_binding_shared_memory_lock(self.ptr) };
+        self.check(result)?;
+        Ok(Lock { memory: self })
+    }
+
+    fn check(&self, result: bool) -> Result<(), Error> {
+        if result {
+            return Ok(());
+        }
+
+        let mut message = String::new();
+        let kind = unsafe {
+            let data: *mut String = &mut message;
+            qt_binding_shared_memory_error(self.ptr, data as *mut c_void, rs_string_fill)
+        };
+        Err(Error {
+            kind: ErrorKind::from_c_int(kind),
+            message,
+        })
+    }
+}
+
+impl fmt::Debug for SharedMemory {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("SharedMemory")
+            .field("key", &self.key())
+            .field("is_attached", &self.is_attached())
+            .field("size", &self.size())
+            .finish()
+    }
+}
+
+impl Drop for SharedMemory {
+    fn drop(&mut self) {
+        ffi_span!("SharedMemory", "drop");
+        track_destroyed!(SharedMemory);
+        unsafe { qt_binding_shared_memory_delete(self.ptr) }
+    }
+}
+
+/// Lock on a shared memory segment
+///
+/// The lock gives access to the bytes of the segment. It is released when dropped.
+///
+/// See [`SharedMemory::lock`] for more information.
+///
+/// [`SharedMemory::lock`]: struct.SharedMemory.html#method.lock
+pub struct Lock<'a> {
+    memory: &'a mut SharedMemory,
+}
+
+impl Lock<'_> {
+    /// Bytes of the segment
+    pub fn as_slice(&self) -> &[u8] {
+        ffi_span!("Lock", "as_slice");
+        unsafe {
+            let data = qt_binding_shared_memory_data(self.memory.ptr);
+            slice::from_raw_parts(data as *const u8, self.memory.size())
+        }
+    }
+
+    /// Mutable bytes of the segment
+    ///
+    /// # Panics
+    ///
+    /// Panics if the segment was attached with [`AccessMode::ReadOnly`].
+    ///
+    /// [`AccessMode::ReadOnly`]: enum.AccessMode.html#variant.ReadOnly
+    pub fn as_mut_slice(&mut self) -> &mut [u8] {
+        ffi_span!("Lock", "as_mut_slice");
+        assert!(
+            !self.memory.access_mode.is_read_only(),
+            "Shared memory segment is read-only"
+        );
+        unsafe {
+            let data = qt_binding_shared_memory_data(self.memory.ptr);
+            slice::from_raw_parts_mut(data as *mut u8, self.memory.size())
+        }
+    }
+}
+
+impl fmt::Debug for Lock<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Lock")
+            .field("memory", &self.memory)
+            .finish()
+    }
+}
+
+impl Drop for Lock<'_> {
+    fn drop(&mut self) {
+        ffi_span!("Lock", "drop");
+        unsafe { qt_binding_shared_memory_unlock(self.memory.ptr) };
+    }
+}
+
+extern "C" {
+    fn qt_binding_shared_memory_create(key: *const c_char, size: u32) -> *mut c_void;
+    fn qt_binding_shared_memory_delete(memory: *mut c_void);
+    fn qt_binding_shared_memory_key(
+        memory: *const c_void,
+        output: *mut c_void,
+        fill: RsStringFillFunc,
+    );
+    fn qt_binding_shared_memory_create_segment(
+        memory: *mut c_void,
+        size: u64,
+        read_only: bool,
+    ) -> bool;
+    fn qt_binding_shared_memory_attach(memory: *mut c_void, read_only: bool) -> bool;
+    fn qt_binding_shared_memory_detach(memory: *mut c_void) -> bool;
+    fn qt_binding_shared_memory_is_attached(memory: *const c_void) -> bool;
+    fn qt_binding_shared_memory_size(memory: *const c_void) -> u64;
+    fn qt_binding_shared_memory_data(memory: *mut c_void) -> *mut c_void;
+    fn qt_binding_shared_memory_lock(memory: *mut c_void) -> bool;
+    fn qt_binding_shared_memory_unlock(memory: *mut c_void) -> bool;
+    fn qt_binding_shared_memory_error(
+        memory: *const c_void,
+        output: *mut c_void,
+        fill: RsStringFillFunc,
+    ) -> c_int;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(name: &str) -> String {
+        format!("qt-binding-test-{}-{}", name, std::process::id())
+    }
+
+    #[test]
+    fn create_and_attach() {
+        let mut writer = SharedMemory::new(key("create_and_attach"));
+        assert!(!writer.is_attached());
+        writer.create(16, AccessMode::ReadWrite).unwrap();
+        assert!(writer.is_attached());
+        assert!(writer.size() >= 16);
+
+        {
+            let mut lock = writer.lock().unwrap();
+            lock.as_mut_slice()[..5].copy_from_slice(b"hello");
+        }
+
+        let mut reader = SharedMemory::new(key("create_and_attach"));
+        reader.attach(AccessMode::ReadOnly).unwrap();
+        assert_eq!(&reader.lock().unwrap().as_slice()[..5], b"hello");
+
+        let mut other = SharedMemory::new(key("create_and_attach"));
+        let error = other.create(16, AccessMode::ReadWrite).unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::AlreadyExists);
+    }
+
+    #[test]
+    fn attach_not_found() {
+        let mut memory = SharedMemory::new(key("attach_not_found"));
+        let error = memory.attach(AccessMode::ReadWrite).unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::NotFound);
+
+        let error = memory.lock().unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::NotFound);
+    }
+
+    #[test]
+    #[should_panic(expected = "Shared memory segment is read-only")]
+    fn write_read_only() {
+        let mut writer = SharedMemory::new(key("write_read_only"));
+        writer.create(4, AccessMode::ReadWrite).unwrap();
+
+        let mut reader = SharedMemory::new(key("write_read_only"));
+        reader.attach(AccessMode::ReadOnly).unwrap();
+        reader.lock().unwrap().as_mut_slice();
+    }
+}
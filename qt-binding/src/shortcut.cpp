@@ -0,0 +1,145 @@
+#include <QtCore/QCoreApplication>
+#include <QtGui/QKeyEvent>
+#include <QtGui/QKeySequence>
+#include <array>
+#include <cstdint>
+#include <utility>
+
+QT_USE_NAMESPACE
+
+using RsStringFillFunc = void (*)(void *output, const char *input, std::uint32_t inputSize);
+using RsActivatedFunc = void (*)(void *data);
+
+namespace {
+
+// Same order as StandardKey in shortcut.rs
+const std::array<QKeySequence::StandardKey, 20> standardKeys{{
+    QKeySequence::Copy,
+    QKeySequence::Cut,
+    QKeySequence::Paste,
+    QKeySequence::Undo,
+    QKeySequence::Redo,
+    QKeySequence::SelectAll,
+    QKeySequence::Delete,
+    QKeySequence::New,
+    QKeySequence::Open,
+    QKeySequence::Save,
+    QKeySequence::Close,
+    QKeySequence::Print,
+    QKeySequence::Find,
+    QKeySequence::Refresh,
+    QKeySequence::ZoomIn,
+    QKeySequence::ZoomOut,
+    QKeySequence::Preferences,
+    QKeySequence::HelpContents,
+    QKeySequence::FullScreen,
+    QKeySequence::Quit,
+}};
+
+} // namespace
+
+namespace qt_binding {
+
+class Shortcut : public QObject
+{
+public:
+    Shortcut(QList<QKeySequence> sequences, void *data, RsActivatedFunc activated)
+        : m_sequences(std::move(sequences))
+        , m_data(data)
+        , m_activated(activated)
+    {
+        QCoreApplication::instance()->installEventFilter(this);
+    }
+
+    const QList<QKeySequence> &sequences() const
+    {
+        return m_sequences;
+    }
+
+    bool isEnabled() const
+    {
+        return m_enabled;
+    }
+
+    void setEnabled(bool enabled)
+    {
+        m_enabled = enabled;
+    }
+
+protected:
+    bool eventFilter(QObject *watched, QEvent *event) override
+    {
+        // Key events are first delivered to windows, then forwarded to items or widgets
+        if (!m_enabled || event->type() != QEvent::KeyPress || !watched->isWindowType()) {
+            return false;
+        }
+
+        const auto keyEvent = static_cast<QKeyEvent *>(event);
+        const auto pressed = QKeySequence(static_cast<int>(keyEvent->modifiers()) | keyEvent->key());
+        for (const auto &sequence : m_sequences) {
+            if (sequence.matches(pressed) == QKeySequence::ExactMatch) {
+                m_activated(m_data);
+                return true;
+            }
+        }
+        return false;
+    }
+
+private:
+    QList<QKeySequence> m_sequences;
+    void *m_data;
+    RsActivatedFunc m_activated;
+    bool m_enabled{true};
+};
+
+} // namespace qt_binding
+
+extern "C" {
+
+qt_binding::Shortcut *qt_binding_shortcut_create(const char *sequence, std::uint32_t size,
+                                                 void *data, RsActivatedFunc activated)
+{
+    const auto string = QString::fromUtf8(sequence, static_cast<int>(size));
+    const auto keySequence = QKeySequence::fromString(string, QKeySequence::PortableText);
+    if (keySequence.isEmpty() || keySequence[0] == Qt::Key_unknown) {
+        return nullptr;
+    }
+    return new qt_binding::Shortcut({keySequence}, data, activated);
+}
+
+qt_binding::Shortcut *qt_binding_shortcut_create_standard(int key, void *data,
+                                                          RsActivatedFunc activated)
+{
+    const auto sequences = QKeySequence::keyBindings(standardKeys.at(static_cast<std::size_t>(key)));
+    return new qt_binding::Shortcut(sequences, data, activated);
+}
+
+void qt_binding_shortcut_delete(qt_binding::Shortcut *shortcut)
+{
+    delete shortcut;
+}
+
+int qt_binding_shortcut_sequence_count(const qt_binding::Shortcut *shortcut)
+{
+    return shortcut->sequences().size();
+}
+
+void qt_binding_shortcut_sequence(const qt_binding::Shortcut *shortcut, int index, void *output,
+                                  RsStringFillFunc fill)
+{
+    const auto text = shortcut->sequences().at(index).toString(QKeySequence::NativeText);
+    const auto byteArray = text.toUtf8();
+    fill(output, byteArray.constData(), static_cast<std::uint32_t>(byteArray.size()));
+}
+
+bool qt_binding_shortcut_is_enabled(const qt_binding::Shortcut *shortcut)
+{
+    return shortcut->isEnabled();
+}
+
+void qt_binding_shortcut_set_enabled(qt_binding::Shortcut *shortcut, bool enabled)
+{
+    shortcut->setEnabled(enabled);
+}
+
+} // extern "C"
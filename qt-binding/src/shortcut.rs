@@ -0,0 +1,265 @@
+//! Keyboard shortcuts
+//!
+//! [`Shortcut`] calls a Rust closure when a key sequence is pressed in any window of the
+//! application. It works both with QML and widgets windows.
+//!
+//! Key sequences are either written in Qt's portable format, like `Ctrl+Shift+P`, or are one of
+//! the [`StandardKey`]s, that are resolved to the right key sequences for the current platform.
+//! On macOS, `Ctrl` refers to the command key.
+//!
+//! [`Shortcut`]: struct.Shortcut.html
+//! [`StandardKey`]: enum.StandardKey.html
+//!
+//! # Features
+//!
+//! This module is only available with the `gui` feature.
+//!
+//! # Limitations
+//!
+//! Only single key combinations are supported. Multiple key sequences, like `Ctrl+K, Ctrl+C`,
+//! never trigger.
+//!
+//! Shortcuts must be created after the [`Application`], and dropped before it.
+//!
+//! [`Application`]: ../app/struct.Application.html
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use qt_binding::app::Application;
+//! use qt_binding::shortcut::{Shortcut, StandardKey};
+//!
+//! let mut app = Application::new();
+//! let _palette = Shortcut::new("Ctrl+Shift+P", || println!("Show command palette"));
+//! let _quit = Shortcut::standard(StandardKey::Quit, Application::quit);
+//! app.exec();
+//! ```
+
+use crate::app;
+use crate::object::Object;
+use crate::variant::convert::{rs_string_fill, RsStringFillFunc};
+use std::os::raw::{c_char, c_int, c_void};
+
+/// Platform dependent standard shortcuts
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum StandardKey {
+    /// Copy the selection
+    Copy,
+    /// Cut the selection
+    Cut,
+    /// Paste the clipboard
+    Paste,
+    /// Undo the last action
+    Undo,
+    /// Redo the last undone action
+    Redo,
+    /// Select all
+    SelectAll,
+    /// Delete the selection
+    Delete,
+    /// Create a new document
+    New,
+    /// Open a document
+    Open,
+    /// Save the document
+    Save,
+    /// Close the document or window
+    Close,
+    /// Print the document
+    Print,
+    /// Find text
+    Find,
+    /// Refresh the content
+    Refresh,
+    /// Zoom in
+    ZoomIn,
+    /// Zoom out
+    ZoomOut,
+    /// Open the preferences
+    Preferences,
+    /// Open the help
+    HelpContents,
+    /// Toggle the full screen mode
+    FullScreen,
+    /// Quit the application
+    Quit,
+}
+
+impl StandardKey {
+    fn as_c_int(self) -> c_int {
+        match self {
+            StandardKey::Copy => 0,
+            StandardKey::Cut => 1,
+            StandardKey::Paste => 2,
+            StandardKey::Undo => 3,
+            StandardKey::Redo => 4,
+            StandardKey::SelectAll => 5,
+            StandardKey::Delete => 6,
+            StandardKey::New => 7,
+            StandardKey::Open => 8,
+            StandardKey::Save => 9,
+            StandardKey::Close => 10,
+            StandardKey::Print => 11,
+            StandardKey::Find => 12,
+            StandardKey::Refresh => 13,
+            StandardKey::ZoomIn => 14,
+            StandardKey::ZoomOut => 15,
+            StandardKey::Preferences => 16,
+            StandardKey::HelpContents => 17,
+            StandardKey::FullScreen => 18,
+            StandardKey::Quit => 19,
+        }
+    }
+}
+
+type ActivatedCallback = Box<dyn FnMut()>;
+
+/// Application-wide keyboard shortcut
+///
+/// See module level documentation for more information.
+pub struct Shortcut {
+    ptr: *mut c_void,
+    _activated: Box<ActivatedCallback>,
+}
+
+impl Shortcut {
+    /// Creates a `Shortcut` from a key sequence in Qt's portable format, like `Ctrl+Shift+P`
+    ///
+    /// # Panics
+    ///
+    /// Panics if the key sequence is not valid.
+    pub fn new<S, F>(sequence: S, activated: F) -> Self
+    where
+        S: AsRef<str>,
+        F: FnMut() + 'static,
+    {
+        ffi_span!("Shortcut", "new");
+        let sequence = sequence.as_ref();
+        let mut activated: Box<ActivatedCallback> = Box::new(Box::new(activated));
+        let data: *mut ActivatedCallback = &mut *activated;
+        let ptr = unsafe {
+            qt_binding_shortcut_create(
+                sequence.as_ptr() as *const c_char,
+                sequence.len() as u32,
+                data as *mut c_void,
+                rs_shortcut_activated,
+            )
+        };
+        assert!(!ptr.is_null(), "Invalid key sequence {:?}", sequence);
+
+        track_created!(Shortcut);
+        Shortcut {
+            ptr,
+            _activated: activated,
+        }
+    }
+
+    /// Creates a `Shortcut` from a standard key
+    ///
+    /// Standard keys can match several key sequences, depending on the platform.
+    pub fn standard<F>(key: StandardKey, activated: F) -> Self
+    where
+        F: FnMut() + 'static,
+    {
+        ffi_span!("Shortcut", "standard");
+        let mut activated: Box<ActivatedCallback> = Box::new(Box::new(activated));
+        let data: *mut ActivatedCallback = &mut *activated;
+        let ptr = unsafe {
+            qt_binding_shortcut_create_standard(
+                key.as_c_int(),
+                data as *mut c_void,
+                rs_shortcut_activated,
+            )
+        };
+
+        track_created!(Shortcut);
+        Shortcut {
+            ptr,
+            _activated: activated,
+        }
+    }
+
+    /// Key sequences that trigger this shortcut, in the platform native format
+    pub fn sequences(&self) -> Vec<String> {
+        ffi_span!("Shortcut", "sequences");
+        let count = unsafe { qt_binding_shortcut_sequence_count(self.ptr) };
+        (0..count)
+            .map(|index| {
+                let mut value = String::new();
+                unsafe {
+                    let data: *mut String = &mut value;
+                    qt_binding_shortcut_sequence(
+                        self.ptr,
+                        index,
+                        data as *mut c_void,
+                        rs_string_fill,
+                    );
+                }
+                value
+            })
+            .collect()
+    }
+
+    /// Check if the shortcut is enabled
+    pub fn is_enabled(&self) -> bool {
+        ffi_span!("Shortcut", "is_enabled");
+        unsafe { qt_binding_shortcut_is_enabled(self.ptr) }
+    }
+
+    /// Enable or disable the shortcut
+    ///
+    /// Shortcuts are enabled by default.
+    pub fn set_enabled(&mut self, enabled: bool) {
+        ffi_span!("Shortcut", "set_enabled");
+        unsafe { qt_binding_shortcut_set_enabled(self.ptr, enabled) }
+    }
+}
+
+impl Object for Shortcut {
+    fn as_object_ptr(&self) -> *mut c_void {
+        self.ptr
+    }
+}
+
+impl Drop for Shortcut {
+    fn drop(&mut self) {
+        ffi_span!("Shortcut", "drop");
+        if app::should_leak("Shortcut") {
+            return;
+        }
+        track_destroyed!(Shortcut);
+        unsafe { qt_binding_shortcut_delete(self.ptr) }
+    }
+}
+
+extern "C" fn rs_shortcut_activated(data: *mut c_void) {
+    let activated = unsafe { &mut *(data as *mut ActivatedCallback) };
+    activated();
+}
+
+type RsActivatedFunc = extern "C" fn(data: *mut c_void);
+
+extern "C" {
+    fn qt_binding_shortcut_create(
+        sequence: *const c_char,
+        size: u32,
+        data: *mut c_void,
+        activated: RsActivatedFunc,
+    ) -> *mut c_void;
+    fn qt_binding_shortcut_create_standard(
+        key: c_int,
+        data: *mut c_void,
+        activated: RsActivatedFunc,
+    ) -> *mut c_void;
+    fn qt_binding_shortcut_delete(shortcut: *mut c_void);
+
+    fn qt_binding_shortcut_sequence_count(shortcut: *const c_void) -> c_int;
+    fn qt_binding_shortcut_sequence(
+        shortcut: *const c_void,
+        index: c_int,
+        output: *mut c_void,
+        fill: RsStringFillFunc,
+    );
+    fn qt_binding_shortcut_is_enabled(shortcut: *const c_void) -> bool;
+    fn qt_binding_shortcut_set_enabled(shortcut: *mut c_void, enabled: bool);
+}
@@ -0,0 +1,271 @@
+#include <QtCore/QVariant>
+#include <QtSql/QSqlDatabase>
+#include <QtSql/QSqlError>
+#include <QtSql/QSqlQuery>
+#include <QtSql/QSqlQueryModel>
+#include <QtSql/QSqlRecord>
+#include <cstdint>
+
+QT_USE_NAMESPACE
+
+using RsStringFillFunc = void (*)(void *output, const char *input, std::uint32_t inputSize);
+
+static void fillString(const QString &value, void *output, RsStringFillFunc fill)
+{
+    const auto byteArray = value.toUtf8();
+    fill(output, byteArray.constData(), static_cast<std::uint32_t>(byteArray.size()));
+}
+
+namespace qt_binding {
+
+class SqlQueryModel : public QSqlQueryModel
+{
+public:
+    QVariant data(const QModelIndex &index, int role) const override
+    {
+        if (role <= Qt::UserRole) {
+            return QSqlQueryModel::data(index, role);
+        }
+        const auto column = role - Qt::UserRole - 1;
+        return QSqlQueryModel::data(this->index(index.row(), column), Qt::DisplayRole);
+    }
+
+    QHash<int, QByteArray> roleNames() const override
+    {
+        auto roles = QSqlQueryModel::roleNames();
+        const auto record = this->record();
+        for (int i = 0; i < record.count(); ++i) {
+            roles.insert(Qt::UserRole + i + 1, record.fieldName(i).toUtf8());
+        }
+        return roles;
+    }
+};
+
+} // namespace qt_binding
+
+extern "C" {
+
+QSqlDatabase *qt_binding_sql_database_add(const char *driver, std::uint32_t driverSize,
+                                          const char *name, std::uint32_t nameSize)
+{
+    return new QSqlDatabase(
+        QSqlDatabase::addDatabase(QString::fromUtf8(driver, static_cast<int>(driverSize)),
+                                  QString::fromUtf8(name, static_cast<int>(nameSize))));
+}
+
+QSqlDatabase *qt_binding_sql_database_get(const char *name, std::uint32_t size)
+{
+    const auto connectionName = QString::fromUtf8(name, static_cast<int>(size));
+    if (!QSqlDatabase::contains(connectionName)) {
+        return nullptr;
+    }
+    return new QSqlDatabase(QSqlDatabase::database(connectionName, false));
+}
+
+void qt_binding_sql_database_delete(QSqlDatabase *database)
+{
+    delete database;
+}
+
+int qt_binding_sql_database_driver_count()
+{
+    return QSqlDatabase::drivers().count();
+}
+
+void qt_binding_sql_database_driver(int index, void *output, RsStringFillFunc fill)
+{
+    fillString(QSqlDatabase::drivers().at(index), output, fill);
+}
+
+void qt_binding_sql_database_set_parameter(QSqlDatabase *database, int parameter,
+                                           const char *value, std::uint32_t size)
+{
+    const auto string = QString::fromUtf8(value, static_cast<int>(size));
+    switch (parameter) {
+    case 0:
+        database->setDatabaseName(string);
+        break;
+    case 1:
+        database->setHostName(string);
+        break;
+    case 2:
+        database->setUserName(string);
+        break;
+    case 3:
+        database->setPassword(string);
+        break;
+    default:
+        break;
+    }
+}
+
+void qt_binding_sql_database_set_port(QSqlDatabase *database, int port)
+{
+    database->setPort(port);
+}
+
+bool qt_binding_sql_database_open(QSqlDatabase *database)
+{
+    return database->open();
+}
+
+void qt_binding_sql_database_close(QSqlDatabase *database)
+{
+    database->close();
+}
+
+bool qt_binding_sql_database_is_open(const QSqlDatabase *database)
+{
+    return database->isOpen();
+}
+
+bool qt_binding_sql_database_transaction(QSqlDatabase *database)
+{
+    return database->transaction();
+}
+
+bool qt_binding_sql_database_commit(QSqlDatabase *database)
+{
+    return database->commit();
+}
+
+bool qt_binding_sql_database_rollback(QSqlDatabase *database)
+{
+    return database->rollback();
+}
+
+void qt_binding_sql_database_last_error(const QSqlDatabase *database, void *output,
+                                        RsStringFillFunc fill)
+{
+    fillString(database->lastError().text(), output, fill);
+}
+
+QSqlQuery *qt_binding_sql_query_create(const QSqlDatabase *database)
+{
+    return new QSqlQuery(*database);
+}
+
+void qt_binding_sql_query_delete(QSqlQuery *query)
+{
+    delete query;
+}
+
+bool qt_binding_sql_query_prepare(QSqlQuery *query, const char *sql, std::uint32_t size)
+{
+    return query->prepare(QString::fromUtf8(sql, static_cast<int>(size)));
+}
+
+void qt_binding_sql_query_bind_value(QSqlQuery *query, const char *placeholder,
+                                     std::uint32_t size, const QVariant *value)
+{
+    query->bindValue(QString::fromUtf8(placeholder, static_cast<int>(size)), *value);
+}
+
+void qt_binding_sql_query_add_bind_value(QSqlQuery *query, const QVariant *value)
+{
+    query->addBindValue(*value);
+}
+
+bool qt_binding_sql_query_exec(QSqlQuery *query)
+{
+    return query->exec();
+}
+
+bool qt_binding_sql_query_exec_sql(QSqlQuery *query, const char *sql, std::uint32_t size)
+{
+    return query->exec(QString::fromUtf8(sql, static_cast<int>(size)));
+}
+
+bool qt_binding_sql_query_next(QSqlQuery *query)
+{
+    return query->next();
+}
+
+int qt_binding_sql_query_column_count(const QSqlQuery *query)
+{
+    return query->record().count();
+}
+
+QVariant *qt_binding_sql_query_value(const QSqlQuery *query, int column)
+{
+    return new QVariant(query->value(column));
+}
+
+QVariant *qt_binding_sql_query_value_by_name(const QSqlQuery *query, const char *name,
+                                             std::uint32_t size)
+{
+    return new QVariant(query->value(QString::fromUtf8(name, static_cast<int>(size))));
+}
+
+int qt_binding_sql_query_rows_affected(const QSqlQuery *query)
+{
+    return query->numRowsAffected();
+}
+
+QVariant *qt_binding_sql_query_last_insert_id(const QSqlQuery *query)
+{
+    return new QVariant(query->lastInsertId());
+}
+
+void qt_binding_sql_query_last_error(const QSqlQuery *query, void *output, RsStringFillFunc fill)
+{
+    fillString(query->lastError().text(), output, fill);
+}
+
+qt_binding::SqlQueryModel *qt_binding_sql_query_model_create()
+{
+    return new qt_binding::SqlQueryModel();
+}
+
+void qt_binding_sql_query_model_delete(qt_binding::SqlQueryModel *model)
+{
+    delete model;
+}
+
+QVariant *qt_binding_sql_query_model_to_variant(qt_binding::SqlQueryModel *model)
+{
+    return new QVariant(QVariant::fromValue<QObject *>(model));
+}
+
+bool qt_binding_sql_query_model_set_sql(qt_binding::SqlQueryModel *model, const char *sql,
+                                        std::uint32_t size, const QSqlDatabase *database)
+{
+    model->setQuery(QString::fromUtf8(sql, static_cast<int>(size)), *database);
+    return !model->lastError().isValid();
+}
+
+bool qt_binding_sql_query_model_set_query(qt_binding::SqlQueryModel *model,
+                                          const QSqlQuery *query)
+{
+    model->setQuery(*query);
+    return !model->lastError().isValid();
+}
+
+int qt_binding_sql_query_model_row_count(const qt_binding::SqlQueryModel *model)
+{
+    return model->rowCount();
+}
+
+int qt_binding_sql_query_model_column_count(const qt_binding::SqlQueryModel *model)
+{
+    return model->columnCount();
+}
+
+QVariant *qt_binding_sql_query_model_get(const qt_binding::SqlQueryModel *model, int row,
+                                         int column)
+{
+    return new QVariant(model->data(model->index(row, column), Qt::DisplayRole));
+}
+
+void qt_binding_sql_query_model_clear(qt_binding::SqlQueryModel *model)
+{
+    model->clear();
+}
+
+void qt_binding_sql_query_model_last_error(const qt_binding::SqlQueryModel *model, void *output,
+                                           RsStringFillFunc fill)
+{
+    fillString(model->lastError().text(), output, fill);
+}
+
+} // extern "C"
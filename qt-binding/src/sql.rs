@@ -0,0 +1,680 @@
+//! Bindings to Qt SQL
+//!
+//! [`Database`] is a binding to `QSqlDatabase`, that represents a connection to a database
+//! using one of Qt's database drivers. [`Query`] is a binding to `QSqlQuery`, that executes
+//! SQL statements, with bound parameters, and iterates over the resulting rows. Values are
+//! read from the current row as [`Variant`].
+//!
+//! [`QueryModel`] is a binding to `QSqlQueryModel`, a read-only item model that contains the
+//! result of a query. It can be exposed to QML views, with one role per column, named after the
+//! column.
+//!
+//! [`Database`]: struct.Database.html
+//! [`Query`]: struct.Query.html
+//! [`QueryModel`]: struct.QueryModel.html
+//! [`Variant`]: ../variant/struct.Variant.html
+//!
+//! # Features
+//!
+//! This module is only available with the `sql` feature.
+//!
+//! # Limitations
+//!
+//! Just like Qt SQL classes, `Database` and `Query` can only be used from the thread that
+//! opened the connection. They are neither `Send` nor `Sync`.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use qt_binding::sql::{Database, Query};
+//! use qt_binding::variant::Variant;
+//! use std::convert::TryFrom;
+//!
+//! let mut database = Database::add("QSQLITE", "main");
+//! database.set_database_name("library.db");
+//! database.open().unwrap();
+//!
+//! let mut query = Query::new(&database);
+//! query.prepare("SELECT title FROM books WHERE year > :year").unwrap();
+//! query.bind(":year", &Variant::from(2000));
+//! query.exec().unwrap();
+//!
+//! while query.next_row() {
+//!     let title = String::try_from(query.value(0)).unwrap();
+//!     println!("{}", title);
+//! }
+//! ```
+
+use crate::app;
+use crate::variant::convert::{rs_string_fill, RsStringFillFunc};
+use crate::variant::Variant;
+use std::error;
+use std::fmt;
+use std::os::raw::{c_char, c_int, c_void};
+
+/// Error reported by a database driver
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Error {
+    message: String,
+}
+
+impl Error {
+    /// Error message, as reported by the driver
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl error::Error for Error {}
+
+/// Binding to `QSqlDatabase`
+///
+/// See module level documentation for more information.
+pub struct Database {
+    ptr: *mut c_void,
+}
+
+impl Database {
+    /// Add a connection
+    ///
+    /// `driver` is the name of a Qt database driver, like `QSQLITE` or `QPSQL`, and `name` is
+    /// the name of the connection. Adding a connection with an existing name replaces it.
+    pub fn add<D, N>(driver: D, name: N) -> Self
+    where
+        D: AsRef<str>,
+        N: AsRef<str>,
+    {
+        ffi_span!("Database", "add");
+        let driver = driver.as_ref();
+        let name = name.as_ref();
+        track_created!(SqlDatabase);
+        Database {
+            ptr: unsafe {
+                qt_binding_sql_database_add(
+                    driver.as_ptr() as *const c_char,
+                    driver.len() as u32,
+                    name.as_ptr() as *const c_char,
+                    name.len() as u32,
+                )
+            },
+        }
+    }
+
+    /// Get an existing connection
+    ///
+    /// Returns `None` if there is no connection with this name.
+    pub fn get<N>(name: N) -> Option<Self>
+    where
+        N: AsRef<str>,
+    {
+        ffi_span!("Database", "get");
+        let name = name.as_ref();
+        let ptr = unsafe {
+            qt_binding_sql_database_get(name.as_ptr() as *const c_char, name.len() as u32)
+        };
+        if ptr.is_null() {
+            None
+        } else {
+            track_created!(SqlDatabase);
+            Some(Database { ptr })
+        }
+    }
+
+    /// Available database drivers
+    pub fn drivers() -> Vec<String> {
+        ffi_span!("Database", "drivers");
+        let count = unsafe { qt_binding_sql_database_driver_count() };
+        (0..count)
+            .map(|index| {
+                let mut value = String::new();
+                unsafe {
+                    let data: *mut String = &mut value;
+                    qt_binding_sql_database_driver(index, data as *mut c_void, rs_string_fill);
+                }
+                value
+            })
+            .collect()
+    }
+
+    /// Set the name of the database
+    ///
+    /// For SQLite, this is the path to the database file.
+    pub fn set_database_name<S>(&mut self, name: S) -> &mut Self
+    where
+        S: AsRef<str>,
+    {
+        ffi_span!("Database", "set_database_name");
+        self.set_parameter(Parameter::DatabaseName, name.as_ref());
+        self
+    }
+
+    /// Set the host name
+    pub fn set_host_name<S>(&mut self, host_name: S) -> &mut Self
+    where
+        S: AsRef<str>,
+    {
+        ffi_span!("Database", "set_host_name");
+        self.set_parameter(Parameter::HostName, host_name.as_ref());
+        self
+    }
+
+    /// Set the user name
+    pub fn set_user_name<S>(&mut self, user_name: S) -> &mut Self
+    where
+        S: AsRef<str>,
+    {
+        ffi_span!("Database", "set_user_name");
+        self.set_parameter(Parameter::UserName, user_name.as_ref());
+        self
+    }
+
+    /// Set the password
+    pub fn set_password<S>(&mut self, password: S) -> &mut Self
+    where
+        S: AsRef<str>,
+    {
+        ffi_span!("Database", "set_password");
+        self.set_parameter(Parameter::Password, password.as_ref());
+        self
+    }
+
+    /// Set the port
+    pub fn set_port(&mut self, port: u16) -> &mut Self {
+        ffi_span!("Database", "set_port");
+        unsafe { qt_binding_sql_database_set_port(self.ptr, c_int::from(port)) }
+        self
+    }
+
+    /// Open the connection
+    pub fn open(&mut self) -> Result<(), Error> {
+        ffi_span!("Database", "open");
+        self.check(unsafe { qt_binding_sql_database_open(self.ptr) })
+    }
+
+    /// Close the connection
+    ///
+    /// Queries created from this connection become invalid.
+    pub fn close(&mut self) {
+        ffi_span!("Database", "close");
+        unsafe { qt_binding_sql_database_close(self.ptr) }
+    }
+
+    /// Returns `true` if the connection is open
+    pub fn is_open(&self) -> bool {
+        ffi_span!("Database", "is_open");
+        unsafe { qt_binding_sql_database_is_open(self.ptr) }
+    }
+
+    /// Start a transaction
+    pub fn transaction(&mut self) -> Result<(), Error> {
+        ffi_span!("Database", "transaction");
+        self.check(unsafe { qt_binding_sql_database_transaction(self.ptr) })
+    }
+
+    /// Commit the current transaction
+    pub fn commit(&mut self) -> Result<(), Error> {
+        ffi_span!("Database", "commit");
+        self.check(unsafe { qt_binding_sql_database_commit(self.ptr) })
+    }
+
+    /// Roll back the current transaction
+    pub fn rollback(&mut self) -> Result<(), Error> {
+        ffi_span!("Database", "rollback");
+        self.check(unsafe { qt_binding_sql_database_rollback(self.ptr) })
+    }
+
+    fn set_parameter(&mut self, parameter: Parameter, value: &str) {
+        unsafe {
+            qt_binding_sql_database_set_parameter(
+                self.ptr,
+                parameter as c_int,
+                value.as_ptr() as *const c_char,
+                value.len() as u32,
+            )
+        }
+    }
+
+    fn check(&self, result: bool) -> Result<(), Error> {
+        if result {
+            Ok(())
+        } else {
+            Err(self.last_error())
+        }
+    }
+
+    fn last_error(&self) -> Error {
+        let mut message = String::new();
+        unsafe {
+            let data: *mut String = &mut message;
+            qt_binding_sql_database_last_error(self.ptr, data as *mut c_void, rs_string_fill);
+        }
+        Error { message }
+    }
+}
+
+impl fmt::Debug for Database {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Database")
+            .field("is_open", &self.is_open())
+            .finish()
+    }
+}
+
+impl Drop for Database {
+    fn drop(&mut self) {
+        ffi_span!("Database", "drop");
+        if app::should_leak("Database") {
+            return;
+        }
+        track_destroyed!(SqlDatabase);
+        unsafe { qt_binding_sql_database_delete(self.ptr) }
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Parameter {
+    DatabaseName = 0,
+    HostName = 1,
+    UserName = 2,
+    Password = 3,
+}
+
+/// Binding to `QSqlQuery`
+///
+/// See module level documentation for more information.
+pub struct Query {
+    ptr: *mut c_void,
+}
+
+impl Query {
+    /// Creates a `Query` on a connection
+    pub fn new(database: &Database) -> Self {
+        ffi_span!("Query", "new");
+        track_created!(SqlQuery);
+        Query {
+            ptr: unsafe { qt_binding_sql_query_create(database.ptr) },
+        }
+    }
+
+    /// Prepare a statement
+    ///
+    /// The statement can contain placeholders, either named, like `:name`, or positional,
+    /// with `?`. Values are bound with [`bind`] and [`add_bind`] before calling [`exec`].
+    ///
+    /// [`bind`]: #method.bind
+    /// [`add_bind`]: #method.add_bind
+    /// [`exec`]: #method.exec
+    pub fn prepare<S>(&mut self, sql: S) -> Result<(), Error>
+    where
+        S: AsRef<str>,
+    {
+        ffi_span!("Query", "prepare");
+        let sql = sql.as_ref();
+        self.check(unsafe {
+            qt_binding_sql_query_prepare(self.ptr, sql.as_ptr() as *const c_char, sql.len() as u32)
+        })
+    }
+
+    /// Bind a value to a named placeholder
+    pub fn bind<S>(&mut self, placeholder: S, value: &Variant) -> &mut Self
+    where
+        S: AsRef<str>,
+    {
+        ffi_span!("Query", "bind");
+        let placeholder = placeholder.as_ref();
+        unsafe {
+            qt_binding_sql_query_bind_value(
+                self.ptr,
+                placeholder.as_ptr() as *const c_char,
+                placeholder.len() as u32,
+                value.as_ptr(),
+            )
+        }
+        self
+    }
+
+    /// Bind a value to the next positional placeholder
+    pub fn add_bind(&mut self, value: &Variant) -> &mut Self {
+        ffi_span!("Query", "add_bind");
+        unsafe { qt_binding_sql_query_add_bind_value(self.ptr, value.as_ptr()) }
+        self
+    }
+
+    /// Execute the prepared statement
+    pub fn exec(&mut self) -> Result<(), Error> {
+        ffi_span!("Query", "exec");
+        self.check(unsafe { qt_binding_sql_query_exec(self.ptr) })
+    }
+
+    /// Execute a statement directly
+    pub fn exec_sql<S>(&mut self, sql: S) -> Result<(), Error>
+    where
+        S: AsRef<str>,
+    {
+        ffi_span!("Query", "exec_sql");
+        let sql = sql.as_ref();
+        self.check(unsafe {
+            qt_binding_sql_query_exec_sql(self.ptr, sql.as_ptr() as *const c_char, sql.len() as u32)
+        })
+    }
+
+    /// Move to the next row
+    ///
+    /// Returns `false` when there are no more rows.
+    pub fn next_row(&mut self) -> bool {
+        ffi_span!("Query", "next_row");
+        unsafe { qt_binding_sql_query_next(self.ptr) }
+    }
+
+    /// Number of columns in the result
+    pub fn column_count(&self) -> usize {
+        ffi_span!("Query", "column_count");
+        unsafe { qt_binding_sql_query_column_count(self.ptr) as usize }
+    }
+
+    /// Value of a column in the current row
+    ///
+    /// Returns an invalid `Variant` if the column does not exist or if the query is not on
+    /// a row.
+    pub fn value(&self, column: usize) -> Variant {
+        ffi_span!("Query", "value");
+        Variant::from_ptr(unsafe { qt_binding_sql_query_value(self.ptr, column as c_int) })
+    }
+
+    /// Value of a column in the current row, by column name
+    ///
+    /// Returns an invalid `Variant` if the column does not exist or if the query is not on
+    /// a row.
+    pub fn value_by_name<S>(&self, name: S) -> Variant
+    where
+        S: AsRef<str>,
+    {
+        ffi_span!("Query", "value_by_name");
+        let name = name.as_ref();
+        Variant::from_ptr(unsafe {
+            qt_binding_sql_query_value_by_name(
+                self.ptr,
+                name.as_ptr() as *const c_char,
+                name.len() as u32,
+            )
+        })
+    }
+
+    /// Number of rows affected by the last statement
+    ///
+    /// Returns `None` if the number could not be determined, or if the statement was a
+    /// `SELECT`.
+    pub fn rows_affected(&self) -> Option<usize> {
+        ffi_span!("Query", "rows_affected");
+        let rows = unsafe { qt_binding_sql_query_rows_affected(self.ptr) };
+        if rows < 0 {
+            None
+        } else {
+            Some(rows as usize)
+        }
+    }
+
+    /// Id of the last inserted row
+    ///
+    /// Returns an invalid `Variant` if the driver does not support it.
+    pub fn last_insert_id(&self) -> Variant {
+        ffi_span!("Query", "last_insert_id");
+        Variant::from_ptr(unsafe { qt_binding_sql_query_last_insert_id(self.ptr) })
+    }
+
+    fn check(&self, result: bool) -> Result<(), Error> {
+        if result {
+            Ok(())
+        } else {
+            let mut message = String::new();
+            unsafe {
+                let data: *mut String = &mut message;
+                qt_binding_sql_query_last_error(self.ptr, data as *mut c_void, rs_string_fill);
+            }
+            Err(Error { message })
+        }
+    }
+}
+
+impl fmt::Debug for Query {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Query")
+            .field("column_count", &self.column_count())
+            .finish()
+    }
+}
+
+impl Drop for Query {
+    fn drop(&mut self) {
+        ffi_span!("Query", "drop");
+        if app::should_leak("Query") {
+            return;
+        }
+        track_destroyed!(SqlQuery);
+        unsafe { qt_binding_sql_query_delete(self.ptr) }
+    }
+}
+
+/// Binding to `QSqlQueryModel`
+///
+/// The model exposes one role per column, named after the column, so that QML delegates can
+/// access values with the column name. Columns are also available as columns of the model,
+/// for widget views.
+///
+/// See module level documentation for more information.
+pub struct QueryModel {
+    ptr: *mut c_void,
+}
+
+impl QueryModel {
+    /// Creates an empty `QueryModel`
+    pub fn new() -> Self {
+        ffi_span!("QueryModel", "new");
+        track_created!(SqlQueryModel);
+        QueryModel {
+            ptr: unsafe { qt_binding_sql_query_model_create() },
+        }
+    }
+
+    /// Execute a statement and fill the model with its result
+    ///
+    /// Views are notified with a model reset.
+    pub fn set_sql<S>(&mut self, sql: S, database: &Database) -> Result<(), Error>
+    where
+        S: AsRef<str>,
+    {
+        ffi_span!("QueryModel", "set_sql");
+        let sql = sql.as_ref();
+        self.check(unsafe {
+            qt_binding_sql_query_model_set_sql(
+                self.ptr,
+                sql.as_ptr() as *const c_char,
+                sql.len() as u32,
+                database.ptr,
+            )
+        })
+    }
+
+    /// Fill the model with the result of an executed query
+    ///
+    /// This can be used to display the result of a prepared statement with bound values.
+    /// Views are notified with a model reset.
+    pub fn set_query(&mut self, query: &Query) -> Result<(), Error> {
+        ffi_span!("QueryModel", "set_query");
+        self.check(unsafe { qt_binding_sql_query_model_set_query(self.ptr, query.ptr) })
+    }
+
+    /// Number of rows
+    pub fn len(&self) -> usize {
+        ffi_span!("QueryModel", "len");
+        unsafe { qt_binding_sql_query_model_row_count(self.ptr) as usize }
+    }
+
+    /// Returns `true` if the model contains no rows
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Number of columns
+    pub fn column_count(&self) -> usize {
+        ffi_span!("QueryModel", "column_count");
+        unsafe { qt_binding_sql_query_model_column_count(self.ptr) as usize }
+    }
+
+    /// Value at a row and column
+    ///
+    /// Returns an invalid `Variant` if the row or column is out of bounds.
+    pub fn get(&self, row: usize, column: usize) -> Variant {
+        ffi_span!("QueryModel", "get");
+        Variant::from_ptr(unsafe {
+            qt_binding_sql_query_model_get(self.ptr, row as c_int, column as c_int)
+        })
+    }
+
+    /// Clear the model
+    ///
+    /// Views are notified with a model reset.
+    pub fn clear(&mut self) {
+        ffi_span!("QueryModel", "clear");
+        unsafe { qt_binding_sql_query_model_clear(self.ptr) }
+    }
+
+    fn check(&self, result: bool) -> Result<(), Error> {
+        if result {
+            Ok(())
+        } else {
+            let mut message = String::new();
+            unsafe {
+                let data: *mut String = &mut message;
+                qt_binding_sql_query_model_last_error(
+                    self.ptr,
+                    data as *mut c_void,
+                    rs_string_fill,
+                );
+            }
+            Err(Error { message })
+        }
+    }
+}
+
+impl Default for QueryModel {
+    fn default() -> Self {
+        QueryModel::new()
+    }
+}
+
+impl From<&'_ QueryModel> for Variant {
+    fn from(model: &QueryModel) -> Self {
+        ffi_span!("Variant", "from_query_model");
+        Variant::from_ptr(unsafe { qt_binding_sql_query_model_to_variant(model.ptr) })
+    }
+}
+
+impl fmt::Debug for QueryModel {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("QueryModel")
+            .field("len", &self.len())
+            .field("column_count", &self.column_count())
+            .finish()
+    }
+}
+
+impl Drop for QueryModel {
+    fn drop(&mut self) {
+        ffi_span!("QueryModel", "drop");
+        if app::should_leak("QueryModel") {
+            return;
+        }
+        track_destroyed!(SqlQueryModel);
+        unsafe { qt_binding_sql_query_model_delete(self.ptr) }
+    }
+}
+
+extern "C" {
+    fn qt_binding_sql_database_add(
+        driver: *const c_char,
+        driver_size: u32,
+        name: *const c_char,
+        name_size: u32,
+    ) -> *mut c_void;
+    fn qt_binding_sql_database_get(name: *const c_char, size: u32) -> *mut c_void;
+    fn qt_binding_sql_database_delete(database: *mut c_void);
+    fn qt_binding_sql_database_driver_count() -> c_int;
+    fn qt_binding_sql_database_driver(index: c_int, output: *mut c_void, fill: RsStringFillFunc);
+    fn qt_binding_sql_database_set_parameter(
+        database: *mut c_void,
+        parameter: c_int,
+        value: *const c_char,
+        size: u32,
+    );
+    fn qt_binding_sql_database_set_port(database: *mut c_void, port: c_int);
+    fn qt_binding_sql_database_open(database: *mut c_void) -> bool;
+    fn qt_binding_sql_database_close(database: *mut c_void);
+    fn qt_binding_sql_database_is_open(database: *const c_void) -> bool;
+    fn qt_binding_sql_database_transaction(database: *mut c_void) -> bool;
+    fn qt_binding_sql_database_commit(database: *mut c_void) -> bool;
+    fn qt_binding_sql_database_rollback(database: *mut c_void) -> bool;
+    fn qt_binding_sql_database_last_error(
+        database: *const c_void,
+        output: *mut c_void,
+        fill: RsStringFillFunc,
+    );
+
+    fn qt_binding_sql_query_create(database: *const c_void) -> *mut c_void;
+    fn qt_binding_sql_query_delete(query: *mut c_void);
+    fn qt_binding_sql_query_prepare(query: *mut c_void, sql: *const c_char, size: u32) -> bool;
+    fn qt_binding_sql_query_bind_value(
+        query: *mut c_void,
+        placeholder: *const c_char,
+        size: u32,
+        value: *const c_void,
+    );
+    fn qt_binding_sql_query_add_bind_value(query: *mut c_void, value: *const c_void);
+    fn qt_binding_sql_query_exec(query: *mut c_void) -> bool;
+    fn qt_binding_sql_query_exec_sql(query: *mut c_void, sql: *const c_char, size: u32) -> bool;
+    fn qt_binding_sql_query_next(query: *mut c_void) -> bool;
+    fn qt_binding_sql_query_column_count(query: *const c_void) -> c_int;
+    fn qt_binding_sql_query_value(query: *const c_void, column: c_int) -> *mut c_void;
+    fn qt_binding_sql_query_value_by_name(
+        query: *const c_void,
+        name: *const c_char,
+        size: u32,
+    ) -> *mut c_void;
+    fn qt_binding_sql_query_rows_affected(query: *const c_void) -> c_int;
+    fn qt_binding_sql_query_last_insert_id(query: *const c_void) -> *mut c_void;
+    fn qt_binding_sql_query_last_error(
+        query: *const c_void,
+        output: *mut c_void,
+        fill: RsStringFillFunc,
+    );
+
+    fn qt_binding_sql_query_model_create() -> *mut c_void;
+    fn qt_binding_sql_query_model_delete(model: *mut c_void);
+    fn qt_binding_sql_query_model_to_variant(model: *mut c_void) -> *mut c_void;
+    fn qt_binding_sql_query_model_set_sql(
+        model: *mut c_void,
+        sql: *const c_char,
+        size: u32,
+        database: *const c_void,
+    ) -> bool;
+    fn qt_binding_sql_query_model_set_query(model: *mut c_void, query: *const c_void) -> bool;
+    fn qt_binding_sql_query_model_row_count(model: *const c_void) -> c_int;
+    fn qt_binding_sql_query_model_column_count(model: *const c_void) -> c_int;
+    fn qt_binding_sql_query_model_get(
+        model: *const c_void,
+        row: c_int,
+        column: c_int,
+    ) -> *mut c_void;
+    fn qt_binding_sql_query_model_clear(model: *mut c_void);
+    fn qt_binding_sql_query_model_last_error(
+        model: *const c_void,
+        output: *mut c_void,
+        fill: RsStringFillFunc,
+    );
+}
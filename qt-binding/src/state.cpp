@@ -0,0 +1,75 @@
+#include "state.h"
+#include <QtCore/QVariant>
+#include <cstdint>
+
+QT_USE_NAMESPACE
+
+static QString toString(const char *value, std::uint32_t size)
+{
+    return QString::fromUtf8(value, static_cast<int>(size));
+}
+
+namespace qt_binding {
+
+StateMachine::StateMachine(const QString &state, void *data, RsTriggerFunc trigger)
+    : m_state(state)
+    , m_data(data)
+    , m_trigger(trigger)
+{
+}
+
+QString StateMachine::state() const
+{
+    return m_state;
+}
+
+void StateMachine::setState(const QString &state)
+{
+    if (m_state != state) {
+        m_state = state;
+        emit stateChanged();
+    }
+}
+
+bool StateMachine::trigger(const QString &event)
+{
+    const auto byteArray = event.toUtf8();
+    return m_trigger(m_data, byteArray.constData(), static_cast<std::uint32_t>(byteArray.size()),
+                     false);
+}
+
+bool StateMachine::canTrigger(const QString &event) const
+{
+    const auto byteArray = event.toUtf8();
+    return m_trigger(m_data, byteArray.constData(), static_cast<std::uint32_t>(byteArray.size()),
+                     true);
+}
+
+} // namespace qt_binding
+
+extern "C" {
+
+qt_binding::StateMachine *qt_binding_state_machine_create(const char *state, std::uint32_t size,
+                                                          void *data,
+                                                          qt_binding::RsTriggerFunc trigger)
+{
+    return new qt_binding::StateMachine(toString(state, size), data, trigger);
+}
+
+void qt_binding_state_machine_delete(qt_binding::StateMachine *machine)
+{
+    delete machine;
+}
+
+void qt_binding_state_machine_set_state(qt_binding::StateMachine *machine, const char *state,
+                                        std::uint32_t size)
+{
+    machine->setState(toString(state, size));
+}
+
+QVariant *qt_binding_state_machine_to_variant(qt_binding::StateMachine *machine)
+{
+    return new QVariant(QVariant::fromValue<QObject *>(machine));
+}
+
+} // extern "C"
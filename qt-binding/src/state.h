@@ -0,0 +1,39 @@
+#ifndef QT_BINDING_STATE_H
+#define QT_BINDING_STATE_H
+
+#include <QtCore/QObject>
+#include <QtCore/QString>
+#include <cstdint>
+
+QT_USE_NAMESPACE
+
+namespace qt_binding {
+
+using RsTriggerFunc = bool (*)(void *data, const char *event, std::uint32_t eventSize,
+                               bool dryRun);
+
+class StateMachine : public QObject
+{
+    Q_OBJECT
+    Q_PROPERTY(QString state READ state NOTIFY stateChanged)
+public:
+    explicit StateMachine(const QString &state, void *data, RsTriggerFunc trigger);
+
+    QString state() const;
+    void setState(const QString &state);
+
+    Q_INVOKABLE bool trigger(const QString &event);
+    Q_INVOKABLE bool canTrigger(const QString &event) const;
+
+signals:
+    void stateChanged();
+
+private:
+    QString m_state;
+    void *m_data{nullptr};
+    RsTriggerFunc m_trigger{nullptr};
+};
+
+} // namespace qt_binding
+
+#endif // QT_BINDING_STATE_H
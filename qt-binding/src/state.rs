@@ -0,0 +1,288 @@
+//! Declarative state machines
+//!
+//! [`StateMachine`] structures UI flows, like a loading screen followed by a login form, as
+//! named states and transitions between them, triggered by named events, instead of ad-hoc
+//! boolean flags.
+//!
+//! A `StateMachine` can be exposed to QML. Its current state is the `state` property, that
+//! QML items bind to, and events are triggered from QML with the `trigger(event)` method,
+//! for example from signal handlers. `canTrigger(event)` checks if an event triggers a
+//! transition from the current state.
+//!
+//! [`StateMachine`]: struct.StateMachine.html
+//!
+//! # Limitations
+//!
+//! Since `StateMachine` is a binding over a Qt object, it is neither `Send` nor `Sync`. It must
+//! outlive the QML items that use it.
+//!
+//! # Examples
+//!
+//! ```
+//! use qt_binding::state::StateMachine;
+//! use qt_binding::variant::Variant;
+//!
+//! let mut machine = StateMachine::new("loading");
+//! machine
+//!     .add_transition("loading", "loaded", "login")
+//!     .add_transition("login", "authenticated", "home")
+//!     .add_transition("home", "logout", "login");
+//! machine.on_state_changed(|from, event, to| println!("{} --{}--> {}", from, event, to));
+//!
+//! assert!(machine.trigger("loaded"));
+//! assert_eq!(machine.state(), "login");
+//! assert!(!machine.trigger("logout"));
+//!
+//! // Expose the state machine to QML
+//! let variant = Variant::from(&machine);
+//! ```
+//!
+//! In QML, with the state machine exposed as the `flow` context property
+//!
+//! ```qml
+//! Loader {
+//!     source: flow.state === "login" ? "Login.qml" : "Home.qml"
+//! }
+//! Button {
+//!     text: "Log out"
+//!     enabled: flow.canTrigger("logout")
+//!     onClicked: flow.trigger("logout")
+//! }
+//! ```
+
+use crate::app;
+use crate::object::Object;
+use crate::variant::Variant;
+use std::collections::HashMap;
+use std::fmt;
+use std::os::raw::{c_char, c_void};
+use std::slice;
+use std::str;
+
+type StateChangedHandler = Option<Box<dyn FnMut(&str, &str, &str)>>;
+
+struct Inner {
+    ptr: *mut c_void,
+    state: String,
+    transitions: HashMap<(String, String), String>,
+    handler: StateChangedHandler,
+}
+
+impl Inner {
+    fn target(&self, event: &str) -> Option<&String> {
+        self.transitions
+            .get(&(self.state.clone(), event.to_string()))
+    }
+
+    fn trigger(&mut self, event: &str) -> bool {
+        let target = match self.target(event) {
+            Some(target) => target.clone(),
+            None => return false,
+        };
+
+        let from = std::mem::replace(&mut self.state, target);
+        if let Some(handler) = &mut self.handler {
+            handler(&from, event, &self.state);
+        }
+        // Updating the Qt object notifies QML, that might trigger other events, so this must
+        // be done last
+        let ptr = self.ptr;
+        let (state, size) = (self.state.as_ptr(), self.state.len());
+        unsafe { qt_binding_state_machine_set_state(ptr, state as *const c_char, size as u32) };
+        true
+    }
+}
+
+/// A state machine, whose state can be used by QML
+///
+/// See module level documentation for more information.
+pub struct StateMachine {
+    inner: Box<Inner>,
+}
+
+impl StateMachine {
+    /// Creates a `StateMachine` in an initial state
+    pub fn new<S>(initial: S) -> Self
+    where
+        S: Into<String>,
+    {
+        ffi_span!("StateMachine", "new");
+        let mut inner = Box::new(Inner {
+            ptr: std::ptr::null_mut(),
+            state: initial.into(),
+            transitions: HashMap::new(),
+            handler: None,
+        });
+        let data: *mut Inner = &mut *inner;
+        inner.ptr = unsafe {
+            qt_binding_state_machine_create(
+                inner.state.as_ptr() as *const c_char,
+                inner.state.len() as u32,
+                data as *mut c_void,
+                rs_state_machine_trigger,
+            )
+        };
+        track_created!(StateMachine);
+        StateMachine { inner }
+    }
+
+    /// Add a transition from a state to another state, triggered by an event
+    ///
+    /// Adding a transition for the same state and event replaces the previous transition.
+    pub fn add_transition<F, E, T>(&mut self, from: F, event: E, to: T) -> &mut Self
+    where
+        F: Into<String>,
+        E: Into<String>,
+        T: Into<String>,
+    {
+        self.inner
+            .transitions
+            .insert((from.into(), event.into()), to.into());
+        self
+    }
+
+    /// Current state
+    pub fn state(&self) -> &str {
+        &self.inner.state
+    }
+
+    /// Trigger an event
+    ///
+    /// Returns `false` if the event does not trigger any transition from the current state.
+    pub fn trigger<S>(&mut self, event: S) -> bool
+    where
+        S: AsRef<str>,
+    {
+        ffi_span!("StateMachine", "trigger");
+        self.inner.trigger(event.as_ref())
+    }
+
+    /// Check if an event triggers a transition from the current state
+    pub fn can_trigger<S>(&self, event: S) -> bool
+    where
+        S: AsRef<str>,
+    {
+        self.inner.target(event.as_ref()).is_some()
+    }
+
+    /// Handle state changes
+    ///
+    /// The handler is called with the previous state, the event and the new state, for every
+    /// transition triggered from Rust or QML. Setting a new handler replaces the previous one.
+    pub fn on_state_changed<F>(&mut self, handler: F) -> &mut Self
+    where
+        F: FnMut(&str, &str, &str) + 'static,
+    {
+        self.inner.handler = Some(Box::new(handler));
+        self
+    }
+}
+
+impl From<&'_ StateMachine> for Variant {
+    fn from(machine: &StateMachine) -> Self {
+        ffi_span!("Variant", "from_state_machine");
+        Variant::from_ptr(unsafe { qt_binding_state_machine_to_variant(machine.inner.ptr) })
+    }
+}
+
+impl fmt::Debug for StateMachine {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("StateMachine")
+            .field("state", &self.inner.state)
+            .finish()
+    }
+}
+
+impl Object for StateMachine {
+    fn as_object_ptr(&self) -> *mut c_void {
+        self.inner.ptr
+    }
+}
+
+impl Drop for StateMachine {
+    fn drop(&mut self) {
+        ffi_span!("StateMachine", "drop");
+        if app::should_leak("StateMachine") {
+            return;
+        }
+        track_destroyed!(StateMachine);
+        unsafe { qt_binding_state_machine_delete(self.inner.ptr) }
+    }
+}
+
+extern "C" fn rs_state_machine_trigger(
+    data: *mut c_void,
+    event: *const c_char,
+    event_size: u32,
+    dry_run: bool,
+) -> bool {
+    let inner = unsafe { &mut *(data as *mut Inner) };
+    let event = unsafe {
+        str::from_utf8_unchecked(slice::from_raw_parts(
+            event as *const u8,
+            event_size as usize,
+        ))
+    };
+    if dry_run {
+        inner.target(event).is_some()
+    } else {
+        inner.trigger(event)
+    }
+}
+
+type RsTriggerFunc =
+    extern "C" fn(data: *mut c_void, event: *const c_char, event_size: u32, dry_run: bool) -> bool;
+
+extern "C" {
+    fn qt_binding_state_machine_create(
+        state: *const c_char,
+        size: u32,
+        data: *mut c_void,
+        trigger: RsTriggerFunc,
+    ) -> *mut c_void;
+    fn qt_binding_state_machine_delete(machine: *mut c_void);
+    fn qt_binding_state_machine_set_state(machine: *mut c_void, state: *const c_char, size: u32);
+    fn qt_binding_state_machine_to_variant(machine: *mut c_void) -> *mut c_void;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    #[test]
+    fn test_transitions() {
+        let mut machine = StateMachine::new("idle");
+        machine
+            .add_transition("idle", "start", "running")
+            .add_transition("running", "stop", "idle");
+
+        assert!(machine.can_trigger("start"));
+        assert!(!machine.can_trigger("stop"));
+        assert!(!machine.trigger("stop"));
+        assert_eq!(machine.state(), "idle");
+
+        assert!(machine.trigger("start"));
+        assert_eq!(machine.state(), "running");
+        assert!(machine.trigger("stop"));
+        assert_eq!(machine.state(), "idle");
+    }
+
+    #[test]
+    fn test_state_changed() {
+        let changes = Rc::new(RefCell::new(Vec::new()));
+        let mut machine = StateMachine::new("idle");
+        machine.add_transition("idle", "start", "running");
+        let handler_changes = changes.clone();
+        machine.on_state_changed(move |from, event, to| {
+            handler_changes
+                .borrow_mut()
+                .push(format!("{} {} {}", from, event, to))
+        });
+
+        machine.trigger("start");
+        machine.trigger("start");
+        assert_eq!(*changes.borrow(), vec!["idle start running"]);
+    }
+}
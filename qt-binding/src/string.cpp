@@ -0,0 +1,26 @@
+#include <QtCore/QString>
+#include <QtCore/QVariant>
+#include <cstdint>
+
+QT_USE_NAMESPACE
+
+extern "C" {
+
+QVariant *qt_binding_string_create_variant(const ushort *value, std::uint32_t size)
+{
+    // QString::fromUtf16 would interpret a leading U+FEFF or U+FFFE as a byte order mark
+    return new QVariant(QString(reinterpret_cast<const QChar *>(value), static_cast<int>(size)));
+}
+
+const ushort *qt_binding_string_borrow_variant(const QVariant *variant, std::uint32_t *size)
+{
+    if (variant->userType() != QMetaType::QString) {
+        return nullptr;
+    }
+
+    const auto value = static_cast<const QString *>(variant->constData());
+    *size = static_cast<std::uint32_t>(value->size());
+    return reinterpret_cast<const ushort *>(value->constData());
+}
+
+} // extern "C"
@@ -0,0 +1,200 @@
+//! UTF-16 strings
+//!
+//! Qt stores strings as UTF-16 while Rust strings are UTF-8. Converting a `String` to a
+//! [`Variant`] and back requires converting between both encodings each time.
+//!
+//! [`Utf16Str`] is a borrowed view over UTF-16 data, similar to Qt's `QStringView`. It can be
+//! borrowed from a [`Variant`] containing a `QString` without any copy or conversion, and a
+//! [`Variant`] can be created from it with a single copy. This avoids redundant conversions
+//! when strings are only passed around, and only decoded when needed.
+//!
+//! [`Utf16Str`]: struct.Utf16Str.html
+//! [`Variant`]: ../variant/struct.Variant.html
+//!
+//! # Features
+//!
+//! With the `widestring` feature, `Utf16Str` can be converted from and to [`widestring`]'s
+//! `U16Str`.
+//!
+//! With the `mock` feature, `Utf16Str` cannot be converted from or to a [`Variant`].
+//!
+//! [`widestring`]: https://docs.rs/widestring
+//!
+//! # Examples
+//!
+//! ```
+//! # #[cfg(not(feature = "mock"))]
+//! # fn main() {
+//! use qt_binding::string::Utf16Str;
+//! use qt_binding::variant::Variant;
+//! use std::convert::TryFrom;
+//!
+//! let variant = Variant::from("hello 世界");
+//! let value = Utf16Str::try_from(&variant).unwrap();
+//!
+//! assert_eq!(value.to_string_lossy(), "hello 世界");
+//! assert_eq!(Variant::from(value), variant);
+//! # }
+//! # #[cfg(feature = "mock")]
+//! # fn main() {}
+//! ```
+
+#[cfg(not(feature = "mock"))]
+use crate::variant::{TryFromError, Variant};
+#[cfg(not(feature = "mock"))]
+use std::convert::TryFrom;
+use std::fmt;
+#[cfg(not(feature = "mock"))]
+use std::os::raw::c_void;
+#[cfg(not(feature = "mock"))]
+use std::slice::from_raw_parts;
+use std::string::FromUtf16Error;
+
+/// Borrowed UTF-16 string
+///
+/// See module level documentation for more information.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Utf16Str<'a> {
+    data: &'a [u16],
+}
+
+impl<'a> Utf16Str<'a> {
+    /// Creates a `Utf16Str` from UTF-16 code units
+    pub fn from_slice(data: &'a [u16]) -> Self {
+        Utf16Str { data }
+    }
+
+    /// UTF-16 code units
+    pub fn as_slice(&self) -> &'a [u16] {
+        self.data
+    }
+
+    /// Number of UTF-16 code units
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Returns `true` if the string is empty
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Decode into a `String`
+    ///
+    /// Returns an error if the string contains invalid UTF-16 data, like unpaired surrogates.
+    pub fn to_string_checked(&self) -> Result<String, FromUtf16Error> {
+        String::from_utf16(self.data)
+    }
+
+    /// Decode into a `String`, replacing invalid data with `U+FFFD`
+    pub fn to_string_lossy(&self) -> String {
+        String::from_utf16_lossy(self.data)
+    }
+}
+
+impl fmt::Display for Utf16Str<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        std::char::decode_utf16(self.data.iter().cloned())
+            .map(|c| c.unwrap_or(std::char::REPLACEMENT_CHARACTER))
+            .try_for_each(|c| write!(f, "{}", c))
+    }
+}
+
+impl<'a> From<&'a [u16]> for Utf16Str<'a> {
+    fn from(data: &'a [u16]) -> Self {
+        Utf16Str::from_slice(data)
+    }
+}
+
+#[cfg(feature = "widestring")]
+impl<'a> From<&'a widestring::U16Str> for Utf16Str<'a> {
+    fn from(value: &'a widestring::U16Str) -> Self {
+        Utf16Str::from_slice(value.as_slice())
+    }
+}
+
+#[cfg(feature = "widestring")]
+impl<'a> From<Utf16Str<'a>> for &'a widestring::U16Str {
+    fn from(value: Utf16Str<'a>) -> Self {
+        widestring::U16Str::from_slice(value.data)
+    }
+}
+
+#[cfg(not(feature = "mock"))]
+impl From<Utf16Str<'_>> for Variant {
+    fn from(value: Utf16Str) -> Self {
+        ffi_span!("Variant", "from_utf16");
+        Variant::from_ptr(unsafe {
+            qt_binding_string_create_variant(value.data.as_ptr(), value.data.len() as u32)
+        })
+    }
+}
+
+#[cfg(not(feature = "mock"))]
+impl<'a> TryFrom<&'a Variant> for Utf16Str<'a> {
+    type Error = TryFromError;
+
+    /// Borrow the string contained in a `Variant`
+    ///
+    /// Unlike the conversion to `String`, this conversion only succeeds if the `Variant`
+    /// contains a `QString`. Other types are not converted.
+    fn try_from(variant: &'a Variant) -> Result<Self, Self::Error> {
+        ffi_span!("Variant", "into_utf16_str");
+        let mut size = 0;
+        let data = unsafe { qt_binding_string_borrow_variant(variant.as_ptr(), &mut size) };
+        if data.is_null() {
+            Err(TryFromError)
+        } else if size == 0 {
+            Ok(Utf16Str::default())
+        } else {
+            Ok(Utf16Str::from_slice(unsafe {
+                from_raw_parts(data, size as usize)
+            }))
+        }
+    }
+}
+
+#[cfg(not(feature = "mock"))]
+extern "C" {
+    fn qt_binding_string_create_variant(value: *const u16, size: u32) -> *mut c_void;
+    fn qt_binding_string_borrow_variant(variant: *const c_void, size: *mut u32) -> *const u16;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode() {
+        let data = "hello 世界 🦀".encode_utf16().collect::<Vec<_>>();
+        let value = Utf16Str::from(data.as_slice());
+
+        assert_eq!(value.len(), data.len());
+        assert_eq!(value.to_string_checked().unwrap(), "hello 世界 🦀");
+        assert_eq!(value.to_string(), "hello 世界 🦀");
+
+        let invalid = [0x68, 0xd800, 0x69];
+        let value = Utf16Str::from(&invalid[..]);
+
+        assert!(value.to_string_checked().is_err());
+        assert_eq!(value.to_string_lossy(), "h\u{fffd}i");
+        assert_eq!(value.to_string(), "h\u{fffd}i");
+    }
+
+    #[test]
+    #[cfg(not(feature = "mock"))]
+    fn variant() {
+        let data = "hello 世界".encode_utf16().collect::<Vec<_>>();
+        let variant = Variant::from(Utf16Str::from(data.as_slice()));
+
+        assert_eq!(String::try_from(&variant), Ok("hello 世界".to_string()));
+        assert_eq!(Utf16Str::try_from(&variant).unwrap().as_slice(), &data[..]);
+
+        let data = "\u{feff}\u{fffe}bom".encode_utf16().collect::<Vec<_>>();
+        let variant = Variant::from(Utf16Str::from(data.as_slice()));
+        assert_eq!(Utf16Str::try_from(&variant).unwrap().as_slice(), &data[..]);
+
+        let variant = Variant::from(123);
+        assert_eq!(Utf16Str::try_from(&variant), Err(TryFromError));
+    }
+}
@@ -0,0 +1,124 @@
+#include <QtCore/QBuffer>
+#include <QtCore/QFile>
+#include <QtGui/QPageSize>
+#include <QtGui/QPdfWriter>
+#include <QtGui/QTextDocument>
+#include <cstdint>
+
+QT_USE_NAMESPACE
+
+using RsStringFillFunc = void (*)(void *output, const char *input, std::uint32_t inputSize);
+
+static void fillString(const QString &value, void *output, RsStringFillFunc fill)
+{
+    auto utf8 = value.toUtf8();
+    fill(output, utf8.constData(), static_cast<std::uint32_t>(utf8.size()));
+}
+
+static void print(const QTextDocument *document, QIODevice *device, double width, double height)
+{
+    QPdfWriter writer(device);
+    writer.setPageSize(QPageSize(QSizeF(width, height), QPageSize::Millimeter));
+
+    // Printing lays out a copy of the document on the pages
+    document->print(&writer);
+}
+
+extern "C" {
+
+QTextDocument *qt_binding_text_document_create()
+{
+    return new QTextDocument();
+}
+
+void qt_binding_text_document_delete(QTextDocument *document)
+{
+    delete document;
+}
+
+void qt_binding_text_document_set_html(QTextDocument *document, const char *html,
+                                       std::uint32_t size)
+{
+    document->setHtml(QString::fromUtf8(html, static_cast<int>(size)));
+}
+
+void qt_binding_text_document_set_markdown(QTextDocument *document, const char *markdown,
+                                           std::uint32_t size)
+{
+    const auto text = QString::fromUtf8(markdown, static_cast<int>(size));
+#if QT_VERSION >= QT_VERSION_CHECK(5, 14, 0)
+    document->setMarkdown(text);
+#else
+    document->setPlainText(text);
+#endif
+}
+
+void qt_binding_text_document_set_plain_text(QTextDocument *document, const char *text,
+                                             std::uint32_t size)
+{
+    document->setPlainText(QString::fromUtf8(text, static_cast<int>(size)));
+}
+
+void qt_binding_text_document_set_default_style_sheet(QTextDocument *document, const char *css,
+                                                      std::uint32_t size)
+{
+    document->setDefaultStyleSheet(QString::fromUtf8(css, static_cast<int>(size)));
+}
+
+void qt_binding_text_document_to_plain_text(const QTextDocument *document, void *output,
+                                            RsStringFillFunc fill)
+{
+    fillString(document->toPlainText(), output, fill);
+}
+
+void qt_binding_text_document_to_html(const QTextDocument *document, void *output,
+                                      RsStringFillFunc fill)
+{
+    fillString(document->toHtml(), output, fill);
+}
+
+void qt_binding_text_document_to_markdown(const QTextDocument *document, void *output,
+                                          RsStringFillFunc fill)
+{
+#if QT_VERSION >= QT_VERSION_CHECK(5, 14, 0)
+    fillString(document->toMarkdown(), output, fill);
+#else
+    fillString(document->toPlainText(), output, fill);
+#endif
+}
+
+bool qt_binding_text_document_is_empty(const QTextDocument *document)
+{
+    return document->isEmpty();
+}
+
+bool qt_binding_text_document_write_pdf(const QTextDocument *document, const char *path,
+                                        std::uint32_t size, double width, double height,
+                                        void *error, RsStringFillFunc fill)
+{
+    QFile file(QString::fromUtf8(path, static_cast<int>(size)));
+    if (!file.open(QIODevice::WriteOnly)) {
+        fillString(file.errorString(), error, fill);
+        return false;
+    }
+
+    print(document, &file, width, height);
+    if (!file.flush()) {
+        fillString(file.errorString(), error, fill);
+        return false;
+    }
+    return true;
+}
+
+void qt_binding_text_document_to_pdf(const QTextDocument *document, double width, double height,
+                                     void *output, RsStringFillFunc fill)
+{
+    QBuffer buffer;
+    buffer.open(QIODevice::WriteOnly);
+    print(document, &buffer, width, height);
+
+    const auto &data = buffer.data();
+    fill(output, data.constData(), static_cast<std::uint32_t>(data.size()));
+}
+
+} // extern "C"
@@ -0,0 +1,363 @@
+//! Rich text documents
+//!
+//! [`TextDocument`] is a binding on Qt's `QTextDocument`. It holds rich text, set as HTML,
+//! Markdown or plain text, and lays it out with Qt's text engine, like the text displayed by
+//! Qt Quick and Qt Widgets. Documents can be exported to HTML, plain text, or rendered to PDF
+//! with [`write_pdf`] and [`to_pdf`], for example to generate reports.
+//!
+//! [`TextDocument`]: struct.TextDocument.html
+//! [`write_pdf`]: struct.TextDocument.html#method.write_pdf
+//! [`to_pdf`]: struct.TextDocument.html#method.to_pdf
+//!
+//! # Features
+//!
+//! This module is only available with the `gui` feature.
+//!
+//! # Limitations
+//!
+//! Markdown requires Qt 5.14 or later. With older versions of Qt, Markdown is set as plain text,
+//! and [`to_markdown`] returns plain text.
+//!
+//! Fonts are loaded by the [`Application`], that must be created before rendering documents.
+//!
+//! [`to_markdown`]: struct.TextDocument.html#method.to_markdown
+//! [`Application`]: ../app/struct.Application.html
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use qt_binding::app::Application;
+//! use qt_binding::text::{PageSize, TextDocument};
+//!
+//! let _app = Application::new();
+//! let mut document = TextDocument::from_markdown("# Report\n\nAll **42** tests passed.");
+//! document.set_default_style_sheet("h1 { color: #336699; }");
+//! println!("{}", document.to_plain_text());
+//! document.write_pdf("report.pdf", PageSize::A4).unwrap();
+//! ```
+
+use crate::app;
+use crate::variant::convert::{rs_string_fill, RsStringFillFunc};
+use std::fmt;
+use std::io;
+use std::os::raw::{c_char, c_void};
+use std::path::Path;
+use std::slice;
+
+/// Size of the pages of a PDF
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PageSize {
+    /// ISO A4, 210 × 297 mm
+    A4,
+    /// US Letter, 8.5 × 11 in
+    Letter,
+    /// Custom size, with the width and the height in millimeters
+    Custom(f64, f64),
+}
+
+impl PageSize {
+    /// Width and height, in millimeters
+    pub(crate) fn millimeters(self) -> (f64, f64) {
+        match self {
+            PageSize::A4 => (210., 297.),
+            PageSize::Letter => (215.9, 279.4),
+            PageSize::Custom(width, height) => (width, height),
+        }
+    }
+}
+
+/// Binding to `QTextDocument`
+///
+/// See module level documentation for more information.
+pub struct TextDocument {
+    ptr: *mut c_void,
+}
+
+impl TextDocument {
+    /// Creates an empty `TextDocument`
+    pub fn new() -> Self {
+        ffi_span!("TextDocument", "new");
+        track_created!(TextDocument);
+        TextDocument {
+            ptr: unsafe { qt_binding_text_document_create() },
+        }
+    }
+
+    /// Creates a `TextDocument` from HTML
+    pub fn from_html<S>(html: S) -> Self
+    where
+        S: AsRef<str>,
+    {
+        let mut document = TextDocument::new();
+        document.set_html(html);
+        document
+    }
+
+    /// Creates a `TextDocument` from Markdown
+    pub fn from_markdown<S>(markdown: S) -> Self
+    where
+        S: AsRef<str>,
+    {
+        let mut document = TextDocument::new();
+        document.set_markdown(markdown);
+        document
+    }
+
+    /// Replace the content with HTML
+    ///
+    /// Qt supports a subset of HTML 4 and CSS 2.1, see Qt's documentation about supported HTML
+    /// subset.
+    pub fn set_html<S>(&mut self, html: S) -> &mut Self
+    where
+        S: AsRef<str>,
+    {
+        ffi_span!("TextDocument", "set_html");
+        let html = html.as_ref();
+        unsafe {
+            qt_binding_text_document_set_html(
+                self.ptr,
+                html.as_ptr() as *const c_char,
+                html.len() as u32,
+            )
+        };
+        self
+    }
+
+    /// Replace the content with Markdown, using the GitHub dialect
+    pub fn set_markdown<S>(&mut self, markdown: S) -> &mut Self
+    where
+        S: AsRef<str>,
+    {
+        ffi_span!("TextDocument", "set_markdown");
+        let markdown = markdown.as_ref();
+        unsafe {
+            qt_binding_text_document_set_markdown(
+                self.ptr,
+                markdown.as_ptr() as *const c_char,
+                markdown.len() as u32,
+            )
+        };
+        self
+    }
+
+    /// Replace the content with plain text
+    pub fn set_plain_text<S>(&mut self, text: S) -> &mut Self
+    where
+        S: AsRef<str>,
+    {
+        ffi_span!("TextDocument", "set_plain_text");
+        let text = text.as_ref();
+        unsafe {
+            qt_binding_text_document_set_plain_text(
+                self.ptr,
+                text.as_ptr() as *const c_char,
+                text.len() as u32,
+            )
+        };
+        self
+    }
+
+    /// Style sheet applied to the HTML set after this call
+    pub fn set_default_style_sheet<S>(&mut self, css: S) -> &mut Self
+    where
+        S: AsRef<str>,
+    {
+        ffi_span!("TextDocument", "set_default_style_sheet");
+        let css = css.as_ref();
+        unsafe {
+            qt_binding_text_document_set_default_style_sheet(
+                self.ptr,
+                css.as_ptr() as *const c_char,
+                css.len() as u32,
+            )
+        };
+        self
+    }
+
+    /// Content as plain text, without formatting
+    pub fn to_plain_text(&self) -> String {
+        self.text(qt_binding_text_document_to_plain_text)
+    }
+
+    /// Content as HTML
+    pub fn to_html(&self) -> String {
+        self.text(qt_binding_text_document_to_html)
+    }
+
+    /// Content as Markdown
+    pub fn to_markdown(&self) -> String {
+        self.text(qt_binding_text_document_to_markdown)
+    }
+
+    /// If the document is empty
+    pub fn is_empty(&self) -> bool {
+        ffi_span!("TextDocument", "is_empty");
+        unsafe { qt_binding_text_document_is_empty(self.ptr) }
+    }
+
+    /// Render the document to a PDF file
+    ///
+    /// The document is laid out on pages of the given size, with Qt's default margins.
+    pub fn write_pdf<P>(&self, path: P, page_size: PageSize) -> io::Result<()>
+    where
+        P: AsRef<Path>,
+    {
+        ffi_span!("TextDocument", "write_pdf");
+        let path = path.as_ref().to_string_lossy();
+        let (width, height) = page_size.millimeters();
+        let mut message = String::new();
+        let written = unsafe {
+            let data: *mut String = &mut message;
+            qt_binding_text_document_write_pdf(
+                self.ptr,
+                path.as_ptr() as *const c_char,
+                path.len() as u32,
+                width,
+                height,
+                data as *mut c_void,
+                rs_string_fill,
+            )
+        };
+        if written {
+            Ok(())
+        } else {
+            Err(io::Error::other(message))
+        }
+    }
+
+    /// Render the document to PDF, in memory
+    ///
+    /// See [`write_pdf`].
+    ///
+    /// [`write_pdf`]: #method.write_pdf
+    pub fn to_pdf(&self, page_size: PageSize) -> Vec<u8> {
+        ffi_span!("TextDocument", "to_pdf");
+        let (width, height) = page_size.millimeters();
+        let mut pdf = Vec::new();
+        unsafe {
+            let data: *mut Vec<u8> = &mut pdf;
+            qt_binding_text_document_to_pdf(
+                self.ptr,
+                width,
+                height,
+                data as *mut c_void,
+                rs_bytes_fill,
+            )
+        };
+        pdf
+    }
+
+    #[cfg(feature = "printsupport")]
+    pub(crate) fn as_ptr(&self) -> *const c_void {
+        self.ptr
+    }
+
+    fn text(
+        &self,
+        function: unsafe extern "C" fn(*const c_void, *mut c_void, RsStringFillFunc),
+    ) -> String {
+        let mut text = String::new();
+        unsafe {
+            let data: *mut String = &mut text;
+            function(self.ptr, data as *mut c_void, rs_string_fill);
+        }
+        text
+    }
+}
+
+impl Default for TextDocument {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for TextDocument {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("TextDocument")
+            .field("text", &self.to_plain_text())
+            .finish()
+    }
+}
+
+impl Drop for TextDocument {
+    fn drop(&mut self) {
+        ffi_span!("TextDocument", "drop");
+        if app::should_leak("TextDocument") {
+            return;
+        }
+        track_destroyed!(TextDocument);
+        unsafe { qt_binding_text_document_delete(self.ptr) }
+    }
+}
+
+extern "C" fn rs_bytes_fill(output: *mut c_void, input: *const c_char, input_size: u32) {
+    let output = unsafe { &mut *(output as *mut Vec<u8>) };
+    let input = unsafe { slice::from_raw_parts(input as *const u8, input_size as usize) };
+    output.extend_from_slice(input);
+}
+
+type RsBytesFunc = extern "C" fn(data: *mut c_void, input: *const c_char, input_size: u32);
+
+extern "C" {
+    fn qt_binding_text_document_create() -> *mut c_void;
+    fn qt_binding_text_document_delete(document: *mut c_void);
+    fn qt_binding_text_document_set_html(document: *mut c_void, html: *const c_char, size: u32);
+    fn qt_binding_text_document_set_markdown(
+        document: *mut c_void,
+        markdown: *const c_char,
+        size: u32,
+    );
+    fn qt_binding_text_document_set_plain_text(
+        document: *mut c_void,
+        text: *const c_char,
+        size: u32,
+    );
+    fn qt_binding_text_document_set_default_style_sheet(
+        document: *mut c_void,
+        css: *const c_char,
+        size: u32,
+    );
+    fn qt_binding_text_document_to_plain_text(
+        document: *const c_void,
+        output: *mut c_void,
+        fill: RsStringFillFunc,
+    );
+    fn qt_binding_text_document_to_html(
+        document: *const c_void,
+        output: *mut c_void,
+        fill: RsStringFillFunc,
+    );
+    fn qt_binding_text_document_to_markdown(
+        document: *const c_void,
+        output: *mut c_void,
+        fill: RsStringFillFunc,
+    );
+    fn qt_binding_text_document_is_empty(document: *const c_void) -> bool;
+    fn qt_binding_text_document_write_pdf(
+        document: *const c_void,
+        path: *const c_char,
+        size: u32,
+        width: f64,
+        height: f64,
+        error: *mut c_void,
+        fill: RsStringFillFunc,
+    ) -> bool;
+    fn qt_binding_text_document_to_pdf(
+        document: *const c_void,
+        width: f64,
+        height: f64,
+        output: *mut c_void,
+        fill: RsBytesFunc,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_page_size() {
+        assert_eq!(PageSize::A4.millimeters(), (210., 297.));
+        assert_eq!(PageSize::Custom(100., 150.).millimeters(), (100., 150.));
+    }
+}
@@ -0,0 +1,73 @@
+#include <QtGui/QGuiApplication>
+#include <QtGui/QPalette>
+#include <QtGui/QStyleHints>
+#include <array>
+#include <cstdint>
+
+QT_USE_NAMESPACE
+
+using RsThemeChangedFunc = void (*)(void *data);
+
+namespace {
+
+// Same order as the fields of Palette in theme.rs
+const std::array<QPalette::ColorRole, 11> colorRoles{{
+    QPalette::Window,
+    QPalette::WindowText,
+    QPalette::Base,
+    QPalette::AlternateBase,
+    QPalette::Text,
+    QPalette::Button,
+    QPalette::ButtonText,
+    QPalette::Highlight,
+    QPalette::HighlightedText,
+    QPalette::Link,
+    QPalette::PlaceholderText,
+}};
+
+} // namespace
+
+extern "C" {
+
+void qt_binding_theme_palette(std::uint32_t *colors)
+{
+    const auto palette = QGuiApplication::palette();
+    for (std::size_t i = 0; i < colorRoles.size(); ++i) {
+        colors[i] = static_cast<std::uint32_t>(palette.color(colorRoles[i]).rgba());
+    }
+}
+
+int qt_binding_theme_double_click_interval()
+{
+    return QGuiApplication::styleHints()->mouseDoubleClickInterval();
+}
+
+int qt_binding_theme_mouse_press_and_hold_interval()
+{
+    return QGuiApplication::styleHints()->mousePressAndHoldInterval();
+}
+
+int qt_binding_theme_cursor_flash_time()
+{
+    return QGuiApplication::styleHints()->cursorFlashTime();
+}
+
+int qt_binding_theme_start_drag_distance()
+{
+    return QGuiApplication::styleHints()->startDragDistance();
+}
+
+QObject *qt_binding_theme_watcher_create(void *data, RsThemeChangedFunc changed)
+{
+    auto watcher = new QObject();
+    QObject::connect(qGuiApp, &QGuiApplication::paletteChanged, watcher,
+                     [data, changed]() { changed(data); });
+    return watcher;
+}
+
+void qt_binding_theme_watcher_delete(QObject *watcher)
+{
+    delete watcher;
+}
+
+} // extern "C"
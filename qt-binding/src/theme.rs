@@ -0,0 +1,258 @@
+//! Platform theme
+//!
+//! [`Palette`] contains the colors used by the application, that follow the platform theme,
+//! so that content generated by Rust code, like chart colors, matches the rest of the UI.
+//! [`Palette::is_dark`] detects if the platform uses a dark or a light theme.
+//!
+//! Style hints, like the [`double_click_interval`], are available as functions, and
+//! [`Watcher`] calls a Rust closure when the palette changes, for example when switching the
+//! platform between dark and light themes.
+//!
+//! [`Palette`]: struct.Palette.html
+//! [`Palette::is_dark`]: struct.Palette.html#method.is_dark
+//! [`double_click_interval`]: fn.double_click_interval.html
+//! [`Watcher`]: struct.Watcher.html
+//!
+//! # Features
+//!
+//! This module is only available with the `gui` feature.
+//!
+//! # Limitations
+//!
+//! The palette and style hints can only be read after the [`Application`] is created, and
+//! watchers must be dropped before it.
+//!
+//! [`Application`]: ../app/struct.Application.html
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use qt_binding::app::Application;
+//! use qt_binding::theme::{Palette, Watcher};
+//!
+//! let mut app = Application::new();
+//! let palette = Palette::current();
+//! println!("Series color: {}", palette.highlight.name());
+//!
+//! let _watcher = Watcher::new(|palette| {
+//!     println!("Dark theme: {}", palette.is_dark());
+//! });
+//! app.exec();
+//! ```
+
+use crate::app;
+use std::fmt;
+use std::os::raw::{c_int, c_void};
+use std::time::Duration;
+
+/// An RGBA color
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Color {
+    /// Red component
+    pub red: u8,
+    /// Green component
+    pub green: u8,
+    /// Blue component
+    pub blue: u8,
+    /// Alpha component, 255 being opaque
+    pub alpha: u8,
+}
+
+impl Color {
+    /// Creates an opaque `Color`
+    pub fn rgb(red: u8, green: u8, blue: u8) -> Self {
+        Color {
+            red,
+            green,
+            blue,
+            alpha: 255,
+        }
+    }
+
+    fn from_argb(argb: u32) -> Self {
+        Color {
+            red: (argb >> 16) as u8,
+            green: (argb >> 8) as u8,
+            blue: argb as u8,
+            alpha: (argb >> 24) as u8,
+        }
+    }
+
+    /// Name of the color, as `#rrggbb`, like QML and CSS colors
+    ///
+    /// The alpha component is ignored.
+    pub fn name(&self) -> String {
+        format!("#{:02x}{:02x}{:02x}", self.red, self.green, self.blue)
+    }
+
+    /// Perceived lightness of the color, between 0 and 1
+    pub fn lightness(&self) -> f64 {
+        let red = f64::from(self.red);
+        let green = f64::from(self.green);
+        let blue = f64::from(self.blue);
+        (0.299 * red + 0.587 * green + 0.114 * blue) / 255.
+    }
+}
+
+impl fmt::Display for Color {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// Colors of the application palette
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Palette {
+    /// Background of windows
+    pub window: Color,
+    /// Text displayed on windows
+    pub window_text: Color,
+    /// Background of text inputs and lists
+    pub base: Color,
+    /// Alternate background of list rows
+    pub alternate_base: Color,
+    /// Text displayed on the base color
+    pub text: Color,
+    /// Background of buttons
+    pub button: Color,
+    /// Text displayed on buttons
+    pub button_text: Color,
+    /// Background of selected items
+    pub highlight: Color,
+    /// Text of selected items
+    pub highlighted_text: Color,
+    /// Hyperlinks
+    pub link: Color,
+    /// Placeholder text of text inputs
+    pub placeholder_text: Color,
+}
+
+impl Palette {
+    /// Get the current application palette
+    pub fn current() -> Self {
+        ffi_span!("Palette", "current");
+        let mut colors = [0; 11];
+        unsafe { qt_binding_theme_palette(colors.as_mut_ptr()) };
+        let color = |index: usize| Color::from_argb(colors[index]);
+        Palette {
+            window: color(0),
+            window_text: color(1),
+            base: color(2),
+            alternate_base: color(3),
+            text: color(4),
+            button: color(5),
+            button_text: color(6),
+            highlight: color(7),
+            highlighted_text: color(8),
+            link: color(9),
+            placeholder_text: color(10),
+        }
+    }
+
+    /// If the palette is a dark theme, with light text on a dark background
+    pub fn is_dark(&self) -> bool {
+        self.window_text.lightness() > self.window.lightness()
+    }
+}
+
+/// Maximum delay between two clicks of a double click
+pub fn double_click_interval() -> Duration {
+    ffi_span!("theme", "double_click_interval");
+    to_duration(unsafe { qt_binding_theme_double_click_interval() })
+}
+
+/// Delay after which a press is a press and hold
+pub fn press_and_hold_interval() -> Duration {
+    ffi_span!("theme", "press_and_hold_interval");
+    to_duration(unsafe { qt_binding_theme_mouse_press_and_hold_interval() })
+}
+
+/// Time for the text cursor to blink on and off, zero if the cursor does not blink
+pub fn cursor_flash_time() -> Duration {
+    ffi_span!("theme", "cursor_flash_time");
+    to_duration(unsafe { qt_binding_theme_cursor_flash_time() })
+}
+
+/// Distance in pixels the mouse must move for a press to start a drag
+pub fn start_drag_distance() -> u32 {
+    ffi_span!("theme", "start_drag_distance");
+    unsafe { qt_binding_theme_start_drag_distance() as u32 }
+}
+
+fn to_duration(milliseconds: c_int) -> Duration {
+    Duration::from_millis(milliseconds.max(0) as u64)
+}
+
+type ChangedHandler = Box<dyn FnMut(&Palette)>;
+
+/// Watches changes of the application palette
+///
+/// See module level documentation for more information.
+pub struct Watcher {
+    ptr: *mut c_void,
+    _handler: Box<ChangedHandler>,
+}
+
+impl Watcher {
+    /// Creates a `Watcher` that calls a closure with the new palette when it changes
+    pub fn new<F>(handler: F) -> Self
+    where
+        F: FnMut(&Palette) + 'static,
+    {
+        ffi_span!("Watcher", "new");
+        let mut handler: Box<ChangedHandler> = Box::new(Box::new(handler));
+        let data: *mut ChangedHandler = &mut *handler;
+        let ptr = unsafe { qt_binding_theme_watcher_create(data as *mut c_void, rs_theme_changed) };
+        track_created!(ThemeWatcher);
+        Watcher {
+            ptr,
+            _handler: handler,
+        }
+    }
+}
+
+impl Drop for Watcher {
+    fn drop(&mut self) {
+        ffi_span!("Watcher", "drop");
+        if app::should_leak("Watcher") {
+            return;
+        }
+        track_destroyed!(ThemeWatcher);
+        unsafe { qt_binding_theme_watcher_delete(self.ptr) }
+    }
+}
+
+extern "C" fn rs_theme_changed(data: *mut c_void) {
+    let handler = unsafe { &mut *(data as *mut ChangedHandler) };
+    handler(&Palette::current());
+}
+
+type RsThemeChangedFunc = extern "C" fn(data: *mut c_void);
+
+extern "C" {
+    fn qt_binding_theme_palette(colors: *mut u32);
+    fn qt_binding_theme_double_click_interval() -> c_int;
+    fn qt_binding_theme_mouse_press_and_hold_interval() -> c_int;
+    fn qt_binding_theme_cursor_flash_time() -> c_int;
+    fn qt_binding_theme_start_drag_distance() -> c_int;
+    fn qt_binding_theme_watcher_create(
+        data: *mut c_void,
+        changed: RsThemeChangedFunc,
+    ) -> *mut c_void;
+    fn qt_binding_theme_watcher_delete(watcher: *mut c_void);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_color() {
+        let color = Color::from_argb(0xff30_8cc6);
+        assert_eq!(color, Color::rgb(0x30, 0x8c, 0xc6));
+        assert_eq!(color.name(), "#308cc6");
+
+        assert!(Color::rgb(255, 255, 255).lightness() > 0.99);
+        assert!(Color::rgb(0, 0, 0).lightness() < 0.01);
+    }
+}
@@ -0,0 +1,82 @@
+//! Optional instrumentation of the binding layer
+//!
+//! When the `tracing` feature is enabled, `ffi_span!` enters a `tracing` span named after the
+//! binding object and member being called, so that the time spent in the binding layer shows
+//! up in flamegraphs.
+//!
+//! Every public function and method, and every trait implementation, that calls into Qt, even
+//! through a private helper, starts with `ffi_span!`, named after its type, or its module for
+//! free functions. Conversions into and from a `Variant` are named after `Variant`, like
+//! `Variant::from_string_list_model`. Private helpers are not instrumented, and run inside the
+//! span of their caller. Callbacks from Qt are not instrumented either: they run inside the span
+//! of the call that made Qt invoke them, like `Application::exec`.
+//!
+//! When the `diagnostics` feature is enabled, `track_created!` and `track_destroyed!` update
+//! the counters of the [`diagnostics`](../diagnostics/index.html) module.
+//!
+//! Without these features, the macros expand to nothing.
+//!
+//! `warning!` reports warnings of the binding layer with `tracing` when the `tracing` feature is
+//! enabled, with `log` when the `log` feature is enabled, and on the standard error otherwise.
+
+#[cfg(feature = "tracing")]
+macro_rules! ffi_span {
+    ($object:expr, $member:expr) => {
+        let _span = tracing::trace_span!(
+            concat!($object, "::", $member),
+            object = $object,
+            member = $member
+        )
+        .entered();
+    };
+}
+
+#[cfg(not(feature = "tracing"))]
+macro_rules! ffi_span {
+    ($object:expr, $member:expr) => {};
+}
+
+#[cfg(feature = "diagnostics")]
+macro_rules! track_created {
+    ($kind:ident) => {
+        crate::diagnostics::created(crate::diagnostics::Kind::$kind)
+    };
+}
+
+#[cfg(not(feature = "diagnostics"))]
+macro_rules! track_created {
+    ($kind:ident) => {};
+}
+
+#[cfg(feature = "diagnostics")]
+macro_rules! track_destroyed {
+    ($kind:ident) => {
+        crate::diagnostics::destroyed(crate::diagnostics::Kind::$kind)
+    };
+}
+
+#[cfg(not(feature = "diagnostics"))]
+macro_rules! track_destroyed {
+    ($kind:ident) => {};
+}
+
+#[cfg(feature = "tracing")]
+macro_rules! warning {
+    ($($arg:tt)*) => {
+        tracing::warn!($($arg)*)
+    };
+}
+
+#[cfg(all(feature = "log", not(feature = "tracing")))]
+macro_rules! warning {
+    ($($arg:tt)*) => {
+        log::warn!($($arg)*)
+    };
+}
+
+#[cfg(not(any(feature = "log", feature = "tracing")))]
+macro_rules! warning {
+    ($($arg:tt)*) => {
+        eprintln!($($arg)*)
+    };
+}
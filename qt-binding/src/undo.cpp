@@ -0,0 +1,173 @@
+#include <QtCore/QVariant>
+#include <QtWidgets/QUndoStack>
+#include <cstdint>
+
+QT_USE_NAMESPACE
+
+using RsStringFillFunc = void (*)(void *output, const char *input, std::uint32_t inputSize);
+using RsCommandFunc = void (*)(void *data, bool redo);
+using RsCommandMergeFunc = void (*)(void *data, void *other);
+using RsCommandDropFunc = void (*)(void *data);
+
+static QString toString(const char *value, std::uint32_t size)
+{
+    return QString::fromUtf8(value, static_cast<int>(size));
+}
+
+static void fillString(const QString &value, void *output, RsStringFillFunc fill)
+{
+    const auto byteArray = value.toUtf8();
+    fill(output, byteArray.constData(), static_cast<std::uint32_t>(byteArray.size()));
+}
+
+namespace qt_binding {
+
+class UndoCommand : public QUndoCommand
+{
+public:
+    UndoCommand(const QString &text, int id, void *data, RsCommandFunc command,
+                RsCommandMergeFunc merge, RsCommandDropFunc drop)
+        : QUndoCommand(text)
+        , m_id(id)
+        , m_data(data)
+        , m_command(command)
+        , m_merge(merge)
+        , m_drop(drop)
+    {
+    }
+
+    ~UndoCommand() override
+    {
+        m_drop(m_data);
+    }
+
+    int id() const override
+    {
+        return m_id;
+    }
+
+    void redo() override
+    {
+        m_command(m_data, true);
+    }
+
+    void undo() override
+    {
+        m_command(m_data, false);
+    }
+
+    bool mergeWith(const QUndoCommand *other) override
+    {
+        // Only called by QUndoStack with commands of the same id, that are UndoCommands
+        const auto command = static_cast<const UndoCommand *>(other);
+        m_merge(m_data, command->m_data);
+        setText(command->text());
+        return true;
+    }
+
+private:
+    int m_id;
+    void *m_data;
+    RsCommandFunc m_command;
+    RsCommandMergeFunc m_merge;
+    RsCommandDropFunc m_drop;
+};
+
+} // namespace qt_binding
+
+extern "C" {
+
+QUndoStack *qt_binding_undo_stack_create()
+{
+    return new QUndoStack();
+}
+
+void qt_binding_undo_stack_delete(QUndoStack *stack)
+{
+    delete stack;
+}
+
+QVariant *qt_binding_undo_stack_to_variant(QUndoStack *stack)
+{
+    return new QVariant(QVariant::fromValue<QObject *>(stack));
+}
+
+void qt_binding_undo_stack_push(QUndoStack *stack, const char *text, std::uint32_t size, int id,
+                                void *data, RsCommandFunc command, RsCommandMergeFunc merge,
+                                RsCommandDropFunc drop)
+{
+    stack->push(
+        new qt_binding::UndoCommand(toString(text, size), id, data, command, merge, drop));
+}
+
+void qt_binding_undo_stack_undo(QUndoStack *stack)
+{
+    stack->undo();
+}
+
+void qt_binding_undo_stack_redo(QUndoStack *stack)
+{
+    stack->redo();
+}
+
+bool qt_binding_undo_stack_can_undo(const QUndoStack *stack)
+{
+    return stack->canUndo();
+}
+
+bool qt_binding_undo_stack_can_redo(const QUndoStack *stack)
+{
+    return stack->canRedo();
+}
+
+void qt_binding_undo_stack_undo_text(const QUndoStack *stack, void *output, RsStringFillFunc fill)
+{
+    fillString(stack->undoText(), output, fill);
+}
+
+void qt_binding_undo_stack_redo_text(const QUndoStack *stack, void *output, RsStringFillFunc fill)
+{
+    fillString(stack->redoText(), output, fill);
+}
+
+int qt_binding_undo_stack_count(const QUndoStack *stack)
+{
+    return stack->count();
+}
+
+int qt_binding_undo_stack_index(const QUndoStack *stack)
+{
+    return stack->index();
+}
+
+void qt_binding_undo_stack_begin_macro(QUndoStack *stack, const char *text, std::uint32_t size)
+{
+    stack->beginMacro(toString(text, size));
+}
+
+void qt_binding_undo_stack_end_macro(QUndoStack *stack)
+{
+    stack->endMacro();
+}
+
+void qt_binding_undo_stack_set_clean(QUndoStack *stack)
+{
+    stack->setClean();
+}
+
+bool qt_binding_undo_stack_is_clean(const QUndoStack *stack)
+{
+    return stack->isClean();
+}
+
+void qt_binding_undo_stack_set_undo_limit(QUndoStack *stack, int limit)
+{
+    stack->setUndoLimit(limit);
+}
+
+void qt_binding_undo_stack_clear(QUndoStack *stack)
+{
+    stack->clear();
+}
+
+} // extern "C"
@@ -0,0 +1,381 @@
+//! Undo and redo
+//!
+//! [`UndoStack`] is a binding on Qt's `QUndoStack`. It records [`Command`]s, whose redo and
+//! undo actions are Rust closures, and undoes and redoes them.
+//!
+//! Successive commands with the same merge id, set with [`Command::merge_id`], are merged in
+//! a single command. This is typically used to undo typing a word at once, instead of
+//! character by character. Commands are also grouped in macros with [`begin_macro`] and
+//! [`end_macro`].
+//!
+//! An `UndoStack` can be exposed to QML, where its `canUndo`, `canRedo`, `undoText`,
+//! `redoText` and `clean` properties can be bound to, for example to enable undo and redo
+//! buttons.
+//!
+//! [`UndoStack`]: struct.UndoStack.html
+//! [`Command`]: struct.Command.html
+//! [`Command::merge_id`]: struct.Command.html#method.merge_id
+//! [`begin_macro`]: struct.UndoStack.html#method.begin_macro
+//! [`end_macro`]: struct.UndoStack.html#method.end_macro
+//!
+//! # Features
+//!
+//! This module is only available with the `widgets` feature, as `QUndoStack` is part of Qt
+//! Widgets in Qt 5.
+//!
+//! # Limitations
+//!
+//! Since `UndoStack` is a binding over a Qt object, it is neither `Send` nor `Sync`. Commands
+//! must not push commands to the stack that is running them.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use qt_binding::app::Application;
+//! use qt_binding::undo::{Command, UndoStack};
+//! use std::cell::RefCell;
+//! use std::rc::Rc;
+//!
+//! let _app = Application::new();
+//! let document = Rc::new(RefCell::new(String::new()));
+//! let mut stack = UndoStack::new();
+//!
+//! let (redo, undo) = (document.clone(), document.clone());
+//! stack.push(Command::new(
+//!     "Type",
+//!     move || redo.borrow_mut().push_str("Hello"),
+//!     move || undo.borrow_mut().clear(),
+//! ));
+//! assert_eq!(*document.borrow(), "Hello");
+//!
+//! stack.undo();
+//! assert_eq!(*document.borrow(), "");
+//! assert_eq!(stack.redo_text(), "Type");
+//! ```
+
+use crate::app;
+use crate::object::Object;
+use crate::variant::convert::{rs_string_fill, RsStringFillFunc};
+use crate::variant::Variant;
+use std::fmt;
+use std::os::raw::{c_char, c_int, c_void};
+
+type Action = Box<dyn FnMut()>;
+
+struct Actions {
+    redo: Action,
+    undo: Action,
+}
+
+/// A command pushed to an [`UndoStack`]
+///
+/// [`UndoStack`]: struct.UndoStack.html
+pub struct Command {
+    text: String,
+    merge_id: c_int,
+    actions: Box<Actions>,
+}
+
+impl Command {
+    /// Creates a `Command` from its description and its redo and undo actions
+    ///
+    /// The description is displayed in undo and redo menus, like `Undo Type`.
+    pub fn new<S, R, U>(text: S, redo: R, undo: U) -> Self
+    where
+        S: Into<String>,
+        R: FnMut() + 'static,
+        U: FnMut() + 'static,
+    {
+        Command {
+            text: text.into(),
+            merge_id: -1,
+            actions: Box::new(Actions {
+                redo: Box::new(redo),
+                undo: Box::new(undo),
+            }),
+        }
+    }
+
+    /// Merge the command with the previous command, if it has the same merge id
+    ///
+    /// The merged command undoes with the undo action of the previous command, and redoes
+    /// with the redo action of the new command, with the description of the new command.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the id is negative.
+    pub fn merge_id(&mut self, id: i32) -> &mut Self {
+        if id < 0 {
+            panic!("Merge id must be positive, got {}", id);
+        }
+        self.merge_id = id as c_int;
+        self
+    }
+}
+
+impl fmt::Debug for Command {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Command")
+            .field("text", &self.text)
+            .field("merge_id", &self.merge_id)
+            .finish()
+    }
+}
+
+/// Binding to `QUndoStack`
+///
+/// See module level documentation for more information.
+pub struct UndoStack {
+    ptr: *mut c_void,
+}
+
+impl UndoStack {
+    /// Creates an empty `UndoStack`
+    pub fn new() -> Self {
+        ffi_span!("UndoStack", "new");
+        track_created!(UndoStack);
+        UndoStack {
+            ptr: unsafe { qt_binding_undo_stack_create() },
+        }
+    }
+
+    /// Push a command, and run its redo action
+    ///
+    /// Commands that were undone are discarded.
+    pub fn push(&mut self, command: Command) {
+        ffi_span!("UndoStack", "push");
+        let data = Box::into_raw(command.actions);
+        unsafe {
+            qt_binding_undo_stack_push(
+                self.ptr,
+                command.text.as_ptr() as *const c_char,
+                command.text.len() as u32,
+                command.merge_id,
+                data as *mut c_void,
+                rs_command_run,
+                rs_command_merge,
+                rs_command_drop,
+            )
+        }
+    }
+
+    /// Undo the last command
+    pub fn undo(&mut self) {
+        ffi_span!("UndoStack", "undo");
+        unsafe { qt_binding_undo_stack_undo(self.ptr) }
+    }
+
+    /// Redo the last undone command
+    pub fn redo(&mut self) {
+        ffi_span!("UndoStack", "redo");
+        unsafe { qt_binding_undo_stack_redo(self.ptr) }
+    }
+
+    /// If there is a command to undo
+    pub fn can_undo(&self) -> bool {
+        ffi_span!("UndoStack", "can_undo");
+        unsafe { qt_binding_undo_stack_can_undo(self.ptr) }
+    }
+
+    /// If there is a command to redo
+    pub fn can_redo(&self) -> bool {
+        ffi_span!("UndoStack", "can_redo");
+        unsafe { qt_binding_undo_stack_can_redo(self.ptr) }
+    }
+
+    /// Description of the command to undo, empty if there is none
+    pub fn undo_text(&self) -> String {
+        self.text(qt_binding_undo_stack_undo_text)
+    }
+
+    /// Description of the command to redo, empty if there is none
+    pub fn redo_text(&self) -> String {
+        self.text(qt_binding_undo_stack_redo_text)
+    }
+
+    /// Number of commands, including undone commands
+    pub fn len(&self) -> usize {
+        ffi_span!("UndoStack", "len");
+        unsafe { qt_binding_undo_stack_count(self.ptr) as usize }
+    }
+
+    /// If there are no commands
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Index of the next command to undo, or the number of commands that are done
+    pub fn index(&self) -> usize {
+        ffi_span!("UndoStack", "index");
+        unsafe { qt_binding_undo_stack_index(self.ptr) as usize }
+    }
+
+    /// Start grouping the next commands in a single command
+    ///
+    /// Macros can be nested, and commands are grouped until the outermost macro is ended
+    /// with [`end_macro`].
+    ///
+    /// [`end_macro`]: #method.end_macro
+    pub fn begin_macro<S>(&mut self, text: S)
+    where
+        S: AsRef<str>,
+    {
+        ffi_span!("UndoStack", "begin_macro");
+        let text = text.as_ref();
+        unsafe {
+            qt_binding_undo_stack_begin_macro(
+                self.ptr,
+                text.as_ptr() as *const c_char,
+                text.len() as u32,
+            )
+        }
+    }
+
+    /// End a macro started with [`begin_macro`]
+    ///
+    /// [`begin_macro`]: #method.begin_macro
+    pub fn end_macro(&mut self) {
+        ffi_span!("UndoStack", "end_macro");
+        unsafe { qt_binding_undo_stack_end_macro(self.ptr) }
+    }
+
+    /// Mark the current state as clean, like when a document is saved
+    pub fn set_clean(&mut self) {
+        ffi_span!("UndoStack", "set_clean");
+        unsafe { qt_binding_undo_stack_set_clean(self.ptr) }
+    }
+
+    /// If the stack is in the state marked as clean
+    pub fn is_clean(&self) -> bool {
+        ffi_span!("UndoStack", "is_clean");
+        unsafe { qt_binding_undo_stack_is_clean(self.ptr) }
+    }
+
+    /// Set the maximum number of commands, older commands being discarded, `0` for no limit
+    ///
+    /// The limit can only be set on an empty stack.
+    pub fn set_undo_limit(&mut self, limit: usize) -> &mut Self {
+        ffi_span!("UndoStack", "set_undo_limit");
+        unsafe { qt_binding_undo_stack_set_undo_limit(self.ptr, limit as c_int) };
+        self
+    }
+
+    /// Remove all the commands, without undoing them
+    pub fn clear(&mut self) {
+        ffi_span!("UndoStack", "clear");
+        unsafe { qt_binding_undo_stack_clear(self.ptr) }
+    }
+
+    fn text(
+        &self,
+        f: unsafe extern "C" fn(*const c_void, *mut c_void, RsStringFillFunc),
+    ) -> String {
+        let mut value = String::new();
+        unsafe {
+            let data: *mut String = &mut value;
+            f(self.ptr, data as *mut c_void, rs_string_fill);
+        }
+        value
+    }
+}
+
+impl Default for UndoStack {
+    fn default() -> Self {
+        UndoStack::new()
+    }
+}
+
+impl From<&'_ UndoStack> for Variant {
+    fn from(stack: &UndoStack) -> Self {
+        ffi_span!("Variant", "from_undo_stack");
+        Variant::from_ptr(unsafe { qt_binding_undo_stack_to_variant(stack.ptr) })
+    }
+}
+
+impl fmt::Debug for UndoStack {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("UndoStack")
+            .field("len", &self.len())
+            .field("index", &self.index())
+            .finish()
+    }
+}
+
+impl Object for UndoStack {
+    fn as_object_ptr(&self) -> *mut c_void {
+        self.ptr
+    }
+}
+
+impl Drop for UndoStack {
+    fn drop(&mut self) {
+        ffi_span!("UndoStack", "drop");
+        if app::should_leak("UndoStack") {
+            return;
+        }
+        track_destroyed!(UndoStack);
+        unsafe { qt_binding_undo_stack_delete(self.ptr) }
+    }
+}
+
+extern "C" fn rs_command_run(data: *mut c_void, redo: bool) {
+    let actions = unsafe { &mut *(data as *mut Actions) };
+    if redo {
+        (actions.redo)();
+    } else {
+        (actions.undo)();
+    }
+}
+
+extern "C" fn rs_command_merge(data: *mut c_void, other: *mut c_void) {
+    let actions = unsafe { &mut *(data as *mut Actions) };
+    let other = unsafe { &mut *(other as *mut Actions) };
+    // The other command is dropped after merging, and keeps the previous redo action
+    std::mem::swap(&mut actions.redo, &mut other.redo);
+}
+
+extern "C" fn rs_command_drop(data: *mut c_void) {
+    drop(unsafe { Box::from_raw(data as *mut Actions) });
+}
+
+type RsCommandFunc = extern "C" fn(data: *mut c_void, redo: bool);
+type RsCommandMergeFunc = extern "C" fn(data: *mut c_void, other: *mut c_void);
+type RsCommandDropFunc = extern "C" fn(data: *mut c_void);
+
+extern "C" {
+    fn qt_binding_undo_stack_create() -> *mut c_void;
+    fn qt_binding_undo_stack_delete(stack: *mut c_void);
+    fn qt_binding_undo_stack_to_variant(stack: *mut c_void) -> *mut c_void;
+    fn qt_binding_undo_stack_push(
+        stack: *mut c_void,
+        text: *const c_char,
+        size: u32,
+        id: c_int,
+        data: *mut c_void,
+        command: RsCommandFunc,
+        merge: RsCommandMergeFunc,
+        drop: RsCommandDropFunc,
+    );
+    fn qt_binding_undo_stack_undo(stack: *mut c_void);
+    fn qt_binding_undo_stack_redo(stack: *mut c_void);
+    fn qt_binding_undo_stack_can_undo(stack: *const c_void) -> bool;
+    fn qt_binding_undo_stack_can_redo(stack: *const c_void) -> bool;
+    fn qt_binding_undo_stack_undo_text(
+        stack: *const c_void,
+        output: *mut c_void,
+        fill: RsStringFillFunc,
+    );
+    fn qt_binding_undo_stack_redo_text(
+        stack: *const c_void,
+        output: *mut c_void,
+        fill: RsStringFillFunc,
+    );
+    fn qt_binding_undo_stack_count(stack: *const c_void) -> c_int;
+    fn qt_binding_undo_stack_index(stack: *const c_void) -> c_int;
+    fn qt_binding_undo_stack_begin_macro(stack: *mut c_void, text: *const c_char, size: u32);
+    fn qt_binding_undo_stack_end_macro(stack: *mut c_void);
+    fn qt_binding_undo_stack_set_clean(stack: *mut c_void);
+    fn qt_binding_undo_stack_is_clean(stack: *const c_void) -> bool;
+    fn qt_binding_undo_stack_set_undo_limit(stack: *mut c_void, limit: c_int);
+    fn qt_binding_undo_stack_clear(stack: *mut c_void);
+}
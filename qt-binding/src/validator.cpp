@@ -0,0 +1,90 @@
+#include <QtCore/QVariant>
+#include <QtGui/QValidator>
+#include <cstdint>
+
+QT_USE_NAMESPACE
+
+using CStringFillFunc = void (*)(void *output, const char *input, std::uint32_t inputSize);
+using RsStringFillFunc = void (*)(void *output, const char *input, std::uint32_t inputSize);
+using RsValidateFunc = int (*)(void *data, const char *input, std::uint32_t size);
+using RsFixupFunc = void (*)(void *data, const char *input, std::uint32_t size, void *output,
+                             CStringFillFunc fill);
+
+static void stringFill(void *output, const char *input, std::uint32_t inputSize)
+{
+    *static_cast<QString *>(output) = QString::fromUtf8(input, static_cast<int>(inputSize));
+}
+
+namespace qt_binding {
+
+class Validator : public QValidator
+{
+public:
+    Validator(void *data, RsValidateFunc validate, RsFixupFunc fixup)
+        : m_data(data)
+        , m_validate(validate)
+        , m_fixup(fixup)
+    {
+    }
+
+    State validate(QString &input, int &pos) const override
+    {
+        Q_UNUSED(pos)
+        const auto byteArray = input.toUtf8();
+        const auto state =
+            m_validate(m_data, byteArray.constData(), static_cast<std::uint32_t>(byteArray.size()));
+        return static_cast<State>(state);
+    }
+
+    void fixup(QString &input) const override
+    {
+        const auto byteArray = input.toUtf8();
+        m_fixup(m_data, byteArray.constData(), static_cast<std::uint32_t>(byteArray.size()), &input,
+                stringFill);
+    }
+
+private:
+    void *m_data;
+    RsValidateFunc m_validate;
+    RsFixupFunc m_fixup;
+};
+
+} // namespace qt_binding
+
+extern "C" {
+
+qt_binding::Validator *qt_binding_validator_create(void *data, RsValidateFunc validate,
+                                                   RsFixupFunc fixup)
+{
+    return new qt_binding::Validator(data, validate, fixup);
+}
+
+void qt_binding_validator_delete(qt_binding::Validator *validator)
+{
+    delete validator;
+}
+
+QVariant *qt_binding_validator_to_variant(qt_binding::Validator *validator)
+{
+    return new QVariant(QVariant::fromValue<QObject *>(validator));
+}
+
+int qt_binding_validator_validate(const qt_binding::Validator *validator, const char *input,
+                                  std::uint32_t size)
+{
+    auto string = QString::fromUtf8(input, static_cast<int>(size));
+    auto pos = string.size();
+    return static_cast<int>(validator->validate(string, pos));
+}
+
+void qt_binding_validator_fixup(const qt_binding::Validator *validator, const char *input,
+                                std::uint32_t size, void *output, RsStringFillFunc fill)
+{
+    auto string = QString::fromUtf8(input, static_cast<int>(size));
+    validator->fixup(string);
+
+    const auto byteArray = string.toUtf8();
+    fill(output, byteArray.constData(), static_cast<std::uint32_t>(byteArray.size()));
+}
+
+} // extern "C"
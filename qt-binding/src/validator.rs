@@ -0,0 +1,265 @@
+//! Input validation backed by Rust
+//!
+//! [`Validator`] is a `QValidator` whose validation and fix-up are implemented by Rust
+//! closures. It can be exposed to QML, for example as a context property, and assigned to the
+//! `validator` property of a `TextField` or a `TextInput`, keeping input validation logic in
+//! the backend instead of duplicating it in JavaScript.
+//!
+//! [`Validator`]: struct.Validator.html
+//!
+//! # Features
+//!
+//! This module is only available with the `gui` feature.
+//!
+//! # Limitations
+//!
+//! A `Validator` must outlive the QML items that use it.
+//!
+//! # Examples
+//!
+//! ```
+//! use qt_binding::validator::{State, Validator};
+//! use qt_binding::variant::Variant;
+//!
+//! let mut validator = Validator::new(|input| {
+//!     if input.is_empty() {
+//!         State::Intermediate
+//!     } else if input.chars().all(|c| c.is_ascii_hexdigit()) {
+//!         State::Acceptable
+//!     } else {
+//!         State::Invalid
+//!     }
+//! });
+//! validator.fixup(|input| Some(input.to_uppercase()));
+//!
+//! assert_eq!(validator.validate("c0ffee"), State::Acceptable);
+//!
+//! // Expose the validator to QML
+//! let variant = Variant::from(&validator);
+//! ```
+
+use crate::app;
+use crate::object::Object;
+use crate::variant::convert::{rs_string_fill, RsStringFillFunc};
+use crate::variant::Variant;
+use std::os::raw::{c_char, c_int, c_void};
+use std::slice::from_raw_parts;
+use std::str::from_utf8_unchecked;
+
+/// Result of a validation
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum State {
+    /// The input is invalid and is rejected
+    Invalid,
+    /// The input is not valid yet, but might become valid with more edits
+    Intermediate,
+    /// The input is valid
+    Acceptable,
+}
+
+impl State {
+    fn as_c_int(self) -> c_int {
+        match self {
+            State::Invalid => 0,
+            State::Intermediate => 1,
+            State::Acceptable => 2,
+        }
+    }
+
+    fn from_c_int(value: c_int) -> Self {
+        match value {
+            2 => State::Acceptable,
+            1 => State::Intermediate,
+            _ => State::Invalid,
+        }
+    }
+}
+
+type ValidateCallback = Box<dyn FnMut(&str) -> State>;
+type FixupCallback = Box<dyn FnMut(&str) -> Option<String>>;
+
+struct Callbacks {
+    validate: ValidateCallback,
+    fixup: Option<FixupCallback>,
+}
+
+/// Binding to a `QValidator` implemented in Rust
+///
+/// See module level documentation for more information.
+pub struct Validator {
+    ptr: *mut c_void,
+    callbacks: Box<Callbacks>,
+}
+
+impl Validator {
+    /// Creates a `Validator` from a validation function
+    pub fn new<F>(validate: F) -> Self
+    where
+        F: FnMut(&str) -> State + 'static,
+    {
+        ffi_span!("Validator", "new");
+        let mut callbacks = Box::new(Callbacks {
+            validate: Box::new(validate),
+            fixup: None,
+        });
+        let data: *mut Callbacks = &mut *callbacks;
+        let ptr = unsafe {
+            qt_binding_validator_create(
+                data as *mut c_void,
+                rs_validator_validate,
+                rs_validator_fixup,
+            )
+        };
+        track_created!(Validator);
+        Validator { ptr, callbacks }
+    }
+
+    /// Set the fix-up function
+    ///
+    /// The fix-up function is called when the input is not acceptable when editing finishes.
+    /// It returns a corrected input, or `None` to keep the input unchanged.
+    pub fn fixup<F>(&mut self, fixup: F) -> &mut Self
+    where
+        F: FnMut(&str) -> Option<String> + 'static,
+    {
+        self.callbacks.fixup = Some(Box::new(fixup));
+        self
+    }
+
+    /// Validate an input
+    ///
+    /// This calls the validation function through `QValidator::validate`.
+    pub fn validate(&self, input: &str) -> State {
+        ffi_span!("Validator", "validate");
+        let state = unsafe {
+            qt_binding_validator_validate(
+                self.ptr,
+                input.as_ptr() as *const c_char,
+                input.len() as u32,
+            )
+        };
+        State::from_c_int(state)
+    }
+
+    /// Fix-up an input
+    ///
+    /// This calls the fix-up function through `QValidator::fixup`.
+    pub fn fixup_input(&self, input: &str) -> String {
+        ffi_span!("Validator", "fixup_input");
+        let mut value = String::new();
+        unsafe {
+            let data: *mut String = &mut value;
+            qt_binding_validator_fixup(
+                self.ptr,
+                input.as_ptr() as *const c_char,
+                input.len() as u32,
+                data as *mut c_void,
+                rs_string_fill,
+            )
+        };
+        value
+    }
+}
+
+impl From<&'_ Validator> for Variant {
+    fn from(validator: &Validator) -> Self {
+        ffi_span!("Variant", "from_validator");
+        Variant::from_ptr(unsafe { qt_binding_validator_to_variant(validator.ptr) })
+    }
+}
+
+impl Object for Validator {
+    fn as_object_ptr(&self) -> *mut c_void {
+        self.ptr
+    }
+}
+
+impl Drop for Validator {
+    fn drop(&mut self) {
+        ffi_span!("Validator", "drop");
+        if app::should_leak("Validator") {
+            return;
+        }
+        track_destroyed!(Validator);
+        unsafe { qt_binding_validator_delete(self.ptr) }
+    }
+}
+
+unsafe fn to_str<'a>(input: *const c_char, input_size: u32) -> &'a str {
+    from_utf8_unchecked(from_raw_parts(input as *const u8, input_size as usize))
+}
+
+extern "C" fn rs_validator_validate(data: *mut c_void, input: *const c_char, size: u32) -> c_int {
+    let callbacks = unsafe { &mut *(data as *mut Callbacks) };
+    let input = unsafe { to_str(input, size) };
+    (callbacks.validate)(input).as_c_int()
+}
+
+extern "C" fn rs_validator_fixup(
+    data: *mut c_void,
+    input: *const c_char,
+    size: u32,
+    output: *mut c_void,
+    fill: CStringFillFunc,
+) {
+    let callbacks = unsafe { &mut *(data as *mut Callbacks) };
+    let input = unsafe { to_str(input, size) };
+    if let Some(fixed) = callbacks.fixup.as_mut().and_then(|fixup| fixup(input)) {
+        fill(output, fixed.as_ptr() as *const c_char, fixed.len() as u32);
+    }
+}
+
+type CStringFillFunc = extern "C" fn(output: *mut c_void, input: *const c_char, input_size: u32);
+type RsValidateFunc = extern "C" fn(data: *mut c_void, input: *const c_char, size: u32) -> c_int;
+type RsFixupFunc = extern "C" fn(
+    data: *mut c_void,
+    input: *const c_char,
+    size: u32,
+    output: *mut c_void,
+    fill: CStringFillFunc,
+);
+
+extern "C" {
+    fn qt_binding_validator_create(
+        data: *mut c_void,
+        validate: RsValidateFunc,
+        fixup: RsFixupFunc,
+    ) -> *mut c_void;
+    fn qt_binding_validator_delete(validator: *mut c_void);
+    fn qt_binding_validator_to_variant(validator: *mut c_void) -> *mut c_void;
+
+    fn qt_binding_validator_validate(
+        validator: *const c_void,
+        input: *const c_char,
+        size: u32,
+    ) -> c_int;
+    fn qt_binding_validator_fixup(
+        validator: *const c_void,
+        input: *const c_char,
+        size: u32,
+        output: *mut c_void,
+        fill: RsStringFillFunc,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validate_and_fixup() {
+        let mut validator = Validator::new(|input| match input.len() {
+            0..=2 => State::Intermediate,
+            3 => State::Acceptable,
+            _ => State::Invalid,
+        });
+
+        assert_eq!(validator.validate("ab"), State::Intermediate);
+        assert_eq!(validator.validate("abc"), State::Acceptable);
+        assert_eq!(validator.validate("abcd"), State::Invalid);
+        assert_eq!(validator.fixup_input("abcd"), "abcd");
+
+        validator.fixup(|input| Some(input.chars().take(3).collect()));
+        assert_eq!(validator.fixup_input("abcd"), "abc");
+    }
+}
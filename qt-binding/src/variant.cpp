@@ -1,5 +1,7 @@
 #include <QtCore/QVariant>
 
+QT_USE_NAMESPACE
+
 extern "C" {
 
 QVariant *qt_binding_variant_create_invalid()
@@ -11,12 +11,28 @@
 //! `QVariantList` or a `QVariantMap` depending on the items contained in the iterator.
 //! Below examples shows how to use `Variant` as a target for collect.
 //!
+//! Lists of `(x, y)` points are converted from and to a `QVariantList` of `QPointF` with
+//! [`from_points`] and [`to_points`], in a single call to Qt. This is much faster than
+//! collecting a `Variant` per point.
+//!
+//! `Variant` can't be created outside of the Qt thread. Values prepared in worker threads are
+//! built as a [`VariantValue`], that is `Send`, and converted to a `Variant` on the Qt thread.
+//!
+//! Qt objects are stored in a `Variant` with [`from_object`], and retrieved as an
+//! [`ObjectHandle`] with [`try_to_object`].
+//!
 //! `Variant` can be converted back to the type it contains with the [`TryFrom`] trait, as type
 //! conversion might fails. `QVariant::canConvert` is used to check if the conversion can be done.
 //! If not, a [`TryFromError`] will be raised.
 //!
 //! [`Variant`]: struct.Variant.html
 //! [`TryFromError`]: struct.TryFromError.html
+//! [`VariantValue`]: enum.VariantValue.html
+//! [`from_points`]: struct.Variant.html#method.from_points
+//! [`to_points`]: struct.Variant.html#method.to_points
+//! [`from_object`]: struct.Variant.html#method.from_object
+//! [`try_to_object`]: struct.Variant.html#method.try_to_object
+//! [`ObjectHandle`]: ../object/struct.ObjectHandle.html
 //! [`From`]: https://doc.rust-lang.org/std/convert/trait.From.html
 //! [`TryFrom`]: https://doc.rust-lang.org/std/convert/trait.TryFrom.html
 //!
@@ -24,6 +40,11 @@
 //!
 //! Since `Variant` is a binding over `QVariant`, it is neither `Send` nor `Sync`.
 //!
+//! A `Variant` should not outlive the [`Application`]. A `Variant` dropped after the
+//! `Application` has been destroyed is leaked.
+//!
+//! [`Application`]: ../app/struct.Application.html
+//!
 //! # Examples
 //!
 //! Converting a primitive type to a `Variant`
@@ -80,12 +101,17 @@
 //! assert_eq!(variant_list, expected_variant_list);
 //! ```
 
+#[cfg(not(feature = "mock"))]
+use crate::app;
 use std::convert::TryFrom;
 use std::ffi::CStr;
 use std::fmt;
 use std::os::raw::{c_char, c_void};
 
-mod convert;
+pub(crate) mod convert;
+mod value;
+
+pub use self::value::VariantValue;
 
 /// Error returned when conversion fails
 ///
@@ -100,28 +126,34 @@ pub struct Variant {
     ptr: *mut c_void,
 }
 
+impl Variant {
+    pub(crate) fn from_ptr(ptr: *mut c_void) -> Self {
+        track_created!(Variant);
+        Variant { ptr }
+    }
+
+    pub(crate) fn as_ptr(&self) -> *const c_void {
+        self.ptr
+    }
+}
+
 impl Default for Variant {
     fn default() -> Self {
-        unsafe {
-            Variant {
-                ptr: qt_binding_variant_create_invalid(),
-            }
-        }
+        ffi_span!("Variant", "default");
+        unsafe { Variant::from_ptr(qt_binding_variant_create_invalid()) }
     }
 }
 
 impl Clone for Variant {
     fn clone(&self) -> Self {
-        unsafe {
-            Variant {
-                ptr: qt_binding_variant_clone(self.ptr),
-            }
-        }
+        ffi_span!("Variant", "clone");
+        unsafe { Variant::from_ptr(qt_binding_variant_clone(self.ptr)) }
     }
 }
 
 impl PartialEq for Variant {
     fn eq(&self, other: &Variant) -> bool {
+        ffi_span!("Variant", "eq");
         unsafe { qt_binding_variant_compare(self.ptr, other.ptr) }
     }
 }
@@ -130,6 +162,7 @@ impl Eq for Variant {}
 
 impl fmt::Debug for Variant {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        ffi_span!("Variant", "fmt");
         let ty = unsafe {
             let ty = qt_binding_variant_get_type_name(self.ptr);
             CStr::from_ptr(ty)
@@ -143,6 +176,12 @@ impl fmt::Debug for Variant {
 
 impl Drop for Variant {
     fn drop(&mut self) {
+        ffi_span!("Variant", "drop");
+        #[cfg(not(feature = "mock"))]
+        if app::should_leak("Variant") {
+            return;
+        }
+        track_destroyed!(Variant);
         unsafe {
             qt_binding_variant_delete(self.ptr);
         }
@@ -1,6 +1,11 @@
+#include "points.h"
+#include <QtCore/QPointF>
 #include <QtCore/QVariant>
+#include <QtCore/QVector>
 #include <cstdint>
 
+QT_USE_NAMESPACE
+
 using CListAppendFunc = void (*)(void *output, const void *variant);
 using CListFillFunc = void (*)(void *input, void *output, CListAppendFunc append);
 using RsStringFillFunc = void (*)(void *output, const char *input, std::uint32_t inputSize);
@@ -72,6 +77,16 @@ QVariant *qt_binding_variant_create_list(void *input, CListFillFunc fill)
     return new QVariant(list);
 }
 
+QVariant *qt_binding_variant_create_points(const double *points, std::uint32_t count)
+{
+    auto list = QVariantList();
+    list.reserve(static_cast<int>(count));
+    for (std::uint32_t i = 0; i < count; ++i) {
+        list.append(QPointF(points[2 * i], points[2 * i + 1]));
+    }
+    return new QVariant(list);
+}
+
 bool qt_binding_variant_fill_bool(const QVariant *variant, bool *value)
 {
     return primitiveConvertTo(*variant, *value);
@@ -133,4 +148,28 @@ bool qt_binding_variant_fill_list(const QVariant *variant, void *output, RsListF
     return true;
 }
 
+bool qt_binding_variant_fill_points(const QVariant *variant, void *output, RsPointsFillFunc fill)
+{
+    if (variant->userType() == qMetaTypeId<QVector<QPointF>>()) {
+        qt_binding::fillPoints(variant->value<QVector<QPointF>>(), output, fill);
+        return true;
+    }
+
+    if (!variant->canConvert<QVariantList>()) {
+        return false;
+    }
+
+    const auto values = variant->value<QVariantList>();
+    auto points = QVector<QPointF>();
+    points.reserve(values.count());
+    for (const auto &value : values) {
+        if (!value.canConvert<QPointF>()) {
+            return false;
+        }
+        points.append(value.toPointF());
+    }
+    qt_binding::fillPoints(points, output, fill);
+    return true;
+}
+
 } // extern "C"
@@ -8,9 +8,8 @@ macro_rules! gen_from_primitive {
     ($ty:ty => $f:ident) => {
         impl From<$ty> for Variant {
             fn from(value: $ty) -> Self {
-                Variant {
-                    ptr: unsafe { $f(value) },
-                }
+                ffi_span!("Variant", concat!("from_", stringify!($ty)));
+                Variant::from_ptr(unsafe { $f(value) })
             }
         }
     };
@@ -30,6 +29,7 @@ macro_rules! gen_into_primitive {
             type Error = TryFromError;
 
             fn try_from(variant: Variant) -> Result<Self, Self::Error> {
+                ffi_span!("Variant", concat!("into_", stringify!($ty)));
                 let mut value = <$ty>::default();
                 if unsafe { $f(variant.ptr, &mut value) } {
                     Ok(value)
@@ -43,6 +43,7 @@ macro_rules! gen_into_primitive {
             type Error = TryFromError;
 
             fn try_from(variant: &Variant) -> Result<Self, Self::Error> {
+                ffi_span!("Variant", concat!("into_", stringify!($ty)));
                 let mut value = <$ty>::default();
                 if unsafe { $f(variant.ptr, &mut value) } {
                     Ok(value)
@@ -64,26 +65,26 @@ gen_into_primitive!(f64 => qt_binding_variant_fill_f64);
 
 impl From<&'_ str> for Variant {
     fn from(value: &str) -> Self {
+        ffi_span!("Variant", "from_str");
         let array = Vec::<u8>::from(value);
         let array: &[_] = &array;
-        Variant {
-            ptr: unsafe {
-                qt_binding_variant_create_string(
-                    array.as_ptr() as *const c_char,
-                    array.len() as u32,
-                )
-            },
-        }
+        Variant::from_ptr(unsafe {
+            qt_binding_variant_create_string(array.as_ptr() as *const c_char, array.len() as u32)
+        })
     }
 }
 
 impl From<String> for Variant {
     fn from(value: String) -> Self {
-        From::from(value.as_ref())
+        From::from(value.as_str())
     }
 }
 
-extern "C" fn rs_string_fill(output: *mut c_void, input: *const c_char, input_size: u32) {
+pub(crate) extern "C" fn rs_string_fill(
+    output: *mut c_void,
+    input: *const c_char,
+    input_size: u32,
+) {
     unsafe {
         let input = from_raw_parts(input as *const u8, input_size as usize);
         let output = &mut *(output as *mut String);
@@ -103,6 +104,7 @@ impl TryFrom<&'_ Variant> for String {
     type Error = TryFromError;
 
     fn try_from(variant: &Variant) -> Result<Self, Self::Error> {
+        ffi_span!("Variant", "into_string");
         let mut value = String::default();
         if unsafe {
             let data: *mut String = &mut value;
@@ -130,26 +132,28 @@ impl<'a> FromIterator<&'a Variant> for Variant {
         T: IntoIterator<Item = &'a Variant>,
         T::IntoIter: Sized,
     {
+        ffi_span!("Variant", "from_iter");
         let mut iter = iter.into_iter();
         let mut input: VariantIteratorRef = Box::new(&mut iter);
 
         let input: *mut VariantIteratorRef = &mut input;
 
-        Variant {
-            ptr: unsafe { qt_binding_variant_create_list(input as *mut c_void, c_list_fill) },
-        }
+        Variant::from_ptr(unsafe {
+            qt_binding_variant_create_list(input as *mut c_void, c_list_fill)
+        })
     }
 }
 
 extern "C" fn rs_list_fill(output: *mut c_void, input: *mut c_void) {
     let output = unsafe { &mut *(output as *mut Vec<Variant>) };
-    output.push(Variant { ptr: input });
+    output.push(Variant::from_ptr(input));
 }
 
 impl TryFrom<&'_ Variant> for Vec<Variant> {
     type Error = TryFromError;
 
     fn try_from(variant: &Variant) -> Result<Self, Self::Error> {
+        ffi_span!("Variant", "into_list");
         let mut value = Vec::default();
         if unsafe {
             let data: *mut Vec<Variant> = &mut value;
@@ -176,12 +180,67 @@ impl From<Vec<Variant>> for Variant {
     }
 }
 
+impl Variant {
+    /// Creates a `Variant` containing a list of points
+    ///
+    /// Each `(x, y)` point is stored as a `QPointF`, in a `QVariantList`. The list is created
+    /// in a single call to Qt.
+    pub fn from_points(points: &[(f64, f64)]) -> Self {
+        ffi_span!("Variant", "from_points");
+        let points = flatten_points(points);
+        Variant::from_ptr(unsafe {
+            qt_binding_variant_create_points(points.as_ptr(), (points.len() / 2) as u32)
+        })
+    }
+
+    /// Converts a `Variant` containing a list of points
+    ///
+    /// The `Variant` should contain a `QVariantList` of values convertible to `QPointF`, or a
+    /// `QVector<QPointF>`. The points are read in a single call to Qt.
+    pub fn to_points(&self) -> Result<Vec<(f64, f64)>, TryFromError> {
+        ffi_span!("Variant", "to_points");
+        let mut value = Vec::default();
+        if unsafe {
+            let data: *mut Vec<(f64, f64)> = &mut value;
+            qt_binding_variant_fill_points(self.ptr, data as *mut c_void, rs_points_fill)
+        } {
+            Ok(value)
+        } else {
+            Err(TryFromError)
+        }
+    }
+}
+
+pub(crate) fn flatten_points(points: &[(f64, f64)]) -> Vec<f64> {
+    let mut values = Vec::with_capacity(2 * points.len());
+    for &(x, y) in points {
+        values.push(x);
+        values.push(y);
+    }
+    values
+}
+
+pub(crate) extern "C" fn rs_points_fill(output: *mut c_void, input: *const f64, count: u32) {
+    // An empty std::vector might have a null data pointer
+    if count == 0 {
+        return;
+    }
+    unsafe {
+        let input = from_raw_parts(input, 2 * count as usize);
+        let output = &mut *(output as *mut Vec<(f64, f64)>);
+        output.extend(input.chunks(2).map(|point| (point[0], point[1])));
+    }
+}
+
 type CListAppendFunc = extern "C" fn(output: *mut c_void, variant: *const c_void);
 type CListFillFunc =
     extern "C" fn(input: *mut c_void, output: *mut c_void, append: CListAppendFunc);
 
-type RsStringFillFunc = extern "C" fn(output: *mut c_void, input: *const c_char, input_size: u32);
+pub(crate) type RsStringFillFunc =
+    extern "C" fn(output: *mut c_void, input: *const c_char, input_size: u32);
 type RsListFillFunc = extern "C" fn(output: *mut c_void, input: *mut c_void);
+pub(crate) type RsPointsFillFunc =
+    extern "C" fn(output: *mut c_void, input: *const f64, count: u32);
 
 extern "C" {
     fn qt_binding_variant_create_bool(value: bool) -> *mut c_void;
@@ -193,6 +252,7 @@ extern "C" {
     fn qt_binding_variant_create_f64(value: f64) -> *mut c_void;
     fn qt_binding_variant_create_string(value: *const c_char, size: u32) -> *mut c_void;
     fn qt_binding_variant_create_list(input: *mut c_void, fill: CListFillFunc) -> *mut c_void;
+    fn qt_binding_variant_create_points(points: *const f64, count: u32) -> *mut c_void;
 
     fn qt_binding_variant_fill_bool(variant: *const c_void, value: *mut bool) -> bool;
     fn qt_binding_variant_fill_i32(variant: *const c_void, value: *mut i32) -> bool;
@@ -211,6 +271,11 @@ extern "C" {
         output: *mut c_void,
         fill: RsListFillFunc,
     ) -> bool;
+    fn qt_binding_variant_fill_points(
+        variant: *const c_void,
+        output: *mut c_void,
+        fill: RsPointsFillFunc,
+    ) -> bool;
 }
 
 #[cfg(test)]
@@ -403,4 +468,19 @@ mod tests {
 
         assert_eq!(value, expected);
     }
+    #[test]
+    fn convert_points() {
+        let expected = vec![(0., 1.5), (1., -2.25), (2., 1e10)];
+
+        let variant = Variant::from_points(&expected);
+        let value = variant.to_points().unwrap();
+
+        assert_eq!(value, expected);
+
+        let variant = Variant::from_points(&[]);
+        assert_eq!(variant.to_points(), Ok(Vec::new()));
+
+        let variant = [Variant::from(1)].iter().collect::<Variant>();
+        assert!(variant.to_points().is_err());
+    }
 }
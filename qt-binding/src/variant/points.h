@@ -0,0 +1,28 @@
+#ifndef QT_BINDING_VARIANT_POINTS_H
+#define QT_BINDING_VARIANT_POINTS_H
+
+#include <QtCore/QPointF>
+#include <QtCore/QVector>
+#include <cstdint>
+#include <vector>
+
+QT_USE_NAMESPACE
+
+using RsPointsFillFunc = void (*)(void *output, const double *points, std::uint32_t count);
+
+namespace qt_binding {
+
+inline void fillPoints(const QVector<QPointF> &points, void *output, RsPointsFillFunc fill)
+{
+    auto values = std::vector<double>();
+    values.reserve(static_cast<std::size_t>(points.count()) * 2);
+    for (const auto &point : points) {
+        values.push_back(point.x());
+        values.push_back(point.y());
+    }
+    fill(output, values.data(), static_cast<std::uint32_t>(points.count()));
+}
+
+} // namespace qt_binding
+
+#endif // QT_BINDING_VARIANT_POINTS_H
@@ -0,0 +1,146 @@
+use crate::variant::Variant;
+
+/// A value that can be converted to a [`Variant`], built in any thread
+///
+/// [`Variant`] is neither `Send` nor `Sync`, and can't be created in worker threads.
+/// `VariantValue` is a plain Rust value, mirroring the types supported by `Variant`, that is
+/// `Send`. It is prepared in a worker thread, sent to the Qt thread, and converted to a
+/// `Variant` there, with the [`From`] conversion trait.
+///
+/// [`Application::deliver`] sends a `VariantValue` to the Qt event-loop, and calls a closure
+/// with the converted `Variant`.
+///
+/// [`Variant`]: struct.Variant.html
+/// [`From`]: https://doc.rust-lang.org/std/convert/trait.From.html
+/// [`Application::deliver`]: ../app/struct.Application.html#method.deliver
+///
+/// # Examples
+///
+/// ```
+/// use qt_binding::variant::{Variant, VariantValue};
+/// use std::thread;
+///
+/// let value = thread::spawn(|| {
+///     VariantValue::List(vec![VariantValue::from("count"), VariantValue::from(3)])
+/// })
+/// .join()
+/// .unwrap();
+///
+/// let variant = Variant::from(value);
+/// assert_eq!(
+///     variant,
+///     Variant::from(vec![Variant::from("count"), Variant::from(3)])
+/// );
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum VariantValue {
+    /// An invalid `Variant`
+    #[default]
+    Invalid,
+    /// A boolean
+    Bool(bool),
+    /// A 32-bit signed integer
+    I32(i32),
+    /// A 32-bit unsigned integer
+    U32(u32),
+    /// A 64-bit signed integer
+    I64(i64),
+    /// A 64-bit unsigned integer
+    U64(u64),
+    /// A 32-bit floating point number
+    F32(f32),
+    /// A 64-bit floating point number
+    F64(f64),
+    /// A string
+    String(String),
+    /// A list of values, converted to a `QVariantList`
+    List(Vec<VariantValue>),
+    /// A list of points, converted like [`Variant::from_points`]
+    ///
+    /// [`Variant::from_points`]: struct.Variant.html#method.from_points
+    Points(Vec<(f64, f64)>),
+}
+
+macro_rules! gen_from_value {
+    ($ty:ty => $variant:ident) => {
+        impl From<$ty> for VariantValue {
+            fn from(value: $ty) -> Self {
+                VariantValue::$variant(value)
+            }
+        }
+    };
+}
+
+gen_from_value!(bool => Bool);
+gen_from_value!(i32 => I32);
+gen_from_value!(u32 => U32);
+gen_from_value!(i64 => I64);
+gen_from_value!(u64 => U64);
+gen_from_value!(f32 => F32);
+gen_from_value!(f64 => F64);
+gen_from_value!(String => String);
+gen_from_value!(Vec<VariantValue> => List);
+
+impl From<&'_ str> for VariantValue {
+    fn from(value: &str) -> Self {
+        VariantValue::String(value.to_string())
+    }
+}
+
+impl From<&'_ VariantValue> for Variant {
+    fn from(value: &VariantValue) -> Self {
+        match value {
+            VariantValue::Invalid => Variant::default(),
+            VariantValue::Bool(value) => Variant::from(*value),
+            VariantValue::I32(value) => Variant::from(*value),
+            VariantValue::U32(value) => Variant::from(*value),
+            VariantValue::I64(value) => Variant::from(*value),
+            VariantValue::U64(value) => Variant::from(*value),
+            VariantValue::F32(value) => Variant::from(*value),
+            VariantValue::F64(value) => Variant::from(*value),
+            VariantValue::String(value) => Variant::from(value.as_str()),
+            VariantValue::List(values) => {
+                values.iter().map(Variant::from).collect::<Vec<_>>().into()
+            }
+            VariantValue::Points(points) => Variant::from_points(points),
+        }
+    }
+}
+
+impl From<VariantValue> for Variant {
+    fn from(value: VariantValue) -> Self {
+        Variant::from(&value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::convert::TryFrom;
+
+    fn assert_send<T: Send>() {}
+
+    #[test]
+    fn test_convert() {
+        assert_send::<VariantValue>();
+
+        assert_eq!(Variant::from(VariantValue::default()), Variant::default());
+        assert_eq!(Variant::from(VariantValue::from(12)), Variant::from(12));
+        assert_eq!(
+            String::try_from(Variant::from(VariantValue::from("hello"))),
+            Ok("hello".to_string())
+        );
+
+        let points = vec![(1., 2.), (3., 4.)];
+        let variant = Variant::from(VariantValue::Points(points.clone()));
+        assert_eq!(variant.to_points(), Ok(points));
+
+        let variant = Variant::from(VariantValue::List(vec![
+            VariantValue::from(true),
+            VariantValue::List(vec![VariantValue::from(1.5)]),
+        ]));
+        let list = Vec::<Variant>::try_from(variant).unwrap();
+        assert_eq!(list[0], Variant::from(true));
+        assert_eq!(list[1], Variant::from(vec![Variant::from(1.5)]));
+    }
+}
@@ -0,0 +1,175 @@
+#include <QtNetwork/QSslCertificate>
+#include <QtNetwork/QSslConfiguration>
+#include <QtNetwork/QSslKey>
+#include <QtWebSockets/QWebSocket>
+#include <QtWebSockets/QWebSocketServer>
+#include <cstdint>
+
+QT_USE_NAMESPACE
+
+using RsStringFillFunc = void (*)(void *output, const char *input, std::uint32_t inputSize);
+using RsSignalFunc = void (*)(void *data);
+using RsMessageFunc = void (*)(void *data, const char *input, std::uint32_t size);
+using RsNewConnectionFunc = void (*)(void *data, QWebSocket *socket);
+
+static void sendMessage(void *data, RsMessageFunc func, const QByteArray &byteArray)
+{
+    func(data, byteArray.constData(), static_cast<std::uint32_t>(byteArray.size()));
+}
+
+extern "C" {
+
+QWebSocket *qt_binding_web_socket_create()
+{
+    return new QWebSocket();
+}
+
+void qt_binding_web_socket_delete(QWebSocket *socket)
+{
+    // The socket might be deleted from one of its own signals, so handlers are disconnected
+    // right away, but the socket is only deleted once control returns to the event-loop
+    socket->disconnect();
+    socket->abort();
+    socket->deleteLater();
+}
+
+void qt_binding_web_socket_connect(QWebSocket *socket, void *data, RsSignalFunc connected,
+                                   RsSignalFunc disconnected, RsMessageFunc textMessage,
+                                   RsMessageFunc binaryMessage, RsMessageFunc error)
+{
+    QObject::connect(socket, &QWebSocket::connected, [data, connected]() { connected(data); });
+    QObject::connect(socket, &QWebSocket::disconnected,
+                     [data, disconnected]() { disconnected(data); });
+    QObject::connect(socket, &QWebSocket::textMessageReceived,
+                     [data, textMessage](const QString &message) {
+                         sendMessage(data, textMessage, message.toUtf8());
+                     });
+    QObject::connect(socket, &QWebSocket::binaryMessageReceived,
+                     [data, binaryMessage](const QByteArray &message) {
+                         sendMessage(data, binaryMessage, message);
+                     });
+    QObject::connect(
+        socket, static_cast<void (QWebSocket::*)(QAbstractSocket::SocketError)>(&QWebSocket::error),
+        [socket, data, error](QAbstractSocket::SocketError) {
+            sendMessage(data, error, socket->errorString().toUtf8());
+        });
+    QObject::connect(socket, &QWebSocket::sslErrors,
+                     [data, error](const QList<QSslError> &errors) {
+                         for (const auto &sslError : errors) {
+                             sendMessage(data, error, sslError.errorString().toUtf8());
+                         }
+                     });
+}
+
+void qt_binding_web_socket_open(QWebSocket *socket, const char *url, std::uint32_t size)
+{
+    socket->open(QUrl(QString::fromUtf8(url, static_cast<int>(size))));
+}
+
+void qt_binding_web_socket_close(QWebSocket *socket)
+{
+    socket->close();
+}
+
+bool qt_binding_web_socket_is_connected(const QWebSocket *socket)
+{
+    return socket->state() == QAbstractSocket::ConnectedState;
+}
+
+void qt_binding_web_socket_ignore_ssl_errors(QWebSocket *socket)
+{
+    QObject::connect(socket, &QWebSocket::sslErrors, socket,
+                     [socket](const QList<QSslError> &) { socket->ignoreSslErrors(); });
+}
+
+std::int64_t qt_binding_web_socket_send_text(QWebSocket *socket, const char *message,
+                                             std::uint32_t size)
+{
+    return socket->sendTextMessage(QString::fromUtf8(message, static_cast<int>(size)));
+}
+
+std::int64_t qt_binding_web_socket_send_binary(QWebSocket *socket, const char *message,
+                                               std::uint32_t size)
+{
+    return socket->sendBinaryMessage(QByteArray(message, static_cast<int>(size)));
+}
+
+QWebSocketServer *qt_binding_web_socket_server_create(const char *name, std::uint32_t size)
+{
+    return new QWebSocketServer(QString::fromUtf8(name, static_cast<int>(size)),
+                                QWebSocketServer::NonSecureMode);
+}
+
+QWebSocketServer *qt_binding_web_socket_server_create_secure(const char *name,
+                                                             std::uint32_t nameSize,
+                                                             const char *certificate,
+                                                             std::uint32_t certificateSize,
+                                                             const char *key,
+                                                             std::uint32_t keySize)
+{
+    const auto sslCertificate =
+        QSslCertificate(QByteArray(certificate, static_cast<int>(certificateSize)), QSsl::Pem);
+    const auto keyData = QByteArray(key, static_cast<int>(keySize));
+    auto sslKey = QSslKey(keyData, QSsl::Rsa, QSsl::Pem);
+    if (sslKey.isNull()) {
+        sslKey = QSslKey(keyData, QSsl::Ec, QSsl::Pem);
+    }
+    if (sslCertificate.isNull() || sslKey.isNull()) {
+        return nullptr;
+    }
+
+    auto sslConfiguration = QSslConfiguration::defaultConfiguration();
+    sslConfiguration.setLocalCertificate(sslCertificate);
+    sslConfiguration.setPrivateKey(sslKey);
+
+    auto server = new QWebSocketServer(QString::fromUtf8(name, static_cast<int>(nameSize)),
+                                       QWebSocketServer::SecureMode);
+    server->setSslConfiguration(sslConfiguration);
+    return server;
+}
+
+void qt_binding_web_socket_server_delete(QWebSocketServer *server)
+{
+    delete server;
+}
+
+void qt_binding_web_socket_server_connect(QWebSocketServer *server, void *data,
+                                          RsNewConnectionFunc newConnection)
+{
+    QObject::connect(server, &QWebSocketServer::newConnection, [server, data, newConnection]() {
+        while (auto socket = server->nextPendingConnection()) {
+            // Sockets are owned by Rust, and should not be deleted with the server
+            socket->setParent(nullptr);
+            newConnection(data, socket);
+        }
+    });
+}
+
+bool qt_binding_web_socket_server_listen(QWebSocketServer *server, std::uint16_t port)
+{
+    return server->listen(QHostAddress::Any, port);
+}
+
+bool qt_binding_web_socket_server_is_listening(const QWebSocketServer *server)
+{
+    return server->isListening();
+}
+
+std::uint16_t qt_binding_web_socket_server_port(const QWebSocketServer *server)
+{
+    return server->serverPort();
+}
+
+void qt_binding_web_socket_server_close(QWebSocketServer *server)
+{
+    server->close();
+}
+
+void qt_binding_web_socket_server_error_string(const QWebSocketServer *server, void *output,
+                                               RsStringFillFunc fill)
+{
+    const auto byteArray = server->errorString().toUtf8();
+    fill(output, byteArray.constData(), static_cast<std::uint32_t>(byteArray.size()));
+}
+
+} // extern "C"
@@ -0,0 +1,545 @@
+//! Bindings to Qt WebSockets
+//!
+//! [`WebSocket`] is a binding to `QWebSocket`, a WebSocket client, and [`WebSocketServer`] is a
+//! binding to `QWebSocketServer`. Sending and receiving is asynchronous and integrated with
+//! Qt's event-loop: messages are sent while the event-loop runs, and received messages are
+//! passed to handlers.
+//!
+//! Secure connections use Qt's TLS backend and configuration. Clients connect securely to
+//! `wss://` urls, and servers are made secure with [`WebSocketServer::new_secure`]. Just like
+//! other Qt networking classes, clients use the application proxy.
+//!
+//! [`WebSocket`]: struct.WebSocket.html
+//! [`WebSocketServer`]: struct.WebSocketServer.html
+//! [`WebSocketServer::new_secure`]: struct.WebSocketServer.html#method.new_secure
+//!
+//! # Features
+//!
+//! This module is only available with the `websockets` feature.
+//!
+//! # Examples
+//!
+//! A client
+//!
+//! ```no_run
+//! use qt_binding::app::Application;
+//! use qt_binding::websocket::WebSocket;
+//!
+//! let mut app = Application::new();
+//!
+//! let mut socket = WebSocket::new();
+//! socket.on_text_message(|message| println!("Received {}", message));
+//! socket.on_error(|error| eprintln!("WebSocket error: {}", error));
+//! socket.open("wss://echo.example.com");
+//!
+//! app.exec();
+//! ```
+//!
+//! An echo server
+//!
+//! ```no_run
+//! use qt_binding::app::Application;
+//! use qt_binding::websocket::WebSocketServer;
+//!
+//! let mut app = Application::new();
+//!
+//! let mut clients = Vec::new();
+//! let mut server = WebSocketServer::new("echo");
+//! server.on_new_connection(move |mut socket| {
+//!     socket.on_text_message(|message| println!("Received {}", message));
+//!     clients.push(socket);
+//! });
+//! server.listen(8080).unwrap();
+//!
+//! app.exec();
+//! ```
+
+use crate::app;
+use crate::variant::convert::{rs_string_fill, RsStringFillFunc};
+use std::error;
+use std::fmt;
+use std::os::raw::{c_char, c_void};
+use std::slice;
+
+/// Error reported by a WebSocket or a WebSocket server
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Error {
+    message: String,
+}
+
+impl Error {
+    /// Error message
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl error::Error for Error {}
+
+type SignalHandler = Option<Box<dyn FnMut()>>;
+type TextMessageHandler = Option<Box<dyn FnMut(&str)>>;
+type BinaryMessageHandler = Option<Box<dyn FnMut(&[u8])>>;
+type ErrorHandler = Option<Box<dyn FnMut(Error)>>;
+
+#[derive(Default)]
+struct Handlers {
+    connected: SignalHandler,
+    disconnected: SignalHandler,
+    text_message: TextMessageHandler,
+    binary_message: BinaryMessageHandler,
+    error: ErrorHandler,
+}
+
+type NewConnectionHandler = Option<Box<dyn FnMut(WebSocket)>>;
+
+/// Binding to `QWebSocket`
+///
+/// See module level documentation for more information.
+pub struct WebSocket {
+    ptr: *mut c_void,
+    handlers: Box<Handlers>,
+}
+
+impl WebSocket {
+    /// Creates a client `WebSocket`
+    ///
+    /// The socket is not connected, see [`open`].
+    ///
+    /// [`open`]: #method.open
+    pub fn new() -> Self {
+        ffi_span!("WebSocket", "new");
+        WebSocket::from_ptr(unsafe { qt_binding_web_socket_create() })
+    }
+
+    fn from_ptr(ptr: *mut c_void) -> Self {
+        let mut handlers = Box::new(Handlers::default());
+        let data: *mut Handlers = &mut *handlers;
+        unsafe {
+            qt_binding_web_socket_connect(
+                ptr,
+                data as *mut c_void,
+                rs_web_socket_connected,
+                rs_web_socket_disconnected,
+                rs_web_socket_text_message,
+                rs_web_socket_binary_message,
+                rs_web_socket_error,
+            )
+        }
+        track_created!(WebSocket);
+        WebSocket { ptr, handlers }
+    }
+
+    /// Connect to a url
+    ///
+    /// The connection is established while the event-loop runs. The handler set with
+    /// [`on_connected`] is called once connected, and the one set with [`on_error`] is called
+    /// if the connection fails.
+    ///
+    /// [`on_connected`]: #method.on_connected
+    /// [`on_error`]: #method.on_error
+    pub fn open<S>(&mut self, url: S)
+    where
+        S: AsRef<str>,
+    {
+        ffi_span!("WebSocket", "open");
+        let url = url.as_ref();
+        unsafe {
+            qt_binding_web_socket_open(self.ptr, url.as_ptr() as *const c_char, url.len() as u32)
+        }
+    }
+
+    /// Close the connection
+    pub fn close(&mut self) {
+        ffi_span!("WebSocket", "close");
+        unsafe { qt_binding_web_socket_close(self.ptr) }
+    }
+
+    /// Returns `true` if the socket is connected
+    pub fn is_connected(&self) -> bool {
+        ffi_span!("WebSocket", "is_connected");
+        unsafe { qt_binding_web_socket_is_connected(self.ptr) }
+    }
+
+    /// Ignore TLS errors
+    ///
+    /// This can be used to connect to servers using self-signed certificates during
+    /// development. Do not use it in production, as it defeats the purpose of TLS.
+    pub fn ignore_ssl_errors(&mut self) -> &mut Self {
+        ffi_span!("WebSocket", "ignore_ssl_errors");
+        unsafe { qt_binding_web_socket_ignore_ssl_errors(self.ptr) }
+        self
+    }
+
+    /// Send a text message
+    ///
+    /// The message is sent while the event-loop runs. Returns the number of bytes buffered.
+    pub fn send_text<S>(&mut self, message: S) -> usize
+    where
+        S: AsRef<str>,
+    {
+        ffi_span!("WebSocket", "send_text");
+        let message = message.as_ref();
+        let sent = unsafe {
+            qt_binding_web_socket_send_text(
+                self.ptr,
+                message.as_ptr() as *const c_char,
+                message.len() as u32,
+            )
+        };
+        sent.max(0) as usize
+    }
+
+    /// Send a binary message
+    ///
+    /// The message is sent while the event-loop runs. Returns the number of bytes buffered.
+    pub fn send_binary(&mut self, message: &[u8]) -> usize {
+        ffi_span!("WebSocket", "send_binary");
+        let sent = unsafe {
+            qt_binding_web_socket_send_binary(
+                self.ptr,
+                message.as_ptr() as *const c_char,
+                message.len() as u32,
+            )
+        };
+        sent.max(0) as usize
+    }
+
+    /// Handle the connection being established
+    ///
+    /// Setting a new handler replaces the previous one.
+    pub fn on_connected<F>(&mut self, handler: F) -> &mut Self
+    where
+        F: FnMut() + 'static,
+    {
+        self.handlers.connected = Some(Box::new(handler));
+        self
+    }
+
+    /// Handle the connection being closed
+    ///
+    /// Setting a new handler replaces the previous one.
+    pub fn on_disconnected<F>(&mut self, handler: F) -> &mut Self
+    where
+        F: FnMut() + 'static,
+    {
+        self.handlers.disconnected = Some(Box::new(handler));
+        self
+    }
+
+    /// Handle received text messages
+    ///
+    /// Setting a new handler replaces the previous one.
+    pub fn on_text_message<F>(&mut self, handler: F) -> &mut Self
+    where
+        F: FnMut(&str) + 'static,
+    {
+        self.handlers.text_message = Some(Box::new(handler));
+        self
+    }
+
+    /// Handle received binary messages
+    ///
+    /// Setting a new handler replaces the previous one.
+    pub fn on_binary_message<F>(&mut self, handler: F) -> &mut Self
+    where
+        F: FnMut(&[u8]) + 'static,
+    {
+        self.handlers.binary_message = Some(Box::new(handler));
+        self
+    }
+
+    /// Handle errors
+    ///
+    /// The handler is called when the connection fails, including TLS errors. Setting a new
+    /// handler replaces the previous one.
+    pub fn on_error<F>(&mut self, handler: F) -> &mut Self
+    where
+        F: FnMut(Error) + 'static,
+    {
+        self.handlers.error = Some(Box::new(handler));
+        self
+    }
+}
+
+impl Default for WebSocket {
+    fn default() -> Self {
+        WebSocket::new()
+    }
+}
+
+impl fmt::Debug for WebSocket {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("WebSocket")
+            .field("is_connected", &self.is_connected())
+            .finish()
+    }
+}
+
+impl Drop for WebSocket {
+    fn drop(&mut self) {
+        ffi_span!("WebSocket", "drop");
+        if app::should_leak("WebSocket") {
+            return;
+        }
+        track_destroyed!(WebSocket);
+        unsafe { qt_binding_web_socket_delete(self.ptr) }
+    }
+}
+
+/// Binding to `QWebSocketServer`
+///
+/// See module level documentation for more information.
+pub struct WebSocketServer {
+    ptr: *mut c_void,
+    new_connection: Box<NewConnectionHandler>,
+}
+
+impl WebSocketServer {
+    /// Creates a `WebSocketServer`
+    ///
+    /// The name is sent to clients during the handshake.
+    pub fn new<S>(name: S) -> Self
+    where
+        S: AsRef<str>,
+    {
+        ffi_span!("WebSocketServer", "new");
+        let name = name.as_ref();
+        let ptr = unsafe {
+            qt_binding_web_socket_server_create(name.as_ptr() as *const c_char, name.len() as u32)
+        };
+        WebSocketServer::from_ptr(ptr)
+    }
+
+    /// Creates a secure `WebSocketServer`
+    ///
+    /// The server uses TLS with a certificate and a private key, both PEM encoded. Returns an
+    /// error if the certificate or the key cannot be read.
+    pub fn new_secure<S>(name: S, certificate: &[u8], key: &[u8]) -> Result<Self, Error>
+    where
+        S: AsRef<str>,
+    {
+        ffi_span!("WebSocketServer", "new_secure");
+        let name = name.as_ref();
+        let ptr = unsafe {
+            qt_binding_web_socket_server_create_secure(
+                name.as_ptr() as *const c_char,
+                name.len() as u32,
+                certificate.as_ptr() as *const c_char,
+                certificate.len() as u32,
+                key.as_ptr() as *const c_char,
+                key.len() as u32,
+            )
+        };
+        if ptr.is_null() {
+            Err(Error {
+                message: "Invalid TLS certificate or key".to_string(),
+            })
+        } else {
+            Ok(WebSocketServer::from_ptr(ptr))
+        }
+    }
+
+    fn from_ptr(ptr: *mut c_void) -> Self {
+        let mut new_connection = Box::new(None);
+        let data: *mut NewConnectionHandler = &mut *new_connection;
+        unsafe {
+            qt_binding_web_socket_server_connect(
+                ptr,
+                data as *mut c_void,
+                rs_web_socket_server_new_connection,
+            )
+        }
+        track_created!(WebSocketServer);
+        WebSocketServer {
+            ptr,
+            new_connection,
+        }
+    }
+
+    /// Listen for connections on a port, on all network interfaces
+    ///
+    /// If the port is 0, a port is chosen automatically, see [`port`].
+    ///
+    /// [`port`]: #method.port
+    pub fn listen(&mut self, port: u16) -> Result<(), Error> {
+        ffi_span!("WebSocketServer", "listen");
+        if unsafe { qt_binding_web_socket_server_listen(self.ptr, port) } {
+            Ok(())
+        } else {
+            let mut message = String::new();
+            unsafe {
+                let data: *mut String = &mut message;
+                qt_binding_web_socket_server_error_string(
+                    self.ptr,
+                    data as *mut c_void,
+                    rs_string_fill,
+                );
+            }
+            Err(Error { message })
+        }
+    }
+
+    /// Port the server is listening on
+    ///
+    /// Returns `None` if the server is not listening.
+    pub fn port(&self) -> Option<u16> {
+        ffi_span!("WebSocketServer", "port");
+        if unsafe { qt_binding_web_socket_server_is_listening(self.ptr) } {
+            Some(unsafe { qt_binding_web_socket_server_port(self.ptr) })
+        } else {
+            None
+        }
+    }
+
+    /// Stop listening for connections
+    ///
+    /// Connected sockets are not closed.
+    pub fn close(&mut self) {
+        ffi_span!("WebSocketServer", "close");
+        unsafe { qt_binding_web_socket_server_close(self.ptr) }
+    }
+
+    /// Handle new connections
+    ///
+    /// The handler is called with the socket of each new client. The connection is closed
+    /// when the socket is dropped. Setting a new handler replaces the previous one.
+    pub fn on_new_connection<F>(&mut self, handler: F) -> &mut Self
+    where
+        F: FnMut(WebSocket) + 'static,
+    {
+        *self.new_connection = Some(Box::new(handler));
+        self
+    }
+}
+
+impl fmt::Debug for WebSocketServer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("WebSocketServer")
+            .field("port", &self.port())
+            .finish()
+    }
+}
+
+impl Drop for WebSocketServer {
+    fn drop(&mut self) {
+        ffi_span!("WebSocketServer", "drop");
+        if app::should_leak("WebSocketServer") {
+            return;
+        }
+        track_destroyed!(WebSocketServer);
+        unsafe { qt_binding_web_socket_server_delete(self.ptr) }
+    }
+}
+
+extern "C" fn rs_web_socket_connected(data: *mut c_void) {
+    let handlers = unsafe { &mut *(data as *mut Handlers) };
+    if let Some(handler) = &mut handlers.connected {
+        handler();
+    }
+}
+
+extern "C" fn rs_web_socket_disconnected(data: *mut c_void) {
+    let handlers = unsafe { &mut *(data as *mut Handlers) };
+    if let Some(handler) = &mut handlers.disconnected {
+        handler();
+    }
+}
+
+extern "C" fn rs_web_socket_text_message(data: *mut c_void, input: *const c_char, size: u32) {
+    let handlers = unsafe { &mut *(data as *mut Handlers) };
+    if let Some(handler) = &mut handlers.text_message {
+        let mut message = String::new();
+        let output: *mut String = &mut message;
+        rs_string_fill(output as *mut c_void, input, size);
+        handler(&message);
+    }
+}
+
+extern "C" fn rs_web_socket_binary_message(data: *mut c_void, input: *const c_char, size: u32) {
+    let handlers = unsafe { &mut *(data as *mut Handlers) };
+    if let Some(handler) = &mut handlers.binary_message {
+        let message = unsafe { slice::from_raw_parts(input as *const u8, size as usize) };
+        handler(message);
+    }
+}
+
+extern "C" fn rs_web_socket_error(data: *mut c_void, input: *const c_char, size: u32) {
+    let handlers = unsafe { &mut *(data as *mut Handlers) };
+    if let Some(handler) = &mut handlers.error {
+        let mut message = String::new();
+        let output: *mut String = &mut message;
+        rs_string_fill(output as *mut c_void, input, size);
+        handler(Error { message });
+    }
+}
+
+extern "C" fn rs_web_socket_server_new_connection(data: *mut c_void, socket: *mut c_void) {
+    let handler = unsafe { &mut *(data as *mut NewConnectionHandler) };
+    let socket = WebSocket::from_ptr(socket);
+    if let Some(handler) = handler {
+        handler(socket);
+    }
+}
+
+type RsSignalFunc = extern "C" fn(data: *mut c_void);
+type RsMessageFunc = extern "C" fn(data: *mut c_void, input: *const c_char, size: u32);
+type RsNewConnectionFunc = extern "C" fn(data: *mut c_void, socket: *mut c_void);
+
+extern "C" {
+    fn qt_binding_web_socket_create() -> *mut c_void;
+    fn qt_binding_web_socket_delete(socket: *mut c_void);
+    fn qt_binding_web_socket_connect(
+        socket: *mut c_void,
+        data: *mut c_void,
+        connected: RsSignalFunc,
+        disconnected: RsSignalFunc,
+        text_message: RsMessageFunc,
+        binary_message: RsMessageFunc,
+        error: RsMessageFunc,
+    );
+    fn qt_binding_web_socket_open(socket: *mut c_void, url: *const c_char, size: u32);
+    fn qt_binding_web_socket_close(socket: *mut c_void);
+    fn qt_binding_web_socket_is_connected(socket: *const c_void) -> bool;
+    fn qt_binding_web_socket_ignore_ssl_errors(socket: *mut c_void);
+    fn qt_binding_web_socket_send_text(
+        socket: *mut c_void,
+        message: *const c_char,
+        size: u32,
+    ) -> i64;
+    fn qt_binding_web_socket_send_binary(
+        socket: *mut c_void,
+        message: *const c_char,
+        size: u32,
+    ) -> i64;
+
+    fn qt_binding_web_socket_server_create(name: *const c_char, size: u32) -> *mut c_void;
+    fn qt_binding_web_socket_server_create_secure(
+        name: *const c_char,
+        name_size: u32,
+        certificate: *const c_char,
+        certificate_size: u32,
+        key: *const c_char,
+        key_size: u32,
+    ) -> *mut c_void;
+    fn qt_binding_web_socket_server_delete(server: *mut c_void);
+    fn qt_binding_web_socket_server_connect(
+        server: *mut c_void,
+        data: *mut c_void,
+        new_connection: RsNewConnectionFunc,
+    );
+    fn qt_binding_web_socket_server_listen(server: *mut c_void, port: u16) -> bool;
+    fn qt_binding_web_socket_server_is_listening(server: *const c_void) -> bool;
+    fn qt_binding_web_socket_server_port(server: *const c_void) -> u16;
+    fn qt_binding_web_socket_server_close(server: *mut c_void);
+    fn qt_binding_web_socket_server_error_string(
+        server: *const c_void,
+        output: *mut c_void,
+        fill: RsStringFillFunc,
+    );
+}
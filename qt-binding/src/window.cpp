@@ -0,0 +1,199 @@
+#include <QtCore/QSettings>
+#include <QtGui/QGuiApplication>
+#include <QtGui/QWindow>
+#include <cstdint>
+#ifdef QT_BINDING_WITH_NATIVE_INTERFACE
+#include <qpa/qplatformnativeinterface.h>
+#endif // QT_BINDING_WITH_NATIVE_INTERFACE
+
+QT_USE_NAMESPACE
+
+using RsStringFillFunc = void (*)(void *output, const char *input, std::uint32_t inputSize);
+using RsWindowAppendFunc = void (*)(void *output, QWindow *window);
+
+namespace {
+
+// Same order as Platform in window.rs
+enum class Platform { Other, Xcb, Wayland, Windows, Cocoa };
+
+Platform platform()
+{
+    const auto name = QGuiApplication::platformName();
+    if (name == QLatin1String("xcb")) {
+        return Platform::Xcb;
+    } else if (name.startsWith(QLatin1String("wayland"))) {
+        return Platform::Wayland;
+    } else if (name == QLatin1String("windows")) {
+        return Platform::Windows;
+    } else if (name == QLatin1String("cocoa")) {
+        return Platform::Cocoa;
+    }
+    return Platform::Other;
+}
+
+// Saves the geometry of a window when it is hidden or closed, and restores it when created
+class GeometryPersistence : public QObject
+{
+public:
+    explicit GeometryPersistence(QWindow *window)
+        : QObject(window)
+        , m_window(window)
+    {
+        m_window->installEventFilter(this);
+        restore();
+    }
+
+    bool eventFilter(QObject *watched, QEvent *event) override
+    {
+        if (event->type() == QEvent::Hide || event->type() == QEvent::Close) {
+            save();
+        }
+        return QObject::eventFilter(watched, event);
+    }
+
+private:
+    QString key(const char *name) const
+    {
+        return QStringLiteral("windows/%1/%2").arg(m_window->objectName(), QLatin1String(name));
+    }
+
+    void restore()
+    {
+        QSettings settings;
+        const auto geometry = settings.value(key("geometry")).toRect();
+        if (geometry.isValid()) {
+            m_window->setGeometry(geometry);
+        }
+        const auto state = static_cast<Qt::WindowState>(
+                settings.value(key("state"), static_cast<int>(Qt::WindowNoState)).toInt());
+        if (state == Qt::WindowMaximized || state == Qt::WindowFullScreen) {
+            m_window->setWindowState(state);
+        }
+    }
+
+    void save()
+    {
+        QSettings settings;
+        const auto state = m_window->windowState();
+        // The geometry of maximized windows is the geometry of the screen, and the geometry
+        // restored when leaving the maximized state is kept instead
+        if (state == Qt::WindowNoState) {
+            settings.setValue(key("geometry"), m_window->geometry());
+        }
+        if (state != Qt::WindowMinimized) {
+            settings.setValue(key("state"), static_cast<int>(state));
+        }
+    }
+
+    QWindow *m_window;
+};
+
+const char *GEOMETRY_PERSISTENCE_PROPERTY = "_qt_binding_geometry_persistence";
+
+} // namespace
+
+extern "C" {
+
+QWindow *qt_binding_window_create()
+{
+    return new QWindow();
+}
+
+void qt_binding_window_delete(QWindow *window)
+{
+    delete window;
+}
+
+void qt_binding_window_children(QObject *parent, void *output, RsWindowAppendFunc append)
+{
+    for (auto child : parent->children()) {
+        if (auto window = qobject_cast<QWindow *>(child)) {
+            append(output, window);
+        }
+    }
+}
+
+void qt_binding_window_title(const QWindow *window, void *output, RsStringFillFunc fill)
+{
+    const auto byteArray = window->title().toUtf8();
+    fill(output, byteArray.constData(), static_cast<std::uint32_t>(byteArray.size()));
+}
+
+void qt_binding_window_set_title(QWindow *window, const char *title, std::uint32_t size)
+{
+    window->setTitle(QString::fromUtf8(title, static_cast<int>(size)));
+}
+
+void qt_binding_window_size(const QWindow *window, std::uint32_t *width, std::uint32_t *height)
+{
+    *width = static_cast<std::uint32_t>(window->width());
+    *height = static_cast<std::uint32_t>(window->height());
+}
+
+void qt_binding_window_resize(QWindow *window, std::uint32_t width, std::uint32_t height)
+{
+    window->resize(static_cast<int>(width), static_cast<int>(height));
+}
+
+double qt_binding_window_device_pixel_ratio(const QWindow *window)
+{
+    return window->devicePixelRatio();
+}
+
+bool qt_binding_window_is_visible(const QWindow *window)
+{
+    return window->isVisible();
+}
+
+void qt_binding_window_set_visible(QWindow *window, bool visible)
+{
+    window->setVisible(visible);
+}
+
+bool qt_binding_window_is_exposed(const QWindow *window)
+{
+    return window->isExposed();
+}
+
+bool qt_binding_window_persist_geometry(QWindow *window)
+{
+    if (window->objectName().isEmpty()) {
+        return false;
+    }
+    if (!window->property(GEOMETRY_PERSISTENCE_PROPERTY).toBool()) {
+        window->setProperty(GEOMETRY_PERSISTENCE_PROPERTY, true);
+        new GeometryPersistence(window);
+    }
+    return true;
+}
+
+int qt_binding_window_native_handle(QWindow *window, std::uint64_t *id, void **display,
+                                    void **surface)
+{
+    // Creates the native window if needed
+    *id = static_cast<std::uint64_t>(window->winId());
+    *display = nullptr;
+    *surface = nullptr;
+
+    const auto windowPlatform = platform();
+#ifdef QT_BINDING_WITH_NATIVE_INTERFACE
+    auto native = QGuiApplication::platformNativeInterface();
+    switch (windowPlatform) {
+    case Platform::Xcb:
+        *display = native->nativeResourceForWindow(QByteArrayLiteral("display"), window);
+        break;
+    case Platform::Wayland:
+        *display = native->nativeResourceForWindow(QByteArrayLiteral("display"), window);
+        *surface = native->nativeResourceForWindow(QByteArrayLiteral("surface"), window);
+        break;
+    case Platform::Cocoa:
+        *display = native->nativeResourceForWindow(QByteArrayLiteral("nswindow"), window);
+        break;
+    default:
+        break;
+    }
+#endif // QT_BINDING_WITH_NATIVE_INTERFACE
+    return static_cast<int>(windowPlatform);
+}
+
+} // extern "C"
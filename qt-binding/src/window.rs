@@ -0,0 +1,348 @@
+//! Native windows
+//!
+//! [`Window`] is a binding on Qt's `QWindow`. Windows are either created from Rust with
+//! [`Window::new`], or are the windows created by QML files, like an `ApplicationWindow`,
+//! returned by [`Engine::windows`].
+//!
+//! With the `raw-window-handle` feature, `Window` implements [`HasRawWindowHandle`], so that
+//! Rust graphics crates, like `wgpu`, can render into a window created by Qt.
+//!
+//! [`Window`]: struct.Window.html
+//! [`Window::new`]: struct.Window.html#method.new
+//! [`Engine::windows`]: ../qml/struct.Engine.html#method.windows
+//! [`HasRawWindowHandle`]: https://docs.rs/raw-window-handle/0.3/raw_window_handle/trait.HasRawWindowHandle.html
+//!
+//! The geometry and state of windows can be saved across runs with
+//! [`Window::persist_geometry`].
+//!
+//! [`Window::persist_geometry`]: struct.Window.html#method.persist_geometry
+//!
+//! # Features
+//!
+//! This module is only available with the `gui` feature. [`Engine::windows`] also requires
+//! the `qml` feature.
+//!
+//! # Limitations
+//!
+//! Windows must be created after the [`Application`], and dropped before it.
+//!
+//! Raw window handles are supported on X11, Wayland, Windows and macOS. On X11 and Wayland,
+//! they are read with Qt's private platform native interface, so Qt's private `QtGui` headers
+//! must be installed.
+//!
+//! [`Application`]: ../app/struct.Application.html
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use qt_binding::app::Application;
+//! use qt_binding::window::Window;
+//!
+//! let mut app = Application::new();
+//! let mut window = Window::new();
+//! window.set_title("Viewport").resize(800, 600).show();
+//! // Render into the window with a graphics crate supporting raw-window-handle
+//! app.exec();
+//! ```
+
+use crate::app;
+use crate::object::Object;
+use crate::variant::convert::{rs_string_fill, RsStringFillFunc};
+use std::marker::PhantomData;
+use std::os::raw::{c_char, c_void};
+
+/// Binding to `QWindow`
+///
+/// Windows created with [`new`] are owned by the `Window`, and are destroyed when it is
+/// dropped. Other windows are borrowed from their owner, like the QML engine that created
+/// them.
+///
+/// See module level documentation for more information.
+///
+/// [`new`]: #method.new
+pub struct Window<'a> {
+    ptr: *mut c_void,
+    owned: bool,
+    _owner: PhantomData<&'a ()>,
+}
+
+impl Window<'static> {
+    /// Creates a new hidden `Window`
+    pub fn new() -> Self {
+        ffi_span!("Window", "new");
+        track_created!(Window);
+        Window {
+            ptr: unsafe { qt_binding_window_create() },
+            owned: true,
+            _owner: PhantomData,
+        }
+    }
+}
+
+impl<'a> Window<'a> {
+    /// Windows that are children of a Qt object
+    #[cfg(feature = "qml")]
+    pub(crate) fn children(parent: *mut c_void) -> Vec<Window<'a>> {
+        let mut windows = Vec::new();
+        unsafe {
+            let data: *mut Vec<Window<'a>> = &mut windows;
+            qt_binding_window_children(parent, data as *mut c_void, rs_window_append);
+        }
+        windows
+    }
+
+    /// Title of the window
+    pub fn title(&self) -> String {
+        ffi_span!("Window", "title");
+        let mut title = String::new();
+        unsafe {
+            let data: *mut String = &mut title;
+            qt_binding_window_title(self.ptr, data as *mut c_void, rs_string_fill);
+        }
+        title
+    }
+
+    /// Set the title of the window
+    pub fn set_title<S>(&mut self, title: S) -> &mut Self
+    where
+        S: AsRef<str>,
+    {
+        ffi_span!("Window", "set_title");
+        let title = title.as_ref();
+        unsafe {
+            qt_binding_window_set_title(
+                self.ptr,
+                title.as_ptr() as *const c_char,
+                title.len() as u32,
+            )
+        };
+        self
+    }
+
+    /// Size of the window, as width and height, in device independent pixels
+    pub fn size(&self) -> (u32, u32) {
+        ffi_span!("Window", "size");
+        let mut width = 0;
+        let mut height = 0;
+        unsafe { qt_binding_window_size(self.ptr, &mut width, &mut height) };
+        (width, height)
+    }
+
+    /// Resize the window, in device independent pixels
+    pub fn resize(&mut self, width: u32, height: u32) -> &mut Self {
+        ffi_span!("Window", "resize");
+        unsafe { qt_binding_window_resize(self.ptr, width, height) };
+        self
+    }
+
+    /// Ratio between physical pixels and device independent pixels
+    pub fn device_pixel_ratio(&self) -> f64 {
+        ffi_span!("Window", "device_pixel_ratio");
+        unsafe { qt_binding_window_device_pixel_ratio(self.ptr) }
+    }
+
+    /// If the window is visible
+    pub fn is_visible(&self) -> bool {
+        ffi_span!("Window", "is_visible");
+        unsafe { qt_binding_window_is_visible(self.ptr) }
+    }
+
+    /// If the window is visible on screen, and can be rendered into
+    pub fn is_exposed(&self) -> bool {
+        ffi_span!("Window", "is_exposed");
+        unsafe { qt_binding_window_is_exposed(self.ptr) }
+    }
+
+    /// Show the window
+    pub fn show(&mut self) {
+        ffi_span!("Window", "show");
+        unsafe { qt_binding_window_set_visible(self.ptr, true) }
+    }
+
+    /// Hide the window
+    pub fn hide(&mut self) {
+        ffi_span!("Window", "hide");
+        unsafe { qt_binding_window_set_visible(self.ptr, false) }
+    }
+
+    /// Save and restore the geometry and state of the window
+    ///
+    /// The position, size, and if the window is maximized or full screen, are restored from
+    /// `QSettings` immediately, so this should be called before showing the window. They are
+    /// saved every time the window is hidden or closed.
+    ///
+    /// Settings are keyed by the [`object_name`] of the window, and returns `false` if the
+    /// window has no object name. Calling this method more than once has no effect.
+    ///
+    /// [`object_name`]: ../object/trait.Object.html#method.object_name
+    ///
+    /// # Limitations
+    ///
+    /// Settings are stored with the organization and application names of the application,
+    /// that default to the name of the executable. Geometries are saved when the window is
+    /// hidden or closed, but not when the application exits while the window is still
+    /// visible, unless the window is hidden before.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use qt_binding::app::Application;
+    /// use qt_binding::object::Object;
+    /// use qt_binding::window::Window;
+    ///
+    /// let mut app = Application::new();
+    /// let mut window = Window::new();
+    /// window.set_object_name("viewport");
+    /// window.resize(800, 600).persist_geometry();
+    /// window.show();
+    /// app.exec();
+    /// window.hide();
+    /// ```
+    pub fn persist_geometry(&mut self) -> bool {
+        ffi_span!("Window", "persist_geometry");
+        unsafe { qt_binding_window_persist_geometry(self.ptr) }
+    }
+}
+
+impl Default for Window<'static> {
+    fn default() -> Self {
+        Window::new()
+    }
+}
+
+impl Object for Window<'_> {
+    fn as_object_ptr(&self) -> *mut c_void {
+        self.ptr
+    }
+}
+
+impl Drop for Window<'_> {
+    fn drop(&mut self) {
+        if self.owned {
+            ffi_span!("Window", "drop");
+            if app::should_leak("Window") {
+                return;
+            }
+            track_destroyed!(Window);
+            unsafe { qt_binding_window_delete(self.ptr) }
+        }
+    }
+}
+
+#[cfg(feature = "raw-window-handle")]
+mod raw {
+    use super::Window;
+    use raw_window_handle::{HasRawWindowHandle, RawWindowHandle};
+    use std::os::raw::{c_int, c_void};
+    use std::ptr;
+
+    // Same order as Platform in window.cpp
+    const XCB: c_int = 1;
+    const WAYLAND: c_int = 2;
+
+    unsafe impl HasRawWindowHandle for Window<'_> {
+        fn raw_window_handle(&self) -> RawWindowHandle {
+            ffi_span!("Window", "raw_window_handle");
+            let mut id = 0;
+            let mut display = ptr::null_mut();
+            let mut surface = ptr::null_mut();
+            let platform = unsafe {
+                qt_binding_window_native_handle(self.ptr, &mut id, &mut display, &mut surface)
+            };
+            handle(platform, id, display, surface)
+        }
+    }
+
+    #[cfg(any(
+        target_os = "linux",
+        target_os = "dragonfly",
+        target_os = "freebsd",
+        target_os = "netbsd",
+        target_os = "openbsd"
+    ))]
+    fn handle(
+        platform: c_int,
+        id: u64,
+        display: *mut c_void,
+        surface: *mut c_void,
+    ) -> RawWindowHandle {
+        use raw_window_handle::unix::{WaylandHandle, XlibHandle};
+
+        match platform {
+            WAYLAND => RawWindowHandle::Wayland(WaylandHandle {
+                surface,
+                display,
+                ..WaylandHandle::empty()
+            }),
+            XCB => RawWindowHandle::Xlib(XlibHandle {
+                window: id as _,
+                display,
+                ..XlibHandle::empty()
+            }),
+            _ => panic!("Unsupported Qt platform for raw window handles"),
+        }
+    }
+
+    #[cfg(target_os = "windows")]
+    fn handle(_: c_int, id: u64, _: *mut c_void, _: *mut c_void) -> RawWindowHandle {
+        use raw_window_handle::windows::WindowsHandle;
+
+        RawWindowHandle::Windows(WindowsHandle {
+            hwnd: id as usize as *mut c_void,
+            ..WindowsHandle::empty()
+        })
+    }
+
+    #[cfg(target_os = "macos")]
+    fn handle(_: c_int, id: u64, ns_window: *mut c_void, _: *mut c_void) -> RawWindowHandle {
+        use raw_window_handle::macos::MacOSHandle;
+
+        RawWindowHandle::MacOS(MacOSHandle {
+            ns_window,
+            ns_view: id as usize as *mut c_void,
+            ..MacOSHandle::empty()
+        })
+    }
+
+    extern "C" {
+        fn qt_binding_window_native_handle(
+            window: *mut c_void,
+            id: *mut u64,
+            display: *mut *mut c_void,
+            surface: *mut *mut c_void,
+        ) -> c_int;
+    }
+}
+
+#[cfg(feature = "qml")]
+extern "C" fn rs_window_append(output: *mut c_void, window: *mut c_void) {
+    let output = unsafe { &mut *(output as *mut Vec<Window>) };
+    output.push(Window {
+        ptr: window,
+        owned: false,
+        _owner: PhantomData,
+    });
+}
+
+#[cfg(feature = "qml")]
+type RsWindowAppendFunc = extern "C" fn(output: *mut c_void, window: *mut c_void);
+
+extern "C" {
+    fn qt_binding_window_create() -> *mut c_void;
+    fn qt_binding_window_delete(window: *mut c_void);
+    #[cfg(feature = "qml")]
+    fn qt_binding_window_children(
+        parent: *mut c_void,
+        output: *mut c_void,
+        append: RsWindowAppendFunc,
+    );
+    fn qt_binding_window_title(window: *const c_void, output: *mut c_void, fill: RsStringFillFunc);
+    fn qt_binding_window_set_title(window: *mut c_void, title: *const c_char, size: u32);
+    fn qt_binding_window_size(window: *const c_void, width: *mut u32, height: *mut u32);
+    fn qt_binding_window_resize(window: *mut c_void, width: u32, height: u32);
+    fn qt_binding_window_device_pixel_ratio(window: *const c_void) -> f64;
+    fn qt_binding_window_is_visible(window: *const c_void) -> bool;
+    fn qt_binding_window_set_visible(window: *mut c_void, visible: bool);
+    fn qt_binding_window_is_exposed(window: *const c_void) -> bool;
+    fn qt_binding_window_persist_geometry(window: *mut c_void) -> bool;
+}
@@ -2,13 +2,19 @@
 
 //! Support library for [`qt-sys`], [`qt-locate`] and [`qt-binding-build`]
 //!
-//! This support library provides [`QtInstall`], a way to describe a Qt installation.
+//! This support library provides [`QtInstall`], a way to describe a Qt installation, and
+//! [`QtTool`], a way to run the tools of a Qt installation.
 //!
 //! [`QtInstall`]: struct.QtInstall.html
+//! [`QtTool`]: struct.QtTool.html
 //! [`qt-sys`]: ../qt_sys/index.html
 //! [`qt-locate`]: ../qt_locate/index.html
 //! [`qt-binding-build`]: ../qt_binding_build/index.html
 
+mod tool;
+
+pub use self::tool::{QtTool, ToolError, ToolOutput};
+use std::env;
 use std::fmt;
 use std::path::{Path, PathBuf};
 
@@ -42,6 +48,8 @@ pub struct QtInstall {
     include_dir: PathBuf,
     moc: PathBuf,
     rcc: PathBuf,
+    namespace: Option<String>,
+    config: Vec<String>,
 }
 
 impl QtInstall {
@@ -64,9 +72,31 @@ impl QtInstall {
             include_dir,
             moc,
             rcc,
+            namespace: None,
+            config: Vec::new(),
         }
     }
 
+    /// Set the namespace Qt was built with
+    ///
+    /// See [`namespace`] for more information.
+    ///
+    /// [`namespace`]: #method.namespace
+    pub fn with_namespace(mut self, namespace: Option<String>) -> QtInstall {
+        self.namespace = namespace;
+        self
+    }
+
+    /// Set the configuration flags Qt was built with
+    ///
+    /// See [`config`] for more information.
+    ///
+    /// [`config`]: #method.config
+    pub fn with_config(mut self, config: Vec<String>) -> QtInstall {
+        self.config = config;
+        self
+    }
+
     /// Qt major version
     pub fn major_version(&self) -> &MajorVersion {
         &self.major_version
@@ -95,6 +125,69 @@ impl QtInstall {
         &self.include_dir
     }
 
+    /// Qt namespace
+    ///
+    /// Qt can be built with `-qtnamespace`, in which case all Qt classes are declared inside
+    /// a C++ namespace. Returns the namespace if Qt was built with one.
+    pub fn namespace(&self) -> Option<&str> {
+        self.namespace.as_deref()
+    }
+
+    /// Qt build configuration
+    ///
+    /// Returns the configuration flags and features Qt was built with, like `shared`, `static`,
+    /// `debug`, `release` or `ssl`. This list is empty if the configuration could not be read.
+    pub fn config(&self) -> &[String] {
+        &self.config
+    }
+
+    /// Check if Qt was built with a configuration flag or feature
+    pub fn has_config(&self, config: &str) -> bool {
+        self.config.iter().any(|c| c == config)
+    }
+
+    /// Check if Qt was built as static libraries
+    pub fn is_static(&self) -> bool {
+        self.has_config("static")
+    }
+
+    /// Check if Qt was built in debug mode
+    ///
+    /// Qt can be built in both debug and release mode.
+    pub fn is_debug(&self) -> bool {
+        self.has_config("debug") || self.has_config("debug_and_release")
+    }
+
+    /// Check if Qt was built in release mode
+    ///
+    /// Qt can be built in both debug and release mode.
+    pub fn is_release(&self) -> bool {
+        self.has_config("release") || self.has_config("debug_and_release")
+    }
+
+    /// Check if a Qt module is part of this installation
+    ///
+    /// Modules are named without the `Qt` prefix, like `Quick`. A module is available when its
+    /// shared or static library is found in the library directory.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use qt_install::QtInstall;
+    /// # fn check(qt_install: &QtInstall) {
+    /// if qt_install.has_module("Quick") {
+    ///     println!("cargo:rustc-cfg=with_quick");
+    /// }
+    /// # }
+    /// ```
+    pub fn has_module(&self, module: &str) -> bool {
+        let files = [
+            lib_file(module, &self.major_version),
+            static_lib_file(module, &self.major_version),
+        ];
+        files.iter().any(|file| self.lib_dir.join(file).exists())
+    }
+
     /// Path to `moc`
     pub fn moc(&self) -> &Path {
         &self.moc
@@ -104,8 +197,43 @@ impl QtInstall {
     pub fn rcc(&self) -> &Path {
         &self.rcc
     }
+
+    /// A tool from `bin`, like `uic`, `lrelease` or `qsb`
+    ///
+    /// The name is given without the platform executable suffix. Returns
+    /// [`ToolError::NotFound`] if the tool is not part of this installation.
+    ///
+    /// [`ToolError::NotFound`]: enum.ToolError.html#variant.NotFound
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use qt_install::{QtInstall, ToolError};
+    /// # fn generate(qt_install: &QtInstall) -> Result<(), ToolError> {
+    /// let output = qt_install
+    ///     .tool("qhelpgenerator")?
+    ///     .args(&["doc/app.qhcp", "-o", "app.qhc"])
+    ///     .run()?;
+    /// println!("{}", output.stdout);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn tool(&self, name: &str) -> Result<QtTool, ToolError> {
+        let path = self.bin_dir.join(format!("{}{}", name, EXE_SUFFIX));
+        if path.is_file() {
+            Ok(QtTool::new(path))
+        } else {
+            Err(ToolError::NotFound(path))
+        }
+    }
 }
 
+#[cfg(unix)]
+const EXE_SUFFIX: &str = "";
+
+#[cfg(windows)]
+const EXE_SUFFIX: &str = ".exe";
+
 #[cfg(unix)]
 const MOC_EXEC: &str = "moc";
 
@@ -160,7 +288,8 @@ pub fn lib_name(lib: &str, version: &MajorVersion) -> String {
 /// Platform-dependent Qt library file
 ///
 /// This function deduces the file name of a Qt library based
-/// on the module name.
+/// on the module name. Under Windows, the file name depends on the toolchain of the target,
+/// read from `CARGO_CFG_TARGET_ENV` when called from a build script.
 ///
 /// # Examples
 ///
@@ -173,8 +302,11 @@ pub fn lib_name(lib: &str, version: &MajorVersion) -> String {
 /// // Under Mac OS
 /// assert_eq!(lib_file("Core", &MajorVersion::Qt5), "QtCore.framework".to_string());
 ///
-/// // Under Windows
+/// // Under Windows, with MSVC
 /// assert_eq!(lib_file("Core", &MajorVersion::Qt5), "Qt5Core.lib".to_string());
+///
+/// // Under Windows, with MinGW
+/// assert_eq!(lib_file("Core", &MajorVersion::Qt5), "libQt5Core.a".to_string());
 /// ```
 pub fn lib_file(lib: &str, version: &MajorVersion) -> String {
     if cfg!(unix) {
@@ -184,15 +316,96 @@ pub fn lib_file(lib: &str, version: &MajorVersion) -> String {
             format!("lib{}.so", lib_name(lib, version))
         }
     } else if cfg!(windows) {
-        format!("{}.lib", lib_name(lib, version))
+        if is_mingw_target() {
+            format!("lib{}.a", lib_name(lib, version))
+        } else {
+            format!("{}.lib", lib_name(lib, version))
+        }
     } else {
         panic!("Unsupported OS");
     }
 }
 
+/// Platform-dependent static Qt library file
+///
+/// This function deduces the file name of a Qt library based on the module name, when Qt is
+/// built as static libraries. Under Windows, it is the same as [`lib_file`]. Under Mac OS,
+/// static libraries are not frameworks.
+///
+/// [`lib_file`]: fn.lib_file.html
+///
+/// # Examples
+///
+/// ```no_run
+/// use qt_install::{MajorVersion, static_lib_file};
+///
+/// // Under Linux and Mac OS
+/// assert_eq!(static_lib_file("Core", &MajorVersion::Qt5), "libQt5Core.a".to_string());
+///
+/// // Under Windows, with MSVC
+/// assert_eq!(static_lib_file("Core", &MajorVersion::Qt5), "Qt5Core.lib".to_string());
+///
+/// // Under Windows, with MinGW
+/// assert_eq!(static_lib_file("Core", &MajorVersion::Qt5), "libQt5Core.a".to_string());
+/// ```
+pub fn static_lib_file(lib: &str, version: &MajorVersion) -> String {
+    if cfg!(unix) {
+        format!("libQt{}{}.a", version_suffix(version), lib)
+    } else {
+        lib_file(lib, version)
+    }
+}
+
+/// Returns `true` if the target is built with MinGW
+///
+/// Build scripts run on the host, so the target is read from `CARGO_CFG_TARGET_ENV`, that Cargo
+/// sets for build scripts. The host is used outside of build scripts.
+fn is_mingw_target() -> bool {
+    env::var("CARGO_CFG_TARGET_ENV")
+        .map(|target_env| target_env == "gnu")
+        .unwrap_or(cfg!(target_env = "gnu"))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::{env, fs, process};
+
+    #[test]
+    fn test_tool_not_found() {
+        let qt_install = QtInstall::new(
+            MajorVersion::Qt5,
+            "5.15.2".to_string(),
+            PathBuf::from("bin"),
+            PathBuf::from("lib"),
+            PathBuf::from("include"),
+        );
+        match qt_install.tool("qsb") {
+            Err(ToolError::NotFound(path)) => assert!(path.starts_with("bin")),
+            _ => panic!("qsb should not be found"),
+        }
+    }
+
+    #[test]
+    fn test_has_module() {
+        let lib_dir = env::temp_dir().join(format!("qt-install-modules-{}", process::id()));
+        fs::create_dir_all(&lib_dir).unwrap();
+        fs::write(lib_dir.join(lib_file("Core", &MajorVersion::Qt5)), "").unwrap();
+
+        let qt_install = QtInstall::new(
+            MajorVersion::Qt5,
+            "5.15.2".to_string(),
+            PathBuf::from("bin"),
+            lib_dir.clone(),
+            PathBuf::from("include"),
+        );
+        assert!(qt_install.has_module("Core"));
+        assert!(!qt_install.has_module("Quick"));
+
+        fs::write(lib_dir.join(static_lib_file("Quick", &MajorVersion::Qt5)), "").unwrap();
+        assert!(qt_install.has_module("Quick"));
+        fs::remove_dir_all(&lib_dir).unwrap();
+    }
 
     #[cfg(unix)]
     mod unix {
@@ -210,6 +423,7 @@ mod tests {
             #[test]
             fn test_lib_file() {
                 assert_eq!(lib_file("Core", &MajorVersion::Qt5), "libQt5Core.so");
+                assert_eq!(static_lib_file("Core", &MajorVersion::Qt5), "libQt5Core.a");
             }
         }
         #[cfg(target_os = "macos")]
@@ -224,6 +438,7 @@ mod tests {
             #[test]
             fn test_lib_file() {
                 assert_eq!(lib_file("Core", &MajorVersion::Qt5), "QtCore.framework");
+                assert_eq!(static_lib_file("Core", &MajorVersion::Qt5), "libQt5Core.a");
             }
         }
     }
@@ -237,8 +452,15 @@ mod tests {
         }
 
         #[test]
+        #[cfg(target_env = "msvc")]
         fn test_lib_file() {
             assert_eq!(lib_file("Core", &MajorVersion::Qt5), "Qt5Core.lib");
         }
+
+        #[test]
+        #[cfg(target_env = "gnu")]
+        fn test_lib_file() {
+            assert_eq!(lib_file("Core", &MajorVersion::Qt5), "libQt5Core.a");
+        }
     }
 }
@@ -0,0 +1,214 @@
+use std::error::Error;
+use std::ffi::{OsStr, OsString};
+use std::fmt;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::{Command, ExitStatus};
+
+/// A Qt tool, like `uic`, `lrelease` or `qsb`
+///
+/// `QtTool` runs an executable of a Qt installation, capturing its output. It is usually
+/// obtained from [`QtInstall::tool`], so that the tool matches the Qt installation used to
+/// build.
+///
+/// [`QtInstall::tool`]: struct.QtInstall.html#method.tool
+///
+/// # Examples
+///
+/// ```no_run
+/// use qt_install::{QtInstall, ToolError};
+///
+/// fn compile_translations(qt_install: &QtInstall) -> Result<(), ToolError> {
+///     qt_install
+///         .tool("lrelease")?
+///         .arg("translations/app_fr.ts")
+///         .args(&["-qm", "app_fr.qm"])
+///         .run()?;
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct QtTool {
+    name: String,
+    path: PathBuf,
+    args: Vec<OsString>,
+    current_dir: Option<PathBuf>,
+}
+
+impl QtTool {
+    /// New instance, for the executable at a path
+    pub fn new<P>(path: P) -> QtTool
+    where
+        P: Into<PathBuf>,
+    {
+        let path = path.into();
+        let name = path
+            .file_stem()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_default();
+        QtTool {
+            name,
+            path,
+            args: Vec::new(),
+            current_dir: None,
+        }
+    }
+
+    /// Name of the tool, like `lrelease`
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Path to the executable
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Add an argument
+    pub fn arg<S>(&mut self, arg: S) -> &mut QtTool
+    where
+        S: AsRef<OsStr>,
+    {
+        self.args.push(arg.as_ref().to_os_string());
+        self
+    }
+
+    /// Add arguments
+    pub fn args<I, S>(&mut self, args: I) -> &mut QtTool
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<OsStr>,
+    {
+        self.args
+            .extend(args.into_iter().map(|arg| arg.as_ref().to_os_string()));
+        self
+    }
+
+    /// Set the working directory of the tool
+    ///
+    /// Defaults to the working directory of the current process.
+    pub fn current_dir<P>(&mut self, dir: P) -> &mut QtTool
+    where
+        P: AsRef<Path>,
+    {
+        self.current_dir = Some(dir.as_ref().to_path_buf());
+        self
+    }
+
+    /// Run the tool, and wait for it to finish
+    ///
+    /// Returns the output of the tool, or an error if the tool could not be started, or if it
+    /// exited with a failure status.
+    pub fn run(&self) -> Result<ToolOutput, ToolError> {
+        let mut command = Command::new(&self.path);
+        command.args(&self.args);
+        if let Some(dir) = &self.current_dir {
+            command.current_dir(dir);
+        }
+
+        let output = command.output().map_err(|err| ToolError::Io {
+            name: self.name.clone(),
+            error: err,
+        })?;
+        let stdout = String::from_utf8_lossy(&output.stdout).to_string();
+        let stderr = String::from_utf8_lossy(&output.stderr).to_string();
+
+        if output.status.success() {
+            Ok(ToolOutput { stdout, stderr })
+        } else {
+            Err(ToolError::Failed {
+                name: self.name.clone(),
+                status: output.status,
+                stderr,
+            })
+        }
+    }
+}
+
+/// Output of a Qt tool that succeeded
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ToolOutput {
+    /// Standard output
+    pub stdout: String,
+    /// Standard error, that usually contains warnings
+    pub stderr: String,
+}
+
+/// Error returned when running a Qt tool fails
+#[derive(Debug)]
+pub enum ToolError {
+    /// The tool is not part of the Qt installation
+    NotFound(PathBuf),
+    /// The tool could not be started
+    Io {
+        /// Name of the tool
+        name: String,
+        /// Error raised when starting the tool
+        error: io::Error,
+    },
+    /// The tool exited with a failure status
+    Failed {
+        /// Name of the tool
+        name: String,
+        /// Exit status of the tool
+        status: ExitStatus,
+        /// Standard error of the tool
+        stderr: String,
+    },
+}
+
+impl fmt::Display for ToolError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ToolError::NotFound(path) => write!(f, "Qt tool {} not found", path.display()),
+            ToolError::Io { name, error } => write!(f, "Failed to execute {}: {}", name, error),
+            ToolError::Failed {
+                name,
+                status,
+                stderr,
+            } => write!(f, "Failed to execute {} ({}).\n\n{}", name, status, stderr),
+        }
+    }
+}
+
+impl Error for ToolError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ToolError::Io { error, .. } => Some(error),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_name() {
+        let tool = QtTool::new(Path::new("bin").join("lrelease"));
+        assert_eq!(tool.name(), "lrelease");
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_run() {
+        let output = QtTool::new("/bin/sh")
+            .args(["-c", "echo hello"])
+            .run()
+            .unwrap();
+        assert_eq!(output.stdout, "hello\n");
+
+        let err = QtTool::new("/bin/sh")
+            .args(["-c", "echo error >&2; exit 2"])
+            .run()
+            .unwrap_err();
+        match err {
+            ToolError::Failed { name, stderr, .. } => {
+                assert_eq!(name, "sh");
+                assert_eq!(stderr, "error\n");
+            }
+            err => panic!("Unexpected error {}", err),
+        }
+    }
+}
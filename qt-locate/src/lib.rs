@@ -7,11 +7,14 @@
 //! [`qt-sys`]: ../qt_sys/index.html
 //! [`locate`]: fn.locate.html
 
+mod qconfig;
 mod qmake;
 
 pub use qt_install::{lib_file, MajorVersion, QtInstall};
 
+use self::qconfig::QConfig;
 use std::env;
+use std::fs;
 use std::path::{Path, PathBuf};
 
 #[cfg(unix)]
@@ -20,11 +23,39 @@ const QMAKE_EXEC: &str = "qmake";
 #[cfg(windows)]
 const QMAKE_EXEC: &str = "qmake.exe";
 
+/// Environment variables set by CMake builds, to use the Qt installation they located
+const CMAKE_VARS: &[&str] = &[
+    "QT_BINDING_FROM_CMAKE",
+    "QT_BINDING_QT_VERSION",
+    "QT_BINDING_QT_BIN_DIR",
+    "QT_BINDING_QT_LIB_DIR",
+    "QT_BINDING_QT_INCLUDE_DIR",
+    "QT_BINDING_QT_HOST_DATA_DIR",
+];
+
+/// Print `cargo:rerun-if-env-changed` for the environment variables used to locate Qt
+///
+/// Build scripts calling [`locate`] should call this function, so that they are run again when
+/// another Qt installation is selected.
+///
+/// [`locate`]: fn.locate.html
+pub fn rerun_if_env_changed() {
+    for var in ["QT_INSTALL_DIR", "QT_SELECT"].iter().chain(CMAKE_VARS) {
+        println!("cargo:rerun-if-env-changed={}", var);
+    }
+}
+
 /// Locate Qt installation
 ///
 /// This function will locate a Qt installation that contains the specified Qt modules.
 /// See [`qt-sys`] for more information about how Qt is located.
 ///
+/// When the `QT_BINDING_FROM_CMAKE` environment variable is set, `qmake` is not used: the Qt
+/// installation is described by the `QT_BINDING_QT_VERSION`, `QT_BINDING_QT_BIN_DIR`,
+/// `QT_BINDING_QT_LIB_DIR` and `QT_BINDING_QT_INCLUDE_DIR` environment variables, that are
+/// usually set by a CMake build from the Qt it already located. `QT_BINDING_QT_HOST_DATA_DIR`,
+/// the directory containing `mkspecs`, is optional, and is used to detect namespaced Qt.
+///
 /// [`qt-sys`]: ../qt_sys/index.html
 ///
 /// # Examples
@@ -38,7 +69,7 @@ const QMAKE_EXEC: &str = "qmake.exe";
 /// # Panics
 ///
 /// This function will panic with a user-friendly error message when `qmake` cannot be found
-/// or when `qmake` fails.
+/// or when `qmake` fails, or when the Qt installation described by a CMake build is incomplete.
 ///
 /// [`Error`]: errors/enum.Error.html
 pub fn locate(modules: &[&str]) -> QtInstall {
@@ -48,8 +79,11 @@ pub fn locate(modules: &[&str]) -> QtInstall {
 
 trait LocateSpi {
     fn qt_install_dir_env(&self) -> Option<String>;
+    fn cmake_env(&self, key: &str) -> Option<String>;
+    fn target_cfg(&self, key: &str) -> Option<String>;
     fn run_qmake_query(&self, qmake: &Path) -> Vec<u8>;
     fn exists(&self, path: &Path) -> bool;
+    fn read_to_string(&self, path: &Path) -> Option<String>;
 }
 
 struct LocatorSpi;
@@ -59,13 +93,26 @@ impl LocateSpi for LocatorSpi {
         env::var("QT_INSTALL_DIR").ok()
     }
 
+    fn cmake_env(&self, key: &str) -> Option<String> {
+        env::var(key).ok().filter(|value| !value.is_empty())
+    }
+
+    fn target_cfg(&self, key: &str) -> Option<String> {
+        // Build scripts run on the host, and Cargo describes the target with these variables
+        env::var(format!("CARGO_CFG_{}", key)).ok()
+    }
+
     fn run_qmake_query(&self, qmake: &Path) -> Vec<u8> {
-        qmake::query(&qmake)
+        qmake::query(qmake)
     }
 
     fn exists(&self, path: &Path) -> bool {
         path.exists()
     }
+
+    fn read_to_string(&self, path: &Path) -> Option<String> {
+        fs::read_to_string(path).ok()
+    }
 }
 
 struct Locator<Spi>
@@ -84,14 +131,64 @@ where
     }
 
     fn locate(&self, modules: &[&str]) -> QtInstall {
-        let qmake = self.qmake_path();
+        let (qt_infos, qt_install) = if self.spi.cmake_env("QT_BINDING_FROM_CMAKE").is_some() {
+            let qt_infos = self.cmake_infos();
+            let qt_install = Locator::<Spi>::from_qt_infos(&qt_infos).unwrap();
+            self.check_path(&qt_install.include_dir().join("QtCore"));
+            (qt_infos, qt_install)
+        } else {
+            let qmake = self.qmake_path();
+            let stdout = self.spi.run_qmake_query(&qmake);
+            let qt_infos = QtInfo::from_query(&stdout);
+            let qt_install = Locator::<Spi>::from_qt_infos(&qt_infos).unwrap_or_else(|| {
+                panic!(
+                    "Could not find Qt with `{}`. Check `qmake -query`'s output",
+                    qmake.to_string_lossy()
+                )
+            });
+            (qt_infos, qt_install)
+        };
+
+        self.check_toolchain(&qt_infos);
+        self.check_qt_install(&qt_install, modules);
+
+        let qconfig = self.read_qconfig(&qt_infos);
+        let namespace = qconfig.as_ref().and_then(QConfig::namespace);
+        let config = qconfig.as_ref().map(QConfig::config).unwrap_or_default();
+        qt_install.with_namespace(namespace).with_config(config)
+    }
 
-        let stdout = self.spi.run_qmake_query(&qmake);
-        let qt_infos = QtInfo::from_query(&stdout);
+    fn read_qconfig(&self, qt_infos: &[QtInfo]) -> Option<QConfig> {
+        let host_data_dir = qt_infos.iter().filter_map(QtInfo::host_data_dir).next()?;
+        let path = [host_data_dir, "mkspecs", "qconfig.pri"]
+            .iter()
+            .collect::<PathBuf>();
 
-        let qt_install = Locator::<Spi>::from_qt_infos(&qt_infos, &qmake);
-        self.check_qt_install(&qt_install, modules);
-        qt_install
+        let content = self.spi.read_to_string(&path)?;
+        Some(QConfig::from_pri(&content))
+    }
+
+    fn cmake_infos(&self) -> Vec<QtInfo> {
+        let var = |key: &str| {
+            self.spi.cmake_env(key).unwrap_or_else(|| {
+                panic!(
+                    "QT_BINDING_FROM_CMAKE is set, but {} is missing. \
+                     It should be set by the CMake build with the Qt it located.",
+                    key
+                )
+            })
+        };
+
+        let mut qt_infos = vec![
+            QtInfo::Version(var("QT_BINDING_QT_VERSION")),
+            QtInfo::BinDir(var("QT_BINDING_QT_BIN_DIR")),
+            QtInfo::LibDir(var("QT_BINDING_QT_LIB_DIR")),
+            QtInfo::IncludeDir(var("QT_BINDING_QT_INCLUDE_DIR")),
+        ];
+        if let Some(host_data_dir) = self.spi.cmake_env("QT_BINDING_QT_HOST_DATA_DIR") {
+            qt_infos.push(QtInfo::HostDataDir(host_data_dir));
+        }
+        qt_infos
     }
 
     fn qmake_path(&self) -> PathBuf {
@@ -117,7 +214,7 @@ where
         }
     }
 
-    fn from_qt_infos(qt_infos: &[QtInfo], qmake: &Path) -> QtInstall {
+    fn from_qt_infos(qt_infos: &[QtInfo]) -> Option<QtInstall> {
         let version = qt_infos.iter().filter_map(QtInfo::version).next();
         let bin_dir = qt_infos.iter().filter_map(QtInfo::bin_dir).next();
         let lib_dir = qt_infos.iter().filter_map(QtInfo::lib_dir).next();
@@ -132,18 +229,39 @@ where
                 panic!("Unsupported Qt version {}", version)
             };
 
-            QtInstall::new(
+            Some(QtInstall::new(
                 major_version,
                 version.to_string(),
                 PathBuf::from(bin_dir),
                 PathBuf::from(lib_dir),
                 PathBuf::from(include_dir),
-            )
+            ))
         } else {
-            panic!(
-                "Could not find Qt with `{}`. Check `qmake -query`'s output",
-                qmake.to_string_lossy()
-            )
+            None
+        }
+    }
+
+    fn check_toolchain(&self, qt_infos: &[QtInfo]) {
+        if self.spi.target_cfg("TARGET_OS").as_deref() != Some("windows") {
+            return;
+        }
+
+        let target_env = self.spi.target_cfg("TARGET_ENV");
+        if let Some(spec) = qt_infos.iter().filter_map(QtInfo::spec).next() {
+            if spec.starts_with("win32-g++") && target_env.as_deref() == Some("msvc") {
+                panic!(
+                    "Qt was built with MinGW ({}), but Rust targets MSVC. \
+                     Use a `windows-gnu` target, or a Qt built with MSVC.",
+                    spec
+                )
+            }
+            if spec.starts_with("win32-msvc") && target_env.as_deref() == Some("gnu") {
+                panic!(
+                    "Qt was built with MSVC ({}), but Rust targets MinGW. \
+                     Use a `windows-msvc` target, or a Qt built with MinGW.",
+                    spec
+                )
+            }
         }
     }
 
@@ -162,7 +280,7 @@ where
     }
 
     fn check_path(&self, path: &Path) {
-        if !self.spi.exists(&path) {
+        if !self.spi.exists(path) {
             panic!(
                 "Qt installation is incomplete. Missing {}",
                 path.to_string_lossy()
@@ -183,6 +301,8 @@ enum QtInfo {
     BinDir(String),
     LibDir(String),
     IncludeDir(String),
+    HostDataDir(String),
+    Spec(String),
 }
 
 impl QtInfo {
@@ -222,17 +342,27 @@ impl QtInfo {
         }
     }
 
+    fn host_data_dir(&self) -> Option<&str> {
+        match self {
+            QtInfo::HostDataDir(host_data_dir) => Some(host_data_dir),
+            _ => None,
+        }
+    }
+
+    fn spec(&self) -> Option<&str> {
+        match self {
+            QtInfo::Spec(spec) => Some(spec),
+            _ => None,
+        }
+    }
+
     fn read_prefixed_value(input: &str, prefix: &'static str) -> Option<String> {
-        if input.starts_with(prefix) {
-            let rest = &input[prefix.len()..];
+        let rest = input.strip_prefix(prefix)?;
 
-            if cfg!(windows) {
-                Some(rest.replace("/", "\\"))
-            } else {
-                Some(rest.to_string())
-            }
+        if cfg!(windows) {
+            Some(rest.replace('/', "\\"))
         } else {
-            None
+            Some(rest.to_string())
         }
     }
 
@@ -246,8 +376,10 @@ impl QtInfo {
         } else if let Some(include_dir) = QtInfo::read_prefixed_value(input, "QT_INSTALL_HEADERS:")
         {
             Some(QtInfo::IncludeDir(include_dir))
+        } else if let Some(host_data_dir) = QtInfo::read_prefixed_value(input, "QT_HOST_DATA:") {
+            Some(QtInfo::HostDataDir(host_data_dir))
         } else {
-            None
+            QtInfo::read_prefixed_value(input, "QMAKE_XSPEC:").map(QtInfo::Spec)
         }
     }
 }
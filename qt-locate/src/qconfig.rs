@@ -0,0 +1,90 @@
+/// Qt build configuration
+///
+/// Qt writes its build configuration in `mkspecs/qconfig.pri`, in the host data directory.
+/// This file is a qmake project include, made of `KEY = values`, `KEY += values` and
+/// `KEY -= values` lines.
+pub(crate) struct QConfig {
+    assignments: Vec<(String, Operator, String)>,
+}
+
+#[derive(Clone, Copy, Eq, PartialEq)]
+enum Operator {
+    Assign,
+    Add,
+    Remove,
+}
+
+impl QConfig {
+    pub(crate) fn from_pri(content: &str) -> Self {
+        let assignments = content
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.starts_with('#'))
+            .filter_map(|line| {
+                let mut parts = line.splitn(2, '=');
+                let key = parts.next()?.trim();
+                let value = parts.next()?.trim();
+                let (key, operator) = if let Some(key) = key.strip_suffix('+') {
+                    (key.trim(), Operator::Add)
+                } else if let Some(key) = key.strip_suffix('-') {
+                    (key.trim(), Operator::Remove)
+                } else {
+                    (key, Operator::Assign)
+                };
+                if key.is_empty() {
+                    None
+                } else {
+                    Some((key.to_string(), operator, value.to_string()))
+                }
+            })
+            .collect();
+        QConfig { assignments }
+    }
+
+    /// Value of the last `KEY = value` assignment
+    pub(crate) fn value(&self, key: &str) -> Option<&str> {
+        self.assignments
+            .iter()
+            .rev()
+            .find(|(k, operator, _)| k == key && *operator == Operator::Assign)
+            .map(|(_, _, value)| value.as_str())
+    }
+
+    /// Values of a list, after applying all assignments, additions and removals
+    pub(crate) fn values(&self, key: &str) -> Vec<String> {
+        let mut values = Vec::<String>::new();
+        for (_, operator, value) in self.assignments.iter().filter(|(k, _, _)| k == key) {
+            let items = value.split_whitespace().map(ToString::to_string);
+            match operator {
+                Operator::Assign => values = items.collect(),
+                Operator::Add => values.extend(items),
+                Operator::Remove => {
+                    let removed = items.collect::<Vec<_>>();
+                    values.retain(|value| !removed.contains(value));
+                }
+            }
+        }
+        values
+    }
+
+    pub(crate) fn namespace(&self) -> Option<String> {
+        self.value("QT_NAMESPACE")
+            .filter(|namespace| !namespace.is_empty())
+            .map(ToString::to_string)
+    }
+
+    /// Configuration flags and features Qt was built with
+    ///
+    /// Merges `CONFIG`, `QT_CONFIG` and the enabled global features, without duplicates.
+    pub(crate) fn config(&self) -> Vec<String> {
+        let mut config = Vec::new();
+        for key in &["CONFIG", "QT_CONFIG", "QT.global.enabled_features"] {
+            for value in self.values(key) {
+                if !config.contains(&value) {
+                    config.push(value);
+                }
+            }
+        }
+        config
+    }
+}
@@ -3,7 +3,7 @@ use std::process::Command;
 
 pub fn query(qmake_path: &Path) -> Vec<u8> {
     let command = Command::new(qmake_path)
-        .args(&["-query"])
+        .args(["-query"])
         .output()
         .unwrap_or_else(|err| panic!("Failed to run {}: {}", qmake_path.display(), err));
 
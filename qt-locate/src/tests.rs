@@ -4,7 +4,7 @@ mod unix;
 mod windows;
 
 use super::*;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::path::Path;
 use std::result;
 
@@ -16,6 +16,8 @@ where
     qt_install_dir: I,
     qmake_query: Q,
     missing: HashSet<&'static str>,
+    files: HashMap<&'static str, &'static str>,
+    env: HashMap<&'static str, &'static str>,
 }
 
 impl<I, Q> LocatorTestSpi<I, Q>
@@ -28,6 +30,8 @@ where
             qt_install_dir,
             qmake_query,
             missing: HashSet::new(),
+            files: HashMap::new(),
+            env: HashMap::new(),
         }
     }
 
@@ -35,6 +39,16 @@ where
         self.missing.insert(path);
         self
     }
+
+    fn add_file(mut self, path: &'static str, content: &'static str) -> Self {
+        self.files.insert(path, content);
+        self
+    }
+
+    fn add_env(mut self, key: &'static str, value: &'static str) -> Self {
+        self.env.insert(key, value);
+        self
+    }
 }
 
 impl<I, Q> LocateSpi for LocatorTestSpi<I, Q>
@@ -46,6 +60,15 @@ where
         (self.qt_install_dir)().map(ToString::to_string)
     }
 
+    fn cmake_env(&self, key: &str) -> Option<String> {
+        self.env.get(key).map(ToString::to_string)
+    }
+
+    fn target_cfg(&self, key: &str) -> Option<String> {
+        let key = format!("CARGO_CFG_{}", key);
+        self.env.get(key.as_str()).map(ToString::to_string)
+    }
+
     fn run_qmake_query(&self, qmake: &Path) -> Vec<u8> {
         let result = (self.qmake_query)(qmake);
         result.map(|stdout| stdout.as_bytes().to_vec()).unwrap()
@@ -57,6 +80,11 @@ where
         println!("Checking if {} exists: {}", path, exists);
         exists
     }
+
+    fn read_to_string(&self, path: &Path) -> Option<String> {
+        let path = path.to_string_lossy().to_string();
+        self.files.get(path.as_str()).map(ToString::to_string)
+    }
 }
 
 #[test]
@@ -71,6 +99,36 @@ fn test_read_prefixed_value() {
     );
 }
 
+#[test]
+fn test_read_qconfig_value() {
+    let qconfig = QConfig::from_pri(include_str!("tests/res/qconfig_namespace.pri"));
+
+    assert_eq!(qconfig.value("QT_ARCH"), Some("x86_64"));
+    assert_eq!(qconfig.value("QT_LIBINFIX"), Some(""));
+    assert_eq!(qconfig.value("QT_CONFIG"), None);
+    assert_eq!(qconfig.value("UNKNOWN"), None);
+}
+
+#[test]
+fn test_read_qconfig_values() {
+    let qconfig = QConfig::from_pri(
+        "QT_CONFIG = a b\nQT_CONFIG += c d\nQT_CONFIG -= b\nCONFIG += shared\nCONFIG += a",
+    );
+
+    assert_eq!(qconfig.values("QT_CONFIG"), vec!["a", "c", "d"]);
+    assert_eq!(qconfig.values("UNKNOWN"), Vec::<String>::new());
+    assert_eq!(qconfig.config(), vec!["shared", "a", "c", "d"]);
+}
+
+#[test]
+fn test_read_qconfig_namespace() {
+    let qconfig = QConfig::from_pri(include_str!("tests/res/qconfig_namespace.pri"));
+    assert_eq!(qconfig.namespace(), Some("MyQt".to_string()));
+
+    let qconfig = QConfig::from_pri(include_str!("tests/res/qconfig_no_namespace.pri"));
+    assert_eq!(qconfig.namespace(), None);
+}
+
 #[test]
 #[should_panic(expected = "Unsupported Qt version 4.8.7")]
 fn test_locate_fails_for_incorrect_qt_version() {
@@ -94,3 +152,45 @@ fn test_locate_fails_if_qmake_fails() {
     let locator = Locator::new(spi);
     locator.locate(&["Core"]);
 }
+
+#[test]
+#[should_panic(expected = "Qt was built with MinGW (win32-g++), but Rust targets MSVC")]
+fn test_locate_fails_for_mingw_qt_with_msvc() {
+    let spi = LocatorTestSpi::new(
+        || Some("/my/qt/install"),
+        |_| Ok(include_str!("tests/res/query_qt5_mingw_win.in")),
+    )
+    .add_env("CARGO_CFG_TARGET_OS", "windows")
+    .add_env("CARGO_CFG_TARGET_ENV", "msvc");
+
+    let locator = Locator::new(spi);
+    locator.locate(&["Core"]);
+}
+
+#[test]
+#[should_panic(expected = "Qt was built with MSVC (win32-msvc), but Rust targets MinGW")]
+fn test_locate_fails_for_msvc_qt_with_mingw() {
+    let spi = LocatorTestSpi::new(
+        || Some("/my/qt/install"),
+        |_| Ok(include_str!("tests/res/query_qt5_msvc_win.in")),
+    )
+    .add_env("CARGO_CFG_TARGET_OS", "windows")
+    .add_env("CARGO_CFG_TARGET_ENV", "gnu");
+
+    let locator = Locator::new(spi);
+    locator.locate(&["Core"]);
+}
+
+#[test]
+fn test_locate_checks_toolchain_of_windows_targets_only() {
+    let spi = LocatorTestSpi::new(
+        || Some("/my/qt/install"),
+        |_| Ok(include_str!("tests/res/query_qt5_mingw_win.in")),
+    )
+    .add_env("CARGO_CFG_TARGET_OS", "linux")
+    .add_env("CARGO_CFG_TARGET_ENV", "msvc");
+
+    let locator = Locator::new(spi);
+    let qt_install = locator.locate(&["Core"]);
+    assert_eq!(qt_install.version(), "5.123.456");
+}
@@ -0,0 +1,8 @@
+QT_ARCH = x86_64
+QT_BUILDABI = x86_64-little_endian-lp64
+QT.global.enabled_features = shared c++11
+QT_CONFIG += accessibility dbus
+CONFIG += shared release
+QT_LIBINFIX =
+QT_NAMESPACE = MyQt
+QT_VERSION = 5.11.1
@@ -105,3 +105,55 @@ fn test_locate_fails_if_rcc_is_not_present() {
     let locator = Locator::new(spi);
     locator.locate(&["Core"]);
 }
+
+#[test]
+fn test_locate_qt5_with_namespace() {
+    let spi = LocatorTestSpi::new(
+        || None, //
+        |_| Ok(include_str!("res/query_qt5_host_data.in")),
+    )
+    .add_file(
+        "/my/data/mkspecs/qconfig.pri",
+        include_str!("res/qconfig_namespace.pri"),
+    );
+
+    let locator = Locator::new(spi);
+    let qt_install = locator.locate(&["Core"]);
+
+    assert_eq!(qt_install.namespace(), Some("MyQt"));
+    assert!(!qt_install.is_static());
+    assert!(qt_install.is_release());
+    assert!(!qt_install.is_debug());
+    assert!(qt_install.has_config("dbus"));
+    assert!(!qt_install.has_config("ssl"));
+}
+
+#[test]
+fn test_locate_qt5_without_namespace() {
+    let spi = LocatorTestSpi::new(
+        || None, //
+        |_| Ok(include_str!("res/query_qt5_host_data.in")),
+    )
+    .add_file(
+        "/my/data/mkspecs/qconfig.pri",
+        include_str!("res/qconfig_no_namespace.pri"),
+    );
+
+    let locator = Locator::new(spi);
+    let qt_install = locator.locate(&["Core"]);
+
+    assert_eq!(qt_install.namespace(), None);
+}
+
+#[test]
+fn test_locate_qt5_without_qconfig() {
+    let spi = LocatorTestSpi::new(
+        || None, //
+        |_| Ok(include_str!("res/query_qt5_test.in")),
+    );
+
+    let locator = Locator::new(spi);
+    let qt_install = locator.locate(&["Core"]);
+
+    assert_eq!(qt_install.namespace(), None);
+}
@@ -34,3 +34,49 @@ fn test_locate_fails_if_qtcore_is_not_present() {
     let locator = Locator::new(spi);
     locator.locate(&["Core"]);
 }
+
+#[test]
+fn test_locate_from_cmake() {
+    let spi = LocatorTestSpi::new(|| None, |_| Err("qmake should not be run".to_string()))
+        .add_env("QT_BINDING_FROM_CMAKE", "1")
+        .add_env("QT_BINDING_QT_VERSION", "5.15.2")
+        .add_env("QT_BINDING_QT_BIN_DIR", "/opt/qt/bin")
+        .add_env("QT_BINDING_QT_LIB_DIR", "/opt/qt/lib")
+        .add_env("QT_BINDING_QT_INCLUDE_DIR", "/opt/qt/include");
+
+    let locator = Locator::new(spi);
+    let qt_install = locator.locate(&["Core"]);
+
+    assert_eq!(qt_install.version(), "5.15.2");
+    assert_eq!(qt_install.lib_dir(), Path::new("/opt/qt/lib"));
+    assert_eq!(qt_install.moc(), Path::new("/opt/qt/bin/moc"));
+    assert_eq!(qt_install.namespace(), None);
+}
+
+#[test]
+#[should_panic(expected = "QT_BINDING_FROM_CMAKE is set, but QT_BINDING_QT_LIB_DIR is missing")]
+fn test_locate_from_cmake_fails_if_a_path_is_missing() {
+    let spi = LocatorTestSpi::new(|| None, |_| Err("qmake should not be run".to_string()))
+        .add_env("QT_BINDING_FROM_CMAKE", "1")
+        .add_env("QT_BINDING_QT_VERSION", "5.15.2")
+        .add_env("QT_BINDING_QT_BIN_DIR", "/opt/qt/bin")
+        .add_env("QT_BINDING_QT_INCLUDE_DIR", "/opt/qt/include");
+
+    let locator = Locator::new(spi);
+    locator.locate(&["Core"]);
+}
+
+#[test]
+#[should_panic(expected = "Qt installation is incomplete. Missing /opt/qt/include/QtCore")]
+fn test_locate_from_cmake_fails_if_headers_are_not_present() {
+    let spi = LocatorTestSpi::new(|| None, |_| Err("qmake should not be run".to_string()))
+        .add_env("QT_BINDING_FROM_CMAKE", "1")
+        .add_env("QT_BINDING_QT_VERSION", "5.15.2")
+        .add_env("QT_BINDING_QT_BIN_DIR", "/opt/qt/bin")
+        .add_env("QT_BINDING_QT_LIB_DIR", "/opt/qt/lib")
+        .add_env("QT_BINDING_QT_INCLUDE_DIR", "/opt/qt/include")
+        .add_missing("/opt/qt/include/QtCore");
+
+    let locator = Locator::new(spi);
+    locator.locate(&["Core"]);
+}
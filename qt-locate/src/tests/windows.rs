@@ -118,6 +118,7 @@ fn test_locate_fails_if_rcc_is_not_present() {
 }
 
 #[test]
+#[cfg(target_env = "msvc")]
 #[should_panic(expected = "Qt installation is incomplete. Missing c:\\my\\lib\\Qt5Core.lib")]
 fn test_locate_fails_if_qtcore_is_not_present() {
     let spi = LocatorTestSpi::new(
@@ -129,3 +130,34 @@ fn test_locate_fails_if_qtcore_is_not_present() {
     let locator = Locator::new(spi);
     locator.locate(&["Core"]);
 }
+
+#[test]
+#[cfg(target_env = "gnu")]
+#[should_panic(expected = "Qt installation is incomplete. Missing c:\\my\\lib\\libQt5Core.a")]
+fn test_locate_fails_if_qtcore_is_not_present() {
+    let spi = LocatorTestSpi::new(
+        || Some("c:\\my\\qt\\install"),
+        |_| Ok(include_str!("res/query_qt5_test_win.in")),
+    )
+    .add_missing("c:\\my\\lib\\libQt5Core.a");
+
+    let locator = Locator::new(spi);
+    locator.locate(&["Core"]);
+}
+
+#[test]
+fn test_locate_qt5_with_namespace() {
+    let spi = LocatorTestSpi::new(
+        || Some("c:\\my\\qt\\install"),
+        |_| Ok(include_str!("res/query_qt5_host_data_win.in")),
+    )
+    .add_file(
+        "c:\\my\\data\\mkspecs\\qconfig.pri",
+        include_str!("res/qconfig_namespace.pri"),
+    );
+
+    let locator = Locator::new(spi);
+    let qt_install = locator.locate(&["Core"]);
+
+    assert_eq!(qt_install.namespace(), Some("MyQt"));
+}
@@ -1,6 +1,13 @@
-use qt_locate::locate;
+use qt_locate::{locate, rerun_if_env_changed};
 
 fn main() {
+    rerun_if_env_changed();
+
+    // Bindings are implemented in Rust by the mock backend of qt-binding
+    if cfg!(feature = "mock") {
+        return;
+    }
+
     let modules = modules();
     let qt_install = locate(&modules);
 
@@ -14,20 +21,57 @@ fn main() {
     println!("cargo:QT_BIN_DIR={}", bin_dir_str);
     println!("cargo:QT_LIB_DIR={}", lib_dir_str);
     println!("cargo:QT_INCLUDE_DIR={}", include_dir_str);
+    if let Some(namespace) = qt_install.namespace() {
+        println!("cargo:QT_NAMESPACE={}", namespace);
+    }
+    println!("cargo:QT_CONFIG={}", qt_install.config().join(" "));
 }
 
 fn modules() -> Vec<&'static str> {
     let mut modules = Vec::new();
     modules.push("Core");
+    if cfg!(feature = "bluetooth") {
+        modules.push("Bluetooth");
+    }
+    if cfg!(feature = "dbus") {
+        modules.push("DBus");
+    }
     if cfg!(feature = "gui") {
         modules.push("Gui");
     }
+    if cfg!(feature = "multimedia") {
+        modules.push("Multimedia");
+    }
+    if cfg!(feature = "network") {
+        modules.push("Network");
+    }
+    if cfg!(feature = "pdf") {
+        modules.push("Pdf");
+    }
+    if cfg!(feature = "positioning") {
+        modules.push("Positioning");
+    }
+    if cfg!(feature = "printsupport") {
+        modules.push("PrintSupport");
+    }
     if cfg!(feature = "qml") {
         modules.push("Qml");
     }
     if cfg!(feature = "quick") {
         modules.push("Quick");
     }
+    if cfg!(feature = "quick-test") {
+        modules.push("QuickTest");
+    }
+    if cfg!(feature = "serialport") {
+        modules.push("SerialPort");
+    }
+    if cfg!(feature = "sql") {
+        modules.push("Sql");
+    }
+    if cfg!(feature = "websockets") {
+        modules.push("WebSockets");
+    }
     if cfg!(feature = "widgets") {
         modules.push("Widgets");
     }
@@ -18,9 +18,21 @@
 //! By default `qt-sys` will only expose `QtCore`. To link against additional modules, you need
 //! to use features:
 //!
+//! - `bluetooth` enables linking against `QtBluetooth`
+//! - `dbus` enables linking against `QtDBus`
 //! - `gui` enables linking against `QtGui`
+//! - `mock` disables locating Qt, for the mock backend of `qt-binding`
+//! - `multimedia` enables linking against `QtMultimedia`
+//! - `network` enables linking against `QtNetwork`
+//! - `pdf` enables linking against `QtPdf`
+//! - `positioning` enables linking against `QtPositioning`
+//! - `printsupport` enables linking against `QtPrintSupport` and `QtWidgets`
 //! - `qml` enables linking against `QtQml`
 //! - `quick` enables linking against `QtQuick`
+//! - `quick-test` enables linking against `QtQuickTest`
+//! - `serialport` enables linking against `QtSerialPort`
+//! - `sql` enables linking against `QtSql`
+//! - `websockets` enables linking against `QtWebSockets`
 //! - `widgets` enables linking against `QtWidgets`
 //!
 //! # Locating Qt
@@ -43,11 +55,29 @@
 //! finding `qmake` automatically. You must use the `QT_INSTALL_DIR` environment variable described
 //! below.
 //!
+//! Both MSVC and MinGW builds of Qt are supported under Windows. The Rust target must use the
+//! same toolchain as Qt: `windows-msvc` targets for MSVC builds, and `windows-gnu` targets for
+//! MinGW builds. When using MinGW, the MinGW compiler shipped with Qt should be in `PATH`.
+//!
 //! # Overriding Qt location
 //!
 //! You can override Qt location with `QT_INSTALL_DIR` environment variable. If this variable is
 //! present, this function will *only* search `qmake` in `${QT_INSTALL_DIR}/bin`.
 //!
+//! # Using Qt located by CMake
+//!
+//! In mixed CMake and Cargo builds, for example with corrosion, CMake already located Qt, and
+//! `qmake` might select another installation. When `QT_BINDING_FROM_CMAKE` is set, `qmake` is
+//! not used, and Qt is described by environment variables set by the CMake build:
+//!
+//! - `QT_BINDING_QT_VERSION`, like `5.15.2`,
+//! - `QT_BINDING_QT_BIN_DIR`, containing `moc` and `rcc`,
+//! - `QT_BINDING_QT_LIB_DIR`, containing Qt libraries,
+//! - `QT_BINDING_QT_INCLUDE_DIR`, containing `QtCore` and the headers of the other modules,
+//! - `QT_BINDING_QT_HOST_DATA_DIR`, containing `mkspecs`, optional, to detect namespaced Qt.
+//!
+//! The layout of the provided directories is validated like for Qt located by `qmake`.
+//!
 //!
 //! # Limitations
 //!
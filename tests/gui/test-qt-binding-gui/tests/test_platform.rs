@@ -0,0 +1,16 @@
+use qt_binding::app::{Application, Platform};
+use std::env;
+
+#[test]
+fn platform_is_passed_as_argument() {
+    // CI selects the offscreen platform with QT_QPA_PLATFORM
+    let platform = env::var("QT_QPA_PLATFORM").ok();
+    let resource_name = env::var("RESOURCE_NAME").ok();
+    Application::set_platform(Platform::Custom("minimal".to_string()));
+    Application::set_x11_wm_class("test-platform");
+
+    let _app = Application::new();
+    assert_eq!(Application::platform_name(), "minimal");
+    assert_eq!(env::var("QT_QPA_PLATFORM").ok(), platform);
+    assert_eq!(env::var("RESOURCE_NAME").ok(), resource_name);
+}
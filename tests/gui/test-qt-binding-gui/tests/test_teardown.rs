@@ -0,0 +1,35 @@
+use qt_binding::app::Application;
+use qt_binding::diagnostics::{counter, Kind};
+use qt_binding::file::TemporaryFile;
+use qt_binding::model::StringListModel;
+use qt_binding::variant::Variant;
+
+#[test]
+fn objects_can_outlive_app() {
+    assert!(!Application::is_alive());
+
+    let app = Application::new();
+    assert!(Application::is_alive());
+
+    let variant = Variant::from(123);
+    let model = StringListModel::new();
+    let handle = Variant::from_object(&model).try_to_object().unwrap();
+    let file = TemporaryFile::new().unwrap();
+    let path = file.path();
+    drop(app);
+    assert!(!Application::is_alive());
+
+    // Objects dropped after the application are leaked instead of crashing
+    let live = counter(Kind::Variant).live();
+    drop(handle);
+    drop(model);
+    drop(variant);
+    assert_eq!(counter(Kind::Variant).live(), live);
+    assert_eq!(counter(Kind::StringListModel).live(), 1);
+    assert_eq!(counter(Kind::ObjectHandle).live(), 1);
+
+    // Files do not depend on the application, and are still removed
+    drop(file);
+    assert!(!path.exists());
+    assert_eq!(counter(Kind::TemporaryFile).live(), 0);
+}
@@ -0,0 +1,24 @@
+use qt_binding::sql::{Database, Query};
+use qt_binding::variant::Variant;
+
+/// Open an in-memory SQLite database, with a `books` table
+pub fn books_database(name: &str) -> Database {
+    let mut database = Database::add("QSQLITE", name);
+    database.set_database_name(":memory:");
+    database.open().unwrap();
+
+    let mut query = Query::new(&database);
+    query
+        .exec_sql("CREATE TABLE books (id INTEGER PRIMARY KEY, title TEXT, year INTEGER)")
+        .unwrap();
+    query
+        .prepare("INSERT INTO books (title, year) VALUES (?, ?)")
+        .unwrap();
+    for (title, year) in &[("Dune", 1965), ("Hyperion", 1989), ("Anathem", 2008)] {
+        query
+            .add_bind(&Variant::from(*title))
+            .add_bind(&Variant::from(*year));
+        query.exec().unwrap();
+    }
+    database
+}
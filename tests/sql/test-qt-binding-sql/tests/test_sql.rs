@@ -0,0 +1,104 @@
+use qt_binding::app::Application;
+use qt_binding::sql::{Database, Query, QueryModel};
+use qt_binding::variant::Variant;
+use std::convert::TryFrom;
+use test_qt_binding_sql::books_database;
+
+#[test]
+fn sqlite_in_memory() {
+    // Database drivers are plugins, that are loaded by the application
+    let app = Application::new();
+    assert!(Database::drivers().iter().any(|driver| driver == "QSQLITE"));
+
+    database(books_database("database"));
+    query(books_database("query"));
+    query_model(books_database("query_model"));
+    drop(app);
+}
+
+fn database(mut database: Database) {
+    assert!(database.is_open());
+    assert!(Database::get("database").is_some());
+    assert!(Database::get("missing").is_none());
+
+    let mut query = Query::new(&database);
+    database.transaction().unwrap();
+    query.exec_sql("DELETE FROM books").unwrap();
+    assert_eq!(query.rows_affected(), Some(3));
+    database.rollback().unwrap();
+
+    query.exec_sql("SELECT COUNT(*) FROM books").unwrap();
+    assert!(query.next_row());
+    assert_eq!(i64::try_from(&query.value(0)), Ok(3));
+
+    database.close();
+    assert!(!database.is_open());
+}
+
+fn query(database: Database) {
+    let mut query = Query::new(&database);
+    query
+        .prepare("SELECT title, year FROM books WHERE year > :year ORDER BY year")
+        .unwrap();
+    query.bind(":year", &Variant::from(1970));
+    query.exec().unwrap();
+    assert_eq!(query.column_count(), 2);
+
+    let mut books = Vec::new();
+    while query.next_row() {
+        let title = String::try_from(query.value(0)).unwrap();
+        let year = i32::try_from(query.value_by_name("year")).unwrap();
+        books.push((title, year));
+    }
+    assert_eq!(
+        books,
+        vec![
+            ("Hyperion".to_string(), 1989),
+            ("Anathem".to_string(), 2008)
+        ]
+    );
+    assert_eq!(query.value(0), Variant::default());
+
+    query
+        .prepare("INSERT INTO books (title, year) VALUES (?, ?)")
+        .unwrap();
+    query
+        .add_bind(&Variant::from("Solaris"))
+        .add_bind(&Variant::from(1961));
+    query.exec().unwrap();
+    assert_eq!(query.rows_affected(), Some(1));
+    assert_eq!(i64::try_from(query.last_insert_id()), Ok(4));
+
+    let error = query.exec_sql("SELECT * FROM missing").unwrap_err();
+    assert!(!error.message().is_empty());
+}
+
+fn query_model(database: Database) {
+    let mut model = QueryModel::new();
+    assert!(model.is_empty());
+
+    model
+        .set_sql("SELECT title, year FROM books ORDER BY id", &database)
+        .unwrap();
+    assert_eq!(model.len(), 3);
+    assert_eq!(model.column_count(), 2);
+    assert_eq!(model.get(0, 0), Variant::from("Dune"));
+    assert_eq!(i32::try_from(model.get(2, 1)), Ok(2008));
+    assert_eq!(model.get(3, 0), Variant::default());
+    assert_ne!(Variant::from(&model), Variant::default());
+
+    let mut query = Query::new(&database);
+    query
+        .prepare("SELECT title FROM books WHERE year < ?")
+        .unwrap();
+    query.add_bind(&Variant::from(1980));
+    query.exec().unwrap();
+    model.set_query(&query).unwrap();
+    assert_eq!(model.len(), 1);
+    assert_eq!(model.get(0, 0), Variant::from("Dune"));
+
+    assert!(model.set_sql("SELECT * FROM missing", &database).is_err());
+
+    model.clear();
+    assert!(model.is_empty());
+}
@@ -0,0 +1,173 @@
+use qt_binding::app::Application;
+use qt_binding::undo::{Command, UndoStack};
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+
+/// Counts how many actions were dropped
+struct Tracked(Rc<Cell<usize>>);
+
+impl Drop for Tracked {
+    fn drop(&mut self) {
+        self.0.set(self.0.get() + 1);
+    }
+}
+
+#[derive(Default)]
+struct Document {
+    text: Rc<RefCell<String>>,
+    dropped: Rc<Cell<usize>>,
+}
+
+impl Document {
+    fn text(&self) -> String {
+        self.text.borrow().clone()
+    }
+
+    /// Command setting the text, and restoring the previous text when undone
+    fn set(&self, text: &str) -> Command {
+        let previous = self.text();
+        let (redo_text, undo_text) = (self.text.clone(), self.text.clone());
+        let (redo_tracked, undo_tracked) =
+            (Tracked(self.dropped.clone()), Tracked(self.dropped.clone()));
+        let text = text.to_string();
+        Command::new(
+            format!("Set {}", text),
+            move || {
+                let _ = &redo_tracked;
+                *redo_text.borrow_mut() = text.clone();
+            },
+            move || {
+                let _ = &undo_tracked;
+                *undo_text.borrow_mut() = previous.clone();
+            },
+        )
+    }
+
+    /// Command appending a character, and removing it when undone
+    fn append(&self, c: char) -> Command {
+        let (redo_text, undo_text) = (self.text.clone(), self.text.clone());
+        Command::new(
+            format!("Append {}", c),
+            move || redo_text.borrow_mut().push(c),
+            move || {
+                undo_text.borrow_mut().pop();
+            },
+        )
+    }
+}
+
+#[test]
+fn undo_stack() {
+    let app = Application::new();
+
+    undo_and_redo();
+    merge();
+    macros();
+    discard();
+    drop(app);
+}
+
+fn undo_and_redo() {
+    let document = Document::default();
+    let mut stack = UndoStack::new();
+    assert!(stack.is_empty());
+    assert!(!stack.can_undo());
+
+    stack.push(document.set("a"));
+    stack.push(document.set("b"));
+    assert_eq!(document.text(), "b");
+    assert_eq!(stack.len(), 2);
+    assert_eq!(stack.index(), 2);
+    assert_eq!(stack.undo_text(), "Set b");
+    stack.set_clean();
+
+    stack.undo();
+    assert_eq!(document.text(), "a");
+    assert!(stack.can_redo());
+    assert_eq!(stack.redo_text(), "Set b");
+    assert!(!stack.is_clean());
+
+    stack.undo();
+    assert_eq!(document.text(), "");
+    assert!(!stack.can_undo());
+
+    stack.redo();
+    stack.redo();
+    assert_eq!(document.text(), "b");
+    assert!(stack.is_clean());
+
+    drop(stack);
+    assert_eq!(document.dropped.get(), 4);
+}
+
+fn merge() {
+    let document = Document::default();
+    let mut stack = UndoStack::new();
+
+    for text in &["h", "he", "hey"] {
+        let mut command = document.set(text);
+        command.merge_id(1);
+        stack.push(command);
+    }
+    assert_eq!(document.text(), "hey");
+    assert_eq!(stack.len(), 1);
+    assert_eq!(stack.undo_text(), "Set hey");
+
+    // Merged commands are dropped, and the actions they do not keep with them
+    assert_eq!(document.dropped.get(), 4);
+
+    stack.undo();
+    assert_eq!(document.text(), "");
+    stack.redo();
+    assert_eq!(document.text(), "hey");
+
+    let mut command = document.set("hey you");
+    command.merge_id(2);
+    stack.push(command);
+    assert_eq!(stack.len(), 2);
+
+    drop(stack);
+    assert_eq!(document.dropped.get(), 8);
+}
+
+fn macros() {
+    let document = Document::default();
+    let mut stack = UndoStack::new();
+
+    stack.begin_macro("Type");
+    stack.push(document.append('o'));
+    stack.push(document.append('k'));
+    stack.end_macro();
+    assert_eq!(document.text(), "ok");
+    assert_eq!(stack.len(), 1);
+    assert_eq!(stack.undo_text(), "Type");
+
+    stack.undo();
+    assert_eq!(document.text(), "");
+    stack.redo();
+    assert_eq!(document.text(), "ok");
+}
+
+fn discard() {
+    let document = Document::default();
+    let mut stack = UndoStack::new();
+    stack.set_undo_limit(2);
+
+    stack.push(document.set("a"));
+    stack.push(document.set("b"));
+    stack.push(document.set("c"));
+    assert_eq!(stack.len(), 2);
+    assert_eq!(document.dropped.get(), 2);
+
+    // Pushing a command discards the undone commands
+    stack.undo();
+    stack.push(document.set("d"));
+    assert_eq!(stack.len(), 2);
+    assert_eq!(document.dropped.get(), 4);
+    assert_eq!(document.text(), "d");
+
+    stack.clear();
+    assert!(stack.is_empty());
+    assert_eq!(document.text(), "d");
+    assert_eq!(document.dropped.get(), 8);
+}